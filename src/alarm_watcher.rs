@@ -0,0 +1,175 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Periodically performs alarm and quorum criticality health checks and reports
+//! state transitions (alarm raised, alarm cleared), see `Client#watch_alarms`.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::time::Duration;
+
+use crate::responses::HealthCheckFailureDetails;
+
+/// The default interval between two rounds of health checks.
+pub const DEFAULT_ALARM_WATCHER_INTERVAL: Duration = Duration::from_secs(10);
+
+/// A health check performed by the alarm watcher.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WatchedCheck {
+    /// See `Client#health_check_cluster_wide_alarms`
+    ClusterWideAlarms,
+    /// See `Client#health_check_local_alarms`
+    LocalAlarms,
+    /// See `Client#health_check_if_node_is_quorum_critical`
+    NodeIsQuorumCritical,
+}
+
+impl fmt::Display for WatchedCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WatchedCheck::ClusterWideAlarms => write!(f, "cluster-wide alarms"),
+            WatchedCheck::LocalAlarms => write!(f, "local alarms"),
+            WatchedCheck::NodeIsQuorumCritical => write!(f, "node is quorum critical"),
+        }
+    }
+}
+
+/// A state transition of a watched health check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AlarmEvent {
+    /// The check started failing
+    Raised {
+        check: WatchedCheck,
+        details: HealthCheckFailureDetails,
+    },
+    /// The check passes again
+    Cleared { check: WatchedCheck },
+    /// The check could not be performed, e.g. because the node is unreachable
+    /// or responded with an unexpected error. The state of the check is unchanged
+    /// and it will be performed again in the next round.
+    CheckFailed { check: WatchedCheck, error: String },
+}
+
+impl AlarmEvent {
+    pub fn check(&self) -> WatchedCheck {
+        match self {
+            AlarmEvent::Raised { check, .. } => *check,
+            AlarmEvent::Cleared { check } => *check,
+            AlarmEvent::CheckFailed { check, .. } => *check,
+        }
+    }
+}
+
+/// Alarm watcher configuration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlarmWatcherSettings {
+    /// How long to wait between two rounds of health checks
+    pub interval: Duration,
+    /// How many consecutive observations of a new state are required
+    /// before a transition is reported. 1 reports every transition immediately.
+    pub debounce: u32,
+    /// The health checks to perform
+    pub checks: Vec<WatchedCheck>,
+}
+
+impl AlarmWatcherSettings {
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_debounce(mut self, debounce: u32) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    pub fn with_checks(mut self, checks: Vec<WatchedCheck>) -> Self {
+        self.checks = checks;
+        self
+    }
+}
+
+impl Default for AlarmWatcherSettings {
+    fn default() -> Self {
+        Self {
+            interval: DEFAULT_ALARM_WATCHER_INTERVAL,
+            debounce: 1,
+            checks: vec![
+                WatchedCheck::ClusterWideAlarms,
+                WatchedCheck::NodeIsQuorumCritical,
+            ],
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct CheckState {
+    raised: bool,
+    // consecutive observations that differ from the current state
+    observations_of_change: u32,
+}
+
+/// The state machine behind the alarm watcher: turns a series of health check
+/// observations into debounced [`AlarmEvent`]s.
+///
+/// All checks are assumed to pass initially, so a check that fails from the very first
+/// observation is reported as raised.
+#[derive(Debug)]
+pub struct AlarmStateTracker {
+    debounce: u32,
+    states: HashMap<WatchedCheck, CheckState>,
+}
+
+impl AlarmStateTracker {
+    pub fn new(debounce: u32) -> Self {
+        Self {
+            debounce: debounce.max(1),
+            states: HashMap::new(),
+        }
+    }
+
+    /// Records the outcome of a health check: `None` if it passed,
+    /// failure details if it failed. Returns an event if this observation
+    /// completes a state transition.
+    pub fn observe(
+        &mut self,
+        check: WatchedCheck,
+        failure: Option<HealthCheckFailureDetails>,
+    ) -> Option<AlarmEvent> {
+        let state = self.states.entry(check).or_default();
+        if failure.is_some() == state.raised {
+            state.observations_of_change = 0;
+            return None;
+        }
+
+        state.observations_of_change += 1;
+        if state.observations_of_change < self.debounce {
+            return None;
+        }
+
+        state.observations_of_change = 0;
+        state.raised = !state.raised;
+        match failure {
+            Some(details) => Some(AlarmEvent::Raised { check, details }),
+            None => Some(AlarmEvent::Cleared { check }),
+        }
+    }
+
+    /// Returns true if the check is currently considered to be failing.
+    pub fn is_raised(&self, check: WatchedCheck) -> bool {
+        self.states.get(&check).is_some_and(|s| s.raised)
+    }
+}
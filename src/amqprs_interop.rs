@@ -0,0 +1,265 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conversions between the types of this library and those of [`amqprs`](https://docs.rs/amqprs),
+//! so that a topology declared over the HTTP API matches what an AMQP 0-9-1 client declares,
+//! and vice versa.
+//!
+//! AMQP 0-9-1 short strings, including field table keys and most message properties,
+//! cannot be longer than 255 bytes. Keys and values that exceed this limit are skipped.
+
+use amqprs::channel::{ExchangeDeclareArguments, QueueDeclareArguments};
+use amqprs::{BasicProperties, FieldArray, FieldName, FieldTable, FieldValue, LongStr};
+use serde_json::{Map, Number, Value};
+
+use crate::commons::ExchangeType;
+use crate::requests::{ExchangeParams, QueueParams, XArguments};
+use crate::responses::{MessageProperties, QueueInfo};
+
+const SHORT_STRING_MAX_LENGTH: usize = u8::MAX as usize;
+
+/// Converts a JSON value to an AMQP 0-9-1 field value.
+///
+/// Integers are converted to signed 64-bit integers (or doubles, if they do not fit),
+/// objects to field tables, `null` to a void value.
+pub fn field_value_from_json(value: &Value) -> FieldValue {
+    match value {
+        Value::Null => FieldValue::V,
+        Value::Bool(b) => FieldValue::from(*b),
+        Value::Number(n) => match n.as_i64() {
+            Some(i) => FieldValue::l(i),
+            None => FieldValue::d(n.as_f64().unwrap_or_default()),
+        },
+        Value::String(s) => match LongStr::try_from(s.as_str()) {
+            Ok(s) => FieldValue::S(s),
+            Err(_) => FieldValue::V,
+        },
+        Value::Array(xs) => {
+            let values: Vec<FieldValue> = xs.iter().map(field_value_from_json).collect();
+            FieldArray::try_from(values)
+                .map(FieldValue::A)
+                .unwrap_or(FieldValue::V)
+        }
+        Value::Object(m) => FieldValue::F(field_table_from_json(m)),
+    }
+}
+
+/// Converts an AMQP 0-9-1 field value to a JSON value.
+///
+/// Decimals are converted to their string representation, byte arrays
+/// to arrays of numbers.
+pub fn json_from_field_value(value: &FieldValue) -> Value {
+    match value {
+        FieldValue::t(b) => Value::Bool(*b),
+        FieldValue::b(i) => Value::from(*i),
+        FieldValue::B(i) => Value::from(*i),
+        FieldValue::s(i) => Value::from(*i),
+        FieldValue::u(i) => Value::from(*i),
+        FieldValue::I(i) => Value::from(*i),
+        FieldValue::i(i) => Value::from(*i),
+        FieldValue::l(i) => Value::from(*i),
+        FieldValue::f(f) => Number::from_f64(f64::from(*f))
+            .map(Value::Number)
+            .unwrap_or(Value::Null),
+        FieldValue::d(f) => Number::from_f64(*f)
+            .map(Value::Number)
+            .unwrap_or(Value::Null),
+        FieldValue::D(d) => Value::String(d.to_string()),
+        FieldValue::S(s) => Value::String(s.as_ref().clone()),
+        FieldValue::A(xs) => {
+            let values: Vec<FieldValue> = xs.clone().into();
+            Value::Array(values.iter().map(json_from_field_value).collect())
+        }
+        FieldValue::T(ts) => Value::from(*ts),
+        FieldValue::F(table) => Value::Object(json_from_field_table(table)),
+        FieldValue::V => Value::Null,
+        FieldValue::x(bytes) => {
+            let bytes: Vec<u8> = bytes.clone().into();
+            Value::from(bytes)
+        }
+    }
+}
+
+/// Converts a JSON object, e.g. optional queue arguments or message headers,
+/// to an AMQP 0-9-1 field table.
+pub fn field_table_from_json(m: &Map<String, Value>) -> FieldTable {
+    let mut table = FieldTable::new();
+    for (k, v) in m {
+        if let Ok(name) = FieldName::try_from(k.as_str()) {
+            table.insert(name, field_value_from_json(v));
+        }
+    }
+    table
+}
+
+/// Converts an AMQP 0-9-1 field table to a JSON object.
+pub fn json_from_field_table(table: &FieldTable) -> Map<String, Value> {
+    table
+        .as_ref()
+        .iter()
+        .map(|(k, v)| (k.as_ref().clone(), json_from_field_value(v)))
+        .collect()
+}
+
+/// Converts optional arguments to an AMQP 0-9-1 field table.
+pub fn field_table_from_x_arguments(args: &XArguments) -> FieldTable {
+    match args {
+        Some(m) => field_table_from_json(m),
+        None => FieldTable::new(),
+    }
+}
+
+/// Converts an AMQP 0-9-1 field table to optional arguments. An empty table
+/// is converted to `None`.
+pub fn x_arguments_from_field_table(table: &FieldTable) -> XArguments {
+    if table.as_ref().is_empty() {
+        None
+    } else {
+        Some(json_from_field_table(table))
+    }
+}
+
+impl From<&QueueParams<'_>> for QueueDeclareArguments {
+    fn from(params: &QueueParams<'_>) -> Self {
+        // the queue type must be passed as an optional argument over AMQP 0-9-1
+        let args = QueueParams::combined_args(params.arguments.clone(), &params.queue_type);
+
+        QueueDeclareArguments::new(params.name)
+            .durable(params.durable)
+            .auto_delete(params.auto_delete)
+            .exclusive(params.exclusive)
+            .arguments(field_table_from_x_arguments(&args))
+            .finish()
+    }
+}
+
+impl From<&QueueInfo> for QueueDeclareArguments {
+    fn from(info: &QueueInfo) -> Self {
+        QueueDeclareArguments::from(&QueueParams::from(info))
+    }
+}
+
+impl From<&ExchangeParams<'_>> for ExchangeDeclareArguments {
+    fn from(params: &ExchangeParams<'_>) -> Self {
+        ExchangeDeclareArguments::new(params.name, &String::from(params.exchange_type.clone()))
+            .durable(params.durable)
+            .auto_delete(params.auto_delete)
+            .arguments(field_table_from_x_arguments(&params.arguments))
+            .finish()
+    }
+}
+
+impl<'a> From<&'a ExchangeDeclareArguments> for ExchangeParams<'a> {
+    fn from(args: &'a ExchangeDeclareArguments) -> Self {
+        ExchangeParams::new(
+            &args.exchange,
+            ExchangeType::from(args.exchange_type.as_str()),
+            args.durable,
+            args.auto_delete,
+            x_arguments_from_field_table(&args.arguments),
+        )
+    }
+}
+
+impl From<&MessageProperties> for BasicProperties {
+    fn from(props: &MessageProperties) -> Self {
+        let mut result = BasicProperties::default();
+        if let Some(val) = short_string(&props.content_type) {
+            result.with_content_type(val);
+        }
+        if let Some(val) = short_string(&props.content_encoding) {
+            result.with_content_encoding(val);
+        }
+        if let Some(val) = props.delivery_mode {
+            result.with_delivery_mode(val);
+        }
+        if let Some(val) = props.priority {
+            result.with_priority(val);
+        }
+        if let Some(val) = short_string(&props.correlation_id) {
+            result.with_correlation_id(val);
+        }
+        if let Some(val) = short_string(&props.reply_to) {
+            result.with_reply_to(val);
+        }
+        if let Some(val) = short_string(&props.expiration) {
+            result.with_expiration(val);
+        }
+        if let Some(val) = short_string(&props.message_id) {
+            result.with_message_id(val);
+        }
+        if let Some(val) = props.timestamp {
+            result.with_timestamp(val);
+        }
+        if let Some(val) = &props.headers {
+            result.with_headers(field_table_from_json(val));
+        }
+        if let Some(val) = short_string_extra(props, "type") {
+            result.with_message_type(val);
+        }
+        if let Some(val) = short_string_extra(props, "user_id") {
+            result.with_user_id(val);
+        }
+        if let Some(val) = short_string_extra(props, "app_id") {
+            result.with_app_id(val);
+        }
+        if let Some(val) = short_string_extra(props, "cluster_id") {
+            result.with_cluster_id(val);
+        }
+        result.finish()
+    }
+}
+
+impl From<&BasicProperties> for MessageProperties {
+    fn from(props: &BasicProperties) -> Self {
+        let mut extras = Map::new();
+        for (key, val) in [
+            ("type", props.message_type()),
+            ("user_id", props.user_id()),
+            ("app_id", props.app_id()),
+            ("cluster_id", props.cluster_id()),
+        ] {
+            if let Some(val) = val {
+                extras.insert(key.to_owned(), Value::String(val.clone()));
+            }
+        }
+
+        MessageProperties {
+            content_type: props.content_type().cloned(),
+            content_encoding: props.content_encoding().cloned(),
+            delivery_mode: props.delivery_mode(),
+            priority: props.priority(),
+            correlation_id: props.correlation_id().cloned(),
+            reply_to: props.reply_to().cloned(),
+            expiration: props.expiration().cloned(),
+            message_id: props.message_id().cloned(),
+            timestamp: props.timestamp(),
+            headers: props.headers().map(json_from_field_table),
+            extras,
+        }
+    }
+}
+
+fn short_string(val: &Option<String>) -> Option<&str> {
+    val.as_deref()
+        .filter(|s| s.len() <= SHORT_STRING_MAX_LENGTH)
+}
+
+fn short_string_extra<'a>(props: &'a MessageProperties, key: &str) -> Option<&'a str> {
+    props
+        .extras
+        .get(key)
+        .and_then(Value::as_str)
+        .filter(|s| s.len() <= SHORT_STRING_MAX_LENGTH)
+}
@@ -13,41 +13,75 @@
 // limitations under the License.
 #![allow(clippy::result_large_err)]
 
+use crate::alarm_watcher::{AlarmEvent, AlarmStateTracker, AlarmWatcherSettings, WatchedCheck};
+use crate::audit::{AuditHook, Mutation};
+use crate::churn_analyzer::{ChurnAnalyzerSettings, ConnectionChurnReport, ConnectionChurnTracker};
+use crate::circuit_breaker::{CircuitBreaker, CircuitBreakerSettings, CircuitState};
+use crate::definition_files;
 use crate::error::Error;
 use crate::error::Error::{ClientErrorResponse, NotFound, ServerErrorResponse};
+use crate::lenient_listings::LenientListings;
+use crate::message_files;
+use crate::payload_limits::PayloadSizeGuard;
+use crate::protection::ProtectedEntities;
+use crate::report::{ClusterReport, ConsumerUtilisationReport, EntityCounts};
+use crate::request_ids::RequestIds;
 use crate::requests::{
     Amqp091ShovelParams, Amqp10ShovelParams, EmptyPayload, FederationUpstreamParams,
-    GlobalRuntimeParameterDefinition, StreamParams, FEDERATION_UPSTREAM_COMPONENT,
+    GlobalRuntimeParameterDefinition, ShovelParams, StreamParams, FEDERATION_UPSTREAM_COMPONENT,
     SHOVEL_COMPONENT,
 };
 use crate::responses::{
     ClusterTags, DeprecatedFeatureList, FeatureFlag, FeatureFlagList, FeatureFlagStability,
     FeatureFlagState, FederationUpstream, GetMessage, OAuthConfiguration, Overview,
-    SchemaDefinitionSyncStatus, VirtualHostDefinitionSet, WarmStandbyReplicationStatus,
+    ResponseEnvelope, SchemaDefinitionSyncStatus, VirtualHostDefinitionSet,
+    WarmStandbyReplicationStatus,
 };
+use crate::utils::{redact_endpoint, redact_last_path_segment, redact_url, REDACTED};
+use crate::watch::{watch_listing, WatchEvent, WatchSettings};
 use crate::{
-    commons::{BindingDestinationType, SupportedProtocol, UserLimitTarget, VirtualHostLimitTarget},
+    commons::{
+        BindingDestinationType, PayloadEncoding, QuorumQueueGrowthStrategy, SupportedProtocol,
+        TimeUnit, UserLimitTarget, VirtualHostLimitTarget,
+    },
     path,
     requests::{
-        self, BulkUserDelete, EnforcedLimitParams, ExchangeParams, Permissions, PolicyParams,
-        QueueParams, RuntimeParameterDefinition, UserParams, VirtualHostParams, XArguments,
+        self, BulkUserDelete, DefinitionOperation, EnforcedLimitParams, ExchangeParams,
+        Permissions, PolicyParams, QueueParams, RuntimeParameterDefinition, UserParams,
+        UserParamsWithPassword, VirtualHostChanges, VirtualHostParams, XArguments,
     },
     responses::{self, BindingInfo, ClusterDefinitionSet},
 };
 use backtrace::Backtrace;
+use futures_util::stream::{self, Stream, TryStreamExt};
 use reqwest::{
-    header::{HeaderMap, HeaderValue},
-    Client as HttpClient, StatusCode,
+    header::{HeaderMap, HeaderValue, CONTENT_TYPE},
+    Body, Client as HttpClient, RequestBuilder, ResponseBuilderExt, StatusCode,
 };
-use serde::Serialize;
+use serde::{de::DeserializeOwned, Serialize};
 use serde_json::{json, Map, Value};
 use std::fmt;
+use std::net::{IpAddr, SocketAddr};
+use std::ops::ControlFlow;
+use std::path::Path;
+use std::sync::{Arc, PoisonError, RwLock};
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
 
 pub type HttpClientResponse = reqwest::Response;
 pub type HttpClientError = crate::error::HttpClientError;
 
 pub type Result<T> = std::result::Result<T, HttpClientError>;
 
+/// The global runtime parameter that maps MQTT clients to virtual hosts.
+const MQTT_DEFAULT_VHOSTS_PARAMETER: &str = "mqtt_default_vhosts";
+/// The size of chunks read from readers passed to [`Client::import_definitions_from_reader`].
+const STREAMED_BODY_CHUNK_SIZE: usize = 64 * 1024;
+/// The initial delay between readiness checks performed by [`Client::await_ready`].
+const READINESS_CHECK_INITIAL_DELAY: Duration = Duration::from_millis(100);
+/// The maximum delay between readiness checks performed by [`Client::await_ready`].
+const READINESS_CHECK_MAX_DELAY: Duration = Duration::from_secs(5);
+
 /// A `ClientBuilder` can be used to create a `Client` with custom configuration.
 ///
 /// Example
@@ -69,7 +103,47 @@ pub struct ClientBuilder<E = &'static str, U = &'static str, P = &'static str> {
     endpoint: E,
     username: U,
     password: P,
-    client: HttpClient,
+    api_prefix: Option<String>,
+    protected_entities: ProtectedEntities,
+    payload_size_guard: Option<PayloadSizeGuard>,
+    request_ids: Option<RequestIds>,
+    max_response_size: Option<u64>,
+    lenient_listings: Option<LenientListings>,
+    circuit_breaker: Option<CircuitBreakerSettings>,
+    audit_hook: Option<AuditHook>,
+    // set with ClientBuilder::with_client
+    client: Option<HttpClient>,
+    transport: TransportSettings,
+}
+
+/// Settings of the HTTP client built by [`ClientBuilder::try_build`].
+#[derive(Debug, Default)]
+struct TransportSettings {
+    dns_overrides: Vec<(String, IpAddr)>,
+    http2_prior_knowledge: bool,
+}
+
+impl TransportSettings {
+    fn is_default(&self) -> bool {
+        self.dns_overrides.is_empty() && !self.http2_prior_knowledge
+    }
+
+    fn build_client(&self) -> Result<HttpClient> {
+        let builder =
+            self.dns_overrides
+                .iter()
+                .fold(HttpClient::builder(), |builder, (host, ip)| {
+                    // port 0 means "the port of the URL"
+                    builder.resolve(host, SocketAddr::new(*ip, 0))
+                });
+        #[cfg(feature = "http2")]
+        let builder = if self.http2_prior_knowledge {
+            builder.http2_prior_knowledge()
+        } else {
+            builder
+        };
+        Ok(builder.build()?)
+    }
 }
 
 impl Default for ClientBuilder {
@@ -83,12 +157,20 @@ impl ClientBuilder {
     ///
     /// This is the same as `Client::builder()`.
     pub fn new() -> Self {
-        let client = HttpClient::new();
         Self {
             endpoint: "http://localhost:15672/api",
             username: "guest",
             password: "guest",
-            client,
+            api_prefix: None,
+            protected_entities: ProtectedEntities::default(),
+            payload_size_guard: None,
+            request_ids: None,
+            max_response_size: None,
+            lenient_listings: None,
+            circuit_breaker: None,
+            audit_hook: None,
+            client: None,
+            transport: TransportSettings::default(),
         }
     }
 }
@@ -112,7 +194,16 @@ where
             endpoint: self.endpoint,
             username,
             password,
+            api_prefix: self.api_prefix,
+            protected_entities: self.protected_entities,
+            payload_size_guard: self.payload_size_guard,
+            request_ids: self.request_ids,
+            max_response_size: self.max_response_size,
+            lenient_listings: self.lenient_listings,
+            circuit_breaker: self.circuit_breaker,
+            audit_hook: self.audit_hook,
             client: self.client,
+            transport: self.transport,
         }
     }
 
@@ -124,20 +215,211 @@ where
             endpoint,
             username: self.username,
             password: self.password,
+            api_prefix: self.api_prefix,
+            protected_entities: self.protected_entities,
+            payload_size_guard: self.payload_size_guard,
+            request_ids: self.request_ids,
+            max_response_size: self.max_response_size,
+            lenient_listings: self.lenient_listings,
+            circuit_breaker: self.circuit_breaker,
+            audit_hook: self.audit_hook,
             client: self.client,
+            transport: self.transport,
         }
     }
 
     pub fn with_client(self, client: HttpClient) -> Self {
-        ClientBuilder { client, ..self }
+        ClientBuilder {
+            client: Some(client),
+            ..self
+        }
+    }
+
+    /// Sets a path prefix that will be inserted between the endpoint and every API path.
+    ///
+    /// Useful when the HTTP API is served behind a reverse proxy under a custom path,
+    /// e.g. an endpoint of `https://proxy.eng.megacorp.local` with the `rabbitmq/api` prefix.
+    pub fn with_api_prefix<S>(self, prefix: S) -> Self
+    where
+        S: Into<String>,
+    {
+        ClientBuilder {
+            api_prefix: Some(prefix.into()),
+            ..self
+        }
+    }
+
+    /// Makes the client refuse destructive operations, such as deletion of virtual hosts,
+    /// queues, exchanges or users, that target the given entities.
+    /// See [`ProtectedEntities`] and [`Client::with_protection_overridden`].
+    pub fn with_protected_entities(self, protected_entities: ProtectedEntities) -> Self {
+        ClientBuilder {
+            protected_entities,
+            ..self
+        }
+    }
+
+    /// Makes the client send an ID with every request, e.g. in the `X-Request-Id`
+    /// or `traceparent` header, see [`RequestIds`]. The ID is included in the errors returned
+    /// for failed requests, see [`Error::request_id`].
+    pub fn with_request_ids(self, request_ids: RequestIds) -> Self {
+        ClientBuilder {
+            request_ids: Some(request_ids),
+            ..self
+        }
+    }
+
+    /// Limits the size of response bodies the client will read, in bytes.
+    ///
+    /// Larger responses, e.g. a listing of all bindings in a cluster with millions of them,
+    /// are rejected with [`Error::ResponseTooLarge`] instead of being read into memory.
+    /// The bodies of error responses are truncated at the limit.
+    pub fn with_max_response_size(self, max_response_size: u64) -> Self {
+        ClientBuilder {
+            max_response_size: Some(max_response_size),
+            ..self
+        }
+    }
+
+    /// Makes listing operations deserialize every element independently, skipping
+    /// (and reporting) the elements that fail to parse instead of failing the entire call.
+    /// See [`LenientListings`].
+    pub fn with_lenient_listings(self, lenient_listings: LenientListings) -> Self {
+        ClientBuilder {
+            lenient_listings: Some(lenient_listings),
+            ..self
+        }
+    }
+
+    /// Makes the client short-circuit requests for a cool-down period after a number
+    /// of consecutive failures, returning [`Error::CircuitOpen`] instead of issuing them.
+    /// See [`CircuitBreakerSettings`].
+    pub fn with_circuit_breaker(self, settings: CircuitBreakerSettings) -> Self {
+        ClientBuilder {
+            circuit_breaker: Some(settings),
+            ..self
+        }
+    }
+
+    /// Makes the client invoke the given hook with a typed [`Mutation`] after every
+    /// successful operation that changes the state of the cluster, e.g. to produce change
+    /// audit records. See [`AuditHook`].
+    pub fn with_audit_hook(self, audit_hook: AuditHook) -> Self {
+        ClientBuilder {
+            audit_hook: Some(audit_hook),
+            ..self
+        }
+    }
+
+    /// Makes the client check the payload size of published messages before sending them,
+    /// see [`PayloadSizeGuard`]. Oversized messages are rejected with [`Error::PayloadTooLarge`].
+    pub fn with_payload_size_guard(self, guard: PayloadSizeGuard) -> Self {
+        ClientBuilder {
+            payload_size_guard: Some(guard),
+            ..self
+        }
+    }
+
+    /// Makes the client connect to the given IP addresses instead of resolving the hostnames,
+    /// e.g. to reach a node through a jump host or before DNS records are updated.
+    /// The port in the endpoint is still used, and so is the original hostname for TLS
+    /// (SNI and certificate validation) and the `Host` header.
+    ///
+    /// Cannot be combined with [`ClientBuilder::with_client`]: to use DNS overrides
+    /// with a custom HTTP client, configure them using `reqwest::ClientBuilder#resolve`.
+    pub fn with_dns_overrides(mut self, overrides: &[(&str, IpAddr)]) -> Self {
+        self.transport
+            .dns_overrides
+            .extend(overrides.iter().map(|(host, ip)| ((*host).to_owned(), *ip)));
+        self
+    }
+
+    /// Makes the client use HTTP/2 without protocol negotiation ("prior knowledge"),
+    /// e.g. for a cleartext endpoint behind an ingress controller that supports HTTP/2.
+    /// With TLS, the `http2` feature is sufficient: HTTP/2 will be negotiated using ALPN.
+    ///
+    /// Cannot be combined with [`ClientBuilder::with_client`]: to use HTTP/2 with
+    /// a custom HTTP client, configure it using `reqwest::ClientBuilder#http2_prior_knowledge`.
+    #[cfg(feature = "http2")]
+    pub fn with_http2_prior_knowledge(self) -> Self {
+        ClientBuilder {
+            transport: TransportSettings {
+                http2_prior_knowledge: true,
+                ..self.transport
+            },
+            ..self
+        }
     }
 
     /// Returns a `Client` that uses this `ClientBuilder` configuration.
+    ///
+    /// # Panics
+    ///
+    /// If the HTTP client cannot be built, see [`ClientBuilder::try_build`].
     pub fn build(self) -> Client<E, U, P> {
-        Client::from_http_client(self.client, self.endpoint, self.username, self.password)
+        self.try_build()
+            .unwrap_or_else(|e| panic!("failed to build a client: {}", e))
+    }
+
+    /// Like [`ClientBuilder::build`] but returns an error if the HTTP client cannot be built,
+    /// e.g. because [`ClientBuilder::with_dns_overrides`] was combined with
+    /// [`ClientBuilder::with_client`].
+    pub fn try_build(self) -> Result<Client<E, U, P>> {
+        let client = match self.client {
+            Some(_) if !self.transport.is_default() => {
+                return Err(Error::InvalidClientSettings {
+                    reason: "DNS overrides and HTTP/2 prior knowledge cannot be applied to a client set with ClientBuilder#with_client, configure them using reqwest's client builder instead".to_owned(),
+                })
+            }
+            Some(client) => client,
+            None => self.transport.build_client()?,
+        };
+        let mut inner = ClientInner::new(client, self.endpoint, self.username, self.password);
+        inner.api_prefix = self.api_prefix;
+        inner.protected_entities = self.protected_entities;
+        inner.payload_size_guard = self.payload_size_guard;
+        inner.request_ids = self.request_ids;
+        inner.max_response_size = self.max_response_size;
+        inner.lenient_listings = self.lenient_listings;
+        inner.circuit_breaker = self.circuit_breaker.map(CircuitBreaker::new);
+        inner.audit_hook = self.audit_hook;
+        Ok(Client {
+            inner: Arc::new(inner),
+            deadline: None,
+            protection_overridden: false,
+        })
+    }
+}
+
+/// Never includes the password, neither the one used for basic authentication,
+/// nor the one in the user info part of the endpoint.
+impl<E, U, P> fmt::Debug for ClientBuilder<E, U, P>
+where
+    E: fmt::Display,
+    U: fmt::Display,
+    P: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClientBuilder")
+            .field("endpoint", &redact_endpoint(&self.endpoint.to_string()))
+            .field("username", &self.username.to_string())
+            .field("password", &REDACTED)
+            .field("api_prefix", &self.api_prefix)
+            .field("protected_entities", &self.protected_entities)
+            .field("payload_size_guard", &self.payload_size_guard)
+            .field("request_ids", &self.request_ids)
+            .field("max_response_size", &self.max_response_size)
+            .field("lenient_listings", &self.lenient_listings)
+            .field("circuit_breaker", &self.circuit_breaker)
+            .field("audit_hook", &self.audit_hook)
+            .field("transport", &self.transport)
+            .finish_non_exhaustive()
     }
 }
 
+/// A client constructed for a specific cluster node, see [`Client::discover_node_clients`].
+pub type NodeClient = Client<String, String, String>;
+
 /// A client for the [RabbitMQ HTTP API](https://rabbitmq.com/docs/management/#http-api).
 ///
 /// Most functions provided by this type represent various HTTP API operations.
@@ -164,12 +446,94 @@ where
 /// let _ = rc.get_queue_info("/", "qq.1").await;
 /// ```
 pub struct Client<E, U, P> {
+    inner: Arc<ClientInner<E, U, P>>,
+    // see Client::with_deadline
+    deadline: Option<Instant>,
+    // see Client::with_protection_overridden
+    protection_overridden: bool,
+}
+
+// shared by all clones of a client
+struct ClientInner<E, U, P> {
     endpoint: E,
     username: U,
     password: P,
+    // set by Client::update_credentials, takes precedence over the above,
+    // shared with the clients constructed by Client::discover_node_clients
+    rotated_credentials: Arc<RwLock<Option<(String, String)>>>,
+    api_prefix: Option<String>,
+    protected_entities: ProtectedEntities,
+    payload_size_guard: Option<PayloadSizeGuard>,
+    request_ids: Option<RequestIds>,
+    max_response_size: Option<u64>,
+    lenient_listings: Option<LenientListings>,
+    circuit_breaker: Option<CircuitBreaker>,
+    audit_hook: Option<AuditHook>,
     client: HttpClient,
 }
 
+impl<E, U, P> ClientInner<E, U, P> {
+    fn new(client: HttpClient, endpoint: E, username: U, password: P) -> Self {
+        Self {
+            endpoint,
+            username,
+            password,
+            rotated_credentials: Arc::new(RwLock::new(None)),
+            api_prefix: None,
+            protected_entities: ProtectedEntities::default(),
+            payload_size_guard: None,
+            request_ids: None,
+            max_response_size: None,
+            lenient_listings: None,
+            circuit_breaker: None,
+            audit_hook: None,
+            client,
+        }
+    }
+}
+
+/// Clones share the underlying HTTP client (and its connection pool), the credentials,
+/// including those set with [`Client::update_credentials`], and the rest of the configuration.
+impl<E, U, P> Clone for Client<E, U, P> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+            deadline: self.deadline,
+            protection_overridden: self.protection_overridden,
+        }
+    }
+}
+
+/// Never includes the password, neither the one used for basic authentication,
+/// nor the one in the user info part of the endpoint.
+impl<E, U, P> fmt::Debug for Client<E, U, P>
+where
+    E: fmt::Display,
+    U: fmt::Display,
+    P: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (username, _) = self.credentials();
+        f.debug_struct("Client")
+            .field(
+                "endpoint",
+                &redact_endpoint(&self.inner.endpoint.to_string()),
+            )
+            .field("username", &username)
+            .field("password", &REDACTED)
+            .field("api_prefix", &self.inner.api_prefix)
+            .field("protected_entities", &self.inner.protected_entities)
+            .field("payload_size_guard", &self.inner.payload_size_guard)
+            .field("request_ids", &self.inner.request_ids)
+            .field("max_response_size", &self.inner.max_response_size)
+            .field("lenient_listings", &self.inner.lenient_listings)
+            .field("circuit_breaker", &self.inner.circuit_breaker)
+            .field("audit_hook", &self.inner.audit_hook)
+            .field("deadline", &self.deadline)
+            .finish_non_exhaustive()
+    }
+}
+
 impl<E, U, P> Client<E, U, P>
 where
     E: fmt::Display,
@@ -189,12 +553,10 @@ where
     /// ```
     pub fn new(endpoint: E, username: U, password: P) -> Self {
         let client = HttpClient::builder().build().unwrap();
-
         Self {
-            endpoint,
-            username,
-            password,
-            client,
+            inner: Arc::new(ClientInner::new(client, endpoint, username, password)),
+            deadline: None,
+            protection_overridden: false,
         }
     }
 
@@ -214,10 +576,9 @@ where
     /// ```
     pub fn from_http_client(client: HttpClient, endpoint: E, username: U, password: P) -> Self {
         Self {
-            endpoint,
-            username,
-            password,
-            client,
+            inner: Arc::new(ClientInner::new(client, endpoint, username, password)),
+            deadline: None,
+            protection_overridden: false,
         }
     }
 
@@ -228,24 +589,101 @@ where
         ClientBuilder::new()
     }
 
+    /// Changes the credentials used by all subsequent requests, e.g. after the password
+    /// was rotated by a secret management tool, without having to rebuild the client.
+    ///
+    /// Requests that are already in flight will continue using the previous credentials.
+    pub fn update_credentials(&self, username: &str, password: &str) {
+        let mut rotated = self
+            .inner
+            .rotated_credentials
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+        *rotated = Some((username.to_owned(), password.to_owned()));
+    }
+
+    /// Returns a clone of this client whose requests must complete before the given deadline.
+    ///
+    /// The remaining time is budgeted across all requests issued by the clone, including
+    /// those issued by multi-request operations such as [`Client::declare_topology`]
+    /// or [`Client::enable_all_stable_feature_flags`]: every request times out when
+    /// the deadline is reached (including while its response body is being read),
+    /// and once it has passed, no more requests are issued.
+    /// In both cases, [`Error::DeadlineExceeded`] is returned.
+    pub fn with_deadline(&self, deadline: Instant) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+            deadline: Some(deadline),
+            protection_overridden: self.protection_overridden,
+        }
+    }
+
+    /// Like [`Client::with_deadline`] but with a deadline that is `timeout` from now.
+    pub fn with_timeout(&self, timeout: Duration) -> Self {
+        self.with_deadline(Instant::now() + timeout)
+    }
+
+    pub fn deadline(&self) -> Option<Instant> {
+        self.deadline
+    }
+
+    /// Returns a clone of this client that performs destructive operations even when they
+    /// target entities protected using [`ClientBuilder::with_protected_entities`].
+    ///
+    /// Only the returned client is affected: this client and its other clones
+    /// keep refusing such operations.
+    pub fn with_protection_overridden(&self) -> Self {
+        Self {
+            protection_overridden: true,
+            ..self.clone()
+        }
+    }
+
+    pub fn is_protection_overridden(&self) -> bool {
+        self.protection_overridden
+    }
+
+    /// Returns the state of the circuit breaker, if the client is configured with one,
+    /// see [`ClientBuilder::with_circuit_breaker`].
+    pub fn circuit_state(&self) -> Option<CircuitState> {
+        self.inner
+            .circuit_breaker
+            .as_ref()
+            .map(CircuitBreaker::state)
+    }
+
     /// Lists cluster nodes.
     pub async fn list_nodes(&self) -> Result<Vec<responses::ClusterNode>> {
         let response = self.http_get("nodes", None, None).await?;
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
+    /// Like [`Client::list_nodes`] but also returns the HTTP response metadata.
+    pub async fn list_nodes_with_response(
+        &self,
+    ) -> Result<ResponseEnvelope<Vec<responses::ClusterNode>>> {
+        self.get_with_response("nodes").await
+    }
+
     /// Lists virtual hosts in the cluster.
     pub async fn list_vhosts(&self) -> Result<Vec<responses::VirtualHost>> {
         let response = self.http_get("vhosts", None, None).await?;
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
+    /// Like [`Client::list_vhosts`] but also returns the HTTP response metadata.
+    pub async fn list_vhosts_with_response(
+        &self,
+    ) -> Result<ResponseEnvelope<Vec<responses::VirtualHost>>> {
+        self.get_with_response("vhosts").await
+    }
+
     /// Lists users in the internal database.
     pub async fn list_users(&self) -> Result<Vec<responses::User>> {
         let response = self.http_get("users", None, None).await?;
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
@@ -255,22 +693,69 @@ where
         let response = self
             .http_get("users/without-permissions", None, None)
             .await?;
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
     /// Lists all AMQP 1.0 and 0-9-1 client connections across the cluster.
     pub async fn list_connections(&self) -> Result<Vec<responses::Connection>> {
         let response = self.http_get("connections", None, None).await?;
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
+    /// Lists client connections that use the given client-provided connection name
+    /// (the `connection_name` client property).
+    ///
+    /// The filtering is performed on the client side.
+    pub async fn list_connections_with_client_name(
+        &self,
+        name: &str,
+    ) -> Result<Vec<responses::Connection>> {
+        let connections = self.list_connections().await?;
+        Ok(connections
+            .into_iter()
+            .filter(|c| c.client_properties.connection_name == name)
+            .collect())
+    }
+
+    /// Lists client connections on the given cluster node, e.g. to see what connections
+    /// would be affected by a restart of the node.
+    ///
+    /// The filtering is performed on the client side.
+    pub async fn list_connections_on_node(&self, node: &str) -> Result<Vec<responses::Connection>> {
+        let connections = self.list_connections().await?;
+        Ok(connections.into_iter().filter(|c| c.node == node).collect())
+    }
+
+    /// Like [`Client::list_connections`] but also returns the HTTP response metadata.
+    pub async fn list_connections_with_response(
+        &self,
+    ) -> Result<ResponseEnvelope<Vec<responses::Connection>>> {
+        self.get_with_response("connections").await
+    }
+
+    /// Returns information about a single client connection, including its client properties.
+    ///
+    /// Connection names can be obtained with [`Client::list_connections`] and similar functions.
     pub async fn get_connection_info(&self, name: &str) -> Result<responses::Connection> {
         let response = self
             .http_get(path!("connections", name), None, None)
             .await?;
-        let response = response.json().await?;
+        let response = self.read_json(response).await?;
+        Ok(response)
+    }
+
+    /// Lists the sessions (and their links) of an AMQP 1.0 connection.
+    /// Requires RabbitMQ 4.0 or later.
+    pub async fn list_connection_sessions(
+        &self,
+        name: &str,
+    ) -> Result<Vec<responses::AmqpSession>> {
+        let response = self
+            .http_get(path!("connections", name, "sessions"), None, None)
+            .await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
@@ -286,11 +771,34 @@ where
                 None,
             )
             .await?;
-        let response = response.json().await?;
+        let response = self.read_json(response).await?;
         Ok(response)
     }
 
+    /// Closes a client connection. The reason, if provided, is sent to the client
+    /// (with the `X-Reason` header) and logged by the server.
+    ///
+    /// Connections that no longer exist are ignored.
     pub async fn close_connection(&self, name: &str, reason: Option<&str>) -> Result<()> {
+        if self.is_protection_enforced() {
+            // connections are protected by the name of their user
+            match self.get_connection_info(name).await {
+                Ok(conn)
+                    if self
+                        .inner
+                        .protected_entities
+                        .is_name_protected(&conn.username) =>
+                {
+                    return Err(Error::ProtectedEntity {
+                        kind: "connection".to_owned(),
+                        vhost: None,
+                        name: name.to_owned(),
+                    });
+                }
+                Ok(_) | Err(NotFound) => {}
+                Err(e) => return Err(e),
+            }
+        }
         match reason {
             None => {
                 self.http_delete(
@@ -304,14 +812,23 @@ where
                 let mut headers = HeaderMap::new();
                 let hdr = HeaderValue::from_str(value)?;
                 headers.insert("X-Reason", hdr);
-                self.http_delete_with_headers(path!("connections", name), headers, None, None)
-                    .await?
+                self.http_delete_with_headers(
+                    path!("connections", name),
+                    headers,
+                    Some(StatusCode::NOT_FOUND),
+                    None,
+                )
+                .await?
             }
         };
+        self.audit(|| Mutation::ClosedConnection {
+            name: name.to_owned(),
+        });
         Ok(())
     }
 
     pub async fn close_user_connections(&self, username: &str, reason: Option<&str>) -> Result<()> {
+        self.ensure_name_unprotected("user", username)?;
         match reason {
             None => {
                 self.http_delete(
@@ -334,6 +851,9 @@ where
                 .await?
             }
         };
+        self.audit(|| Mutation::ClosedUserConnections {
+            user: username.to_owned(),
+        });
         Ok(())
     }
 
@@ -345,7 +865,7 @@ where
         let response = self
             .http_get(path!("vhosts", virtual_host, "connections"), None, None)
             .await?;
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
@@ -357,14 +877,14 @@ where
         let response = self
             .http_get(path!("connections", "username", username), None, None)
             .await?;
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
     /// Lists all RabbitMQ Stream Protocol client connections across the cluster.
     pub async fn list_stream_connections(&self) -> Result<Vec<responses::Connection>> {
         let response = self.http_get("stream/connections", None, None).await?;
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
@@ -376,14 +896,14 @@ where
         let response = self
             .http_get(path!("stream", "connections", virtual_host), None, None)
             .await?;
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
     /// Lists all channels across the cluster.
     pub async fn list_channels(&self) -> Result<Vec<responses::Channel>> {
         let response = self.http_get("channels", None, None).await?;
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
@@ -392,7 +912,29 @@ where
         let response = self
             .http_get(path!("vhosts", virtual_host, "channels"), None, None)
             .await?;
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
+        Ok(response)
+    }
+
+    /// Lists channels on the given cluster node, e.g. to see what channels
+    /// would be affected by a restart of the node.
+    ///
+    /// The filtering is performed on the client side.
+    pub async fn list_channels_on_node(&self, node: &str) -> Result<Vec<responses::Channel>> {
+        let channels = self.list_channels().await?;
+        Ok(channels.into_iter().filter(|c| c.node == node).collect())
+    }
+
+    /// Lists the channels of the given client connection.
+    pub async fn list_channels_on(&self, connection_name: &str) -> Result<Vec<responses::Channel>> {
+        let response = self
+            .http_get(
+                path!("connections", connection_name, "channels"),
+                None,
+                None,
+            )
+            .await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
@@ -401,7 +943,7 @@ where
         let response = self
             .http_get(path!("stream", "publishers"), None, None)
             .await?;
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
@@ -413,7 +955,7 @@ where
         let response = self
             .http_get(path!("stream", "publishers", virtual_host), None, None)
             .await?;
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
@@ -430,7 +972,7 @@ where
                 None,
             )
             .await?;
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
@@ -448,7 +990,7 @@ where
             )
             .await?;
 
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
@@ -457,7 +999,7 @@ where
         let response = self
             .http_get(path!("stream", "consumers"), None, None)
             .await?;
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
@@ -469,7 +1011,7 @@ where
         let response = self
             .http_get(path!("stream", "consumers", virtual_host), None, None)
             .await?;
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
@@ -487,30 +1029,63 @@ where
             )
             .await?;
 
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
     /// Lists all queues and streams across the cluster.
     pub async fn list_queues(&self) -> Result<Vec<responses::QueueInfo>> {
         let response = self.http_get("queues", None, None).await?;
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
+    /// Like [`Client::list_queues`] but also returns the HTTP response metadata.
+    pub async fn list_queues_with_response(
+        &self,
+    ) -> Result<ResponseEnvelope<Vec<responses::QueueInfo>>> {
+        self.get_with_response("queues").await
+    }
+
     /// Lists all queues and streams in the given virtual host.
     pub async fn list_queues_in(&self, virtual_host: &str) -> Result<Vec<responses::QueueInfo>> {
         let response = self
             .http_get(path!("queues", virtual_host), None, None)
             .await?;
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
+        Ok(response)
+    }
+
+    /// Lists all queues and streams across the cluster, returning only their
+    /// identity and message counts. Much cheaper than [`Client::list_queues`]
+    /// both for the server and the client, so a good fit for frequent polling.
+    pub async fn list_queue_summaries(&self) -> Result<Vec<responses::QueueSummary>> {
+        let path = format!("queues?columns={}", responses::QueueSummary::COLUMNS);
+        let response = self.http_get(path, None, None).await?;
+        let response = self.deserialize_list(response).await?;
+        Ok(response)
+    }
+
+    /// Like [`Client::list_queue_summaries`] but only lists queues and streams
+    /// in the given virtual host.
+    pub async fn list_queue_summaries_in(
+        &self,
+        virtual_host: &str,
+    ) -> Result<Vec<responses::QueueSummary>> {
+        let path = format!(
+            "{}?columns={}",
+            path!("queues", virtual_host),
+            responses::QueueSummary::COLUMNS
+        );
+        let response = self.http_get(path, None, None).await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
     /// Lists all exchanges across the cluster.
     pub async fn list_exchanges(&self) -> Result<Vec<responses::ExchangeInfo>> {
         let response = self.http_get("exchanges", None, None).await?;
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
@@ -522,14 +1097,14 @@ where
         let response = self
             .http_get(path!("exchanges", virtual_host), None, None)
             .await?;
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
     /// Lists all bindings (both queue-to-exchange and exchange-to-exchange ones) across the cluster.
     pub async fn list_bindings(&self) -> Result<Vec<responses::BindingInfo>> {
         let response = self.http_get("bindings", None, None).await?;
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
@@ -541,7 +1116,7 @@ where
         let response = self
             .http_get(path!("bindings", virtual_host), None, None)
             .await?;
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
@@ -554,7 +1129,7 @@ where
         let response = self
             .http_get(path!("queues", virtual_host, queue, "bindings"), None, None)
             .await?;
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
@@ -567,7 +1142,7 @@ where
         self.list_exchange_bindings_with_source_or_destination(
             virtual_host,
             exchange,
-            BindindVertex::Source,
+            BindingVertex::Source,
         )
         .await
     }
@@ -581,7 +1156,7 @@ where
         self.list_exchange_bindings_with_source_or_destination(
             virtual_host,
             exchange,
-            BindindVertex::Destination,
+            BindingVertex::Destination,
         )
         .await
     }
@@ -589,7 +1164,7 @@ where
     /// Lists all consumers across the cluster.
     pub async fn list_consumers(&self) -> Result<Vec<responses::Consumer>> {
         let response = self.http_get("consumers", None, None).await?;
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
@@ -598,14 +1173,29 @@ where
         let response = self
             .http_get(path!("consumers", virtual_host), None, None)
             .await?;
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
+    /// Lists consumers of the given queue or stream.
+    ///
+    /// The filtering is performed on the client side.
+    pub async fn list_consumers_on_queue(
+        &self,
+        virtual_host: &str,
+        queue: &str,
+    ) -> Result<Vec<responses::Consumer>> {
+        let consumers = self.list_consumers_in(virtual_host).await?;
+        Ok(consumers
+            .into_iter()
+            .filter(|c| c.queue.name == queue)
+            .collect())
+    }
+
     /// Returns information about a cluster node.
     pub async fn get_node_info(&self, name: &str) -> Result<responses::ClusterNode> {
         let response = self.http_get(path!("nodes", name), None, None).await?;
-        let response = response.json().await?;
+        let response = self.read_json(response).await?;
         Ok(response)
     }
 
@@ -617,21 +1207,63 @@ where
         let response = self
             .http_get(path!("nodes", name, "memory"), None, None)
             .await?;
-        let response = response.json().await?;
+        let response = self.read_json(response).await?;
+        Ok(response)
+    }
+
+    /// Returns the memory breakdown of a cluster node as percentages of its total memory footprint,
+    /// as computed by the node. See [`responses::NodeMemoryFootprint::percentages`] for an alternative
+    /// that does not require an extra request.
+    pub async fn get_node_memory_footprint_relative(
+        &self,
+        name: &str,
+    ) -> Result<responses::NodeMemoryRelativeFootprint> {
+        let response = self
+            .http_get(path!("nodes", name, "memory", "relative"), None, None)
+            .await?;
+        let response = self.read_json(response).await?;
         Ok(response)
     }
 
     /// Returns information about a virtual host.
     pub async fn get_vhost(&self, name: &str) -> Result<responses::VirtualHost> {
         let response = self.http_get(path!("vhosts", name), None, None).await?;
-        let response = response.json().await?;
+        let response = self.read_json(response).await?;
+        Ok(response)
+    }
+
+    /// Returns the user the client is authenticated as, and its tags.
+    /// Useful for validating credentials before performing other operations.
+    pub async fn current_user(&self) -> Result<responses::CurrentUser> {
+        let response = self.http_get("whoami", None, None).await?;
+        let response = self.read_json(response).await?;
         Ok(response)
     }
 
+    /// Salts and hashes a password on the server, using the hashing algorithm
+    /// configured on the cluster. The result can be passed as [`UserParams::password_hash`].
+    ///
+    /// The HTTP API expects the plaintext password as a part of the request path, so it will
+    /// end up in the access logs of any proxies or load balancers in front of the node.
+    /// The password is redacted from the URLs in the errors returned by this function.
+    ///
+    /// To compute a hash without contacting the server, use [`crate::password_hashing`].
+    pub async fn hash_password(&self, password: &str) -> Result<String> {
+        let result = async {
+            let response = self
+                .http_get(path!("auth", "hash_password", password), None, None)
+                .await?;
+            let response: responses::HashedPassword = self.read_json(response).await?;
+            Ok(response.password_hash)
+        }
+        .await;
+        result.map_err(|e: HttpClientError| e.map_url(redact_last_path_segment))
+    }
+
     /// Returns information about a user in the internal database.
     pub async fn get_user(&self, name: &str) -> Result<responses::User> {
         let response = self.http_get(path!("users", name), None, None).await?;
-        let response = response.json().await?;
+        let response = self.read_json(response).await?;
         Ok(response)
     }
 
@@ -644,10 +1276,20 @@ where
         let response = self
             .http_get(path!("queues", virtual_host, name), None, None)
             .await?;
-        let response = response.json().await?;
+        let response = self.read_json(response).await?;
         Ok(response)
     }
 
+    /// Like [`Client::get_queue_info`] but also returns the HTTP response metadata.
+    pub async fn get_queue_info_with_response(
+        &self,
+        virtual_host: &str,
+        name: &str,
+    ) -> Result<ResponseEnvelope<responses::QueueInfo>> {
+        self.get_with_response(path!("queues", virtual_host, name))
+            .await
+    }
+
     /// Returns information about a stream.
     pub async fn get_stream_info(
         &self,
@@ -666,7 +1308,7 @@ where
         let response = self
             .http_get(path!("exchanges", virtual_host, name), None, None)
             .await?;
-        let response = response.json().await?;
+        let response = self.read_json(response).await?;
         Ok(response)
     }
 
@@ -684,51 +1326,176 @@ where
         let _response = self
             .http_put(path!("vhosts", params.name), params, None, None)
             .await?;
+        self.audit(|| Mutation::DeclaredVirtualHost {
+            name: params.name.to_owned(),
+        });
         Ok(())
     }
 
-    /// Adds a user to the internal database.
+    /// Updates the metadata (description, tags, default queue type, tracing) of an existing
+    /// virtual host, keeping the metadata that is not changed.
     ///
-    /// See [`UserParams`] and [`crate::password_hashing`].
-    pub async fn create_user(&self, params: &UserParams<'_>) -> Result<()> {
+    /// Unlike [`Client::update_vhost`], which replaces all metadata, this fetches the virtual host
+    /// first and merges the changes into its current metadata.
+    ///
+    /// See [`VirtualHostChanges`]
+    pub async fn amend_vhost(&self, name: &str, changes: &VirtualHostChanges<'_>) -> Result<()> {
+        let vh = self.get_vhost(name).await?;
+        let params = changes.apply_to(VirtualHostParams::from(&vh));
+        self.update_vhost(&params).await
+    }
+
+    /// Starts a virtual host on a node, e.g. one that has been stopped on that node
+    /// because of a failure. Starting a virtual host that is already running is a no-op.
+    pub async fn start_vhost_on_node(&self, vhost: &str, node: &str) -> Result<()> {
         let _response = self
-            .http_put(path!("users", params.name), params, None, None)
+            .http_post(
+                path!("vhosts", vhost, "start", node),
+                &json!({}),
+                None,
+                None,
+            )
             .await?;
+        self.audit(|| Mutation::StartedVirtualHost {
+            name: vhost.to_owned(),
+            node: node.to_owned(),
+        });
         Ok(())
     }
 
-    pub async fn declare_permissions(&self, params: &Permissions<'_>) -> Result<()> {
+    /// Protects a virtual host from deletion: the server will refuse to delete it
+    /// until the protection is disabled with [`Client::disable_vhost_deletion_protection`].
+    ///
+    /// Requires RabbitMQ 4.1 or later.
+    pub async fn enable_vhost_deletion_protection(&self, vhost: &str) -> Result<()> {
+        let payload = EmptyPayload::new();
         let _response = self
-            .http_put(
-                // /api/permissions/vhost/user
-                path!("permissions", params.vhost, params.user),
-                params,
+            .http_post(
+                path!("vhosts", vhost, "deletion", "protection"),
+                &payload,
                 None,
                 None,
             )
             .await?;
+        self.audit(|| Mutation::EnabledVirtualHostDeletionProtection {
+            name: vhost.to_owned(),
+        });
         Ok(())
     }
 
-    pub async fn grant_permissions(&self, vhost: &str, user: &str) -> Result<()> {
+    /// Removes the deletion protection of a virtual host enabled with
+    /// [`Client::enable_vhost_deletion_protection`].
+    ///
+    /// Requires RabbitMQ 4.1 or later.
+    pub async fn disable_vhost_deletion_protection(&self, vhost: &str) -> Result<()> {
         let _response = self
-            .http_delete(path!("permissions", vhost, user), None, None)
+            .http_delete(path!("vhosts", vhost, "deletion", "protection"), None, None)
             .await?;
+        self.audit(|| Mutation::DisabledVirtualHostDeletionProtection {
+            name: vhost.to_owned(),
+        });
         Ok(())
     }
 
-    pub async fn declare_queue(&self, vhost: &str, params: &QueueParams<'_>) -> Result<()> {
+    /// Adds a user to the internal database.
+    ///
+    /// See [`UserParams`] and [`crate::password_hashing`].
+    pub async fn create_user(&self, params: &UserParams<'_>) -> Result<()> {
         let _response = self
-            .http_put(path!("queues", vhost, params.name), params, None, None)
+            .http_put(path!("users", params.name), params, None, None)
             .await?;
+        self.audit(|| Mutation::DeclaredUser {
+            name: params.name.to_owned(),
+        });
         Ok(())
     }
 
-    pub async fn declare_stream(&self, vhost: &str, params: &StreamParams<'_>) -> Result<()> {
-        let mut m: Map<String, Value> = Map::new();
-
-        if let Some(m2) = params.arguments.clone() {
-            m.extend(m2);
+    /// Adds a user to the internal database, or updates an existing one, with a plaintext password.
+    /// The password is salted and hashed by the server.
+    ///
+    /// See [`UserParamsWithPassword`].
+    pub async fn create_user_with_password(
+        &self,
+        params: &UserParamsWithPassword<'_>,
+    ) -> Result<()> {
+        let _response = self
+            .http_put(path!("users", params.name), params, None, None)
+            .await?;
+        self.audit(|| Mutation::DeclaredUser {
+            name: params.name.to_owned(),
+        });
+        Ok(())
+    }
+
+    /// Updates an existing user.
+    ///
+    /// If `params.password_hash` is empty, the current password hash of the user
+    /// and the algorithm it was produced with are fetched and re-submitted,
+    /// so that updating, say, only the tags does not reset the credentials of the user.
+    /// As a consequence, this function cannot be used to make a user passwordless,
+    /// use [`Client::create_user`] with an empty password hash for that.
+    ///
+    /// Fails with [`Error::UnsupportedValue`] if the current password hash was produced with
+    /// an algorithm this client is not aware of, e.g. the legacy MD5 one, since it cannot be re-submitted.
+    pub async fn update_user(&self, params: &UserParams<'_>) -> Result<()> {
+        if !params.password_hash.is_empty() {
+            return self.create_user(params).await;
+        }
+
+        let existing = self.get_user(params.name).await?;
+        let params = UserParams {
+            name: params.name,
+            password_hash: &existing.password_hash,
+            tags: params.tags,
+            hashing_algorithm: existing.parsed_hashing_algorithm()?,
+        };
+        self.create_user(&params).await
+    }
+
+    pub async fn declare_permissions(&self, params: &Permissions<'_>) -> Result<()> {
+        let _response = self
+            .http_put(
+                // /api/permissions/vhost/user
+                path!("permissions", params.vhost, params.user),
+                params,
+                None,
+                None,
+            )
+            .await?;
+        self.audit(|| Mutation::DeclaredPermissions {
+            vhost: params.vhost.to_owned(),
+            user: params.user.to_owned(),
+        });
+        Ok(())
+    }
+
+    pub async fn grant_permissions(&self, vhost: &str, user: &str) -> Result<()> {
+        let _response = self
+            .http_delete(path!("permissions", vhost, user), None, None)
+            .await?;
+        self.audit(|| Mutation::ClearedPermissions {
+            vhost: vhost.to_owned(),
+            user: user.to_owned(),
+        });
+        Ok(())
+    }
+
+    pub async fn declare_queue(&self, vhost: &str, params: &QueueParams<'_>) -> Result<()> {
+        let _response = self
+            .http_put(path!("queues", vhost, params.name), params, None, None)
+            .await?;
+        self.audit(|| Mutation::DeclaredQueue {
+            vhost: vhost.to_owned(),
+            name: params.name.to_owned(),
+        });
+        Ok(())
+    }
+
+    pub async fn declare_stream(&self, vhost: &str, params: &StreamParams<'_>) -> Result<()> {
+        let mut m: Map<String, Value> = Map::new();
+
+        if let Some(m2) = params.arguments.clone() {
+            m.extend(m2);
         };
 
         if let Some(val) = params.max_length_bytes {
@@ -742,6 +1509,10 @@ where
         let _response = self
             .http_put(path!("queues", vhost, params.name), &q_params, None, None)
             .await?;
+        self.audit(|| Mutation::DeclaredStream {
+            vhost: vhost.to_owned(),
+            name: params.name.to_owned(),
+        });
         Ok(())
     }
 
@@ -749,6 +1520,10 @@ where
         let _response = self
             .http_put(path!("exchanges", vhost, params.name), params, None, None)
             .await?;
+        self.audit(|| Mutation::DeclaredExchange {
+            vhost: vhost.to_owned(),
+            name: params.name.to_owned(),
+        });
         Ok(())
     }
 
@@ -776,6 +1551,13 @@ where
                 None,
             )
             .await?;
+        self.audit(|| Mutation::DeclaredBinding {
+            vhost: vhost.to_owned(),
+            source: exchange.to_owned(),
+            destination: queue.to_owned(),
+            destination_type: BindingDestinationType::Queue,
+            routing_key: routing_key.map(str::to_owned),
+        });
         Ok(())
     }
 
@@ -803,10 +1585,18 @@ where
                 None,
             )
             .await?;
+        self.audit(|| Mutation::DeclaredBinding {
+            vhost: vhost.to_owned(),
+            source: source.to_owned(),
+            destination: destination.to_owned(),
+            destination_type: BindingDestinationType::Exchange,
+            routing_key: routing_key.map(str::to_owned),
+        });
         Ok(())
     }
 
     pub async fn delete_vhost(&self, vhost: &str, idempotently: bool) -> Result<()> {
+        self.ensure_virtual_host_unprotected(vhost)?;
         let excludes = if idempotently {
             Some(StatusCode::NOT_FOUND)
         } else {
@@ -815,10 +1605,14 @@ where
         let _response = self
             .http_delete(path!("vhosts", vhost), excludes, None)
             .await?;
+        self.audit(|| Mutation::DeletedVirtualHost {
+            name: vhost.to_owned(),
+        });
         Ok(())
     }
 
     pub async fn delete_user(&self, username: &str, idempotently: bool) -> Result<()> {
+        self.ensure_name_unprotected("user", username)?;
         let excludes = if idempotently {
             Some(StatusCode::NOT_FOUND)
         } else {
@@ -827,14 +1621,23 @@ where
         let _response = self
             .http_delete(path!("users", username), excludes, None)
             .await?;
+        self.audit(|| Mutation::DeletedUser {
+            name: username.to_owned(),
+        });
         Ok(())
     }
 
     pub async fn delete_users(&self, usernames: Vec<&str>) -> Result<()> {
+        for username in &usernames {
+            self.ensure_name_unprotected("user", username)?;
+        }
         let delete = BulkUserDelete { usernames };
         let _response = self
             .http_post(path!("users", "bulk-delete"), &delete, None, None)
             .await?;
+        self.audit(|| Mutation::DeletedUsers {
+            names: delete.usernames.iter().map(|s| (*s).to_owned()).collect(),
+        });
         Ok(())
     }
 
@@ -852,10 +1655,15 @@ where
         let _response = self
             .http_delete(path!("permissions", vhost, username), excludes, None)
             .await?;
+        self.audit(|| Mutation::ClearedPermissions {
+            vhost: vhost.to_owned(),
+            user: username.to_owned(),
+        });
         Ok(())
     }
 
     pub async fn delete_queue(&self, vhost: &str, name: &str, idempotently: bool) -> Result<()> {
+        self.ensure_object_unprotected("queue", vhost, name)?;
         let excludes = if idempotently {
             Some(StatusCode::NOT_FOUND)
         } else {
@@ -864,6 +1672,10 @@ where
         let _response = self
             .http_delete(path!("queues", vhost, name), excludes, None)
             .await?;
+        self.audit(|| Mutation::DeletedQueue {
+            vhost: vhost.to_owned(),
+            name: name.to_owned(),
+        });
         Ok(())
     }
 
@@ -872,6 +1684,7 @@ where
     }
 
     pub async fn delete_exchange(&self, vhost: &str, name: &str, idempotently: bool) -> Result<()> {
+        self.ensure_object_unprotected("exchange", vhost, name)?;
         let excludes = if idempotently {
             Some(StatusCode::NOT_FOUND)
         } else {
@@ -880,6 +1693,10 @@ where
         let _response = self
             .http_delete(path!("exchanges", vhost, name), excludes, None)
             .await?;
+        self.audit(|| Mutation::DeletedExchange {
+            vhost: vhost.to_owned(),
+            name: name.to_owned(),
+        });
         Ok(())
     }
 
@@ -941,6 +1758,13 @@ where
                     }
                 };
                 let response = self.http_delete(&path, None, None).await?;
+                self.audit(|| Mutation::DeletedBinding {
+                    vhost: virtual_host.to_owned(),
+                    source: source.to_owned(),
+                    destination: destination.to_owned(),
+                    destination_type: destination_type.clone(),
+                    routing_key: routing_key.to_owned(),
+                });
                 Ok(response)
             }
             _ => Err(Error::MultipleMatchingBindings),
@@ -948,15 +1772,20 @@ where
     }
 
     pub async fn purge_queue(&self, virtual_host: &str, name: &str) -> Result<()> {
+        self.ensure_object_unprotected("queue", virtual_host, name)?;
         let _response = self
             .http_delete(path!("queues", virtual_host, name, "contents"), None, None)
             .await?;
+        self.audit(|| Mutation::PurgedQueue {
+            vhost: virtual_host.to_owned(),
+            name: name.to_owned(),
+        });
         Ok(())
     }
 
     pub async fn list_runtime_parameters(&self) -> Result<Vec<responses::RuntimeParameter>> {
         let response = self.http_get("parameters", None, None).await?;
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
@@ -967,7 +1796,7 @@ where
         let response = self
             .http_get(path!("parameters", component), None, None)
             .await?;
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
@@ -979,7 +1808,7 @@ where
         let response = self
             .http_get(path!("parameters", component, vhost), None, None)
             .await?;
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
@@ -992,7 +1821,7 @@ where
         let response = self
             .http_get(path!("parameters", component, vhost, name), None, None)
             .await?;
-        let response = response.json().await?;
+        let response = self.read_json(response).await?;
         Ok(response)
     }
 
@@ -1008,6 +1837,11 @@ where
                 None,
             )
             .await?;
+        self.audit(|| Mutation::UpsertedRuntimeParameter {
+            component: param.component.to_owned(),
+            vhost: param.vhost.to_owned(),
+            name: param.name.to_owned(),
+        });
         Ok(())
     }
 
@@ -1020,6 +1854,11 @@ where
         let _response = self
             .http_delete(path!("parameters", component, vhost, name), None, None)
             .await?;
+        self.audit(|| Mutation::ClearedRuntimeParameter {
+            component: component.to_owned(),
+            vhost: vhost.to_owned(),
+            name: name.to_owned(),
+        });
         Ok(())
     }
 
@@ -1045,7 +1884,7 @@ where
         &self,
     ) -> Result<Vec<responses::GlobalRuntimeParameter>> {
         let response = self.http_get("global-parameters", None, None).await?;
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
@@ -1056,7 +1895,7 @@ where
         let response = self
             .http_get(path!("global-parameters", name), None, None)
             .await?;
-        let response = response.json().await?;
+        let response = self.read_json(response).await?;
         Ok(response)
     }
 
@@ -1067,6 +1906,9 @@ where
         let _response = self
             .http_put(path!("global-parameters", param.name), &param, None, None)
             .await?;
+        self.audit(|| Mutation::UpsertedGlobalRuntimeParameter {
+            name: param.name.to_owned(),
+        });
         Ok(())
     }
 
@@ -1074,6 +1916,9 @@ where
         let _response = self
             .http_delete(path!("global-parameters", name), None, None)
             .await?;
+        self.audit(|| Mutation::ClearedGlobalRuntimeParameter {
+            name: name.to_owned(),
+        });
         Ok(())
     }
 
@@ -1091,6 +1936,11 @@ where
                 None,
             )
             .await?;
+        self.audit(|| Mutation::SetUserLimit {
+            user: username.to_owned(),
+            kind: limit.kind.clone(),
+            value: limit.value,
+        });
         Ok(())
     }
 
@@ -1098,12 +1948,16 @@ where
         let _response = self
             .http_delete(path!("user-limits", username, kind), None, None)
             .await?;
+        self.audit(|| Mutation::ClearedUserLimit {
+            user: username.to_owned(),
+            kind: kind.clone(),
+        });
         Ok(())
     }
 
     pub async fn list_all_user_limits(&self) -> Result<Vec<responses::UserLimits>> {
         let response = self.http_get("user-limits", None, None).await?;
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
@@ -1111,7 +1965,7 @@ where
         let response = self
             .http_get(path!("user-limits", username), None, None)
             .await?;
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
@@ -1124,6 +1978,11 @@ where
         let _response = self
             .http_put(path!("vhost-limits", vhost, limit.kind), &body, None, None)
             .await?;
+        self.audit(|| Mutation::SetVirtualHostLimit {
+            vhost: vhost.to_owned(),
+            kind: limit.kind.clone(),
+            value: limit.value,
+        });
         Ok(())
     }
 
@@ -1135,12 +1994,16 @@ where
                 None,
             )
             .await?;
+        self.audit(|| Mutation::ClearedVirtualHostLimit {
+            vhost: vhost.to_owned(),
+            kind: kind.clone(),
+        });
         Ok(())
     }
 
     pub async fn list_all_vhost_limits(&self) -> Result<Vec<responses::VirtualHostLimits>> {
         let response = self.http_get("vhost-limits", None, None).await?;
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
@@ -1151,19 +2014,22 @@ where
         let response = self
             .http_get(path!("vhost-limits", vhost), None, None)
             .await?;
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
     pub async fn get_cluster_name(&self) -> Result<responses::ClusterIdentity> {
         let response = self.http_get("cluster-name", None, None).await?;
-        let response = response.json().await?;
+        let response = self.read_json(response).await?;
         Ok(response)
     }
 
     pub async fn set_cluster_name(&self, new_name: &str) -> Result<()> {
         let body = json!({"name": new_name});
         let _response = self.http_put("cluster-name", &body, None, None).await?;
+        self.audit(|| Mutation::SetClusterName {
+            name: new_name.to_owned(),
+        });
         Ok(())
     }
 
@@ -1186,23 +2052,49 @@ where
         Ok(())
     }
 
+    /// Returns the mappings of client certificate distinguished names (or usernames)
+    /// to the virtual hosts MQTT clients connect to.
+    pub async fn get_mqtt_default_vhosts(&self) -> Result<responses::MqttDefaultVirtualHosts> {
+        let response = self
+            .get_global_runtime_parameter(MQTT_DEFAULT_VHOSTS_PARAMETER)
+            .await?;
+        Ok(responses::MqttDefaultVirtualHosts::from(response.value))
+    }
+
+    /// Sets the mappings of client certificate distinguished names (or usernames)
+    /// to virtual hosts used by MQTT clients, e.g. `{"O=client,CN=sensor-1": "sensors"}`.
+    pub async fn set_mqtt_default_vhosts(&self, mappings: Map<String, Value>) -> Result<()> {
+        let grp = GlobalRuntimeParameterDefinition {
+            name: MQTT_DEFAULT_VHOSTS_PARAMETER,
+            value: mappings,
+        };
+        self.upsert_global_runtime_parameter(&grp).await?;
+        Ok(())
+    }
+
+    pub async fn clear_mqtt_default_vhosts(&self) -> Result<()> {
+        self.clear_global_runtime_parameter(MQTT_DEFAULT_VHOSTS_PARAMETER)
+            .await?;
+        Ok(())
+    }
+
     pub async fn get_policy(&self, vhost: &str, name: &str) -> Result<responses::Policy> {
         let response = self
             .http_get(path!("policies", vhost, name), None, None)
             .await?;
-        let response = response.json().await?;
+        let response = self.read_json(response).await?;
         Ok(response)
     }
 
     pub async fn list_policies(&self) -> Result<Vec<responses::Policy>> {
         let response = self.http_get("policies", None, None).await?;
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
     pub async fn list_policies_in(&self, vhost: &str) -> Result<Vec<responses::Policy>> {
         let response = self.http_get(path!("policies", vhost), None, None).await?;
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
@@ -1215,6 +2107,10 @@ where
                 None,
             )
             .await?;
+        self.audit(|| Mutation::DeclaredPolicy {
+            vhost: params.vhost.to_owned(),
+            name: params.name.to_owned(),
+        });
         Ok(())
     }
 
@@ -1226,6 +2122,10 @@ where
                 None,
             )
             .await?;
+        self.audit(|| Mutation::DeletedPolicy {
+            vhost: vhost.to_owned(),
+            name: name.to_owned(),
+        });
         Ok(())
     }
 
@@ -1233,13 +2133,13 @@ where
         let response = self
             .http_get(path!("operator-policies", vhost, name), None, None)
             .await?;
-        let response = response.json().await?;
+        let response = self.read_json(response).await?;
         Ok(response)
     }
 
     pub async fn list_operator_policies(&self) -> Result<Vec<responses::Policy>> {
         let response = self.http_get("operator-policies", None, None).await?;
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
@@ -1247,7 +2147,7 @@ where
         let response = self
             .http_get(path!("operator-policies", vhost), None, None)
             .await?;
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
@@ -1260,6 +2160,10 @@ where
                 None,
             )
             .await?;
+        self.audit(|| Mutation::DeclaredOperatorPolicy {
+            vhost: params.vhost.to_owned(),
+            name: params.name.to_owned(),
+        });
         Ok(())
     }
 
@@ -1271,12 +2175,16 @@ where
                 None,
             )
             .await?;
+        self.audit(|| Mutation::DeletedOperatorPolicy {
+            vhost: vhost.to_owned(),
+            name: name.to_owned(),
+        });
         Ok(())
     }
 
     pub async fn list_permissions(&self) -> Result<Vec<responses::Permissions>> {
         let response = self.http_get("permissions", None, None).await?;
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
@@ -1284,7 +2192,7 @@ where
         let response = self
             .http_get(path!("vhosts", vhost, "permissions"), None, None)
             .await?;
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
@@ -1292,7 +2200,7 @@ where
         let response = self
             .http_get(path!("users", user, "permissions"), None, None)
             .await?;
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
@@ -1300,7 +2208,7 @@ where
         let response = self
             .http_get(path!("permissions", vhost, user), None, None)
             .await?;
-        let response = response.json().await?;
+        let response = self.read_json(response).await?;
         Ok(response)
     }
 
@@ -1311,6 +2219,103 @@ where
     pub async fn rebalance_queue_leaders(&self) -> Result<()> {
         self.http_post("rebalance/queues", &json!({}), None, None)
             .await?;
+        self.audit(|| Mutation::RebalancedQueueLeaders);
+        Ok(())
+    }
+
+    //
+    // Quorum queue replicas
+    //
+
+    /// Adds a replica of a quorum queue on the given node.
+    pub async fn add_quorum_queue_replica(
+        &self,
+        vhost: &str,
+        name: &str,
+        node: &str,
+    ) -> Result<()> {
+        let _response = self
+            .http_post(
+                path!("queues", "quorum", vhost, name, "replicas", "add"),
+                &json!({ "node": node }),
+                None,
+                None,
+            )
+            .await?;
+        self.audit(|| Mutation::AddedQuorumQueueReplica {
+            vhost: vhost.to_owned(),
+            name: name.to_owned(),
+            node: node.to_owned(),
+        });
+        Ok(())
+    }
+
+    /// Removes the replica of a quorum queue on the given node.
+    pub async fn delete_quorum_queue_replica(
+        &self,
+        vhost: &str,
+        name: &str,
+        node: &str,
+    ) -> Result<()> {
+        let _response = self
+            .http_delete_with_body(
+                path!("queues", "quorum", vhost, name, "replicas", "delete"),
+                &json!({ "node": node }),
+                None,
+                None,
+            )
+            .await?;
+        self.audit(|| Mutation::DeletedQuorumQueueReplica {
+            vhost: vhost.to_owned(),
+            name: name.to_owned(),
+            node: node.to_owned(),
+        });
+        Ok(())
+    }
+
+    /// Adds a replica on the given node to every quorum queue that matches the patterns,
+    /// e.g. after a new node joins the cluster.
+    ///
+    /// See [`QuorumQueueGrowthStrategy`] for which of the matching queues are grown.
+    pub async fn grow_quorum_queue(
+        &self,
+        node: &str,
+        vhost_pattern: &str,
+        queue_pattern: &str,
+        strategy: QuorumQueueGrowthStrategy,
+    ) -> Result<()> {
+        let body = json!({
+            "strategy": strategy,
+            "vhost_pattern": vhost_pattern,
+            "queue_pattern": queue_pattern,
+        });
+        let _response = self
+            .http_post(
+                path!("queues", "quorum", "replicas", "on", node, "grow"),
+                &body,
+                None,
+                None,
+            )
+            .await?;
+        self.audit(|| Mutation::GrewQuorumQueues {
+            node: node.to_owned(),
+        });
+        Ok(())
+    }
+
+    /// Removes the replicas on the given node from all quorum queues,
+    /// e.g. before the node is permanently removed from the cluster.
+    pub async fn shrink_quorum_queue(&self, node: &str) -> Result<()> {
+        let _response = self
+            .http_delete(
+                path!("queues", "quorum", "replicas", "on", node, "shrink"),
+                None,
+                None,
+            )
+            .await?;
+        self.audit(|| Mutation::ShrankQuorumQueues {
+            node: node.to_owned(),
+        });
         Ok(())
     }
 
@@ -1323,13 +2328,13 @@ where
 
     pub async fn export_cluster_wide_definitions_as_string(&self) -> Result<String> {
         let response = self.http_get("definitions", None, None).await?;
-        let response = response.text().await?;
+        let response = self.read_text(response).await?;
         Ok(response)
     }
 
     pub async fn export_cluster_wide_definitions_as_data(&self) -> Result<ClusterDefinitionSet> {
         let response = self.http_get("definitions", None, None).await?;
-        let response = response.json().await?;
+        let response = self.read_json(response).await?;
         Ok(response)
     }
 
@@ -1341,7 +2346,7 @@ where
         let response = self
             .http_get(path!("definitions", vhost), None, None)
             .await?;
-        let response = response.text().await?;
+        let response = self.read_text(response).await?;
         Ok(response)
     }
 
@@ -1352,10 +2357,198 @@ where
         let response = self
             .http_get(path!("definitions", vhost), None, None)
             .await?;
-        let response = response.json().await?;
+        let response = self.read_json(response).await?;
         Ok(response)
     }
 
+    /// Exports cluster-wide definitions and writes them to `writer` as they arrive,
+    /// without buffering the entire document in memory.
+    ///
+    /// Returns the number of bytes written. With [`ClientBuilder#with_max_response_size`],
+    /// a body of unknown size that exceeds the limit fails with [`Error::ResponseTooLarge`]
+    /// after the chunks received within the limit have been written.
+    pub async fn export_definitions_to_writer<W>(&self, writer: &mut W) -> Result<u64>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        let response = self.http_get("definitions", None, None).await?;
+        self.copy_body_to_writer(response, writer).await
+    }
+
+    /// Exports definitions of a single virtual host and writes them to `writer` as they arrive.
+    ///
+    /// See [`Client::export_definitions_to_writer`].
+    pub async fn export_vhost_definitions_to_writer<W>(
+        &self,
+        vhost: &str,
+        writer: &mut W,
+    ) -> Result<u64>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        let response = self
+            .http_get(path!("definitions", vhost), None, None)
+            .await?;
+        self.copy_body_to_writer(response, writer).await
+    }
+
+    /// Applies a single operation produced by [`responses::ClusterDefinitionSet::operations`].
+    ///
+    /// Applying operations one by one is slower than [`Client::import_cluster_wide_definitions`]
+    /// but makes it possible to report progress and handle errors for individual objects.
+    pub async fn apply_definition_operation(&self, op: &DefinitionOperation<'_>) -> Result<()> {
+        match op {
+            DefinitionOperation::CreateVirtualHost(params) => self.create_vhost(params).await,
+            DefinitionOperation::CreateUser(user) => {
+                let tags = user.tags.0.join(",");
+                let params = UserParams {
+                    name: &user.name,
+                    password_hash: &user.password_hash,
+                    tags: &tags,
+                    hashing_algorithm: user.parsed_hashing_algorithm()?,
+                };
+                self.create_user(&params).await
+            }
+            DefinitionOperation::DeclarePermissions(params) => {
+                self.declare_permissions(params).await
+            }
+            DefinitionOperation::UpsertRuntimeParameter(param) => {
+                self.upsert_runtime_parameter(param).await
+            }
+            DefinitionOperation::DeclarePolicy(params) => self.declare_policy(params).await,
+            DefinitionOperation::DeclareExchange { vhost, params } => {
+                self.declare_exchange(vhost, params).await
+            }
+            DefinitionOperation::DeclareQueue { vhost, params } => {
+                self.declare_queue(vhost, params).await
+            }
+            DefinitionOperation::DeclareBinding(b) => {
+                let arguments = if b.arguments.0.is_empty() {
+                    None
+                } else {
+                    Some(b.arguments.0.clone())
+                };
+                match b.destination_type {
+                    BindingDestinationType::Queue => {
+                        self.bind_queue(
+                            &b.vhost,
+                            &b.destination,
+                            &b.source,
+                            Some(&b.routing_key),
+                            arguments,
+                        )
+                        .await
+                    }
+                    BindingDestinationType::Exchange => {
+                        self.bind_exchange(
+                            &b.vhost,
+                            &b.destination,
+                            &b.source,
+                            Some(&b.routing_key),
+                            arguments,
+                        )
+                        .await
+                    }
+                }
+            }
+        }
+    }
+
+    /// Declares the policies, exchanges, queues and bindings of the given definition set,
+    /// for example, one produced by [`crate::topology_generator::TopologyGenerator`].
+    /// Other objects in the set, e.g. users or virtual hosts, are ignored.
+    ///
+    /// Up to `max_concurrency` requests are issued at a time. Bindings are declared
+    /// after all exchanges and queues. Stops at the first error.
+    pub async fn declare_topology(
+        &self,
+        topology: &ClusterDefinitionSet,
+        max_concurrency: usize,
+    ) -> Result<()> {
+        let (bindings, others): (Vec<_>, Vec<_>) = topology
+            .operations()
+            .into_iter()
+            .filter(|op| {
+                matches!(
+                    op,
+                    DefinitionOperation::DeclarePolicy(_)
+                        | DefinitionOperation::DeclareExchange { .. }
+                        | DefinitionOperation::DeclareQueue { .. }
+                        | DefinitionOperation::DeclareBinding(_)
+                )
+            })
+            .partition(|op| matches!(op, DefinitionOperation::DeclareBinding(_)));
+        stream::iter(others.iter().map(Ok))
+            .try_for_each_concurrent(max_concurrency.max(1), |op| {
+                self.apply_definition_operation(op)
+            })
+            .await?;
+        stream::iter(bindings.iter().map(Ok))
+            .try_for_each_concurrent(max_concurrency.max(1), |op| {
+                self.apply_definition_operation(op)
+            })
+            .await
+    }
+
+    /// Deletes the queues, exchanges and policies of the given definition set, for example,
+    /// one produced by [`crate::topology_generator::TopologyGenerator`]. Their bindings are
+    /// deleted together with them. Objects that do not exist are skipped.
+    ///
+    /// Up to `max_concurrency` requests are issued at a time. Stops at the first error.
+    pub async fn delete_topology(
+        &self,
+        topology: &ClusterDefinitionSet,
+        max_concurrency: usize,
+    ) -> Result<()> {
+        stream::iter(topology.queues.iter().map(Ok))
+            .try_for_each_concurrent(max_concurrency.max(1), |q| {
+                self.delete_queue(&q.vhost, &q.name, true)
+            })
+            .await?;
+        stream::iter(topology.exchanges.iter().map(Ok))
+            .try_for_each_concurrent(max_concurrency.max(1), |x| {
+                self.delete_exchange(&x.vhost, &x.name, true)
+            })
+            .await?;
+        stream::iter(topology.policies.iter().map(Ok))
+            .try_for_each_concurrent(max_concurrency.max(1), |p| {
+                self.delete_policy(&p.vhost, &p.name)
+            })
+            .await
+    }
+
+    /// Exports cluster-wide definitions and writes them to a file.
+    ///
+    /// The definitions are first written to a temporary file in the same directory
+    /// which is flushed to disk and then renamed, so `path` never contains a partially written backup.
+    /// Paths with the `.gz` extension are compressed with gzip, which requires the `gzip` feature:
+    /// without it, [`crate::error::Error::FeatureNotEnabled`] is returned before anything is exported.
+    pub async fn backup_definitions_to_file<T>(&self, path: T) -> Result<()>
+    where
+        T: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        definition_files::check_path(path)?;
+        let definitions = self.export_cluster_wide_definitions_as_string().await?;
+        let bytes = definition_files::encode(path, &definitions)?;
+
+        definition_files::write_atomically(path, &bytes).await
+    }
+
+    /// Reads definitions from a file produced by [`Client::backup_definitions_to_file`]
+    /// (or exported by other means) and imports them.
+    ///
+    /// Gzip-compressed files are detected automatically. The file is checked to contain
+    /// a definitions document before anything is imported.
+    pub async fn restore_definitions_from_file<T>(&self, path: T) -> Result<()>
+    where
+        T: AsRef<Path>,
+    {
+        let bytes = tokio::fs::read(path).await?;
+        let definitions = definition_files::decode(&bytes)?;
+        self.import_cluster_wide_definitions(definitions).await
+    }
+
     pub async fn import_definitions(&self, definitions: Value) -> Result<()> {
         self.import_cluster_wide_definitions(definitions).await
     }
@@ -1363,12 +2556,49 @@ where
     pub async fn import_cluster_wide_definitions(&self, definitions: Value) -> Result<()> {
         self.http_post("definitions", &definitions, None, None)
             .await?;
+        self.audit(|| Mutation::ImportedDefinitions { vhost: None });
         Ok(())
     }
 
     pub async fn import_vhost_definitions(&self, vhost: &str, definitions: Value) -> Result<()> {
         self.http_post(path!("definitions", vhost), &definitions, None, None)
             .await?;
+        self.audit(|| Mutation::ImportedDefinitions {
+            vhost: Some(vhost.to_owned()),
+        });
+        Ok(())
+    }
+
+    /// Imports cluster-wide definitions read from `reader`. The request body is streamed
+    /// from the reader, so the document is never fully loaded into memory.
+    ///
+    /// Unlike [`Client::import_cluster_wide_definitions`], the document is not
+    /// validated before it is sent, the server will reject invalid definitions.
+    pub async fn import_definitions_from_reader<R>(&self, reader: R) -> Result<()>
+    where
+        R: AsyncRead + Send + 'static,
+    {
+        self.http_post_stream("definitions", reader).await?;
+        self.audit(|| Mutation::ImportedDefinitions { vhost: None });
+        Ok(())
+    }
+
+    /// Imports definitions of a single virtual host read from `reader`.
+    ///
+    /// See [`Client::import_definitions_from_reader`].
+    pub async fn import_vhost_definitions_from_reader<R>(
+        &self,
+        vhost: &str,
+        reader: R,
+    ) -> Result<()>
+    where
+        R: AsyncRead + Send + 'static,
+    {
+        self.http_post_stream(path!("definitions", vhost), reader)
+            .await?;
+        self.audit(|| Mutation::ImportedDefinitions {
+            vhost: Some(vhost.to_owned()),
+        });
         Ok(())
     }
 
@@ -1376,6 +2606,24 @@ where
     // Health Checks
     //
 
+    /// Performs an aliveness test: the node declares a test queue in the given virtual host,
+    /// publishes a message to it and consumes it.
+    ///
+    /// This is an older, coarse-grained check. Prefer the more specific `health_check_*` functions
+    /// on RabbitMQ versions that provide them.
+    pub async fn aliveness_test(&self, vhost: &str) -> Result<responses::AlivenessTestResult> {
+        // failed tests are reported with a 503 Service Unavailable response with a body
+        let response = self
+            .http_get(
+                path!("aliveness-test", vhost),
+                None,
+                Some(StatusCode::SERVICE_UNAVAILABLE),
+            )
+            .await?;
+        let response = self.read_json(response).await?;
+        Ok(response)
+    }
+
     pub async fn health_check_cluster_wide_alarms(&self) -> Result<()> {
         self.health_check_alarms("health/checks/alarms").await
     }
@@ -1389,6 +2637,14 @@ where
         self.boolean_health_check(path).await
     }
 
+    /// Checks if the node has classic mirrored queues without online synchronised mirrors.
+    ///
+    /// Only relevant for RabbitMQ 3.13 and earlier versions, classic queue mirroring was removed in RabbitMQ 4.0.
+    pub async fn health_check_if_node_is_mirror_sync_critical(&self) -> Result<()> {
+        let path = "health/checks/node-is-mirror-sync-critical";
+        self.boolean_health_check(path).await
+    }
+
     pub async fn health_check_port_listener(&self, port: u16) -> Result<()> {
         let port_s = port.to_string();
         let path = path!("health", "checks", "port-listener", port_s);
@@ -1401,19 +2657,182 @@ where
         self.boolean_health_check(&path).await
     }
 
-    async fn boolean_health_check(&self, path: &str) -> std::result::Result<(), HttpClientError> {
-        // we expect that StatusCode::SERVICE_UNAVAILABLE may be return and ignore
-        // it here to provide a custom error type later
-        let response = self
-            .http_get(path, None, Some(StatusCode::SERVICE_UNAVAILABLE))
-            .await?;
+    /// Checks if any of the TLS-enabled listeners on the target node use certificates
+    /// that expire within the given time window, e.g. 4 weeks.
+    pub async fn health_check_certificate_expiration(
+        &self,
+        within: u32,
+        unit: TimeUnit,
+    ) -> Result<()> {
+        let within_s = within.to_string();
+        let unit_s = String::from(unit);
+        let path = path!(
+            "health",
+            "checks",
+            "certificate-expiration",
+            within_s,
+            unit_s
+        );
+        self.boolean_health_check(&path).await
+    }
 
-        let status_code = response.status();
+    /// Checks if all virtual hosts are running on the target node.
+    pub async fn health_check_virtual_hosts(&self) -> Result<()> {
+        let path = "health/checks/virtual-hosts";
+        self.boolean_health_check(path).await
+    }
+
+    /// Checks if there are quorum queues without an elected leader in any virtual host.
+    /// The affected queues are listed in the failure details,
+    /// see [`responses::HealthCheckFailureDetails::QuorumQueuesWithoutElectedLeaders`].
+    pub async fn health_check_quorum_queues_without_elected_leaders(&self) -> Result<()> {
+        let path = "health/checks/quorum-queues-without-elected-leaders/all-vhosts";
+        self.quorum_queues_without_elected_leaders_health_check(path)
+            .await
+    }
+
+    /// Like [`Client::health_check_quorum_queues_without_elected_leaders`] but only checks
+    /// the queues with names that match the given regular expression.
+    pub async fn health_check_quorum_queues_without_elected_leaders_matching(
+        &self,
+        pattern: &str,
+    ) -> Result<()> {
+        let path = path!(
+            "health",
+            "checks",
+            "quorum-queues-without-elected-leaders",
+            "all-vhosts",
+            "pattern",
+            pattern
+        );
+        self.quorum_queues_without_elected_leaders_health_check(&path)
+            .await
+    }
+
+    /// Like [`Client::health_check_quorum_queues_without_elected_leaders`] but only checks
+    /// the queues in the given virtual host and, optionally, with names that match
+    /// the given regular expression.
+    pub async fn health_check_quorum_queues_without_elected_leaders_in(
+        &self,
+        vhost: &str,
+        pattern: Option<&str>,
+    ) -> Result<()> {
+        let path = match pattern {
+            Some(pattern) => path!(
+                "health",
+                "checks",
+                "quorum-queues-without-elected-leaders",
+                "vhost",
+                vhost,
+                "pattern",
+                pattern
+            ),
+            None => path!(
+                "health",
+                "checks",
+                "quorum-queues-without-elected-leaders",
+                "vhost",
+                vhost
+            ),
+        };
+        self.quorum_queues_without_elected_leaders_health_check(&path)
+            .await
+    }
+
+    /// Checks if the metadata store (Khepri on RabbitMQ 4.x) has been initialized on the target node.
+    pub async fn health_check_metadata_store_initialized(&self) -> Result<()> {
+        let path = "health/checks/metadata-store/initialized";
+        self.boolean_health_check(path).await
+    }
+
+    /// Like [`Client::health_check_metadata_store_initialized`] but also checks that
+    /// the metadata store has been populated with data, e.g. synced from its peers.
+    pub async fn health_check_metadata_store_initialized_with_data(&self) -> Result<()> {
+        let path = "health/checks/metadata-store/initialized/with-data";
+        self.boolean_health_check(path).await
+    }
+
+    /// Periodically performs the health checks listed in `settings` and invokes `on_event`
+    /// for every (debounced) state transition, that is, when an alarm is raised or cleared.
+    ///
+    /// Health checks that cannot be performed, e.g. because the node is temporarily unreachable,
+    /// are reported as [`AlarmEvent::CheckFailed`] and retried in the next round.
+    /// Runs until `on_event` returns [`ControlFlow::Break`].
+    pub async fn watch_alarms<F>(
+        &self,
+        settings: &AlarmWatcherSettings,
+        mut on_event: F,
+    ) -> Result<()>
+    where
+        F: FnMut(AlarmEvent) -> ControlFlow<()>,
+    {
+        let mut tracker = AlarmStateTracker::new(settings.debounce);
+        loop {
+            for check in &settings.checks {
+                let result = match check {
+                    WatchedCheck::ClusterWideAlarms => {
+                        self.health_check_cluster_wide_alarms().await
+                    }
+                    WatchedCheck::LocalAlarms => self.health_check_local_alarms().await,
+                    WatchedCheck::NodeIsQuorumCritical => {
+                        self.health_check_if_node_is_quorum_critical().await
+                    }
+                };
+                let event = match result {
+                    Ok(()) => tracker.observe(*check, None),
+                    Err(Error::HealthCheckFailed { details, .. }) => {
+                        tracker.observe(*check, Some(details))
+                    }
+                    Err(e) => Some(AlarmEvent::CheckFailed {
+                        check: *check,
+                        error: e.to_string(),
+                    }),
+                };
+
+                if let Some(event) = event {
+                    if on_event(event).is_break() {
+                        return Ok(());
+                    }
+                }
+            }
+
+            tokio::time::sleep(settings.interval).await;
+        }
+    }
+
+    async fn boolean_health_check(&self, path: &str) -> std::result::Result<(), HttpClientError> {
+        self.health_check_with_details(path, responses::HealthCheckFailureDetails::from_body)
+            .await
+    }
+
+    async fn quorum_queues_without_elected_leaders_health_check(&self, path: &str) -> Result<()> {
+        // the failure details have the same shape as those of the quorum criticality
+        // check, so they have to be parsed explicitly
+        self.health_check_with_details(path, |body| {
+            serde_json::from_str(body)
+                .map(responses::HealthCheckFailureDetails::QuorumQueuesWithoutElectedLeaders)
+                .unwrap_or_else(|_| responses::HealthCheckFailureDetails::from_body(body))
+        })
+        .await
+    }
+
+    async fn health_check_with_details<F>(&self, path: &str, parse_details: F) -> Result<()>
+    where
+        F: FnOnce(&str) -> responses::HealthCheckFailureDetails,
+    {
+        // we expect that StatusCode::SERVICE_UNAVAILABLE may be return and ignore
+        // it here to provide a custom error type later
+        let response = self
+            .http_get(path, None, Some(StatusCode::SERVICE_UNAVAILABLE))
+            .await?;
+
+        let status_code = response.status();
         if status_code.is_success() {
             return Ok(());
         }
 
-        let failure_details = response.json().await?;
+        let body = self.read_text(response).await?;
+        let failure_details = parse_details(&body);
         Err(Error::HealthCheckFailed {
             path: path.to_owned(),
             status_code,
@@ -1421,6 +2840,101 @@ where
         })
     }
 
+    //
+    // Readiness
+    //
+
+    /// Waits for the HTTP API to respond and for the node that serves it to report
+    /// itself as running. Checks are retried with an exponential backoff until
+    /// `timeout` elapses, in which case [`Error::NotReady`] is returned.
+    ///
+    /// Individual checks are also bounded by `timeout`, so a connection attempt
+    /// or a response that hangs during node startup does not extend the wait.
+    ///
+    /// Meant to be used right after node startup, e.g. in CI or provisioning scripts.
+    pub async fn await_ready(&self, timeout: Duration) -> Result<()> {
+        let started_at = Instant::now();
+        let deadline = match self.deadline {
+            Some(d) => d.min(started_at + timeout),
+            None => started_at + timeout,
+        };
+        let probing = self.with_deadline(deadline);
+        let mut delay = READINESS_CHECK_INITIAL_DELAY;
+
+        loop {
+            if probing.is_ready().await {
+                return Ok(());
+            }
+            if self.is_past_deadline() {
+                return Err(Error::DeadlineExceeded);
+            }
+
+            let elapsed = started_at.elapsed();
+            if elapsed >= timeout {
+                return Err(Error::NotReady { timeout });
+            }
+
+            tokio::time::sleep(delay.min(timeout - elapsed)).await;
+            delay = (delay * 2).min(READINESS_CHECK_MAX_DELAY);
+        }
+    }
+
+    //
+    // Node discovery
+    //
+
+    /// Lists cluster nodes and constructs a client for every one of them,
+    /// e.g. to use node-local endpoints such as health checks on every node.
+    ///
+    /// `endpoint_of` maps a node to the HTTP API endpoint of that node, for example,
+    /// `|node| format!("http://{}:15672/api", node.hostname())`.
+    /// The clients share the credentials, the API prefix and the underlying HTTP client with this one.
+    /// Credentials changed later with [`Client::update_credentials`], on this client
+    /// or on any of the node clients, are used by all of them.
+    pub async fn discover_node_clients<F>(
+        &self,
+        endpoint_of: F,
+    ) -> Result<Vec<(String, NodeClient)>>
+    where
+        F: Fn(&responses::ClusterNode) -> String,
+    {
+        let nodes = self.list_nodes().await?;
+        let clients = nodes
+            .iter()
+            .map(|node| {
+                let (username, password) = self.credentials();
+                let mut inner = ClientInner::new(
+                    self.inner.client.clone(),
+                    endpoint_of(node),
+                    username,
+                    password,
+                );
+                inner.rotated_credentials = Arc::clone(&self.inner.rotated_credentials);
+                inner.api_prefix = self.inner.api_prefix.clone();
+                inner.protected_entities = self.inner.protected_entities.clone();
+                inner.payload_size_guard = self.inner.payload_size_guard.clone();
+                inner.request_ids = self.inner.request_ids.clone();
+                inner.max_response_size = self.inner.max_response_size;
+                inner.lenient_listings = self.inner.lenient_listings.clone();
+                // every node gets its own circuit breaker
+                inner.circuit_breaker = self
+                    .inner
+                    .circuit_breaker
+                    .as_ref()
+                    .map(|cb| CircuitBreaker::new(cb.settings()));
+                inner.audit_hook = self.inner.audit_hook.clone();
+                let client = Client {
+                    inner: Arc::new(inner),
+                    deadline: self.deadline,
+                    protection_overridden: self.protection_overridden,
+                };
+                (node.name.clone(), client)
+            })
+            .collect();
+
+        Ok(clients)
+    }
+
     //
     // Federation
     //
@@ -1432,16 +2946,14 @@ where
         let upstreams = response
             .into_iter()
             .map(FederationUpstream::try_from)
-            // TODO: in theory this can be an Err
-            .map(|r| r.unwrap())
-            .collect::<Vec<_>>();
+            .collect::<std::result::Result<Vec<_>, _>>()?;
 
         Ok(upstreams)
     }
 
     pub async fn list_federation_links(&self) -> Result<Vec<responses::FederationLink>> {
         let response = self.http_get("federation-links", None, None).await?;
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
@@ -1466,10 +2978,25 @@ where
 
     pub async fn list_shovels(&self) -> Result<Vec<responses::Shovel>> {
         let response = self.http_get("shovels", None, None).await?;
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
         Ok(response)
     }
 
+    /// Lists dynamic shovels in the given virtual host.
+    pub async fn list_shovels_in(&self, vhost: &str) -> Result<Vec<responses::Shovel>> {
+        let response = self.http_get(path!("shovels", vhost), None, None).await?;
+        let response = self.deserialize_list(response).await?;
+        Ok(response)
+    }
+
+    /// Declares a dynamic shovel of either protocol. See [`Client::declare_amqp091_shovel`]
+    /// and [`Client::declare_amqp10_shovel`].
+    pub async fn declare_shovel(&self, params: ShovelParams<'_>) -> Result<()> {
+        let runtime_param = RuntimeParameterDefinition::from(params);
+
+        self.declare_shovel_parameters(&runtime_param).await
+    }
+
     pub async fn declare_amqp091_shovel(&self, params: Amqp091ShovelParams<'_>) -> Result<()> {
         let runtime_param = RuntimeParameterDefinition::from(params);
 
@@ -1491,6 +3018,27 @@ where
         let _response = self
             .http_delete(path!("shovels", "vhost", vhost, name), excludes, None)
             .await?;
+        self.audit(|| Mutation::DeletedShovel {
+            vhost: vhost.to_owned(),
+            name: name.to_owned(),
+        });
+        Ok(())
+    }
+
+    /// Restarts a dynamic shovel, e.g. one that has stopped because its source or destination
+    /// was temporarily unavailable. Use [`Client::list_shovels`] to find out the state of shovels.
+    pub async fn restart_shovel(&self, vhost: &str, name: &str) -> Result<()> {
+        let _response = self
+            .http_delete(
+                path!("shovels", "vhost", vhost, name, "restart"),
+                None,
+                None,
+            )
+            .await?;
+        self.audit(|| Mutation::RestartedShovel {
+            vhost: vhost.to_owned(),
+            name: name.to_owned(),
+        });
         Ok(())
     }
 
@@ -1506,23 +3054,38 @@ where
         payload: &str,
         properties: requests::MessageProperties,
     ) -> Result<responses::MessageRouted> {
-        let body = serde_json::json!({
-          "routing_key": routing_key,
-          "payload": payload,
-          "payload_encoding": "string",
-          "properties": properties,
-        });
+        self.publish_message_with_payload_encoding(
+            vhost,
+            exchange,
+            routing_key,
+            payload,
+            PayloadEncoding::String,
+            properties,
+        )
+        .await
+    }
 
-        let response = self
-            .http_post(
-                path!("exchanges", vhost, exchange, "publish"),
-                &body,
-                None,
-                None,
-            )
-            .await?;
-        let response = response.json().await?;
-        Ok(response)
+    /// Publishes a message with a binary payload. The payload is Base64-encoded
+    /// and published with the `base64` payload encoding, so the broker routes
+    /// the original bytes.
+    pub async fn publish_binary_message(
+        &self,
+        vhost: &str,
+        exchange: &str,
+        routing_key: &str,
+        payload: &[u8],
+        properties: requests::MessageProperties,
+    ) -> Result<responses::MessageRouted> {
+        let encoded = rbase64::encode(payload);
+        self.publish_message_with_payload_encoding(
+            vhost,
+            exchange,
+            routing_key,
+            &encoded,
+            PayloadEncoding::Base64,
+            properties,
+        )
+        .await
     }
 
     pub async fn get_messages(
@@ -1541,19 +3104,394 @@ where
         let response = self
             .http_post(path!("queues", vhost, queue, "get"), &body, None, None)
             .await?;
-        let response = response.json().await?;
+        let response = self.read_json(response).await?;
         Ok(response)
     }
 
+    /// Fetches messages from a queue and republishes them with their original properties,
+    /// e.g. to replay messages from a dead-letter queue.
+    ///
+    /// Messages are fetched in batches of `params.batch_size` until the source queue is empty
+    /// or `params.max_messages` messages were republished. Replay also stops after a batch
+    /// that contains a message identical to one already republished, e.g. when the destination
+    /// routes messages back to the source queue. In the dry run mode, a single batch is
+    /// fetched and requeued and nothing is republished. Note that requeueing sets the `redelivered`
+    /// flag, so every message inspected in the dry run mode will be marked as redelivered.
+    ///
+    /// Fetched messages are removed from the source queue before they are republished.
+    /// If a message cannot be republished or is not routed to any queue, the messages of the current
+    /// batch that were not republished are returned in [`HttpClientError::MessageReplayInterrupted`].
+    ///
+    /// Returns the messages that were republished (or would be, in the dry run mode).
+    pub async fn replay_messages(
+        &self,
+        params: &requests::MessageReplayParams<'_>,
+    ) -> Result<Vec<GetMessage>> {
+        let mut replayed: Vec<GetMessage> = Vec::new();
+
+        loop {
+            let count = params.next_batch_size(replayed.len() as u32);
+            if count == 0 {
+                break;
+            }
+
+            if params.dry_run {
+                let batch = self
+                    .get_messages(params.vhost, params.source_queue, count, "ack_requeue_true")
+                    .await?;
+                replayed.extend(batch);
+                break;
+            }
+
+            let batch = self
+                .get_messages(
+                    params.vhost,
+                    params.source_queue,
+                    count,
+                    "ack_requeue_false",
+                )
+                .await?;
+            let fetched = batch.len() as u32;
+            let refetched = batch
+                .iter()
+                .any(|msg| replayed.iter().any(|r| is_same_message(r, msg)));
+
+            let mut pending = batch.into_iter();
+            while let Some(msg) = pending.next() {
+                let routing_key = params
+                    .destination_routing_key
+                    .unwrap_or(msg.routing_key.as_str());
+                let failure = match self
+                    .publish_message_with_payload_encoding(
+                        params.vhost,
+                        params.destination_exchange,
+                        routing_key,
+                        &msg.payload,
+                        msg.payload_encoding,
+                        requests::MessageProperties::from(&msg.properties),
+                    )
+                    .await
+                {
+                    Ok(responses::MessageRouted { routed: true }) => None,
+                    Ok(_) => Some(HttpClientError::MessageNotRouted {
+                        exchange: params.destination_exchange.to_owned(),
+                        routing_key: routing_key.to_owned(),
+                    }),
+                    Err(e) => Some(e),
+                };
+
+                if let Some(cause) = failure {
+                    let mut unreplayed = vec![msg];
+                    unreplayed.extend(pending);
+                    return Err(HttpClientError::MessageReplayInterrupted {
+                        replayed,
+                        unreplayed,
+                        cause: Box::new(cause),
+                    });
+                }
+                replayed.push(msg);
+            }
+
+            if fetched < count || refetched {
+                break;
+            }
+        }
+
+        Ok(replayed)
+    }
+
+    /// Drains a queue into a [JSON Lines](https://jsonlines.org/) file, one message
+    /// (payload, properties and routing information) per line, e.g. for incident forensics
+    /// or to move messages to a different cluster using [`Client::publish_messages_from_file`].
+    ///
+    /// Messages are fetched in batches of `batch_size` until the queue is empty. Every batch is
+    /// appended to the file as soon as it is fetched, after which `on_progress` is invoked
+    /// with the number of messages drained so far.
+    ///
+    /// The file is created by this function: if `path` already exists, an I/O error
+    /// of the [`std::io::ErrorKind::AlreadyExists`] kind is returned and nothing is drained.
+    ///
+    /// Note that fetched messages are acknowledged before they are written to the file,
+    /// so an I/O error can result in the loss of the current batch. Since every message
+    /// is transferred over HTTP, this is only meant to be used with small queues.
+    ///
+    /// Returns the number of drained messages.
+    pub async fn drain_queue_to_file<T, F>(
+        &self,
+        vhost: &str,
+        queue: &str,
+        path: T,
+        batch_size: u32,
+        mut on_progress: F,
+    ) -> Result<u64>
+    where
+        T: AsRef<Path>,
+        F: FnMut(u64),
+    {
+        let mut file = tokio::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(path)
+            .await?;
+        let batch_size = batch_size.max(1);
+        let mut drained: u64 = 0;
+
+        loop {
+            let batch = self
+                .get_messages(vhost, queue, batch_size, "ack_requeue_false")
+                .await?;
+            let fetched = batch.len() as u32;
+            if fetched > 0 {
+                file.write_all(&message_files::encode(&batch)?).await?;
+                drained += u64::from(fetched);
+                on_progress(drained);
+            }
+
+            if fetched < batch_size {
+                break;
+            }
+        }
+
+        file.sync_all().await?;
+        Ok(drained)
+    }
+
+    /// Publishes messages from a file produced by [`Client::drain_queue_to_file`].
+    ///
+    /// Every message is published with its original properties to its original exchange
+    /// using its original routing key, unless `exchange` or `routing_key` are provided.
+    /// `on_progress` is invoked with the number of messages published so far after every message.
+    ///
+    /// The entire file is parsed before anything is published. Publishing stops at the first
+    /// message that is not routed to any queue, returning [`crate::error::Error::MessageNotRouted`].
+    /// Returns the number of published messages.
+    pub async fn publish_messages_from_file<T, F>(
+        &self,
+        vhost: &str,
+        path: T,
+        exchange: Option<&str>,
+        routing_key: Option<&str>,
+        mut on_progress: F,
+    ) -> Result<u64>
+    where
+        T: AsRef<Path>,
+        F: FnMut(u64),
+    {
+        let bytes = tokio::fs::read(path).await?;
+        let messages = message_files::decode(&bytes)?;
+
+        let mut published: u64 = 0;
+        for msg in messages {
+            let exchange = exchange.unwrap_or_else(|| message_files::exchange_name(&msg.exchange));
+            let routing_key = routing_key.unwrap_or(msg.routing_key.as_str());
+            let outcome = self
+                .publish_message_with_payload_encoding(
+                    vhost,
+                    exchange,
+                    routing_key,
+                    &msg.payload,
+                    msg.payload_encoding,
+                    requests::MessageProperties::from(&msg.properties),
+                )
+                .await?;
+            if !outcome.routed {
+                return Err(Error::MessageNotRouted {
+                    exchange: exchange.to_owned(),
+                    routing_key: routing_key.to_owned(),
+                });
+            }
+            published += 1;
+            on_progress(published);
+        }
+
+        Ok(published)
+    }
+
+    /// Produces a cluster summary report (versions, nodes and their alarms, listeners,
+    /// entity counts, the `top_queue_count` queues with the most messages),
+    /// querying the required endpoints concurrently.
+    ///
+    /// See [`crate::report::ClusterReport`].
+    pub async fn cluster_report(&self, top_queue_count: usize) -> Result<ClusterReport> {
+        let (overview, nodes, vhosts, users, policies, queues) = futures_util::try_join!(
+            self.overview(),
+            self.list_nodes(),
+            self.list_vhosts(),
+            self.list_users(),
+            self.list_policies(),
+            self.list_queue_summaries(),
+        )?;
+
+        let counts = EntityCounts {
+            virtual_hosts: vhosts.len() as u64,
+            users: users.len() as u64,
+            policies: policies.len() as u64,
+            connections: overview.object_totals.connections,
+            channels: overview.object_totals.channels,
+            consumers: overview.object_totals.consumers,
+            queues: overview.object_totals.queues,
+            exchanges: overview.object_totals.exchanges,
+        };
+        Ok(ClusterReport::new(
+            &overview,
+            &nodes,
+            counts,
+            queues,
+            top_queue_count,
+        ))
+    }
+
+    /// Fetches the queues, exchanges, bindings, policies and runtime parameters
+    /// of a virtual host concurrently.
+    ///
+    /// See [`responses::VirtualHostTopology`].
+    pub async fn get_vhost_topology(&self, vhost: &str) -> Result<responses::VirtualHostTopology> {
+        let (queues, exchanges, bindings, policies, parameters) = futures_util::try_join!(
+            self.list_queues_in(vhost),
+            self.list_exchanges_in(vhost),
+            self.list_bindings_in(vhost),
+            self.list_policies_in(vhost),
+            // there is no endpoint for all parameters of a virtual host
+            self.list_runtime_parameters(),
+        )?;
+
+        Ok(responses::VirtualHostTopology {
+            vhost: vhost.to_owned(),
+            queues,
+            exchanges,
+            bindings,
+            policies,
+            parameters: parameters
+                .into_iter()
+                .filter(|p| p.vhost == vhost)
+                .collect(),
+        })
+    }
+
+    /// Produces a per-queue consumer utilisation report (consumer count, prefetch,
+    /// utilisation and ack backlog) for the given virtual host, or all of them,
+    /// and flags queues whose consumers are saturated.
+    ///
+    /// See [`crate::report::ConsumerUtilisationReport`].
+    pub async fn consumer_utilisation_report(
+        &self,
+        vhost: Option<&str>,
+        saturation_threshold: f64,
+    ) -> Result<ConsumerUtilisationReport> {
+        let (queues, consumers) = match vhost {
+            Some(vhost) => {
+                futures_util::try_join!(self.list_queues_in(vhost), self.list_consumers_in(vhost))?
+            }
+            None => futures_util::try_join!(self.list_queues(), self.list_consumers())?,
+        };
+        Ok(ConsumerUtilisationReport::new(
+            &queues,
+            &consumers,
+            saturation_threshold,
+        ))
+    }
+
+    /// Samples cluster-wide churn rates and connection listings every `settings.interval`
+    /// for `settings.window` and reports connections opened and closed by every application
+    /// (identified by user and client-provided connection name).
+    ///
+    /// Use [`ConnectionChurnReport::offenders`] to find the applications that open
+    /// and close connections at rates above `settings.threshold_per_minute`.
+    pub async fn analyze_connection_churn(
+        &self,
+        settings: &ChurnAnalyzerSettings,
+    ) -> Result<ConnectionChurnReport> {
+        let mut tracker = ConnectionChurnTracker::new();
+        let started_at = Instant::now();
+        loop {
+            let overview = self.overview().await?;
+            tracker.observe_churn_rates(&overview.churn_rates);
+            let connections = self.list_connections().await?;
+            tracker.observe_connections(&connections);
+
+            let elapsed = started_at.elapsed();
+            if elapsed >= settings.window {
+                return Ok(tracker.report(elapsed, settings.threshold_per_minute));
+            }
+            let pause = settings.interval.min(settings.window - elapsed);
+            tokio::time::sleep(pause).await;
+        }
+    }
+
+    /// Polls the queues (in the given virtual host, or in all of them) every `settings.interval`
+    /// and produces a stream of queues that were declared, deleted or changed (e.g. their state,
+    /// leader, effective policy or number of consumers), see [`crate::watch::Watchable`].
+    ///
+    /// The stream ends after the first error.
+    pub fn watch_queues<'a>(
+        &'a self,
+        vhost: Option<&'a str>,
+        settings: WatchSettings,
+    ) -> impl Stream<Item = Result<WatchEvent<responses::QueueInfo>>> + 'a {
+        watch_listing(settings, move || async move {
+            match vhost {
+                Some(vhost) => self.list_queues_in(vhost).await,
+                None => self.list_queues().await,
+            }
+        })
+    }
+
+    /// Like [`Client::watch_queues`] but for client connections.
+    pub fn watch_connections<'a>(
+        &'a self,
+        vhost: Option<&'a str>,
+        settings: WatchSettings,
+    ) -> impl Stream<Item = Result<WatchEvent<responses::Connection>>> + 'a {
+        watch_listing(settings, move || async move {
+            match vhost {
+                Some(vhost) => self.list_connections_in(vhost).await,
+                None => self.list_connections().await,
+            }
+        })
+    }
+
+    /// Like [`Client::watch_queues`] but for policies.
+    pub fn watch_policies<'a>(
+        &'a self,
+        vhost: Option<&'a str>,
+        settings: WatchSettings,
+    ) -> impl Stream<Item = Result<WatchEvent<responses::Policy>>> + 'a {
+        watch_listing(settings, move || async move {
+            match vhost {
+                Some(vhost) => self.list_policies_in(vhost).await,
+                None => self.list_policies().await,
+            }
+        })
+    }
+
     pub async fn overview(&self) -> Result<responses::Overview> {
         let response = self.http_get("overview", None, None).await?;
-        let response = response.json().await?;
+        let response = self.read_json(response).await?;
         Ok(response)
     }
 
+    /// Like [`Client::overview`] but also returns the HTTP response metadata.
+    pub async fn overview_with_response(&self) -> Result<ResponseEnvelope<responses::Overview>> {
+        self.get_with_response("overview").await
+    }
+
+    /// Lists the listeners (protocol, port and interface) of all cluster nodes.
+    pub async fn list_listeners(&self) -> Result<Vec<responses::Listener>> {
+        let overview = self.overview().await?;
+        Ok(overview.listeners)
+    }
+
+    /// Lists the listeners (protocol, port and interface) of the given node.
+    ///
+    /// The filtering is performed on the client side.
+    pub async fn listeners_of(&self, node: &str) -> Result<Vec<responses::Listener>> {
+        let listeners = self.list_listeners().await?;
+        Ok(listeners.into_iter().filter(|l| l.node == node).collect())
+    }
+
     pub async fn server_version(&self) -> Result<String> {
         let response = self.http_get("overview", None, None).await?;
-        let response: Overview = response.json().await?;
+        let response: Overview = self.read_json(response).await?;
 
         Ok(response.rabbitmq_version)
     }
@@ -1567,7 +3505,7 @@ where
     /// will succeed.
     pub async fn list_feature_flags(&self) -> Result<FeatureFlagList> {
         let response = self.http_get("feature-flags", None, None).await?;
-        let response = response.json().await?;
+        let response = self.read_json(response).await?;
         Ok(response)
     }
 
@@ -1581,6 +3519,9 @@ where
         let _response = self
             .http_put(path!("feature-flags", name, "enable"), &body, None, None)
             .await?;
+        self.audit(|| Mutation::EnabledFeatureFlag {
+            name: name.to_owned(),
+        });
         Ok(())
     }
 
@@ -1612,9 +3553,15 @@ where
     // Deprecated Features
     //
 
+    /// Lists all deprecated features known to the cluster, in use or not.
+    /// An alias for [`Client::list_all_deprecated_features`].
+    pub async fn list_deprecated_features(&self) -> Result<DeprecatedFeatureList> {
+        self.list_all_deprecated_features().await
+    }
+
     pub async fn list_all_deprecated_features(&self) -> Result<DeprecatedFeatureList> {
         let response = self.http_get("deprecated-features", None, None).await?;
-        let response = response.json().await?;
+        let response = self.read_json(response).await?;
         Ok(response)
     }
 
@@ -1622,7 +3569,7 @@ where
         let response = self
             .http_get("deprecated-features/used", None, None)
             .await?;
-        let response = response.json().await?;
+        let response = self.read_json(response).await?;
         Ok(response)
     }
 
@@ -1632,7 +3579,7 @@ where
 
     pub async fn oauth_configuration(&self) -> Result<OAuthConfiguration> {
         let response = self.http_get("auth", None, None).await?;
-        let response = response.json().await?;
+        let response = self.read_json(response).await?;
 
         Ok(response)
     }
@@ -1652,7 +3599,7 @@ where
             }
             None => self.http_get("tanzu/osr/schema/status", None, None).await?,
         };
-        let response = response.json().await?;
+        let response = self.read_json(response).await?;
 
         Ok(response)
     }
@@ -1675,6 +3622,9 @@ where
             }
         };
 
+        self.audit(|| Mutation::EnabledSchemaDefinitionSync {
+            node: node.map(str::to_owned),
+        });
         Ok(())
     }
 
@@ -1690,6 +3640,9 @@ where
             }
         };
 
+        self.audit(|| Mutation::DisabledSchemaDefinitionSync {
+            node: node.map(str::to_owned),
+        });
         Ok(())
     }
 
@@ -1699,6 +3652,7 @@ where
             .http_put("tanzu/osr/schema/enable-cluster-wide", &payload, None, None)
             .await?;
 
+        self.audit(|| Mutation::EnabledSchemaDefinitionSync { node: None });
         Ok(())
     }
 
@@ -1707,6 +3661,7 @@ where
             .http_delete("tanzu/osr/schema/disable-cluster-wide", None, None)
             .await?;
 
+        self.audit(|| Mutation::DisabledSchemaDefinitionSync { node: None });
         Ok(())
     }
 
@@ -1718,7 +3673,7 @@ where
         let response = self
             .http_get("tanzu/osr/standby/status", None, None)
             .await?;
-        let response = response.json().await?;
+        let response = self.read_json(response).await?;
 
         Ok(response)
     }
@@ -1744,6 +3699,11 @@ where
                 None,
             )
             .await?;
+        self.audit(|| Mutation::UpsertedRuntimeParameter {
+            component: SHOVEL_COMPONENT.to_owned(),
+            vhost: runtime_param.vhost.to_owned(),
+            name: runtime_param.name.to_owned(),
+        });
         Ok(())
     }
 
@@ -1764,9 +3724,21 @@ where
                 None,
             )
             .await?;
+        self.audit(|| Mutation::UpsertedRuntimeParameter {
+            component: FEDERATION_UPSTREAM_COMPONENT.to_owned(),
+            vhost: runtime_param.vhost.to_owned(),
+            name: runtime_param.name.to_owned(),
+        });
         Ok(())
     }
 
+    async fn is_ready(&self) -> bool {
+        match self.overview().await {
+            Ok(ov) => matches!(self.get_node_info(&ov.node).await, Ok(node) if node.running),
+            Err(_) => false,
+        }
+    }
+
     async fn health_check_alarms(&self, path: &str) -> Result<()> {
         // we expect that StatusCode::SERVICE_UNAVAILABLE may be return and ignore
         // it here to provide a custom error type later
@@ -1778,8 +3750,11 @@ where
             return Ok(());
         }
 
-        let body = response.json().await?;
-        let failure_details = responses::HealthCheckFailureDetails::AlarmCheck(body);
+        let body = self.read_text(response).await?;
+        let failure_details = match serde_json::from_str(&body) {
+            Ok(details) => responses::HealthCheckFailureDetails::AlarmCheck(details),
+            Err(_) => responses::HealthCheckFailureDetails::from_body(&body),
+        };
         Err(Error::HealthCheckFailed {
             path: path.to_owned(),
             details: failure_details,
@@ -1791,7 +3766,7 @@ where
         &self,
         vhost: &str,
         exchange: &str,
-        vertex: BindindVertex,
+        vertex: BindingVertex,
     ) -> Result<Vec<responses::BindingInfo>> {
         let response = self
             .http_get(
@@ -1800,10 +3775,71 @@ where
                 None,
             )
             .await?;
-        let response = response.json().await?;
+        let response = self.deserialize_list(response).await?;
+        Ok(response)
+    }
+
+    async fn publish_message_with_payload_encoding(
+        &self,
+        vhost: &str,
+        exchange: &str,
+        routing_key: &str,
+        payload: &str,
+        payload_encoding: PayloadEncoding,
+        properties: requests::MessageProperties,
+    ) -> Result<responses::MessageRouted> {
+        if let Some(guard) = &self.inner.payload_size_guard {
+            let size = match payload_encoding {
+                PayloadEncoding::String => payload.len(),
+                // every 4 characters encode 3 bytes, padding characters encode none
+                PayloadEncoding::Base64 => {
+                    let padding = payload.bytes().rev().take_while(|b| *b == b'=').count();
+                    (payload.len() / 4 * 3).saturating_sub(padding)
+                }
+            };
+            guard
+                .enforce(size)
+                .map_err(|(size, limit)| Error::PayloadTooLarge { size, limit })?;
+        }
+
+        let body = serde_json::json!({
+          "routing_key": routing_key,
+          "payload": payload,
+          "payload_encoding": payload_encoding,
+          "properties": properties,
+        });
+
+        let response = self
+            .http_post(
+                path!("exchanges", vhost, exchange, "publish"),
+                &body,
+                None,
+                None,
+            )
+            .await?;
+        let response = self.read_json(response).await?;
         Ok(response)
     }
 
+    async fn get_with_response<S, T>(&self, path: S) -> Result<ResponseEnvelope<T>>
+    where
+        S: AsRef<str>,
+        T: DeserializeOwned,
+    {
+        let started_at = Instant::now();
+        let response = self.http_get(path, None, None).await?;
+        let status_code = response.status();
+        let headers = response.headers().clone();
+        let body = self.read_json(response).await?;
+
+        Ok(ResponseEnvelope {
+            body,
+            status_code,
+            headers,
+            duration: started_at.elapsed(),
+        })
+    }
+
     async fn http_get<S>(
         &self,
         path: S,
@@ -1813,20 +3849,18 @@ where
     where
         S: AsRef<str>,
     {
-        let response = self
+        let (username, password) = self.credentials();
+        let request = self
+            .inner
             .client
             .get(self.rooted_path(path))
-            .basic_auth(&self.username, Some(&self.password))
-            .send()
-            .await?;
-        let response = self
-            .ok_or_status_code_error(
-                response,
-                client_code_to_accept_or_ignore,
-                server_code_to_accept_or_ignore,
-            )
-            .await?;
-        Ok(response)
+            .basic_auth(username, Some(password));
+        self.send_request(
+            request,
+            client_code_to_accept_or_ignore,
+            server_code_to_accept_or_ignore,
+        )
+        .await
     }
 
     async fn http_put<S, T>(
@@ -1840,21 +3874,19 @@ where
         S: AsRef<str>,
         T: Serialize,
     {
-        let response = self
+        let (username, password) = self.credentials();
+        let request = self
+            .inner
             .client
             .put(self.rooted_path(path))
             .json(&payload)
-            .basic_auth(&self.username, Some(&self.password))
-            .send()
-            .await?;
-        let response = self
-            .ok_or_status_code_error(
-                response,
-                client_code_to_accept_or_ignore,
-                server_code_to_accept_or_ignore,
-            )
-            .await?;
-        Ok(response)
+            .basic_auth(username, Some(password));
+        self.send_request(
+            request,
+            client_code_to_accept_or_ignore,
+            server_code_to_accept_or_ignore,
+        )
+        .await
     }
 
     async fn http_post<S, T>(
@@ -1868,21 +3900,46 @@ where
         S: AsRef<str>,
         T: Serialize,
     {
-        let response = self
+        let (username, password) = self.credentials();
+        let request = self
+            .inner
             .client
             .post(self.rooted_path(path))
             .json(&payload)
-            .basic_auth(&self.username, Some(&self.password))
-            .send()
-            .await?;
-        let response = self
-            .ok_or_status_code_error(
-                response,
-                client_code_to_accept_or_ignore,
-                server_code_to_accept_or_ignore,
-            )
-            .await?;
-        Ok(response)
+            .basic_auth(username, Some(password));
+        self.send_request(
+            request,
+            client_code_to_accept_or_ignore,
+            server_code_to_accept_or_ignore,
+        )
+        .await
+    }
+
+    /// Sends a POST request with a JSON body streamed from `reader`.
+    async fn http_post_stream<S, R>(&self, path: S, reader: R) -> Result<HttpClientResponse>
+    where
+        S: AsRef<str>,
+        R: AsyncRead + Send + 'static,
+    {
+        let chunks = stream::try_unfold(Box::pin(reader), |mut reader| async move {
+            let mut buf = vec![0; STREAMED_BODY_CHUNK_SIZE];
+            let n = reader.read(&mut buf).await?;
+            if n == 0 {
+                return Ok::<_, std::io::Error>(None);
+            }
+            buf.truncate(n);
+            Ok(Some((buf, reader)))
+        });
+
+        let (username, password) = self.credentials();
+        let request = self
+            .inner
+            .client
+            .post(self.rooted_path(path))
+            .header(CONTENT_TYPE, "application/json")
+            .body(Body::wrap_stream(chunks))
+            .basic_auth(username, Some(password));
+        self.send_request(request, None, None).await
     }
 
     async fn http_delete<S>(
@@ -1894,20 +3951,44 @@ where
     where
         S: AsRef<str>,
     {
-        let response = self
+        let (username, password) = self.credentials();
+        let request = self
+            .inner
             .client
             .delete(self.rooted_path(path))
-            .basic_auth(&self.username, Some(&self.password))
-            .send()
-            .await?;
-        let response = self
-            .ok_or_status_code_error(
-                response,
-                client_code_to_accept_or_ignore,
-                server_code_to_accept_or_ignore,
-            )
-            .await?;
-        Ok(response)
+            .basic_auth(username, Some(password));
+        self.send_request(
+            request,
+            client_code_to_accept_or_ignore,
+            server_code_to_accept_or_ignore,
+        )
+        .await
+    }
+
+    async fn http_delete_with_body<S, T>(
+        &self,
+        path: S,
+        payload: &T,
+        client_code_to_accept_or_ignore: Option<StatusCode>,
+        server_code_to_accept_or_ignore: Option<StatusCode>,
+    ) -> Result<HttpClientResponse>
+    where
+        S: AsRef<str>,
+        T: Serialize,
+    {
+        let (username, password) = self.credentials();
+        let request = self
+            .inner
+            .client
+            .delete(self.rooted_path(path))
+            .json(&payload)
+            .basic_auth(username, Some(password));
+        self.send_request(
+            request,
+            client_code_to_accept_or_ignore,
+            server_code_to_accept_or_ignore,
+        )
+        .await
     }
 
     async fn http_delete_with_headers<S>(
@@ -1920,21 +4001,325 @@ where
     where
         S: AsRef<str>,
     {
-        let response = self
+        let (username, password) = self.credentials();
+        let request = self
+            .inner
             .client
             .delete(self.rooted_path(path))
-            .basic_auth(&self.username, Some(&self.password))
-            .headers(headers)
-            .send()
-            .await?;
-        let response = self
-            .ok_or_status_code_error(
-                response,
-                client_code_to_accept_or_ignore,
-                server_code_to_accept_or_ignore,
-            )
-            .await?;
-        Ok(response)
+            .basic_auth(username, Some(password))
+            .headers(headers);
+        self.send_request(
+            request,
+            client_code_to_accept_or_ignore,
+            server_code_to_accept_or_ignore,
+        )
+        .await
+    }
+
+    fn is_protection_enforced(&self) -> bool {
+        !self.inner.protected_entities.is_empty() && !self.protection_overridden
+    }
+
+    fn ensure_virtual_host_unprotected(&self, vhost: &str) -> Result<()> {
+        if self.is_protection_enforced()
+            && self
+                .inner
+                .protected_entities
+                .is_virtual_host_protected(vhost)
+        {
+            return Err(Error::ProtectedEntity {
+                kind: "virtual host".to_owned(),
+                vhost: None,
+                name: vhost.to_owned(),
+            });
+        }
+        Ok(())
+    }
+
+    fn ensure_object_unprotected(&self, kind: &str, vhost: &str, name: &str) -> Result<()> {
+        if self.is_protection_enforced()
+            && self
+                .inner
+                .protected_entities
+                .is_object_protected(vhost, name)
+        {
+            return Err(Error::ProtectedEntity {
+                kind: kind.to_owned(),
+                vhost: Some(vhost.to_owned()),
+                name: name.to_owned(),
+            });
+        }
+        Ok(())
+    }
+
+    fn ensure_name_unprotected(&self, kind: &str, name: &str) -> Result<()> {
+        if self.is_protection_enforced() && self.inner.protected_entities.is_name_protected(name) {
+            return Err(Error::ProtectedEntity {
+                kind: kind.to_owned(),
+                vhost: None,
+                name: name.to_owned(),
+            });
+        }
+        Ok(())
+    }
+
+    fn credentials(&self) -> (String, String) {
+        let rotated = self
+            .inner
+            .rotated_credentials
+            .read()
+            .unwrap_or_else(PoisonError::into_inner);
+        match rotated.as_ref() {
+            Some((username, password)) => (username.clone(), password.clone()),
+            None => (
+                self.inner.username.to_string(),
+                self.inner.password.to_string(),
+            ),
+        }
+    }
+
+    /// Sends a request, tagging it and the error returned, if any,
+    /// with a request ID when the client is configured to use them.
+    async fn send_request(
+        &self,
+        request: RequestBuilder,
+        client_code_to_accept_or_ignore: Option<StatusCode>,
+        server_code_to_accept_or_ignore: Option<StatusCode>,
+    ) -> Result<HttpClientResponse> {
+        let request = match self.deadline {
+            Some(deadline) => {
+                let remaining = deadline.saturating_duration_since(Instant::now());
+                if remaining.is_zero() {
+                    return Err(Error::DeadlineExceeded);
+                }
+                request.timeout(remaining)
+            }
+            None => request,
+        };
+        if let Some(cb) = &self.inner.circuit_breaker {
+            cb.check()
+                .map_err(|retry_after| Error::CircuitOpen { retry_after })?;
+        }
+
+        let (request, request_id) = match &self.inner.request_ids {
+            Some(ids) => {
+                let id = ids.next_id();
+                (request.header(ids.header(), &id), Some(id))
+            }
+            None => (request, None),
+        };
+
+        let result = match request.send().await {
+            Ok(mut response) => {
+                // lets the errors of a later body read carry the ID, too
+                if let Some(id) = &request_id {
+                    response.extensions_mut().insert(SentRequestId(id.clone()));
+                }
+                match self
+                    .ok_or_status_code_error(
+                        response,
+                        client_code_to_accept_or_ignore,
+                        server_code_to_accept_or_ignore,
+                    )
+                    .await
+                {
+                    Ok(response) => self.enforce_max_response_size(response),
+                    Err(e) => Err(e),
+                }
+            }
+            // the request timed out because of the deadline
+            Err(_) if self.is_past_deadline() => Err(Error::DeadlineExceeded),
+            Err(e) => Err(HttpClientError::from(e)),
+        };
+        if let Some(cb) = &self.inner.circuit_breaker {
+            match &result {
+                Err(Error::RequestError { .. }) | Err(Error::ServerErrorResponse { .. }) => {
+                    cb.record_failure()
+                }
+                // says nothing about the state of the node
+                Err(Error::DeadlineExceeded) => cb.record_inconclusive(),
+                _ => cb.record_success(),
+            }
+        }
+        result.map_err(|e| e.with_request_id(request_id))
+    }
+
+    fn is_past_deadline(&self) -> bool {
+        self.deadline.is_some_and(|d| Instant::now() >= d)
+    }
+
+    fn audit<F>(&self, mutation: F)
+    where
+        F: FnOnce() -> Mutation,
+    {
+        if let Some(hook) = &self.inner.audit_hook {
+            hook.record(&mutation());
+        }
+    }
+
+    async fn deserialize_list<T>(&self, response: HttpClientResponse) -> Result<Vec<T>>
+    where
+        T: DeserializeOwned,
+    {
+        match &self.inner.lenient_listings {
+            Some(lenient) => {
+                let elements: Vec<serde_json::Value> = self.read_json(response).await?;
+                Ok(lenient.deserialize_elements(elements))
+            }
+            None => self.read_json(response).await,
+        }
+    }
+
+    async fn copy_body_to_writer<W>(
+        &self,
+        mut response: HttpClientResponse,
+        writer: &mut W,
+    ) -> Result<u64>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        let request_id = sent_request_id(&response);
+        let result = async {
+            let limit = self.inner.max_response_size;
+            let mut written = 0;
+            while let Some(chunk) = response
+                .chunk()
+                .await
+                .map_err(|e| self.body_read_error(e))?
+            {
+                written += chunk.len() as u64;
+                if let Some(limit) = limit.filter(|limit| written > *limit) {
+                    return Err(Self::response_too_large(&response, limit));
+                }
+                writer.write_all(&chunk).await?;
+            }
+            writer.flush().await?;
+            Ok(written)
+        }
+        .await;
+        result.map_err(|e| e.with_request_id(request_id))
+    }
+
+    /// Rejects responses that advertise a body larger than the limit set with
+    /// [`ClientBuilder#with_max_response_size`]. Bodies of unknown size
+    /// (chunked transfer encoding) are checked as they are read.
+    fn enforce_max_response_size(
+        &self,
+        response: HttpClientResponse,
+    ) -> Result<HttpClientResponse> {
+        match (self.inner.max_response_size, response.content_length()) {
+            (Some(limit), Some(content_length)) if content_length > limit => {
+                Err(Error::ResponseTooLarge {
+                    url: Some(redact_url(response.url().clone())),
+                    content_length: Some(content_length),
+                    limit,
+                    request_id: None,
+                })
+            }
+            _ => Ok(response),
+        }
+    }
+
+    /// Reads a body of unknown size into memory, failing as soon as it exceeds
+    /// the limit. The returned response retains the status, headers and URL of the original one.
+    async fn buffer_within_max_response_size(
+        &self,
+        mut response: HttpClientResponse,
+    ) -> Result<HttpClientResponse> {
+        let Some(limit) = self.inner.max_response_size else {
+            return Ok(response);
+        };
+        if response.content_length().is_some() {
+            return Ok(response);
+        }
+
+        let mut body = Vec::new();
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .map_err(|e| self.body_read_error(e))?
+        {
+            body.extend_from_slice(&chunk);
+            if body.len() as u64 > limit {
+                return Err(Self::response_too_large(&response, limit));
+            }
+        }
+
+        let mut buffered = http::Response::builder()
+            .status(response.status())
+            .version(response.version())
+            .url(response.url().clone())
+            .body(body)
+            .expect("status, version and URL of a received response are valid");
+        *buffered.headers_mut() = response.headers().clone();
+        Ok(HttpClientResponse::from(buffered))
+    }
+
+    async fn read_json<T>(&self, response: HttpClientResponse) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        let request_id = sent_request_id(&response);
+        let result = async {
+            let response = self.buffer_within_max_response_size(response).await?;
+            response.json().await.map_err(|e| self.body_read_error(e))
+        }
+        .await;
+        result.map_err(|e| e.with_request_id(request_id))
+    }
+
+    async fn read_text(&self, response: HttpClientResponse) -> Result<String> {
+        let request_id = sent_request_id(&response);
+        let result = async {
+            let response = self.buffer_within_max_response_size(response).await?;
+            response.text().await.map_err(|e| self.body_read_error(e))
+        }
+        .await;
+        result.map_err(|e| e.with_request_id(request_id))
+    }
+
+    /// Reads the body of an error response. With [`ClientBuilder#with_max_response_size`],
+    /// the body is truncated at the limit, so that a huge error page, e.g. one served by a proxy,
+    /// does not have to be read into memory.
+    async fn read_error_body(&self, mut response: HttpClientResponse) -> Result<String> {
+        let Some(limit) = self.inner.max_response_size else {
+            return response.text().await.map_err(|e| self.body_read_error(e));
+        };
+
+        let limit = usize::try_from(limit).unwrap_or(usize::MAX);
+        let mut body = Vec::new();
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .map_err(|e| self.body_read_error(e))?
+        {
+            let remaining = limit - body.len();
+            if chunk.len() >= remaining {
+                body.extend_from_slice(&chunk[..remaining]);
+                break;
+            }
+            body.extend_from_slice(&chunk);
+        }
+        Ok(String::from_utf8_lossy(&body).into_owned())
+    }
+
+    /// Reading a response body is bounded by the deadline just like sending the request,
+    /// see [`Client::with_deadline`], so a timeout is reported as [`Error::DeadlineExceeded`].
+    fn body_read_error(&self, err: reqwest::Error) -> HttpClientError {
+        if err.is_timeout() && self.is_past_deadline() {
+            Error::DeadlineExceeded
+        } else {
+            HttpClientError::from(err)
+        }
+    }
+
+    fn response_too_large(response: &HttpClientResponse, limit: u64) -> HttpClientError {
+        Error::ResponseTooLarge {
+            url: Some(redact_url(response.url().clone())),
+            content_length: None,
+            limit,
+            request_id: None,
+        }
     }
 
     async fn ok_or_status_code_error(
@@ -1962,12 +4347,13 @@ where
                     let headers = response.headers().clone();
                     // this consumes `self` and makes the response largely useless to the caller,
                     // so we copy the key parts into the error first
-                    let body = response.text().await?;
+                    let body = self.read_error_body(response).await?;
                     return Err(ClientErrorResponse {
-                        url: Some(url),
+                        url: Some(redact_url(url)),
                         body: Some(body),
                         headers: Some(headers),
                         status_code: status,
+                        request_id: None,
                         backtrace: Backtrace::new(),
                     });
                 }
@@ -1982,12 +4368,13 @@ where
                     let headers = response.headers().clone();
                     // this consumes `self` and makes the response largely useless to the caller,
                     // so we copy the key parts into the error first
-                    let body = response.text().await?;
+                    let body = self.read_error_body(response).await?;
                     return Err(ServerErrorResponse {
-                        url: Some(url),
+                        url: Some(redact_url(url)),
                         body: Some(body),
                         headers: Some(headers),
                         status_code: status,
+                        request_id: None,
                         backtrace: Backtrace::new(),
                     });
                 }
@@ -2001,7 +4388,17 @@ where
     where
         S: AsRef<str>,
     {
-        format!("{}/{}", self.endpoint, path.as_ref())
+        let endpoint = self.inner.endpoint.to_string();
+        let endpoint = endpoint.trim_end_matches('/');
+        match &self.inner.api_prefix {
+            Some(prefix) => format!(
+                "{}/{}/{}",
+                endpoint,
+                prefix.trim_matches('/'),
+                path.as_ref()
+            ),
+            None => format!("{}/{}", endpoint, path.as_ref()),
+        }
     }
 }
 
@@ -2012,12 +4409,12 @@ impl Default for Client<&'static str, &'static str, &'static str> {
 }
 
 #[derive(Debug, Clone, Copy)]
-enum BindindVertex {
+enum BindingVertex {
     Source,
     Destination,
 }
 
-impl AsRef<str> for BindindVertex {
+impl AsRef<str> for BindingVertex {
     fn as_ref(&self) -> &str {
         match self {
             Self::Source => "source",
@@ -2025,3 +4422,22 @@ impl AsRef<str> for BindindVertex {
         }
     }
 }
+
+/// The ID a response was requested with, see [`ClientBuilder#with_request_ids`].
+#[derive(Clone)]
+struct SentRequestId(String);
+
+fn sent_request_id(response: &HttpClientResponse) -> Option<String> {
+    response
+        .extensions()
+        .get::<SentRequestId>()
+        .map(|SentRequestId(id)| id.clone())
+}
+
+/// Whether two fetched messages are indistinguishable, e.g. a message
+/// replayed back into the queue it was fetched from.
+fn is_same_message(a: &GetMessage, b: &GetMessage) -> bool {
+    a.payload == b.payload
+        && a.payload_encoding == b.payload_encoding
+        && a.properties == b.properties
+}
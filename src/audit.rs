@@ -0,0 +1,238 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Change auditing: a client configured with an [`AuditHook`]
+//! (see `ClientBuilder#with_audit_hook`) invokes it with a typed [`Mutation`]
+//! after every successful operation that changes the state of the cluster,
+//! so that change audit records can be produced without parsing HTTP request paths.
+//!
+//! Composite operations, e.g. `Client#declare_topology`, report every
+//! individual mutation they perform.
+
+use std::fmt;
+use std::sync::Arc;
+
+use crate::commons::{BindingDestinationType, UserLimitTarget, VirtualHostLimitTarget};
+
+/// A successfully performed mutation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Mutation {
+    DeclaredVirtualHost {
+        name: String,
+    },
+    DeletedVirtualHost {
+        name: String,
+    },
+    /// A virtual host was started on a specific node
+    StartedVirtualHost {
+        name: String,
+        node: String,
+    },
+    EnabledVirtualHostDeletionProtection {
+        name: String,
+    },
+    DisabledVirtualHostDeletionProtection {
+        name: String,
+    },
+    /// A user was created or updated
+    DeclaredUser {
+        name: String,
+    },
+    DeletedUser {
+        name: String,
+    },
+    /// See `Client#delete_users`
+    DeletedUsers {
+        names: Vec<String>,
+    },
+    DeclaredPermissions {
+        vhost: String,
+        user: String,
+    },
+    ClearedPermissions {
+        vhost: String,
+        user: String,
+    },
+    DeclaredQueue {
+        vhost: String,
+        name: String,
+    },
+    DeclaredStream {
+        vhost: String,
+        name: String,
+    },
+    /// A queue or a stream was deleted
+    DeletedQueue {
+        vhost: String,
+        name: String,
+    },
+    PurgedQueue {
+        vhost: String,
+        name: String,
+    },
+    DeclaredExchange {
+        vhost: String,
+        name: String,
+    },
+    DeletedExchange {
+        vhost: String,
+        name: String,
+    },
+    DeclaredBinding {
+        vhost: String,
+        source: String,
+        destination: String,
+        destination_type: BindingDestinationType,
+        routing_key: Option<String>,
+    },
+    DeletedBinding {
+        vhost: String,
+        source: String,
+        destination: String,
+        destination_type: BindingDestinationType,
+        routing_key: String,
+    },
+    DeclaredPolicy {
+        vhost: String,
+        name: String,
+    },
+    DeletedPolicy {
+        vhost: String,
+        name: String,
+    },
+    DeclaredOperatorPolicy {
+        vhost: String,
+        name: String,
+    },
+    DeletedOperatorPolicy {
+        vhost: String,
+        name: String,
+    },
+    /// Includes federation upstreams and dynamic shovels
+    UpsertedRuntimeParameter {
+        component: String,
+        vhost: String,
+        name: String,
+    },
+    ClearedRuntimeParameter {
+        component: String,
+        vhost: String,
+        name: String,
+    },
+    /// Includes cluster tags
+    UpsertedGlobalRuntimeParameter {
+        name: String,
+    },
+    ClearedGlobalRuntimeParameter {
+        name: String,
+    },
+    SetUserLimit {
+        user: String,
+        kind: UserLimitTarget,
+        value: i64,
+    },
+    ClearedUserLimit {
+        user: String,
+        kind: UserLimitTarget,
+    },
+    SetVirtualHostLimit {
+        vhost: String,
+        kind: VirtualHostLimitTarget,
+        value: i64,
+    },
+    ClearedVirtualHostLimit {
+        vhost: String,
+        kind: VirtualHostLimitTarget,
+    },
+    SetClusterName {
+        name: String,
+    },
+    ClosedConnection {
+        name: String,
+    },
+    ClosedUserConnections {
+        user: String,
+    },
+    DeletedShovel {
+        vhost: String,
+        name: String,
+    },
+    RestartedShovel {
+        vhost: String,
+        name: String,
+    },
+    RebalancedQueueLeaders,
+    AddedQuorumQueueReplica {
+        vhost: String,
+        name: String,
+        node: String,
+    },
+    DeletedQuorumQueueReplica {
+        vhost: String,
+        name: String,
+        node: String,
+    },
+    /// Quorum queues were grown onto a node, see `Client#grow_quorum_queue`
+    GrewQuorumQueues {
+        node: String,
+    },
+    /// Quorum queue replicas were removed from a node, see `Client#shrink_quorum_queue`
+    ShrankQuorumQueues {
+        node: String,
+    },
+    /// Definitions were imported, cluster-wide or into a single virtual host
+    ImportedDefinitions {
+        vhost: Option<String>,
+    },
+    EnabledFeatureFlag {
+        name: String,
+    },
+    /// Schema definition sync was enabled, on a specific node or cluster-wide
+    EnabledSchemaDefinitionSync {
+        node: Option<String>,
+    },
+    /// Schema definition sync was disabled, on a specific node or cluster-wide
+    DisabledSchemaDefinitionSync {
+        node: Option<String>,
+    },
+}
+
+type Hook = Arc<dyn Fn(&Mutation) + Send + Sync>;
+
+/// A function invoked with every successfully performed [`Mutation`].
+///
+/// The hook is invoked synchronously, before the operation returns, so it should not block.
+#[derive(Clone)]
+pub struct AuditHook {
+    hook: Hook,
+}
+
+impl AuditHook {
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(&Mutation) + Send + Sync + 'static,
+    {
+        Self { hook: Arc::new(f) }
+    }
+
+    pub fn record(&self, mutation: &Mutation) {
+        (self.hook)(mutation)
+    }
+}
+
+impl fmt::Debug for AuditHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AuditHook").finish_non_exhaustive()
+    }
+}
@@ -11,38 +11,67 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
+
 #![allow(clippy::result_large_err)]
 
+//! A blocking client for the RabbitMQ HTTP API.
+//!
+//! [`Client`] is a thin wrapper over [`crate::api::Client`]: every function runs its async
+//! counterpart to completion on a Tokio runtime. By default, every client (and its clones)
+//! owns a small single-threaded runtime. An existing multi-threaded runtime can be used
+//! instead, see [`ClientBuilder::with_runtime_handle`].
+//!
+//! The client can be used from within a multi-threaded runtime (e.g. in a `#[tokio::main]` program),
+//! in that case the calling worker thread is blocked using [`tokio::task::block_in_place`].
+//! On a single-threaded runtime, functions return [`Error::BlockingNotSupported`] instead of panicking.
+
+use crate::alarm_watcher::{AlarmEvent, AlarmWatcherSettings};
+use crate::api;
+use crate::audit::AuditHook;
+use crate::churn_analyzer::{ChurnAnalyzerSettings, ConnectionChurnReport};
+use crate::circuit_breaker::{CircuitBreakerSettings, CircuitState};
 use crate::error::Error;
-use crate::error::Error::{ClientErrorResponse, NotFound, ServerErrorResponse};
+use crate::lenient_listings::LenientListings;
+use crate::payload_limits::PayloadSizeGuard;
+use crate::protection::ProtectedEntities;
+use crate::report::{ClusterReport, ConsumerUtilisationReport};
+use crate::request_ids::RequestIds;
 use crate::requests::{
-    Amqp091ShovelParams, Amqp10ShovelParams, EmptyPayload, FederationUpstreamParams,
-    GlobalRuntimeParameterDefinition, StreamParams, FEDERATION_UPSTREAM_COMPONENT,
-    SHOVEL_COMPONENT,
+    Amqp091ShovelParams, Amqp10ShovelParams, FederationUpstreamParams,
+    GlobalRuntimeParameterDefinition, ShovelParams, StreamParams,
 };
 use crate::responses::{
-    ClusterTags, DeprecatedFeatureList, FeatureFlag, FeatureFlagList, FeatureFlagStability,
-    FeatureFlagState, FederationUpstream, GetMessage, OAuthConfiguration, VirtualHostDefinitionSet,
-    WarmStandbyReplicationStatus,
+    DeprecatedFeatureList, FeatureFlagList, GetMessage, OAuthConfiguration, ResponseEnvelope,
+    VirtualHostDefinitionSet, WarmStandbyReplicationStatus,
 };
+use crate::watch::{WatchEvent, WatchSettings};
 use crate::{
-    commons::{BindingDestinationType, SupportedProtocol, UserLimitTarget, VirtualHostLimitTarget},
-    path,
+    commons::{
+        BindingDestinationType, QuorumQueueGrowthStrategy, SupportedProtocol, TimeUnit,
+        UserLimitTarget, VirtualHostLimitTarget,
+    },
     requests::{
-        self, BulkUserDelete, EnforcedLimitParams, ExchangeParams, Permissions, PolicyParams,
-        QueueParams, RuntimeParameterDefinition, UserParams, VirtualHostParams, XArguments,
+        self, DefinitionOperation, EnforcedLimitParams, ExchangeParams, Permissions, PolicyParams,
+        QueueParams, RuntimeParameterDefinition, UserParams, UserParamsWithPassword,
+        VirtualHostChanges, VirtualHostParams, XArguments,
     },
-    responses::{self, BindingInfo, ClusterDefinitionSet, SchemaDefinitionSyncStatus},
+    responses::{self, ClusterDefinitionSet, SchemaDefinitionSyncStatus},
 };
-use backtrace::Backtrace;
-use reqwest::{
-    blocking::Client as HttpClient,
-    header::{HeaderMap, HeaderValue},
-    StatusCode,
-};
-use serde::Serialize;
-use serde_json::{json, Map, Value};
+use futures_util::{pin_mut, TryStreamExt};
+use reqwest::Client as HttpClient;
+use serde_json::{Map, Value};
 use std::fmt;
+use std::future::Future;
+use std::io::{Read, Write};
+use std::net::IpAddr;
+use std::ops::ControlFlow;
+use std::path::Path;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+use tokio::runtime::{Handle, Runtime, RuntimeFlavor};
 
 pub type HttpClientResponse = reqwest::blocking::Response;
 pub type HttpClientError = crate::error::HttpClientError;
@@ -67,10 +96,8 @@ pub type Result<T> = std::result::Result<T, HttpClientError>;
 /// let _ = rc.get_queue_info("/", "qq.1");
 /// ```
 pub struct ClientBuilder<E = &'static str, U = &'static str, P = &'static str> {
-    endpoint: E,
-    username: U,
-    password: P,
-    client: HttpClient,
+    inner: api::ClientBuilder<E, U, P>,
+    runtime_handle: Option<Handle>,
 }
 
 impl Default for ClientBuilder {
@@ -84,12 +111,9 @@ impl ClientBuilder {
     ///
     /// This is the same as `Client::builder()`.
     pub fn new() -> Self {
-        let client = HttpClient::new();
         Self {
-            endpoint: "http://localhost:15672",
-            username: "guest",
-            password: "guest",
-            client,
+            inner: api::ClientBuilder::new(),
+            runtime_handle: None,
         }
     }
 }
@@ -110,10 +134,8 @@ where
         NewP: fmt::Display,
     {
         ClientBuilder {
-            endpoint: self.endpoint,
-            username,
-            password,
-            client: self.client,
+            inner: self.inner.with_basic_auth_credentials(username, password),
+            runtime_handle: self.runtime_handle,
         }
     }
 
@@ -122,20 +144,244 @@ where
         T: fmt::Display,
     {
         ClientBuilder {
-            endpoint,
-            username: self.username,
-            password: self.password,
-            client: self.client,
+            inner: self.inner.with_endpoint(endpoint),
+            runtime_handle: self.runtime_handle,
         }
     }
 
     pub fn with_client(self, client: HttpClient) -> Self {
-        ClientBuilder { client, ..self }
+        ClientBuilder {
+            inner: self.inner.with_client(client),
+            ..self
+        }
+    }
+
+    /// Makes the client run requests on the given runtime instead of a runtime
+    /// owned by the client.
+    ///
+    /// The runtime must be a multi-threaded one with both the I/O and time drivers enabled:
+    /// the blocked thread cannot drive a single-threaded runtime.
+    pub fn with_runtime_handle(self, handle: Handle) -> Self {
+        ClientBuilder {
+            runtime_handle: Some(handle),
+            ..self
+        }
+    }
+
+    /// Sets a path prefix that will be inserted between the endpoint and every API path.
+    ///
+    /// Useful when the HTTP API is served behind a reverse proxy under a custom path,
+    /// e.g. an endpoint of `https://proxy.eng.megacorp.local` with the `rabbitmq/api` prefix.
+    pub fn with_api_prefix<S>(self, prefix: S) -> Self
+    where
+        S: Into<String>,
+    {
+        ClientBuilder {
+            inner: self.inner.with_api_prefix(prefix),
+            ..self
+        }
+    }
+
+    /// Makes the client refuse destructive operations, such as deletion of virtual hosts,
+    /// queues, exchanges or users, that target the given entities.
+    /// See [`ProtectedEntities`] and [`Client::with_protection_overridden`].
+    pub fn with_protected_entities(self, protected_entities: ProtectedEntities) -> Self {
+        ClientBuilder {
+            inner: self.inner.with_protected_entities(protected_entities),
+            ..self
+        }
+    }
+
+    /// Makes the client send an ID with every request, e.g. in the `X-Request-Id`
+    /// or `traceparent` header, see [`RequestIds`]. The ID is included in the errors returned
+    /// for failed requests, see [`Error::request_id`].
+    pub fn with_request_ids(self, request_ids: RequestIds) -> Self {
+        ClientBuilder {
+            inner: self.inner.with_request_ids(request_ids),
+            ..self
+        }
+    }
+
+    /// Limits the size of response bodies the client will read, in bytes.
+    ///
+    /// Larger responses, e.g. a listing of all bindings in a cluster with millions of them,
+    /// are rejected with [`Error::ResponseTooLarge`] instead of being read into memory.
+    /// The bodies of error responses are truncated at the limit.
+    pub fn with_max_response_size(self, max_response_size: u64) -> Self {
+        ClientBuilder {
+            inner: self.inner.with_max_response_size(max_response_size),
+            ..self
+        }
+    }
+
+    /// Makes listing operations deserialize every element independently, skipping
+    /// (and reporting) the elements that fail to parse instead of failing the entire call.
+    /// See [`LenientListings`].
+    pub fn with_lenient_listings(self, lenient_listings: LenientListings) -> Self {
+        ClientBuilder {
+            inner: self.inner.with_lenient_listings(lenient_listings),
+            ..self
+        }
+    }
+
+    /// Makes the client short-circuit requests for a cool-down period after a number
+    /// of consecutive failures, returning [`Error::CircuitOpen`] instead of issuing them.
+    /// See [`CircuitBreakerSettings`].
+    pub fn with_circuit_breaker(self, settings: CircuitBreakerSettings) -> Self {
+        ClientBuilder {
+            inner: self.inner.with_circuit_breaker(settings),
+            ..self
+        }
+    }
+
+    /// Makes the client invoke the given hook with a typed [`crate::audit::Mutation`] after every
+    /// successful operation that changes the state of the cluster, e.g. to produce change
+    /// audit records. See [`AuditHook`].
+    pub fn with_audit_hook(self, audit_hook: AuditHook) -> Self {
+        ClientBuilder {
+            inner: self.inner.with_audit_hook(audit_hook),
+            ..self
+        }
+    }
+
+    /// Makes the client check the payload size of published messages before sending them,
+    /// see [`PayloadSizeGuard`]. Oversized messages are rejected with [`Error::PayloadTooLarge`].
+    pub fn with_payload_size_guard(self, guard: PayloadSizeGuard) -> Self {
+        ClientBuilder {
+            inner: self.inner.with_payload_size_guard(guard),
+            ..self
+        }
+    }
+
+    /// Makes the client connect to the given IP addresses instead of resolving the hostnames,
+    /// e.g. to reach a node through a jump host or before DNS records are updated.
+    /// The port in the endpoint is still used, and so is the original hostname for TLS
+    /// (SNI and certificate validation) and the `Host` header.
+    ///
+    /// Cannot be combined with [`ClientBuilder::with_client`]: to use DNS overrides
+    /// with a custom HTTP client, configure them using `reqwest::ClientBuilder#resolve`.
+    pub fn with_dns_overrides(self, overrides: &[(&str, IpAddr)]) -> Self {
+        ClientBuilder {
+            inner: self.inner.with_dns_overrides(overrides),
+            ..self
+        }
+    }
+
+    /// Makes the client use HTTP/2 without protocol negotiation ("prior knowledge"),
+    /// e.g. for a cleartext endpoint behind an ingress controller that supports HTTP/2.
+    /// With TLS, the `http2` feature is sufficient: HTTP/2 will be negotiated using ALPN.
+    ///
+    /// Cannot be combined with [`ClientBuilder::with_client`]: to use HTTP/2 with
+    /// a custom HTTP client, configure it using `reqwest::ClientBuilder#http2_prior_knowledge`.
+    #[cfg(feature = "http2")]
+    pub fn with_http2_prior_knowledge(self) -> Self {
+        ClientBuilder {
+            inner: self.inner.with_http2_prior_knowledge(),
+            ..self
+        }
     }
 
     /// Returns a `Client` that uses this `ClientBuilder` configuration.
+    ///
+    /// # Panics
+    ///
+    /// If the HTTP client cannot be built, see [`ClientBuilder::try_build`].
     pub fn build(self) -> Client<E, U, P> {
-        Client::from_http_client(self.client, self.endpoint, self.username, self.password)
+        self.try_build()
+            .unwrap_or_else(|e| panic!("failed to build a client: {}", e))
+    }
+
+    /// Like [`ClientBuilder::build`] but returns an error if the HTTP client cannot be built,
+    /// e.g. because [`ClientBuilder::with_dns_overrides`] was combined with
+    /// [`ClientBuilder::with_client`].
+    pub fn try_build(self) -> Result<Client<E, U, P>> {
+        let client = self.inner.try_build()?;
+        let runtime = match self.runtime_handle {
+            Some(handle) => ClientRuntime::Borrowed(handle),
+            None => ClientRuntime::owned(),
+        };
+        Ok(Client {
+            client,
+            runtime: Arc::new(runtime),
+        })
+    }
+}
+
+/// Never includes the password, neither the one used for basic authentication,
+/// nor the one in the user info part of the endpoint.
+impl<E, U, P> fmt::Debug for ClientBuilder<E, U, P>
+where
+    E: fmt::Display,
+    U: fmt::Display,
+    P: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+/// A client constructed for a specific cluster node, see [`Client::discover_node_clients`].
+pub type NodeClient = Client<String, String, String>;
+
+/// The runtime blocking functions are executed on.
+/// Also used by [`crate::prometheus::BlockingPrometheusClient`].
+pub(crate) enum ClientRuntime {
+    Owned(Option<Runtime>),
+    Borrowed(Handle),
+}
+
+impl ClientRuntime {
+    pub(crate) fn owned() -> Self {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        ClientRuntime::Owned(Some(runtime))
+    }
+
+    pub(crate) fn block_on<F, T>(&self, future: F) -> Result<T>
+    where
+        F: Future<Output = Result<T>>,
+    {
+        if let ClientRuntime::Borrowed(handle) = self {
+            if handle.runtime_flavor() == RuntimeFlavor::CurrentThread {
+                return Err(Error::BlockingNotSupported {
+                    reason: "the runtime passed to with_runtime_handle is a single-threaded one"
+                        .to_owned(),
+                });
+            }
+        }
+
+        match Handle::try_current() {
+            Err(_) => self.run(future),
+            Ok(current) if current.runtime_flavor() == RuntimeFlavor::MultiThread => {
+                tokio::task::block_in_place(|| self.run(future))
+            }
+            Ok(_) => Err(Error::BlockingNotSupported {
+                reason: "called from a single-threaded runtime, use the async client instead"
+                    .to_owned(),
+            }),
+        }
+    }
+
+    fn run<F: Future>(&self, future: F) -> F::Output {
+        match self {
+            // only taken when dropped
+            ClientRuntime::Owned(runtime) => runtime.as_ref().unwrap().block_on(future),
+            ClientRuntime::Borrowed(handle) => handle.block_on(future),
+        }
+    }
+}
+
+impl Drop for ClientRuntime {
+    fn drop(&mut self) {
+        // a runtime cannot be dropped in an async context, e.g. when the last clone
+        // of a client is dropped by a task
+        if let ClientRuntime::Owned(runtime) = self {
+            if let Some(runtime) = runtime.take() {
+                runtime.shutdown_background();
+            }
+        }
     }
 }
 
@@ -164,11 +410,38 @@ where
 /// // fetch information and metrics of a specific queue
 /// rc.get_queue_info("/", "qq.1");
 /// ```
+///
+/// Every function runs its counterpart in [`crate::api::Client`] on a Tokio runtime,
+/// see the module documentation.
 pub struct Client<E, U, P> {
-    endpoint: E,
-    username: U,
-    password: P,
-    client: HttpClient,
+    client: api::Client<E, U, P>,
+    // shared by all clones
+    runtime: Arc<ClientRuntime>,
+}
+
+/// Never includes the password, neither the one used for basic authentication,
+/// nor the one in the user info part of the endpoint.
+impl<E, U, P> fmt::Debug for Client<E, U, P>
+where
+    E: fmt::Display,
+    U: fmt::Display,
+    P: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.client.fmt(f)
+    }
+}
+
+/// Clones share the underlying HTTP client (and its connection pool), the credentials,
+/// including those set with [`Client::update_credentials`], the rest of the configuration
+/// and the runtime.
+impl<E, U, P> Clone for Client<E, U, P> {
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client.clone(),
+            runtime: Arc::clone(&self.runtime),
+        }
+    }
 }
 
 impl<E, U, P> Client<E, U, P>
@@ -189,13 +462,9 @@ where
     /// let rc = Client::new(endpoint, username, password);
     /// ```
     pub fn new(endpoint: E, username: U, password: P) -> Self {
-        let client = HttpClient::builder().build().unwrap();
-
         Self {
-            endpoint,
-            username,
-            password,
-            client,
+            client: api::Client::new(endpoint, username, password),
+            runtime: Arc::new(ClientRuntime::owned()),
         }
     }
 
@@ -204,7 +473,7 @@ where
     ///
     /// Example
     /// ```rust
-    /// use reqwest::blocking::Client as HttpClient;
+    /// use reqwest::Client as HttpClient;
     /// use rabbitmq_http_client::blocking_api::Client;
     ///
     /// let client = HttpClient::new();
@@ -215,10 +484,8 @@ where
     /// ```
     pub fn from_http_client(client: HttpClient, endpoint: E, username: U, password: P) -> Self {
         Self {
-            endpoint,
-            username,
-            password,
-            client,
+            client: api::Client::from_http_client(client, endpoint, username, password),
+            runtime: Arc::new(ClientRuntime::owned()),
         }
     }
 
@@ -229,46 +496,137 @@ where
         ClientBuilder::new()
     }
 
+    /// Changes the credentials used by all subsequent requests, e.g. after the password
+    /// was rotated by a secret management tool, without having to rebuild the client.
+    ///
+    /// Requests that are already in flight will continue using the previous credentials.
+    pub fn update_credentials(&self, username: &str, password: &str) {
+        self.client.update_credentials(username, password)
+    }
+
+    /// Returns a clone of this client whose requests must complete before the given deadline.
+    ///
+    /// The remaining time is budgeted across all requests issued by the clone, including
+    /// those issued by multi-request operations such as [`Client::declare_topology`]
+    /// or [`Client::enable_all_stable_feature_flags`]: every request times out when
+    /// the deadline is reached (including while its response body is being read),
+    /// and once it has passed, no more requests are issued.
+    /// In both cases, [`Error::DeadlineExceeded`] is returned.
+    pub fn with_deadline(&self, deadline: Instant) -> Self {
+        Self {
+            client: self.client.with_deadline(deadline),
+            runtime: Arc::clone(&self.runtime),
+        }
+    }
+
+    /// Like [`Client::with_deadline`] but with a deadline that is `timeout` from now.
+    pub fn with_timeout(&self, timeout: Duration) -> Self {
+        self.with_deadline(Instant::now() + timeout)
+    }
+
+    pub fn deadline(&self) -> Option<Instant> {
+        self.client.deadline()
+    }
+
+    /// Returns a clone of this client that performs destructive operations even when they
+    /// target entities protected using [`ClientBuilder::with_protected_entities`].
+    ///
+    /// Only the returned client is affected: this client and its other clones
+    /// keep refusing such operations.
+    pub fn with_protection_overridden(&self) -> Self {
+        Self {
+            client: self.client.with_protection_overridden(),
+            runtime: Arc::clone(&self.runtime),
+        }
+    }
+
+    pub fn is_protection_overridden(&self) -> bool {
+        self.client.is_protection_overridden()
+    }
+
+    /// Returns the state of the circuit breaker, if the client is configured with one,
+    /// see [`ClientBuilder::with_circuit_breaker`].
+    pub fn circuit_state(&self) -> Option<CircuitState> {
+        self.client.circuit_state()
+    }
+
     /// Lists cluster nodes.
     pub fn list_nodes(&self) -> Result<Vec<responses::ClusterNode>> {
-        let response = self.http_get("nodes", None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.list_nodes())
+    }
+
+    /// Like [`Client::list_nodes`] but also returns the HTTP response metadata.
+    pub fn list_nodes_with_response(
+        &self,
+    ) -> Result<ResponseEnvelope<Vec<responses::ClusterNode>>> {
+        self.block_on(self.client.list_nodes_with_response())
     }
 
     /// Lists virtual hosts in the cluster.
     pub fn list_vhosts(&self) -> Result<Vec<responses::VirtualHost>> {
-        let response = self.http_get("vhosts", None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.list_vhosts())
+    }
+
+    /// Like [`Client::list_vhosts`] but also returns the HTTP response metadata.
+    pub fn list_vhosts_with_response(
+        &self,
+    ) -> Result<ResponseEnvelope<Vec<responses::VirtualHost>>> {
+        self.block_on(self.client.list_vhosts_with_response())
     }
 
     /// Lists users in the internal database.
     pub fn list_users(&self) -> Result<Vec<responses::User>> {
-        let response = self.http_get("users", None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.list_users())
     }
 
     /// Lists users in the internal database that do not have access
     /// to any virtual hosts.
     pub fn list_users_without_permissions(&self) -> Result<Vec<responses::User>> {
-        let response = self.http_get("users/without-permissions", None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.list_users_without_permissions())
     }
 
     /// Lists all AMQP 1.0 and 0-9-1 client connections across the cluster.
     pub fn list_connections(&self) -> Result<Vec<responses::Connection>> {
-        let response = self.http_get("connections", None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.list_connections())
+    }
+
+    /// Lists client connections that use the given client-provided connection name
+    /// (the `connection_name` client property).
+    ///
+    /// The filtering is performed on the client side.
+    pub fn list_connections_with_client_name(
+        &self,
+        name: &str,
+    ) -> Result<Vec<responses::Connection>> {
+        self.block_on(self.client.list_connections_with_client_name(name))
     }
 
+    /// Lists client connections on the given cluster node, e.g. to see what connections
+    /// would be affected by a restart of the node.
+    ///
+    /// The filtering is performed on the client side.
+    pub fn list_connections_on_node(&self, node: &str) -> Result<Vec<responses::Connection>> {
+        self.block_on(self.client.list_connections_on_node(node))
+    }
+
+    /// Like [`Client::list_connections`] but also returns the HTTP response metadata.
+    pub fn list_connections_with_response(
+        &self,
+    ) -> Result<ResponseEnvelope<Vec<responses::Connection>>> {
+        self.block_on(self.client.list_connections_with_response())
+    }
+
+    /// Returns information about a single client connection, including its client properties.
+    ///
+    /// Connection names can be obtained with [`Client::list_connections`] and similar functions.
     pub fn get_connection_info(&self, name: &str) -> Result<responses::Connection> {
-        let response = self.http_get(path!("connections", name), None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.get_connection_info(name))
+    }
+
+    /// Lists the sessions (and their links) of an AMQP 1.0 connection.
+    /// Requires RabbitMQ 4.0 or later.
+    pub fn list_connection_sessions(&self, name: &str) -> Result<Vec<responses::AmqpSession>> {
+        self.block_on(self.client.list_connection_sessions(name))
     }
 
     pub fn get_stream_connection_info(
@@ -276,73 +634,34 @@ where
         virtual_host: &str,
         name: &str,
     ) -> Result<responses::Connection> {
-        let response = self.http_get(
-            path!("stream", "connections", virtual_host, name),
-            None,
-            None,
-        )?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.get_stream_connection_info(virtual_host, name))
     }
 
+    /// Closes a client connection. The reason, if provided, is sent to the client
+    /// (with the `X-Reason` header) and logged by the server.
+    ///
+    /// Connections that no longer exist are ignored.
     pub fn close_connection(&self, name: &str, reason: Option<&str>) -> Result<()> {
-        match reason {
-            None => self.http_delete(
-                path!("connections", name),
-                Some(StatusCode::NOT_FOUND),
-                None,
-            )?,
-            Some(value) => {
-                let mut headers = HeaderMap::new();
-                let hdr = HeaderValue::from_str(value)?;
-                headers.insert("X-Reason", hdr);
-                self.http_delete_with_headers(path!("connections", name), headers, None, None)?
-            }
-        };
-        Ok(())
+        self.block_on(self.client.close_connection(name, reason))
     }
 
     pub fn close_user_connections(&self, username: &str, reason: Option<&str>) -> Result<()> {
-        match reason {
-            None => self.http_delete(
-                path!("connections", "username", username),
-                Some(StatusCode::NOT_FOUND),
-                None,
-            )?,
-            Some(value) => {
-                let mut headers = HeaderMap::new();
-                let hdr = HeaderValue::from_str(value)?;
-                headers.insert("X-Reason", hdr);
-                self.http_delete_with_headers(
-                    path!("connections", "username", username),
-                    headers,
-                    None,
-                    None,
-                )?
-            }
-        };
-        Ok(())
+        self.block_on(self.client.close_user_connections(username, reason))
     }
 
     /// Lists all connections in the given virtual host.
     pub fn list_connections_in(&self, virtual_host: &str) -> Result<Vec<responses::Connection>> {
-        let response = self.http_get(path!("vhosts", virtual_host, "connections"), None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.list_connections_in(virtual_host))
     }
 
     /// Lists all connections of a specific user.
     pub fn list_user_connections(&self, username: &str) -> Result<Vec<responses::UserConnection>> {
-        let response = self.http_get(path!("connections", "username", username), None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.list_user_connections(username))
     }
 
     /// Lists all RabbitMQ Stream Protocol client connections across the cluster.
     pub fn list_stream_connections(&self) -> Result<Vec<responses::Connection>> {
-        let response = self.http_get("stream/connections", None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.list_stream_connections())
     }
 
     /// Lists RabbitMQ Stream Protocol client connections in the given virtual host.
@@ -350,32 +669,35 @@ where
         &self,
         virtual_host: &str,
     ) -> Result<Vec<responses::Connection>> {
-        let response = self.http_get(path!("stream", "connections", virtual_host), None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.list_stream_connections_in(virtual_host))
     }
 
     /// Lists all channels across the cluster.
     pub fn list_channels(&self) -> Result<Vec<responses::Channel>> {
-        let response = self.http_get("channels", None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.list_channels())
     }
 
     /// Lists all channels in the given virtual host.
     pub fn list_channels_in(&self, virtual_host: &str) -> Result<Vec<responses::Channel>> {
-        let response = self.http_get(path!("vhosts", virtual_host, "channels"), None, None)?;
+        self.block_on(self.client.list_channels_in(virtual_host))
+    }
+
+    /// Lists channels on the given cluster node, e.g. to see what channels
+    /// would be affected by a restart of the node.
+    ///
+    /// The filtering is performed on the client side.
+    pub fn list_channels_on_node(&self, node: &str) -> Result<Vec<responses::Channel>> {
+        self.block_on(self.client.list_channels_on_node(node))
+    }
 
-        let response = response.json()?;
-        Ok(response)
+    /// Lists the channels of the given client connection.
+    pub fn list_channels_on(&self, connection_name: &str) -> Result<Vec<responses::Channel>> {
+        self.block_on(self.client.list_channels_on(connection_name))
     }
 
     /// Lists all stream publishers across the cluster.
     pub fn list_stream_publishers(&self) -> Result<Vec<responses::StreamPublisher>> {
-        let response = self.http_get(path!("stream", "publishers"), None, None)?;
-
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.list_stream_publishers())
     }
 
     /// Lists stream publishers publishing to the given stream.
@@ -383,10 +705,7 @@ where
         &self,
         virtual_host: &str,
     ) -> Result<Vec<responses::StreamPublisher>> {
-        let response = self.http_get(path!("stream", "publishers", virtual_host), None, None)?;
-
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.list_stream_publishers_in(virtual_host))
     }
 
     /// Lists stream publishers of the given stream.
@@ -395,14 +714,7 @@ where
         virtual_host: &str,
         name: &str,
     ) -> Result<Vec<responses::StreamPublisher>> {
-        let response = self.http_get(
-            path!("stream", "publishers", virtual_host, name),
-            None,
-            None,
-        )?;
-
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.list_stream_publishers_of(virtual_host, name))
     }
 
     /// Lists stream publishers on the given stream connection.
@@ -411,22 +723,15 @@ where
         virtual_host: &str,
         name: &str,
     ) -> Result<Vec<responses::StreamPublisher>> {
-        let response = self.http_get(
-            path!("stream", "connections", virtual_host, name, "publishers"),
-            None,
-            None,
-        )?;
-
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(
+            self.client
+                .list_stream_publishers_on_connection(virtual_host, name),
+        )
     }
 
     /// Lists all stream consumers across the cluster.
     pub fn list_stream_consumers(&self) -> Result<Vec<responses::StreamConsumer>> {
-        let response = self.http_get(path!("stream", "consumers"), None, None)?;
-
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.list_stream_consumers())
     }
 
     /// Lists stream consumers on connections in the given virtual host.
@@ -434,10 +739,7 @@ where
         &self,
         virtual_host: &str,
     ) -> Result<Vec<responses::StreamConsumer>> {
-        let response = self.http_get(path!("stream", "consumers", virtual_host), None, None)?;
-
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.list_stream_consumers_in(virtual_host))
     }
 
     /// Lists stream consumers on the given stream connection.
@@ -446,56 +748,61 @@ where
         virtual_host: &str,
         name: &str,
     ) -> Result<Vec<responses::StreamConsumer>> {
-        let response = self.http_get(
-            path!("stream", "connections", virtual_host, name, "consumers"),
-            None,
-            None,
-        )?;
-
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(
+            self.client
+                .list_stream_consumers_on_connection(virtual_host, name),
+        )
     }
 
     /// Lists all queues and streams across the cluster.
     pub fn list_queues(&self) -> Result<Vec<responses::QueueInfo>> {
-        let response = self.http_get("queues", None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.list_queues())
+    }
+
+    /// Like [`Client::list_queues`] but also returns the HTTP response metadata.
+    pub fn list_queues_with_response(&self) -> Result<ResponseEnvelope<Vec<responses::QueueInfo>>> {
+        self.block_on(self.client.list_queues_with_response())
     }
 
     /// Lists all queues and streams in the given virtual host.
     pub fn list_queues_in(&self, virtual_host: &str) -> Result<Vec<responses::QueueInfo>> {
-        let response = self.http_get(path!("queues", virtual_host), None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.list_queues_in(virtual_host))
+    }
+
+    /// Lists all queues and streams across the cluster, returning only their
+    /// identity and message counts. Much cheaper than [`Client::list_queues`]
+    /// both for the server and the client, so a good fit for frequent polling.
+    pub fn list_queue_summaries(&self) -> Result<Vec<responses::QueueSummary>> {
+        self.block_on(self.client.list_queue_summaries())
+    }
+
+    /// Like [`Client::list_queue_summaries`] but only lists queues and streams
+    /// in the given virtual host.
+    pub fn list_queue_summaries_in(
+        &self,
+        virtual_host: &str,
+    ) -> Result<Vec<responses::QueueSummary>> {
+        self.block_on(self.client.list_queue_summaries_in(virtual_host))
     }
 
     /// Lists all exchanges across the cluster.
     pub fn list_exchanges(&self) -> Result<Vec<responses::ExchangeInfo>> {
-        let response = self.http_get("exchanges", None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.list_exchanges())
     }
 
     /// Lists all exchanges in the given virtual host.
     pub fn list_exchanges_in(&self, virtual_host: &str) -> Result<Vec<responses::ExchangeInfo>> {
-        let response = self.http_get(path!("exchanges", virtual_host), None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.list_exchanges_in(virtual_host))
     }
 
     /// Lists all bindings (both queue-to-exchange and exchange-to-exchange ones) across the cluster.
     pub fn list_bindings(&self) -> Result<Vec<responses::BindingInfo>> {
-        let response = self.http_get("bindings", None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.list_bindings())
     }
 
     /// Lists all bindings (both queue-to-exchange and exchange-to-exchange ones)  in the given virtual host.
     pub fn list_bindings_in(&self, virtual_host: &str) -> Result<Vec<responses::BindingInfo>> {
-        let response = self.http_get(path!("bindings", virtual_host), None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.list_bindings_in(virtual_host))
     }
 
     /// Lists all bindings of a specific queue.
@@ -504,10 +811,7 @@ where
         virtual_host: &str,
         queue: &str,
     ) -> Result<Vec<responses::BindingInfo>> {
-        let response =
-            self.http_get(path!("queues", virtual_host, queue, "bindings"), None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.list_queue_bindings(virtual_host, queue))
     }
 
     /// Lists all bindings of a specific exchange where it is the source.
@@ -516,10 +820,9 @@ where
         virtual_host: &str,
         exchange: &str,
     ) -> Result<Vec<responses::BindingInfo>> {
-        self.list_exchange_bindings_with_source_or_destination(
-            virtual_host,
-            exchange,
-            BindindVertex::Source,
+        self.block_on(
+            self.client
+                .list_exchange_bindings_with_source(virtual_host, exchange),
         )
     }
 
@@ -529,65 +832,98 @@ where
         virtual_host: &str,
         exchange: &str,
     ) -> Result<Vec<responses::BindingInfo>> {
-        self.list_exchange_bindings_with_source_or_destination(
-            virtual_host,
-            exchange,
-            BindindVertex::Destination,
+        self.block_on(
+            self.client
+                .list_exchange_bindings_with_destination(virtual_host, exchange),
         )
     }
 
     /// Lists all consumers across the cluster.
     pub fn list_consumers(&self) -> Result<Vec<responses::Consumer>> {
-        let response = self.http_get("consumers", None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.list_consumers())
     }
 
     /// Lists all consumers in the given virtual host.
     pub fn list_consumers_in(&self, virtual_host: &str) -> Result<Vec<responses::Consumer>> {
-        let response = self.http_get(path!("consumers", virtual_host), None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.list_consumers_in(virtual_host))
+    }
+
+    /// Lists consumers of the given queue or stream.
+    ///
+    /// The filtering is performed on the client side.
+    pub fn list_consumers_on_queue(
+        &self,
+        virtual_host: &str,
+        queue: &str,
+    ) -> Result<Vec<responses::Consumer>> {
+        self.block_on(self.client.list_consumers_on_queue(virtual_host, queue))
     }
 
     /// Returns information about a cluster node.
     pub fn get_node_info(&self, name: &str) -> Result<responses::ClusterNode> {
-        let response = self.http_get(path!("nodes", name), None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.get_node_info(name))
     }
 
     /// Returns information about a cluster node.
     pub fn get_node_memory_footprint(&self, name: &str) -> Result<responses::NodeMemoryFootprint> {
-        let response = self.http_get(path!("nodes", name, "memory"), None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.get_node_memory_footprint(name))
+    }
+
+    /// Returns the memory breakdown of a cluster node as percentages of its total memory footprint,
+    /// as computed by the node. See [`responses::NodeMemoryFootprint::percentages`] for an alternative
+    /// that does not require an extra request.
+    pub fn get_node_memory_footprint_relative(
+        &self,
+        name: &str,
+    ) -> Result<responses::NodeMemoryRelativeFootprint> {
+        self.block_on(self.client.get_node_memory_footprint_relative(name))
     }
 
     /// Returns information about a virtual host.
     pub fn get_vhost(&self, name: &str) -> Result<responses::VirtualHost> {
-        let response = self.http_get(path!("vhosts", name), None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.get_vhost(name))
+    }
+
+    /// Returns the user the client is authenticated as, and its tags.
+    /// Useful for validating credentials before performing other operations.
+    pub fn current_user(&self) -> Result<responses::CurrentUser> {
+        self.block_on(self.client.current_user())
+    }
+
+    /// Salts and hashes a password on the server, using the hashing algorithm
+    /// configured on the cluster. The result can be passed as [`UserParams::password_hash`].
+    ///
+    /// The HTTP API expects the plaintext password as a part of the request path, so it will
+    /// end up in the access logs of any proxies or load balancers in front of the node.
+    /// The password is redacted from the URLs in the errors returned by this function.
+    ///
+    /// To compute a hash without contacting the server, use [`crate::password_hashing`].
+    pub fn hash_password(&self, password: &str) -> Result<String> {
+        self.block_on(self.client.hash_password(password))
     }
 
     /// Returns information about a user in the internal database.
     pub fn get_user(&self, name: &str) -> Result<responses::User> {
-        let response = self.http_get(path!("users", name), None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.get_user(name))
     }
 
     /// Returns information about a queue or stream.
     pub fn get_queue_info(&self, virtual_host: &str, name: &str) -> Result<responses::QueueInfo> {
-        let response = self.http_get(path!("queues", virtual_host, name), None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.get_queue_info(virtual_host, name))
+    }
+
+    /// Like [`Client::get_queue_info`] but also returns the HTTP response metadata.
+    pub fn get_queue_info_with_response(
+        &self,
+        virtual_host: &str,
+        name: &str,
+    ) -> Result<ResponseEnvelope<responses::QueueInfo>> {
+        self.block_on(self.client.get_queue_info_with_response(virtual_host, name))
     }
 
     /// Returns information about a stream.
     pub fn get_stream_info(&self, virtual_host: &str, name: &str) -> Result<responses::QueueInfo> {
-        self.get_queue_info(virtual_host, name)
+        self.block_on(self.client.get_stream_info(virtual_host, name))
     }
 
     /// Returns information about an exchange.
@@ -596,79 +932,103 @@ where
         virtual_host: &str,
         name: &str,
     ) -> Result<responses::ExchangeInfo> {
-        let response = self.http_get(path!("exchanges", virtual_host, name), None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.get_exchange_info(virtual_host, name))
     }
 
     /// Creates a virtual host.
     ///
     /// See [`VirtualHostParams`]
     pub fn create_vhost(&self, params: &VirtualHostParams) -> Result<()> {
-        self.update_vhost(params)
+        self.block_on(self.client.create_vhost(params))
     }
 
     /// Creates a virtual host or updates metadata of an existing one.
     ///
     /// See [`VirtualHostParams`]
     pub fn update_vhost(&self, params: &VirtualHostParams) -> Result<()> {
-        let _response = self.http_put(path!("vhosts", params.name), params, None, None)?;
-        Ok(())
+        self.block_on(self.client.update_vhost(params))
+    }
+
+    /// Updates the metadata (description, tags, default queue type, tracing) of an existing
+    /// virtual host, keeping the metadata that is not changed.
+    ///
+    /// Unlike [`Client::update_vhost`], which replaces all metadata, this fetches the virtual host
+    /// first and merges the changes into its current metadata.
+    ///
+    /// See [`VirtualHostChanges`]
+    pub fn amend_vhost(&self, name: &str, changes: &VirtualHostChanges<'_>) -> Result<()> {
+        self.block_on(self.client.amend_vhost(name, changes))
+    }
+
+    /// Starts a virtual host on a node, e.g. one that has been stopped on that node
+    /// because of a failure. Starting a virtual host that is already running is a no-op.
+    pub fn start_vhost_on_node(&self, vhost: &str, node: &str) -> Result<()> {
+        self.block_on(self.client.start_vhost_on_node(vhost, node))
+    }
+
+    /// Protects a virtual host from deletion: the server will refuse to delete it
+    /// until the protection is disabled with [`Client::disable_vhost_deletion_protection`].
+    ///
+    /// Requires RabbitMQ 4.1 or later.
+    pub fn enable_vhost_deletion_protection(&self, vhost: &str) -> Result<()> {
+        self.block_on(self.client.enable_vhost_deletion_protection(vhost))
+    }
+
+    /// Removes the deletion protection of a virtual host enabled with
+    /// [`Client::enable_vhost_deletion_protection`].
+    ///
+    /// Requires RabbitMQ 4.1 or later.
+    pub fn disable_vhost_deletion_protection(&self, vhost: &str) -> Result<()> {
+        self.block_on(self.client.disable_vhost_deletion_protection(vhost))
     }
 
     /// Adds a user to the internal database.
     ///
     /// See [`UserParams`] and [`crate::password_hashing`].
     pub fn create_user(&self, params: &UserParams) -> Result<()> {
-        let _response = self.http_put(path!("users", params.name), params, None, None)?;
-        Ok(())
+        self.block_on(self.client.create_user(params))
+    }
+
+    /// Adds a user to the internal database, or updates an existing one, with a plaintext password.
+    /// The password is salted and hashed by the server.
+    ///
+    /// See [`UserParamsWithPassword`].
+    pub fn create_user_with_password(&self, params: &UserParamsWithPassword<'_>) -> Result<()> {
+        self.block_on(self.client.create_user_with_password(params))
+    }
+
+    /// Updates an existing user.
+    ///
+    /// If `params.password_hash` is empty, the current password hash of the user
+    /// and the algorithm it was produced with are fetched and re-submitted,
+    /// so that updating, say, only the tags does not reset the credentials of the user.
+    /// As a consequence, this function cannot be used to make a user passwordless,
+    /// use [`Client::create_user`] with an empty password hash for that.
+    ///
+    /// Fails with [`Error::UnsupportedValue`] if the current password hash was produced with
+    /// an algorithm this client is not aware of, e.g. the legacy MD5 one, since it cannot be re-submitted.
+    pub fn update_user(&self, params: &UserParams) -> Result<()> {
+        self.block_on(self.client.update_user(params))
     }
 
     pub fn declare_permissions(&self, params: &Permissions) -> Result<()> {
-        let _response = self.http_put(
-            // /api/permissions/vhost/user
-            path!("permissions", params.vhost, params.user),
-            params,
-            None,
-            None,
-        )?;
-        Ok(())
+        self.block_on(self.client.declare_permissions(params))
     }
 
     pub fn grant_permissions(&self, vhost: &str, user: &str) -> Result<()> {
-        let _response = self.http_delete(path!("permissions", vhost, user), None, None)?;
-        Ok(())
+        self.block_on(self.client.grant_permissions(vhost, user))
     }
 
     pub fn declare_queue(&self, vhost: &str, params: &QueueParams) -> Result<()> {
-        let _response = self.http_put(path!("queues", vhost, params.name), params, None, None)?;
-        Ok(())
+        self.block_on(self.client.declare_queue(vhost, params))
     }
 
     pub fn declare_stream(&self, vhost: &str, params: &StreamParams<'_>) -> Result<()> {
-        let mut m: Map<String, Value> = Map::new();
-
-        if let Some(m2) = params.arguments.clone() {
-            m.extend(m2);
-        };
-
-        if let Some(val) = params.max_length_bytes {
-            m.insert("max_length_bytes".to_owned(), json!(val));
-        };
-        if let Some(val) = params.max_segment_length_bytes {
-            m.insert("max_segment_length_bytes".to_owned(), json!(val));
-        };
-
-        let q_params = QueueParams::new_stream(params.name, Some(m));
-        let _response =
-            self.http_put(path!("queues", vhost, params.name), &q_params, None, None)?;
-        Ok(())
+        self.block_on(self.client.declare_stream(vhost, params))
     }
 
     pub fn declare_exchange(&self, vhost: &str, params: &ExchangeParams) -> Result<()> {
-        let _response =
-            self.http_put(path!("exchanges", vhost, params.name), params, None, None)?;
-        Ok(())
+        self.block_on(self.client.declare_exchange(vhost, params))
     }
 
     pub fn bind_queue(
@@ -679,21 +1039,10 @@ where
         routing_key: Option<&str>,
         arguments: XArguments,
     ) -> Result<()> {
-        let mut body = Map::<String, Value>::new();
-        if let Some(rk) = routing_key {
-            body.insert("routing_key".to_owned(), json!(rk));
-        }
-        if let Some(args) = arguments {
-            body.insert("arguments".to_owned(), json!(args));
-        }
-
-        let _response = self.http_post(
-            path!("bindings", vhost, "e", exchange, "q", queue),
-            &body,
-            None,
-            None,
-        )?;
-        Ok(())
+        self.block_on(
+            self.client
+                .bind_queue(vhost, queue, exchange, routing_key, arguments),
+        )
     }
 
     pub fn bind_exchange(
@@ -704,81 +1053,38 @@ where
         routing_key: Option<&str>,
         arguments: XArguments,
     ) -> Result<()> {
-        let mut body = Map::<String, Value>::new();
-        if let Some(rk) = routing_key {
-            body.insert("routing_key".to_owned(), json!(rk));
-        }
-        if let Some(args) = arguments {
-            body.insert("arguments".to_owned(), json!(args));
-        }
-
-        let _response = self.http_post(
-            path!("bindings", vhost, "e", source, "e", destination),
-            &body,
-            None,
-            None,
-        )?;
-        Ok(())
+        self.block_on(
+            self.client
+                .bind_exchange(vhost, destination, source, routing_key, arguments),
+        )
     }
 
     pub fn delete_vhost(&self, vhost: &str, idempotently: bool) -> Result<()> {
-        let excludes = if idempotently {
-            Some(StatusCode::NOT_FOUND)
-        } else {
-            None
-        };
-        let _response = self.http_delete(path!("vhosts", vhost), excludes, None)?;
-        Ok(())
+        self.block_on(self.client.delete_vhost(vhost, idempotently))
     }
 
     pub fn delete_user(&self, username: &str, idempotently: bool) -> Result<()> {
-        let excludes = if idempotently {
-            Some(StatusCode::NOT_FOUND)
-        } else {
-            None
-        };
-        let _response = self.http_delete(path!("users", username), excludes, None)?;
-        Ok(())
+        self.block_on(self.client.delete_user(username, idempotently))
     }
 
     pub fn delete_users(&self, usernames: Vec<&str>) -> Result<()> {
-        let delete = BulkUserDelete { usernames };
-        let _response = self.http_post(path!("users", "bulk-delete"), &delete, None, None)?;
-        Ok(())
+        self.block_on(self.client.delete_users(usernames))
     }
 
     pub fn clear_permissions(&self, vhost: &str, username: &str, idempotently: bool) -> Result<()> {
-        let excludes = if idempotently {
-            Some(StatusCode::NOT_FOUND)
-        } else {
-            None
-        };
-        let _response = self.http_delete(path!("permissions", vhost, username), excludes, None)?;
-        Ok(())
+        self.block_on(self.client.clear_permissions(vhost, username, idempotently))
     }
 
     pub fn delete_queue(&self, vhost: &str, name: &str, idempotently: bool) -> Result<()> {
-        let excludes = if idempotently {
-            Some(StatusCode::NOT_FOUND)
-        } else {
-            None
-        };
-        let _response = self.http_delete(path!("queues", vhost, name), excludes, None)?;
-        Ok(())
+        self.block_on(self.client.delete_queue(vhost, name, idempotently))
     }
 
     pub fn delete_stream(&self, vhost: &str, name: &str, idempotently: bool) -> Result<()> {
-        self.delete_queue(vhost, name, idempotently)
+        self.block_on(self.client.delete_stream(vhost, name, idempotently))
     }
 
     pub fn delete_exchange(&self, vhost: &str, name: &str, idempotently: bool) -> Result<()> {
-        let excludes = if idempotently {
-            Some(StatusCode::NOT_FOUND)
-        } else {
-            None
-        };
-        let _response = self.http_delete(path!("exchanges", vhost, name), excludes, None)?;
-        Ok(())
+        self.block_on(self.client.delete_exchange(vhost, name, idempotently))
     }
 
     pub fn delete_binding(
@@ -790,77 +1096,35 @@ where
         routing_key: &str,
         arguments: XArguments,
     ) -> Result<HttpClientResponse> {
-        let args = arguments.unwrap();
-
-        // to delete a binding, we need properties, that we can get from the server
-        // so we search for the binding before deleting it
-        let bindings = match destination_type {
-            BindingDestinationType::Queue => self.list_queue_bindings(virtual_host, destination)?,
-            BindingDestinationType::Exchange => {
-                self.list_exchange_bindings_with_destination(virtual_host, destination)?
-            }
-        };
-
-        let bs: Vec<&BindingInfo> = bindings
-            .iter()
-            .filter(|b| b.source == source && b.routing_key == routing_key && b.arguments.0 == args)
-            .collect();
-        match bs.len() {
-            0 => Err(NotFound),
-            1 => {
-                let first_key = bs.first().unwrap().properties_key.clone();
-                let path_appreviation = destination_type.path_appreviation();
-                let path = match first_key {
-                    Some(pk) => {
-                        path!(
-                            // /api/bindings/vhost/e/exchange/[eq]/destination/props
-                            "bindings",
-                            virtual_host,
-                            "e",
-                            source,
-                            path_appreviation,
-                            destination,
-                            pk.as_str()
-                        )
-                    }
-                    None => {
-                        path!(
-                            // /api/bindings/vhost/e/exchange/[eq]/destination/
-                            "bindings",
-                            virtual_host,
-                            "e",
-                            source,
-                            path_appreviation,
-                            destination
-                        )
-                    }
-                };
-                let response = self.http_delete(&path, None, None)?;
-                Ok(response)
-            }
-            _ => Err(Error::MultipleMatchingBindings),
-        }
+        self.block_on(async {
+            let response = self
+                .client
+                .delete_binding(
+                    virtual_host,
+                    source,
+                    destination,
+                    destination_type,
+                    routing_key,
+                    arguments,
+                )
+                .await?;
+            into_blocking_response(response).await
+        })
     }
 
     pub fn purge_queue(&self, virtual_host: &str, name: &str) -> Result<()> {
-        let _response =
-            self.http_delete(path!("queues", virtual_host, name, "contents"), None, None)?;
-        Ok(())
+        self.block_on(self.client.purge_queue(virtual_host, name))
     }
 
     pub fn list_runtime_parameters(&self) -> Result<Vec<responses::RuntimeParameter>> {
-        let response = self.http_get("parameters", None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.list_runtime_parameters())
     }
 
     pub fn list_runtime_parameters_of_component(
         &self,
         component: &str,
     ) -> Result<Vec<responses::RuntimeParameter>> {
-        let response = self.http_get(path!("parameters", component), None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.list_runtime_parameters_of_component(component))
     }
 
     pub fn list_runtime_parameters_of_component_in(
@@ -868,9 +1132,10 @@ where
         component: &str,
         vhost: &str,
     ) -> Result<Vec<responses::RuntimeParameter>> {
-        let response = self.http_get(path!("parameters", component, vhost), None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(
+            self.client
+                .list_runtime_parameters_of_component_in(component, vhost),
+        )
     }
 
     pub fn get_runtime_parameter(
@@ -879,73 +1144,51 @@ where
         vhost: &str,
         name: &str,
     ) -> Result<responses::RuntimeParameter> {
-        let response = self.http_get(path!("parameters", component, vhost, name), None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.get_runtime_parameter(component, vhost, name))
     }
 
     pub fn upsert_runtime_parameter<'a>(
         &self,
         param: &'a RuntimeParameterDefinition<'a>,
     ) -> Result<()> {
-        let _response = self.http_put(
-            path!("parameters", param.component, param.vhost, param.name),
-            &param,
-            None,
-            None,
-        )?;
-        Ok(())
+        self.block_on(self.client.upsert_runtime_parameter(param))
     }
 
     pub fn clear_runtime_parameter(&self, component: &str, vhost: &str, name: &str) -> Result<()> {
-        let _response =
-            self.http_delete(path!("parameters", component, vhost, name), None, None)?;
-        Ok(())
+        self.block_on(self.client.clear_runtime_parameter(component, vhost, name))
     }
 
     pub fn clear_all_runtime_parameters(&self) -> Result<()> {
-        let params = self.list_runtime_parameters()?;
-        for rp in params {
-            self.clear_runtime_parameter(&rp.component, &rp.vhost, &rp.name)?
-        }
-        Ok(())
+        self.block_on(self.client.clear_all_runtime_parameters())
     }
 
     pub fn clear_all_runtime_parameters_of_component(&self, component: &str) -> Result<()> {
-        let params = self.list_runtime_parameters_of_component(component)?;
-        for rp in params {
-            self.clear_runtime_parameter(&rp.component, &rp.vhost, &rp.name)?
-        }
-        Ok(())
+        self.block_on(
+            self.client
+                .clear_all_runtime_parameters_of_component(component),
+        )
     }
 
     pub fn list_global_runtime_parameters(&self) -> Result<Vec<responses::GlobalRuntimeParameter>> {
-        let response = self.http_get("global-parameters", None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.list_global_runtime_parameters())
     }
 
     pub fn get_global_runtime_parameter(
         &self,
         name: &str,
     ) -> Result<responses::GlobalRuntimeParameter> {
-        let response = self.http_get(path!("global-parameters", name), None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.get_global_runtime_parameter(name))
     }
 
     pub fn upsert_global_runtime_parameter<'a>(
         &self,
         param: &'a GlobalRuntimeParameterDefinition<'a>,
     ) -> Result<()> {
-        let _response =
-            self.http_put(path!("global-parameters", param.name), &param, None, None)?;
-        Ok(())
+        self.block_on(self.client.upsert_global_runtime_parameter(param))
     }
 
     pub fn clear_global_runtime_parameter(&self, name: &str) -> Result<()> {
-        let _response = self.http_delete(path!("global-parameters", name), None, None)?;
-        Ok(())
+        self.block_on(self.client.clear_global_runtime_parameter(name))
     }
 
     pub fn set_user_limit(
@@ -953,31 +1196,19 @@ where
         username: &str,
         limit: EnforcedLimitParams<UserLimitTarget>,
     ) -> Result<()> {
-        let body = json!({"value": limit.value});
-        let _response = self.http_put(
-            path!("user-limits", username, limit.kind),
-            &body,
-            None,
-            None,
-        )?;
-        Ok(())
+        self.block_on(self.client.set_user_limit(username, limit))
     }
 
     pub fn clear_user_limit(&self, username: &str, kind: UserLimitTarget) -> Result<()> {
-        let _response = self.http_delete(path!("user-limits", username, kind), None, None)?;
-        Ok(())
+        self.block_on(self.client.clear_user_limit(username, kind))
     }
 
     pub fn list_all_user_limits(&self) -> Result<Vec<responses::UserLimits>> {
-        let response = self.http_get("user-limits", None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.list_all_user_limits())
     }
 
     pub fn list_user_limits(&self, username: &str) -> Result<Vec<responses::UserLimits>> {
-        let response = self.http_get(path!("user-limits", username), None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.list_user_limits(username))
     }
 
     pub fn set_vhost_limit(
@@ -985,160 +1216,111 @@ where
         vhost: &str,
         limit: EnforcedLimitParams<VirtualHostLimitTarget>,
     ) -> Result<()> {
-        let body = json!({"value": limit.value});
-        let _response =
-            self.http_put(path!("vhost-limits", vhost, limit.kind), &body, None, None)?;
-        Ok(())
+        self.block_on(self.client.set_vhost_limit(vhost, limit))
     }
 
     pub fn clear_vhost_limit(&self, vhost: &str, kind: VirtualHostLimitTarget) -> Result<()> {
-        let _response = self.http_delete(
-            path!("vhost-limits", vhost, kind),
-            Some(StatusCode::NOT_FOUND),
-            None,
-        )?;
-        Ok(())
+        self.block_on(self.client.clear_vhost_limit(vhost, kind))
     }
 
     pub fn list_all_vhost_limits(&self) -> Result<Vec<responses::VirtualHostLimits>> {
-        let response = self.http_get("vhost-limits", None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.list_all_vhost_limits())
     }
 
     pub fn list_vhost_limits(&self, vhost: &str) -> Result<Vec<responses::VirtualHostLimits>> {
-        let response = self.http_get(path!("vhost-limits", vhost), None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.list_vhost_limits(vhost))
     }
 
     pub fn get_cluster_name(&self) -> Result<responses::ClusterIdentity> {
-        let response = self.http_get("cluster-name", None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.get_cluster_name())
     }
 
     pub fn set_cluster_name(&self, new_name: &str) -> Result<()> {
-        let body = json!({"name": new_name});
-        let _response = self.http_put("cluster-name", &body, None, None)?;
-        Ok(())
+        self.block_on(self.client.set_cluster_name(new_name))
     }
 
     pub fn get_cluster_tags(&self) -> Result<responses::ClusterTags> {
-        let response = self.get_global_runtime_parameter("cluster_tags")?;
-        Ok(ClusterTags::from(response.value))
+        self.block_on(self.client.get_cluster_tags())
     }
 
     pub fn set_cluster_tags(&self, tags: Map<String, Value>) -> Result<()> {
-        let grp = GlobalRuntimeParameterDefinition {
-            name: "cluster_tags",
-            value: tags,
-        };
-        self.upsert_global_runtime_parameter(&grp)?;
-        Ok(())
+        self.block_on(self.client.set_cluster_tags(tags))
     }
 
     pub fn clear_cluster_tags(&self) -> Result<()> {
-        self.clear_global_runtime_parameter("cluster_tags")?;
-        Ok(())
+        self.block_on(self.client.clear_cluster_tags())
     }
 
-    pub fn get_policy(&self, vhost: &str, name: &str) -> Result<responses::Policy> {
-        let response = self.http_get(path!("policies", vhost, name), None, None)?;
-        let response = response.json()?;
-        Ok(response)
+    /// Returns the mappings of client certificate distinguished names (or usernames)
+    /// to the virtual hosts MQTT clients connect to.
+    pub fn get_mqtt_default_vhosts(&self) -> Result<responses::MqttDefaultVirtualHosts> {
+        self.block_on(self.client.get_mqtt_default_vhosts())
     }
 
-    pub fn list_policies(&self) -> Result<Vec<responses::Policy>> {
-        let response = self.http_get("policies", None, None)?;
-        let response = response.json()?;
-        Ok(response)
+    /// Sets the mappings of client certificate distinguished names (or usernames)
+    /// to virtual hosts used by MQTT clients, e.g. `{"O=client,CN=sensor-1": "sensors"}`.
+    pub fn set_mqtt_default_vhosts(&self, mappings: Map<String, Value>) -> Result<()> {
+        self.block_on(self.client.set_mqtt_default_vhosts(mappings))
     }
 
-    pub fn list_policies_in(&self, vhost: &str) -> Result<Vec<responses::Policy>> {
-        let response = self.http_get(path!("policies", vhost), None, None)?;
-        let response = response.json()?;
-        Ok(response)
+    pub fn clear_mqtt_default_vhosts(&self) -> Result<()> {
+        self.block_on(self.client.clear_mqtt_default_vhosts())
+    }
+
+    pub fn get_policy(&self, vhost: &str, name: &str) -> Result<responses::Policy> {
+        self.block_on(self.client.get_policy(vhost, name))
+    }
+
+    pub fn list_policies(&self) -> Result<Vec<responses::Policy>> {
+        self.block_on(self.client.list_policies())
+    }
+
+    pub fn list_policies_in(&self, vhost: &str) -> Result<Vec<responses::Policy>> {
+        self.block_on(self.client.list_policies_in(vhost))
     }
 
     pub fn declare_policy(&self, params: &PolicyParams) -> Result<()> {
-        let _response = self.http_put(
-            path!("policies", params.vhost, params.name),
-            params,
-            None,
-            None,
-        )?;
-        Ok(())
+        self.block_on(self.client.declare_policy(params))
     }
 
     pub fn delete_policy(&self, vhost: &str, name: &str) -> Result<()> {
-        let _response = self.http_delete(
-            path!("policies", vhost, name),
-            Some(StatusCode::NOT_FOUND),
-            None,
-        )?;
-        Ok(())
+        self.block_on(self.client.delete_policy(vhost, name))
     }
 
     pub fn get_operator_policy(&self, vhost: &str, name: &str) -> Result<responses::Policy> {
-        let response = self.http_get(path!("operator-policies", vhost, name), None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.get_operator_policy(vhost, name))
     }
 
     pub fn list_operator_policies(&self) -> Result<Vec<responses::Policy>> {
-        let response = self.http_get("operator-policies", None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.list_operator_policies())
     }
 
     pub fn list_operator_policies_in(&self, vhost: &str) -> Result<Vec<responses::Policy>> {
-        let response = self.http_get(path!("operator-policies", vhost), None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.list_operator_policies_in(vhost))
     }
 
     pub fn declare_operator_policy(&self, params: &PolicyParams) -> Result<()> {
-        let _response = self.http_put(
-            path!("operator-policies", params.vhost, params.name),
-            params,
-            None,
-            None,
-        )?;
-        Ok(())
+        self.block_on(self.client.declare_operator_policy(params))
     }
 
     pub fn delete_operator_policy(&self, vhost: &str, name: &str) -> Result<()> {
-        let _response = self.http_delete(
-            path!("operator-policies", vhost, name),
-            Some(StatusCode::NOT_FOUND),
-            None,
-        )?;
-        Ok(())
+        self.block_on(self.client.delete_operator_policy(vhost, name))
     }
 
     pub fn list_permissions(&self) -> Result<Vec<responses::Permissions>> {
-        let response = self.http_get("permissions", None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.list_permissions())
     }
 
     pub fn list_permissions_in(&self, vhost: &str) -> Result<Vec<responses::Permissions>> {
-        let response = self.http_get(path!("vhosts", vhost, "permissions"), None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.list_permissions_in(vhost))
     }
 
     pub fn list_permissions_of(&self, user: &str) -> Result<Vec<responses::Permissions>> {
-        let response = self.http_get(path!("users", user, "permissions"), None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.list_permissions_of(user))
     }
 
     pub fn get_permissions(&self, vhost: &str, user: &str) -> Result<responses::Permissions> {
-        let response = self.http_get(path!("permissions", vhost, user), None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.get_permissions(vhost, user))
     }
 
     //
@@ -1146,139 +1328,387 @@ where
     //
 
     pub fn rebalance_queue_leaders(&self) -> Result<()> {
-        self.http_post("rebalance/queues", &json!({}), None, None)?;
-        Ok(())
+        self.block_on(self.client.rebalance_queue_leaders())
+    }
+
+    //
+    // Quorum queue replicas
+    //
+
+    /// Adds a replica of a quorum queue on the given node.
+    pub fn add_quorum_queue_replica(&self, vhost: &str, name: &str, node: &str) -> Result<()> {
+        self.block_on(self.client.add_quorum_queue_replica(vhost, name, node))
+    }
+
+    /// Removes the replica of a quorum queue on the given node.
+    pub fn delete_quorum_queue_replica(&self, vhost: &str, name: &str, node: &str) -> Result<()> {
+        self.block_on(self.client.delete_quorum_queue_replica(vhost, name, node))
+    }
+
+    /// Adds a replica on the given node to every quorum queue that matches the patterns,
+    /// e.g. after a new node joins the cluster.
+    ///
+    /// See [`QuorumQueueGrowthStrategy`] for which of the matching queues are grown.
+    pub fn grow_quorum_queue(
+        &self,
+        node: &str,
+        vhost_pattern: &str,
+        queue_pattern: &str,
+        strategy: QuorumQueueGrowthStrategy,
+    ) -> Result<()> {
+        self.block_on(
+            self.client
+                .grow_quorum_queue(node, vhost_pattern, queue_pattern, strategy),
+        )
+    }
+
+    /// Removes the replicas on the given node from all quorum queues,
+    /// e.g. before the node is permanently removed from the cluster.
+    pub fn shrink_quorum_queue(&self, node: &str) -> Result<()> {
+        self.block_on(self.client.shrink_quorum_queue(node))
     }
 
     //
     // Definitions
+    //
 
     pub fn export_cluster_wide_definitions(&self) -> Result<String> {
-        self.export_cluster_wide_definitions_as_string()
+        self.block_on(self.client.export_cluster_wide_definitions())
     }
 
     pub fn export_cluster_wide_definitions_as_string(&self) -> Result<String> {
-        let response = self.http_get("definitions", None, None)?;
-        let response = response.text()?;
-        Ok(response)
+        self.block_on(self.client.export_cluster_wide_definitions_as_string())
     }
 
     pub fn export_cluster_wide_definitions_as_data(&self) -> Result<ClusterDefinitionSet> {
-        let response = self.http_get("definitions", None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.export_cluster_wide_definitions_as_data())
     }
 
     pub fn export_vhost_definitions(&self, vhost: &str) -> Result<String> {
-        self.export_vhost_definitions_as_string(vhost)
+        self.block_on(self.client.export_vhost_definitions(vhost))
     }
 
     pub fn export_vhost_definitions_as_string(&self, vhost: &str) -> Result<String> {
-        let response = self.http_get(path!("definitions", vhost), None, None)?;
-        let response = response.text()?;
-        Ok(response)
+        self.block_on(self.client.export_vhost_definitions_as_string(vhost))
     }
 
     pub fn export_vhost_definitions_as_data(
         &self,
         vhost: &str,
     ) -> Result<VirtualHostDefinitionSet> {
-        let response = self.http_get(path!("definitions", vhost), None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.export_vhost_definitions_as_data(vhost))
+    }
+
+    /// Exports cluster-wide definitions and writes them to `writer` as they arrive,
+    /// without buffering the entire document in memory.
+    ///
+    /// Returns the number of bytes written. With [`ClientBuilder#with_max_response_size`],
+    /// a body of unknown size that exceeds the limit fails with [`Error::ResponseTooLarge`]
+    /// after the chunks received within the limit have been written.
+    pub fn export_definitions_to_writer<W>(&self, writer: &mut W) -> Result<u64>
+    where
+        W: Write,
+    {
+        let mut writer = BlockingWriter(writer);
+        self.block_on(self.client.export_definitions_to_writer(&mut writer))
+    }
+
+    /// Exports definitions of a single virtual host and writes them to `writer` as they arrive.
+    ///
+    /// See [`Client::export_definitions_to_writer`].
+    pub fn export_vhost_definitions_to_writer<W>(&self, vhost: &str, writer: &mut W) -> Result<u64>
+    where
+        W: Write,
+    {
+        let mut writer = BlockingWriter(writer);
+        self.block_on(
+            self.client
+                .export_vhost_definitions_to_writer(vhost, &mut writer),
+        )
+    }
+
+    /// Applies a single operation produced by [`responses::ClusterDefinitionSet::operations`].
+    ///
+    /// Applying operations one by one is slower than [`Client::import_cluster_wide_definitions`]
+    /// but makes it possible to report progress and handle errors for individual objects.
+    pub fn apply_definition_operation(&self, op: &DefinitionOperation<'_>) -> Result<()> {
+        self.block_on(self.client.apply_definition_operation(op))
+    }
+
+    /// Declares the policies, exchanges, queues and bindings of the given definition set,
+    /// for example, one produced by [`crate::topology_generator::TopologyGenerator`].
+    /// Other objects in the set, e.g. users or virtual hosts, are ignored.
+    ///
+    /// Up to `max_concurrency` requests are issued at a time. Bindings are declared
+    /// after all exchanges and queues. Stops at the first error.
+    pub fn declare_topology(
+        &self,
+        topology: &ClusterDefinitionSet,
+        max_concurrency: usize,
+    ) -> Result<()> {
+        self.block_on(self.client.declare_topology(topology, max_concurrency))
+    }
+
+    /// Deletes the queues, exchanges and policies of the given definition set, for example,
+    /// one produced by [`crate::topology_generator::TopologyGenerator`]. Their bindings are
+    /// deleted together with them. Objects that do not exist are skipped.
+    ///
+    /// Up to `max_concurrency` requests are issued at a time. Stops at the first error.
+    pub fn delete_topology(
+        &self,
+        topology: &ClusterDefinitionSet,
+        max_concurrency: usize,
+    ) -> Result<()> {
+        self.block_on(self.client.delete_topology(topology, max_concurrency))
+    }
+
+    /// Exports cluster-wide definitions and writes them to a file.
+    ///
+    /// The definitions are first written to a temporary file in the same directory
+    /// which is flushed to disk and then renamed, so `path` never contains a partially written backup.
+    /// Paths with the `.gz` extension are compressed with gzip, which requires the `gzip` feature:
+    /// without it, [`crate::error::Error::FeatureNotEnabled`] is returned before anything is exported.
+    pub fn backup_definitions_to_file<T>(&self, path: T) -> Result<()>
+    where
+        T: AsRef<Path>,
+    {
+        self.block_on(self.client.backup_definitions_to_file(path))
+    }
+
+    /// Reads definitions from a file produced by [`Client::backup_definitions_to_file`]
+    /// (or exported by other means) and imports them.
+    ///
+    /// Gzip-compressed files are detected automatically. The file is checked to contain
+    /// a definitions document before anything is imported.
+    pub fn restore_definitions_from_file<T>(&self, path: T) -> Result<()>
+    where
+        T: AsRef<Path>,
+    {
+        self.block_on(self.client.restore_definitions_from_file(path))
     }
 
     pub fn import_definitions(&self, definitions: Value) -> Result<()> {
-        self.import_cluster_wide_definitions(definitions)
+        self.block_on(self.client.import_definitions(definitions))
     }
 
     pub fn import_cluster_wide_definitions(&self, definitions: Value) -> Result<()> {
-        self.http_post("definitions", &definitions, None, None)?;
-        Ok(())
+        self.block_on(self.client.import_cluster_wide_definitions(definitions))
     }
 
     pub fn import_vhost_definitions(&self, vhost: &str, definitions: Value) -> Result<()> {
-        self.http_post(path!("definitions", vhost), &definitions, None, None)?;
-        Ok(())
+        self.block_on(self.client.import_vhost_definitions(vhost, definitions))
+    }
+
+    /// Imports cluster-wide definitions read from `reader`. The request body is streamed
+    /// from the reader, so the document is never fully loaded into memory.
+    ///
+    /// Unlike [`Client::import_cluster_wide_definitions`], the document is not
+    /// validated before it is sent, the server will reject invalid definitions.
+    pub fn import_definitions_from_reader<R>(&self, reader: R) -> Result<()>
+    where
+        R: Read + Send + 'static,
+    {
+        self.block_on(
+            self.client
+                .import_definitions_from_reader(BlockingReader(Box::new(reader))),
+        )
+    }
+
+    /// Imports definitions of a single virtual host read from `reader`.
+    ///
+    /// See [`Client::import_definitions_from_reader`].
+    pub fn import_vhost_definitions_from_reader<R>(&self, vhost: &str, reader: R) -> Result<()>
+    where
+        R: Read + Send + 'static,
+    {
+        self.block_on(
+            self.client
+                .import_vhost_definitions_from_reader(vhost, BlockingReader(Box::new(reader))),
+        )
     }
 
     //
     // Health Checks
     //
 
+    /// Performs an aliveness test: the node declares a test queue in the given virtual host,
+    /// publishes a message to it and consumes it.
+    ///
+    /// This is an older, coarse-grained check. Prefer the more specific `health_check_*` functions
+    /// on RabbitMQ versions that provide them.
+    pub fn aliveness_test(&self, vhost: &str) -> Result<responses::AlivenessTestResult> {
+        self.block_on(self.client.aliveness_test(vhost))
+    }
+
     pub fn health_check_cluster_wide_alarms(&self) -> Result<()> {
-        self.health_check_alarms("health/checks/alarms")
+        self.block_on(self.client.health_check_cluster_wide_alarms())
     }
 
     pub fn health_check_local_alarms(&self) -> Result<()> {
-        self.health_check_alarms("health/checks/local-alarms")
+        self.block_on(self.client.health_check_local_alarms())
     }
 
     pub fn health_check_if_node_is_quorum_critical(&self) -> Result<()> {
-        let path = "health/checks/node-is-quorum-critical";
-        self.boolean_health_check(path)
+        self.block_on(self.client.health_check_if_node_is_quorum_critical())
+    }
+
+    /// Checks if the node has classic mirrored queues without online synchronised mirrors.
+    ///
+    /// Only relevant for RabbitMQ 3.13 and earlier versions, classic queue mirroring was removed in RabbitMQ 4.0.
+    pub fn health_check_if_node_is_mirror_sync_critical(&self) -> Result<()> {
+        self.block_on(self.client.health_check_if_node_is_mirror_sync_critical())
     }
 
     pub fn health_check_port_listener(&self, port: u16) -> Result<()> {
-        let port_s = port.to_string();
-        let path = path!("health", "checks", "port-listener", port_s);
-        self.boolean_health_check(&path)
+        self.block_on(self.client.health_check_port_listener(port))
     }
 
     pub fn health_check_protocol_listener(&self, protocol: SupportedProtocol) -> Result<()> {
-        let proto: String = String::from(protocol);
-        let path = path!("health", "checks", "protocol-listener", proto);
-        self.boolean_health_check(&path)
+        self.block_on(self.client.health_check_protocol_listener(protocol))
     }
 
-    fn boolean_health_check(&self, path: &str) -> std::result::Result<(), HttpClientError> {
-        // we expect that StatusCode::SERVICE_UNAVAILABLE may be return and ignore
-        // it here to provide a custom error type later
-        let response = self.http_get(path, None, Some(StatusCode::SERVICE_UNAVAILABLE))?;
+    /// Checks if any of the TLS-enabled listeners on the target node use certificates
+    /// that expire within the given time window, e.g. 4 weeks.
+    pub fn health_check_certificate_expiration(&self, within: u32, unit: TimeUnit) -> Result<()> {
+        self.block_on(
+            self.client
+                .health_check_certificate_expiration(within, unit),
+        )
+    }
 
-        let status_code = response.status();
-        if status_code.is_success() {
-            return Ok(());
-        }
+    /// Checks if all virtual hosts are running on the target node.
+    pub fn health_check_virtual_hosts(&self) -> Result<()> {
+        self.block_on(self.client.health_check_virtual_hosts())
+    }
 
-        let failure_details = response.json()?;
-        Err(Error::HealthCheckFailed {
-            path: path.to_owned(),
-            status_code,
-            details: failure_details,
-        })
+    /// Checks if there are quorum queues without an elected leader in any virtual host.
+    /// The affected queues are listed in the failure details,
+    /// see [`responses::HealthCheckFailureDetails::QuorumQueuesWithoutElectedLeaders`].
+    pub fn health_check_quorum_queues_without_elected_leaders(&self) -> Result<()> {
+        self.block_on(
+            self.client
+                .health_check_quorum_queues_without_elected_leaders(),
+        )
+    }
+
+    /// Like [`Client::health_check_quorum_queues_without_elected_leaders`] but only checks
+    /// the queues with names that match the given regular expression.
+    pub fn health_check_quorum_queues_without_elected_leaders_matching(
+        &self,
+        pattern: &str,
+    ) -> Result<()> {
+        self.block_on(
+            self.client
+                .health_check_quorum_queues_without_elected_leaders_matching(pattern),
+        )
+    }
+
+    /// Like [`Client::health_check_quorum_queues_without_elected_leaders`] but only checks
+    /// the queues in the given virtual host and, optionally, with names that match
+    /// the given regular expression.
+    pub fn health_check_quorum_queues_without_elected_leaders_in(
+        &self,
+        vhost: &str,
+        pattern: Option<&str>,
+    ) -> Result<()> {
+        self.block_on(
+            self.client
+                .health_check_quorum_queues_without_elected_leaders_in(vhost, pattern),
+        )
+    }
+
+    /// Checks if the metadata store (Khepri on RabbitMQ 4.x) has been initialized on the target node.
+    pub fn health_check_metadata_store_initialized(&self) -> Result<()> {
+        self.block_on(self.client.health_check_metadata_store_initialized())
+    }
+
+    /// Like [`Client::health_check_metadata_store_initialized`] but also checks that
+    /// the metadata store has been populated with data, e.g. synced from its peers.
+    pub fn health_check_metadata_store_initialized_with_data(&self) -> Result<()> {
+        self.block_on(
+            self.client
+                .health_check_metadata_store_initialized_with_data(),
+        )
+    }
+
+    /// Periodically performs the health checks listed in `settings` and invokes `on_event`
+    /// for every (debounced) state transition, that is, when an alarm is raised or cleared.
+    ///
+    /// Health checks that cannot be performed, e.g. because the node is temporarily unreachable,
+    /// are reported as [`AlarmEvent::CheckFailed`] and retried in the next round.
+    /// Runs until `on_event` returns [`ControlFlow::Break`].
+    pub fn watch_alarms<F>(&self, settings: &AlarmWatcherSettings, on_event: F) -> Result<()>
+    where
+        F: FnMut(AlarmEvent) -> ControlFlow<()>,
+    {
+        self.block_on(self.client.watch_alarms(settings, on_event))
     }
 
     //
-    // Federation
+    // Readiness
     //
 
-    pub fn list_federation_upstreams(&self) -> Result<Vec<responses::FederationUpstream>> {
-        let response = self.list_runtime_parameters_of_component(FEDERATION_UPSTREAM_COMPONENT)?;
-        let upstreams = response
+    /// Waits for the HTTP API to respond and for the node that serves it to report
+    /// itself as running. Checks are retried with an exponential backoff until
+    /// `timeout` elapses, in which case [`Error::NotReady`] is returned.
+    ///
+    /// Individual checks are also bounded by `timeout`, so a connection attempt
+    /// or a response that hangs during node startup does not extend the wait.
+    ///
+    /// Meant to be used right after node startup, e.g. in CI or provisioning scripts.
+    pub fn await_ready(&self, timeout: Duration) -> Result<()> {
+        self.block_on(self.client.await_ready(timeout))
+    }
+
+    //
+    // Node discovery
+    //
+
+    /// Lists cluster nodes and constructs a client for every one of them,
+    /// e.g. to use node-local endpoints such as health checks on every node.
+    ///
+    /// `endpoint_of` maps a node to the HTTP API endpoint of that node, for example,
+    /// `|node| format!("http://{}:15672/api", node.hostname())`.
+    /// The clients share the credentials, the API prefix and the underlying HTTP client with this one.
+    /// Credentials changed later with [`Client::update_credentials`], on this client
+    /// or on any of the node clients, are used by all of them.
+    pub fn discover_node_clients<F>(&self, endpoint_of: F) -> Result<Vec<(String, NodeClient)>>
+    where
+        F: Fn(&responses::ClusterNode) -> String,
+    {
+        let clients = self.block_on(self.client.discover_node_clients(endpoint_of))?;
+        let clients = clients
             .into_iter()
-            .map(FederationUpstream::try_from)
-            // TODO: in theory this can be an Err
-            .map(|r| r.unwrap())
-            .collect::<Vec<_>>();
+            .map(|(name, client)| {
+                let client = Client {
+                    client,
+                    runtime: Arc::clone(&self.runtime),
+                };
+                (name, client)
+            })
+            .collect();
 
-        Ok(upstreams)
+        Ok(clients)
+    }
+
+    //
+    // Federation
+    //
+
+    pub fn list_federation_upstreams(&self) -> Result<Vec<responses::FederationUpstream>> {
+        self.block_on(self.client.list_federation_upstreams())
     }
 
     pub fn list_federation_links(&self) -> Result<Vec<responses::FederationLink>> {
-        let response = self.http_get("federation-links", None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.list_federation_links())
     }
 
     pub fn declare_federation_upstream(&self, params: FederationUpstreamParams<'_>) -> Result<()> {
-        let runtime_param = RuntimeParameterDefinition::from(params);
-
-        self.declare_federation_upstream_with_parameters(&runtime_param)
+        self.block_on(self.client.declare_federation_upstream(params))
     }
 
     pub fn delete_federation_upstream(&self, vhost: &str, name: &str) -> Result<()> {
-        self.clear_runtime_parameter(FEDERATION_UPSTREAM_COMPONENT, vhost, name)
+        self.block_on(self.client.delete_federation_upstream(vhost, name))
     }
 
     //
@@ -1286,31 +1716,36 @@ where
     //
 
     pub fn list_shovels(&self) -> Result<Vec<responses::Shovel>> {
-        let response = self.http_get("shovels", None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.list_shovels())
     }
 
-    pub fn declare_amqp091_shovel(&self, params: Amqp091ShovelParams<'_>) -> Result<()> {
-        let runtime_param = RuntimeParameterDefinition::from(params);
+    /// Lists dynamic shovels in the given virtual host.
+    pub fn list_shovels_in(&self, vhost: &str) -> Result<Vec<responses::Shovel>> {
+        self.block_on(self.client.list_shovels_in(vhost))
+    }
 
-        self.declare_shovel_parameter(&runtime_param)
+    /// Declares a dynamic shovel of either protocol. See [`Client::declare_amqp091_shovel`]
+    /// and [`Client::declare_amqp10_shovel`].
+    pub fn declare_shovel(&self, params: ShovelParams<'_>) -> Result<()> {
+        self.block_on(self.client.declare_shovel(params))
     }
 
-    pub fn declare_amqp10_shovel(&self, params: Amqp10ShovelParams<'_>) -> Result<()> {
-        let runtime_param = RuntimeParameterDefinition::from(params);
+    pub fn declare_amqp091_shovel(&self, params: Amqp091ShovelParams<'_>) -> Result<()> {
+        self.block_on(self.client.declare_amqp091_shovel(params))
+    }
 
-        self.declare_shovel_parameter(&runtime_param)
+    pub fn declare_amqp10_shovel(&self, params: Amqp10ShovelParams<'_>) -> Result<()> {
+        self.block_on(self.client.declare_amqp10_shovel(params))
     }
 
     pub fn delete_shovel(&self, vhost: &str, name: &str, idempotently: bool) -> Result<()> {
-        let excludes = if idempotently {
-            Some(StatusCode::NOT_FOUND)
-        } else {
-            None
-        };
-        let _response = self.http_delete(path!("shovels", "vhost", vhost, name), excludes, None)?;
-        Ok(())
+        self.block_on(self.client.delete_shovel(vhost, name, idempotently))
+    }
+
+    /// Restarts a dynamic shovel, e.g. one that has stopped because its source or destination
+    /// was temporarily unavailable. Use [`Client::list_shovels`] to find out the state of shovels.
+    pub fn restart_shovel(&self, vhost: &str, name: &str) -> Result<()> {
+        self.block_on(self.client.restart_shovel(vhost, name))
     }
 
     //
@@ -1325,21 +1760,33 @@ where
         payload: &str,
         properties: requests::MessageProperties,
     ) -> Result<responses::MessageRouted> {
-        let body = serde_json::json!({
-          "routing_key": routing_key,
-          "payload": payload,
-          "payload_encoding": "string",
-          "properties": properties,
-        });
-
-        let response = self.http_post(
-            path!("exchanges", vhost, exchange, "publish"),
-            &body,
-            None,
-            None,
-        )?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.publish_message(
+            vhost,
+            exchange,
+            routing_key,
+            payload,
+            properties,
+        ))
+    }
+
+    /// Publishes a message with a binary payload. The payload is Base64-encoded
+    /// and published with the `base64` payload encoding, so the broker routes
+    /// the original bytes.
+    pub fn publish_binary_message(
+        &self,
+        vhost: &str,
+        exchange: &str,
+        routing_key: &str,
+        payload: &[u8],
+        properties: requests::MessageProperties,
+    ) -> Result<responses::MessageRouted> {
+        self.block_on(self.client.publish_binary_message(
+            vhost,
+            exchange,
+            routing_key,
+            payload,
+            properties,
+        ))
     }
 
     pub fn get_messages(
@@ -1349,27 +1796,236 @@ where
         count: u32,
         ack_mode: &str,
     ) -> Result<Vec<GetMessage>> {
-        let body = json!({
-          "count": count,
-          "ackmode": ack_mode,
-          "encoding": "auto"
-        });
+        self.block_on(self.client.get_messages(vhost, queue, count, ack_mode))
+    }
+
+    /// Fetches messages from a queue and republishes them with their original properties,
+    /// e.g. to replay messages from a dead-letter queue.
+    ///
+    /// Messages are fetched in batches of `params.batch_size` until the source queue is empty
+    /// or `params.max_messages` messages were republished. Replay also stops after a batch
+    /// that contains a message identical to one already republished, e.g. when the destination
+    /// routes messages back to the source queue. In the dry run mode, a single batch is
+    /// fetched and requeued and nothing is republished. Note that requeueing sets the `redelivered`
+    /// flag, so every message inspected in the dry run mode will be marked as redelivered.
+    ///
+    /// Fetched messages are removed from the source queue before they are republished.
+    /// If a message cannot be republished or is not routed to any queue, the messages of the current
+    /// batch that were not republished are returned in [`HttpClientError::MessageReplayInterrupted`].
+    ///
+    /// Returns the messages that were republished (or would be, in the dry run mode).
+    pub fn replay_messages(
+        &self,
+        params: &requests::MessageReplayParams<'_>,
+    ) -> Result<Vec<GetMessage>> {
+        self.block_on(self.client.replay_messages(params))
+    }
+
+    /// Drains a queue into a [JSON Lines](https://jsonlines.org/) file, one message
+    /// (payload, properties and routing information) per line, e.g. for incident forensics
+    /// or to move messages to a different cluster using [`Client::publish_messages_from_file`].
+    ///
+    /// Messages are fetched in batches of `batch_size` until the queue is empty. Every batch is
+    /// appended to the file as soon as it is fetched, after which `on_progress` is invoked
+    /// with the number of messages drained so far.
+    ///
+    /// The file is created by this function: if `path` already exists, an I/O error
+    /// of the [`std::io::ErrorKind::AlreadyExists`] kind is returned and nothing is drained.
+    ///
+    /// Note that fetched messages are acknowledged before they are written to the file,
+    /// so an I/O error can result in the loss of the current batch. Since every message
+    /// is transferred over HTTP, this is only meant to be used with small queues.
+    ///
+    /// Returns the number of drained messages.
+    pub fn drain_queue_to_file<T, F>(
+        &self,
+        vhost: &str,
+        queue: &str,
+        path: T,
+        batch_size: u32,
+        on_progress: F,
+    ) -> Result<u64>
+    where
+        T: AsRef<Path>,
+        F: FnMut(u64),
+    {
+        self.block_on(
+            self.client
+                .drain_queue_to_file(vhost, queue, path, batch_size, on_progress),
+        )
+    }
+
+    /// Publishes messages from a file produced by [`Client::drain_queue_to_file`].
+    ///
+    /// Every message is published with its original properties to its original exchange
+    /// using its original routing key, unless `exchange` or `routing_key` are provided.
+    /// `on_progress` is invoked with the number of messages published so far after every message.
+    ///
+    /// The entire file is parsed before anything is published. Publishing stops at the first
+    /// message that is not routed to any queue, returning [`crate::error::Error::MessageNotRouted`].
+    /// Returns the number of published messages.
+    pub fn publish_messages_from_file<T, F>(
+        &self,
+        vhost: &str,
+        path: T,
+        exchange: Option<&str>,
+        routing_key: Option<&str>,
+        on_progress: F,
+    ) -> Result<u64>
+    where
+        T: AsRef<Path>,
+        F: FnMut(u64),
+    {
+        self.block_on(self.client.publish_messages_from_file(
+            vhost,
+            path,
+            exchange,
+            routing_key,
+            on_progress,
+        ))
+    }
+
+    /// Produces a cluster summary report (versions, nodes and their alarms, listeners,
+    /// entity counts, the `top_queue_count` queues with the most messages),
+    /// querying the required endpoints concurrently.
+    ///
+    /// See [`crate::report::ClusterReport`].
+    pub fn cluster_report(&self, top_queue_count: usize) -> Result<ClusterReport> {
+        self.block_on(self.client.cluster_report(top_queue_count))
+    }
+
+    /// Fetches the queues, exchanges, bindings, policies and runtime parameters
+    /// of a virtual host concurrently.
+    ///
+    /// See [`responses::VirtualHostTopology`].
+    pub fn get_vhost_topology(&self, vhost: &str) -> Result<responses::VirtualHostTopology> {
+        self.block_on(self.client.get_vhost_topology(vhost))
+    }
+
+    /// Produces a per-queue consumer utilisation report (consumer count, prefetch,
+    /// utilisation and ack backlog) for the given virtual host, or all of them,
+    /// and flags queues whose consumers are saturated.
+    ///
+    /// See [`crate::report::ConsumerUtilisationReport`].
+    pub fn consumer_utilisation_report(
+        &self,
+        vhost: Option<&str>,
+        saturation_threshold: f64,
+    ) -> Result<ConsumerUtilisationReport> {
+        self.block_on(
+            self.client
+                .consumer_utilisation_report(vhost, saturation_threshold),
+        )
+    }
 
-        let response = self.http_post(path!("queues", vhost, queue, "get"), &body, None, None)?;
-        let response = response.json()?;
-        Ok(response)
+    /// Samples cluster-wide churn rates and connection listings every `settings.interval`
+    /// for `settings.window` and reports connections opened and closed by every application
+    /// (identified by user and client-provided connection name).
+    ///
+    /// Use [`ConnectionChurnReport::offenders`] to find the applications that open
+    /// and close connections at rates above `settings.threshold_per_minute`.
+    pub fn analyze_connection_churn(
+        &self,
+        settings: &ChurnAnalyzerSettings,
+    ) -> Result<ConnectionChurnReport> {
+        self.block_on(self.client.analyze_connection_churn(settings))
+    }
+
+    /// Polls the queues (in the given virtual host, or in all of them) every `settings.interval`
+    /// and invokes `on_event` for every queue that was declared, deleted or changed
+    /// (e.g. its state, leader, effective policy or number of consumers),
+    /// see [`crate::watch::Watchable`].
+    ///
+    /// Runs until `on_event` returns [`ControlFlow::Break`] or a listing cannot be fetched.
+    /// In the latter case, the error is returned.
+    pub fn watch_queues<F>(
+        &self,
+        vhost: Option<&str>,
+        settings: &WatchSettings,
+        mut on_event: F,
+    ) -> Result<()>
+    where
+        F: FnMut(WatchEvent<responses::QueueInfo>) -> ControlFlow<()>,
+    {
+        self.block_on(async {
+            let events = self.client.watch_queues(vhost, *settings);
+            pin_mut!(events);
+            while let Some(event) = events.try_next().await? {
+                if on_event(event).is_break() {
+                    return Ok(());
+                }
+            }
+            Ok(())
+        })
+    }
+
+    /// Like [`Client::watch_queues`] but for client connections.
+    pub fn watch_connections<F>(
+        &self,
+        vhost: Option<&str>,
+        settings: &WatchSettings,
+        mut on_event: F,
+    ) -> Result<()>
+    where
+        F: FnMut(WatchEvent<responses::Connection>) -> ControlFlow<()>,
+    {
+        self.block_on(async {
+            let events = self.client.watch_connections(vhost, *settings);
+            pin_mut!(events);
+            while let Some(event) = events.try_next().await? {
+                if on_event(event).is_break() {
+                    return Ok(());
+                }
+            }
+            Ok(())
+        })
+    }
+
+    /// Like [`Client::watch_queues`] but for policies.
+    pub fn watch_policies<F>(
+        &self,
+        vhost: Option<&str>,
+        settings: &WatchSettings,
+        mut on_event: F,
+    ) -> Result<()>
+    where
+        F: FnMut(WatchEvent<responses::Policy>) -> ControlFlow<()>,
+    {
+        self.block_on(async {
+            let events = self.client.watch_policies(vhost, *settings);
+            pin_mut!(events);
+            while let Some(event) = events.try_next().await? {
+                if on_event(event).is_break() {
+                    return Ok(());
+                }
+            }
+            Ok(())
+        })
     }
 
     pub fn overview(&self) -> Result<responses::Overview> {
-        let response = self.http_get("overview", None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.overview())
+    }
+
+    /// Like [`Client::overview`] but also returns the HTTP response metadata.
+    pub fn overview_with_response(&self) -> Result<ResponseEnvelope<responses::Overview>> {
+        self.block_on(self.client.overview_with_response())
+    }
+
+    /// Lists the listeners (protocol, port and interface) of all cluster nodes.
+    pub fn list_listeners(&self) -> Result<Vec<responses::Listener>> {
+        self.block_on(self.client.list_listeners())
+    }
+
+    /// Lists the listeners (protocol, port and interface) of the given node.
+    ///
+    /// The filtering is performed on the client side.
+    pub fn listeners_of(&self, node: &str) -> Result<Vec<responses::Listener>> {
+        self.block_on(self.client.listeners_of(node))
     }
 
     pub fn server_version(&self) -> Result<String> {
-        let response = self.http_get("overview", None, None)?;
-        let response: responses::Overview = response.json()?;
-        Ok(response.rabbitmq_version)
+        self.block_on(self.client.server_version())
     }
 
     //
@@ -1377,60 +2033,39 @@ where
     //
 
     pub fn list_feature_flags(&self) -> Result<FeatureFlagList> {
-        let response = self.http_get("feature-flags", None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.list_feature_flags())
     }
 
     /// Enables a feature flag.
     /// This function is idempotent: enabling an already enabled feature flag
     /// will succeed.
     pub fn enable_feature_flag(&self, name: &str) -> Result<()> {
-        let body = serde_json::json!({
-            "name": name
-        });
-        let _response = self.http_put(path!("feature-flags", name, "enable"), &body, None, None)?;
-        Ok(())
+        self.block_on(self.client.enable_feature_flag(name))
     }
 
     /// Enables all stable feature flags.
     /// This function is idempotent: enabling an already enabled feature flag
     /// will succeed.
     pub fn enable_all_stable_feature_flags(&self) -> Result<()> {
-        // PUT /api/feature-flags/{name}/enable does not support the special 'all' value like 'rabbitmqctl enable_feature_flag' does.
-        // Thus we do what management UI does: discover the stable disabled flags and enable
-        // them one by one.
-        let discovered_flags = self.list_feature_flags()?;
-        let flags_to_enable: Vec<&FeatureFlag> = discovered_flags
-            .0
-            .iter()
-            .filter(|&ff| {
-                ff.state == FeatureFlagState::Disabled
-                    && ff.stability == FeatureFlagStability::Stable
-            })
-            .collect();
-
-        for ff in flags_to_enable {
-            self.enable_feature_flag(&ff.name)?;
-        }
-
-        Ok(())
+        self.block_on(self.client.enable_all_stable_feature_flags())
     }
 
     //
     // Deprecated Features
     //
 
+    /// Lists all deprecated features known to the cluster, in use or not.
+    /// An alias for [`Client::list_all_deprecated_features`].
+    pub fn list_deprecated_features(&self) -> Result<DeprecatedFeatureList> {
+        self.block_on(self.client.list_deprecated_features())
+    }
+
     pub fn list_all_deprecated_features(&self) -> Result<DeprecatedFeatureList> {
-        let response = self.http_get("deprecated-features", None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.list_all_deprecated_features())
     }
 
     pub fn list_deprecated_features_in_use(&self) -> Result<DeprecatedFeatureList> {
-        let response = self.http_get("deprecated-features/used", None, None)?;
-        let response = response.json()?;
-        Ok(response)
+        self.block_on(self.client.list_deprecated_features_in_use())
     }
 
     //
@@ -1438,10 +2073,7 @@ where
     //
 
     pub fn oauth_configuration(&self) -> Result<OAuthConfiguration> {
-        let response = self.http_get("auth", None, None)?;
-        let response = response.json()?;
-
-        Ok(response)
+        self.block_on(self.client.oauth_configuration())
     }
 
     //
@@ -1452,55 +2084,29 @@ where
         &self,
         node: Option<&str>,
     ) -> Result<SchemaDefinitionSyncStatus> {
-        let response = match node {
-            Some(val) => {
-                self.http_get(path!("tanzu", "osr", "schema", "status", val), None, None)?
-            }
-            None => self.http_get("tanzu/osr/schema/status", None, None)?,
-        };
-        let response = response.json()?;
-
-        Ok(response)
+        self.block_on(self.client.schema_definition_sync_status(node))
     }
 
     pub fn enable_schema_definition_sync_on_node(&self, node: &str) -> Result<()> {
-        let payload = EmptyPayload::new();
-        self.http_put(
-            path!("tanzu", "osr", "schema", "enable", node),
-            &payload,
-            None,
-            None,
-        )?;
-
-        Ok(())
+        self.block_on(
+            self.client
+                .enable_schema_definition_sync_one_node(Some(node)),
+        )
     }
 
     pub fn disable_schema_definition_sync_on_node(&self, node: &str) -> Result<()> {
-        self.http_delete(path!("tanzu", "osr", "schema", "disable", node), None, None)?;
-
-        Ok(())
+        self.block_on(
+            self.client
+                .disable_schema_definition_sync_on_node(Some(node)),
+        )
     }
 
     pub fn enable_schema_definition_sync(&self) -> Result<()> {
-        let payload = EmptyPayload::new();
-        self.http_put(
-            path!("tanzu", "osr", "schema", "enable-cluster-wide"),
-            &payload,
-            None,
-            None,
-        )?;
-
-        Ok(())
+        self.block_on(self.client.enable_schema_definition_sync())
     }
 
     pub fn disable_schema_definition_sync(&self) -> Result<()> {
-        self.http_delete(
-            path!("tanzu", "osr", "schema", "disable-cluster-wide"),
-            None,
-            None,
-        )?;
-
-        Ok(())
+        self.block_on(self.client.disable_schema_definition_sync())
     }
 
     //
@@ -1508,268 +2114,14 @@ where
     //
 
     pub fn warm_standby_replication_status(&self) -> Result<WarmStandbyReplicationStatus> {
-        let response = self.http_get("tanzu/osr/standby/status", None, None)?;
-        let response = response.json()?;
-
-        Ok(response)
-    }
-
-    //
-    // Implementation
-    //
-
-    fn declare_shovel_parameter(
-        &self,
-        runtime_param: &RuntimeParameterDefinition<'_>,
-    ) -> Result<()> {
-        let _response = self.http_put(
-            path!(
-                "parameters",
-                SHOVEL_COMPONENT,
-                runtime_param.vhost,
-                runtime_param.name
-            ),
-            &runtime_param,
-            None,
-            None,
-        )?;
-        Ok(())
-    }
-
-    fn declare_federation_upstream_with_parameters(
-        &self,
-        runtime_param: &RuntimeParameterDefinition<'_>,
-    ) -> Result<()> {
-        let _response = self.http_put(
-            path!(
-                "parameters",
-                FEDERATION_UPSTREAM_COMPONENT,
-                runtime_param.vhost,
-                runtime_param.name
-            ),
-            &runtime_param,
-            None,
-            None,
-        )?;
-        Ok(())
-    }
-
-    fn health_check_alarms(&self, path: &str) -> Result<()> {
-        // we expect that StatusCode::SERVICE_UNAVAILABLE may be return and ignore
-        // it here to provide a custom error type later
-        let response = self.http_get(path, None, Some(StatusCode::SERVICE_UNAVAILABLE))?;
-        let status_code = response.status();
-        if status_code.is_success() {
-            return Ok(());
-        }
-
-        let body = response.json()?;
-        let failure_details = responses::HealthCheckFailureDetails::AlarmCheck(body);
-        Err(Error::HealthCheckFailed {
-            path: path.to_owned(),
-            details: failure_details,
-            status_code,
-        })
-    }
-
-    fn list_exchange_bindings_with_source_or_destination(
-        &self,
-        vhost: &str,
-        exchange: &str,
-        vertex: BindindVertex,
-    ) -> Result<Vec<BindingInfo>> {
-        let response = self.http_get(
-            path!("exchanges", vhost, exchange, "bindings", vertex),
-            None,
-            None,
-        )?;
-        let response = response.json()?;
-        Ok(response)
-    }
-
-    fn http_get<S>(
-        &self,
-        path: S,
-        client_code_to_accept_or_ignore: Option<StatusCode>,
-        server_code_to_accept_or_ignore: Option<StatusCode>,
-    ) -> Result<HttpClientResponse>
-    where
-        S: AsRef<str>,
-    {
-        let response = self
-            .client
-            .get(self.rooted_path(path))
-            .basic_auth(&self.username, Some(&self.password))
-            .send()?;
-        let response = self.ok_or_status_code_error(
-            response,
-            client_code_to_accept_or_ignore,
-            server_code_to_accept_or_ignore,
-        )?;
-        Ok(response)
-    }
-
-    fn http_put<S, T>(
-        &self,
-        path: S,
-        payload: &T,
-        client_code_to_accept_or_ignore: Option<StatusCode>,
-        server_code_to_accept_or_ignore: Option<StatusCode>,
-    ) -> Result<HttpClientResponse>
-    where
-        S: AsRef<str>,
-        T: Serialize,
-    {
-        let response = self
-            .client
-            .put(self.rooted_path(path))
-            .json(&payload)
-            .basic_auth(&self.username, Some(&self.password))
-            .send()?;
-        let response = self.ok_or_status_code_error(
-            response,
-            client_code_to_accept_or_ignore,
-            server_code_to_accept_or_ignore,
-        )?;
-        Ok(response)
-    }
-
-    fn http_post<S, T>(
-        &self,
-        path: S,
-        payload: &T,
-        client_code_to_accept_or_ignore: Option<StatusCode>,
-        server_code_to_accept_or_ignore: Option<StatusCode>,
-    ) -> Result<HttpClientResponse>
-    where
-        S: AsRef<str>,
-        T: Serialize,
-    {
-        let response = self
-            .client
-            .post(self.rooted_path(path))
-            .json(&payload)
-            .basic_auth(&self.username, Some(&self.password))
-            .send()?;
-        let response = self.ok_or_status_code_error(
-            response,
-            client_code_to_accept_or_ignore,
-            server_code_to_accept_or_ignore,
-        )?;
-        Ok(response)
-    }
-
-    fn http_delete<S>(
-        &self,
-        path: S,
-        client_code_to_accept_or_ignore: Option<StatusCode>,
-        server_code_to_accept_or_ignore: Option<StatusCode>,
-    ) -> Result<HttpClientResponse>
-    where
-        S: AsRef<str>,
-    {
-        let response = self
-            .client
-            .delete(self.rooted_path(path))
-            .basic_auth(&self.username, Some(&self.password))
-            .send()?;
-        let response = self.ok_or_status_code_error(
-            response,
-            client_code_to_accept_or_ignore,
-            server_code_to_accept_or_ignore,
-        )?;
-        Ok(response)
-    }
-
-    fn http_delete_with_headers<S>(
-        &self,
-        path: S,
-        headers: HeaderMap,
-        client_code_to_accept_or_ignore: Option<StatusCode>,
-        server_code_to_accept_or_ignore: Option<StatusCode>,
-    ) -> Result<HttpClientResponse>
-    where
-        S: AsRef<str>,
-    {
-        let response = self
-            .client
-            .delete(self.rooted_path(path))
-            .basic_auth(&self.username, Some(&self.password))
-            .headers(headers)
-            .send()?;
-        let response = self.ok_or_status_code_error(
-            response,
-            client_code_to_accept_or_ignore,
-            server_code_to_accept_or_ignore,
-        )?;
-        Ok(response)
-    }
-
-    fn ok_or_status_code_error(
-        &self,
-        response: HttpClientResponse,
-        client_code_to_accept_or_ignore: Option<StatusCode>,
-        server_code_to_accept_or_ignore: Option<StatusCode>,
-    ) -> Result<HttpClientResponse> {
-        let status = response.status();
-
-        match client_code_to_accept_or_ignore {
-            Some(status_code) if status_code == StatusCode::NOT_FOUND => {}
-            _ => {
-                if status == StatusCode::NOT_FOUND {
-                    return Err(NotFound);
-                }
-            }
-        }
-
-        if status.is_client_error() {
-            match client_code_to_accept_or_ignore {
-                Some(expect) if status == expect => {}
-                _ => {
-                    let url = response.url().clone();
-                    let headers = response.headers().clone();
-                    // this consumes `self` and makes the response largely useless to the caller,
-                    // so we copy the key parts into the error first
-                    let body = response.text()?;
-                    return Err(ClientErrorResponse {
-                        url: Some(url),
-                        body: Some(body),
-                        headers: Some(headers),
-                        status_code: status,
-                        backtrace: Backtrace::new(),
-                    });
-                }
-            }
-        }
-
-        if status.is_server_error() {
-            match server_code_to_accept_or_ignore {
-                Some(expect) if status == expect => {}
-                _ => {
-                    let url = response.url().clone();
-                    let headers = response.headers().clone();
-                    // this consumes `self` and makes the response largely useless to the caller,
-                    // so we copy the key parts into the error first
-                    let body = response.text()?;
-                    return Err(ServerErrorResponse {
-                        url: Some(url),
-                        body: Some(body),
-                        headers: Some(headers),
-                        status_code: status,
-                        backtrace: Backtrace::new(),
-                    });
-                }
-            }
-        }
-
-        Ok(response)
+        self.block_on(self.client.warm_standby_replication_status())
     }
 
-    fn rooted_path<S>(&self, path: S) -> String
+    fn block_on<F, T>(&self, future: F) -> Result<T>
     where
-        S: AsRef<str>,
+        F: Future<Output = Result<T>>,
     {
-        format!("{}/{}", self.endpoint, path.as_ref())
+        self.runtime.block_on(future)
     }
 }
 
@@ -1779,17 +2131,50 @@ impl Default for Client<&'static str, &'static str, &'static str> {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-enum BindindVertex {
-    Source,
-    Destination,
+// buffers the body while the runtime is driven, so that it can be read
+// without one
+async fn into_blocking_response(response: api::HttpClientResponse) -> Result<HttpClientResponse> {
+    let mut buffered = http::Response::new(Vec::new());
+    *buffered.status_mut() = response.status();
+    *buffered.version_mut() = response.version();
+    *buffered.headers_mut() = response.headers().clone();
+    *buffered.body_mut() = response.bytes().await?.to_vec();
+    Ok(HttpClientResponse::from(buffered))
 }
 
-impl AsRef<str> for BindindVertex {
-    fn as_ref(&self) -> &str {
-        match self {
-            Self::Source => "source",
-            Self::Destination => "destination",
-        }
+// lets the async client write to a `std::io::Write`; the writes block the
+// thread that drives the runtime, which is the caller's thread
+struct BlockingWriter<'a, W>(&'a mut W);
+
+impl<W: Write> AsyncWrite for BlockingWriter<'_, W> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        Poll::Ready(self.get_mut().0.write(buf))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Poll::Ready(self.get_mut().0.flush())
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        self.poll_flush(cx)
+    }
+}
+
+// lets the async client stream a request body from a `std::io::Read`
+struct BlockingReader<R>(Box<R>);
+
+impl<R: Read> AsyncRead for BlockingReader<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        let n = self.get_mut().0.read(buf.initialize_unfilled())?;
+        buf.advance(n);
+        Poll::Ready(Ok(()))
     }
 }
@@ -0,0 +1,265 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Samples connection listings and churn rates over a time window and reports
+//! applications that open and close connections at high rates,
+//! see `Client#analyze_connection_churn`.
+//!
+//! High connection churn is a common anti-pattern: applications that open a connection
+//! per operation put a lot of avoidable load on the nodes.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::time::Duration;
+
+use crate::responses::{ChurnRates, Connection};
+
+/// The default length of the sampling window.
+pub const DEFAULT_CHURN_ANALYZER_WINDOW: Duration = Duration::from_secs(60);
+/// The default interval between two samples.
+pub const DEFAULT_CHURN_ANALYZER_INTERVAL: Duration = Duration::from_secs(5);
+/// The default number of connections opened or closed per minute
+/// above which an application is considered to have high churn.
+pub const DEFAULT_CHURN_THRESHOLD_PER_MINUTE: f64 = 30.0;
+
+/// Connection churn analyzer configuration.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChurnAnalyzerSettings {
+    /// How long to sample for
+    pub window: Duration,
+    /// How long to wait between two samples. Connections that are opened
+    /// and closed between two samples are only accounted for in cluster-wide totals.
+    pub interval: Duration,
+    /// Connections opened or closed per minute above which an application
+    /// is reported as a high churn offender
+    pub threshold_per_minute: f64,
+}
+
+impl ChurnAnalyzerSettings {
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    pub fn with_threshold_per_minute(mut self, threshold: f64) -> Self {
+        self.threshold_per_minute = threshold;
+        self
+    }
+}
+
+impl Default for ChurnAnalyzerSettings {
+    fn default() -> Self {
+        Self {
+            window: DEFAULT_CHURN_ANALYZER_WINDOW,
+            interval: DEFAULT_CHURN_ANALYZER_INTERVAL,
+            threshold_per_minute: DEFAULT_CHURN_THRESHOLD_PER_MINUTE,
+        }
+    }
+}
+
+/// Identifies the application a connection belongs to: the user it authenticated as
+/// and the client-provided connection name, if any.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ConnectionOrigin {
+    pub username: String,
+    pub client_provided_name: Option<String>,
+}
+
+impl From<&Connection> for ConnectionOrigin {
+    fn from(conn: &Connection) -> Self {
+        let name = &conn.client_properties.connection_name;
+        Self {
+            username: conn.username.clone(),
+            client_provided_name: (!name.is_empty()).then(|| name.clone()),
+        }
+    }
+}
+
+impl fmt::Display for ConnectionOrigin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.client_provided_name {
+            Some(name) => write!(f, "{} (user: {})", name, self.username),
+            None => write!(f, "user: {}", self.username),
+        }
+    }
+}
+
+/// Connections opened and closed by a single application during the sampling window.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApplicationChurn {
+    pub origin: ConnectionOrigin,
+    pub opened: u64,
+    pub closed: u64,
+    pub opened_per_minute: f64,
+    pub closed_per_minute: f64,
+}
+
+impl ApplicationChurn {
+    /// The higher of the two rates.
+    pub fn peak_rate_per_minute(&self) -> f64 {
+        self.opened_per_minute.max(self.closed_per_minute)
+    }
+}
+
+/// The outcome of a connection churn analysis.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConnectionChurnReport {
+    /// How long the connections were sampled for
+    pub elapsed: Duration,
+    /// Connections opened cluster-wide during the window, according to the churn rates
+    /// reported by the overview endpoint
+    pub cluster_connections_opened: u64,
+    /// Connections closed cluster-wide during the window, according to the churn rates
+    /// reported by the overview endpoint
+    pub cluster_connections_closed: u64,
+    /// Per-application churn, sorted by peak rate in descending order
+    pub applications: Vec<ApplicationChurn>,
+    /// See [`ChurnAnalyzerSettings::threshold_per_minute`]
+    pub threshold_per_minute: f64,
+}
+
+impl ConnectionChurnReport {
+    /// Applications whose opening or closing rate exceeds the threshold.
+    pub fn offenders(&self) -> Vec<&ApplicationChurn> {
+        self.applications
+            .iter()
+            .filter(|a| a.peak_rate_per_minute() > self.threshold_per_minute)
+            .collect()
+    }
+
+    pub fn has_offenders(&self) -> bool {
+        !self.offenders().is_empty()
+    }
+}
+
+impl fmt::Display for ConnectionChurnReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Connections opened: {}, closed: {} in {}s",
+            self.cluster_connections_opened,
+            self.cluster_connections_closed,
+            self.elapsed.as_secs()
+        )?;
+        for app in self.offenders() {
+            writeln!(
+                f,
+                "{}: {:.1} opened/min, {:.1} closed/min",
+                app.origin, app.opened_per_minute, app.closed_per_minute
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// The state behind the churn analyzer: turns a series of connection listings
+/// and churn rate samples into a [`ConnectionChurnReport`].
+///
+/// Connections are identified by name. The first listing only establishes a baseline,
+/// so connections that were already open when sampling started are not counted as opened.
+#[derive(Debug, Default)]
+pub struct ConnectionChurnTracker {
+    // connection name => origin, as of the last observed listing
+    known: Option<HashMap<String, ConnectionOrigin>>,
+    // origin => (opened, closed)
+    counts: BTreeMap<ConnectionOrigin, (u64, u64)>,
+    first_churn_rates: Option<(u32, u32)>,
+    last_churn_rates: Option<(u32, u32)>,
+}
+
+impl ConnectionChurnTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a connection listing, comparing it to the previous one.
+    pub fn observe_connections(&mut self, connections: &[Connection]) {
+        let current: HashMap<String, ConnectionOrigin> = connections
+            .iter()
+            .map(|c| (c.name.clone(), ConnectionOrigin::from(c)))
+            .collect();
+
+        if let Some(previous) = &self.known {
+            for (name, origin) in &current {
+                if !previous.contains_key(name) {
+                    self.counts.entry(origin.clone()).or_default().0 += 1;
+                }
+            }
+            for (name, origin) in previous {
+                if !current.contains_key(name) {
+                    self.counts.entry(origin.clone()).or_default().1 += 1;
+                }
+            }
+        }
+        self.known = Some(current);
+    }
+
+    /// Records the cluster-wide churn rates reported by the overview endpoint.
+    pub fn observe_churn_rates(&mut self, rates: &ChurnRates) {
+        let sample = (rates.connection_created, rates.connection_closed);
+        if self.first_churn_rates.is_none() {
+            self.first_churn_rates = Some(sample);
+        }
+        self.last_churn_rates = Some(sample);
+    }
+
+    /// Produces a report for the given sampling duration.
+    pub fn report(&self, elapsed: Duration, threshold_per_minute: f64) -> ConnectionChurnReport {
+        let minutes = elapsed.as_secs_f64() / 60.0;
+        let per_minute = |n: u64| {
+            if minutes > 0.0 {
+                n as f64 / minutes
+            } else {
+                0.0
+            }
+        };
+
+        let mut applications: Vec<ApplicationChurn> = self
+            .counts
+            .iter()
+            .map(|(origin, (opened, closed))| ApplicationChurn {
+                origin: origin.clone(),
+                opened: *opened,
+                closed: *closed,
+                opened_per_minute: per_minute(*opened),
+                closed_per_minute: per_minute(*closed),
+            })
+            .collect();
+        applications.sort_by(|a, b| {
+            b.peak_rate_per_minute()
+                .total_cmp(&a.peak_rate_per_minute())
+        });
+
+        // the counters are reset when a node restarts, in which case the delta is unknown
+        let (opened, closed) = match (self.first_churn_rates, self.last_churn_rates) {
+            (Some((o1, c1)), Some((o2, c2))) => (o2.saturating_sub(o1), c2.saturating_sub(c1)),
+            _ => (0, 0),
+        };
+
+        ConnectionChurnReport {
+            elapsed,
+            cluster_connections_opened: u64::from(opened),
+            cluster_connections_closed: u64::from(closed),
+            applications,
+            threshold_per_minute,
+        }
+    }
+}
@@ -0,0 +1,178 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A circuit breaker: a client configured with [`CircuitBreakerSettings`]
+//! (see `ClientBuilder#with_circuit_breaker`) stops issuing requests for a cool-down period
+//! after a number of consecutive failures, returning an `Error::CircuitOpen` instead.
+//!
+//! This protects both the client and a struggling node (or its management plugin)
+//! from a flood of requests that are likely to fail anyway. Clients produced by
+//! `Client#discover_node_clients` use a separate circuit breaker per node.
+
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+/// The default number of consecutive failures that opens the circuit.
+pub const DEFAULT_FAILURE_THRESHOLD: u32 = 5;
+/// The default period during which requests are short-circuited.
+pub const DEFAULT_COOL_DOWN: Duration = Duration::from_secs(30);
+
+/// Circuit breaker configuration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CircuitBreakerSettings {
+    /// How many consecutive failures open the circuit
+    pub failure_threshold: u32,
+    /// How long requests are short-circuited for once the circuit is open
+    pub cool_down: Duration,
+}
+
+impl CircuitBreakerSettings {
+    pub fn new(failure_threshold: u32, cool_down: Duration) -> Self {
+        Self {
+            failure_threshold: failure_threshold.max(1),
+            cool_down,
+        }
+    }
+}
+
+impl Default for CircuitBreakerSettings {
+    fn default() -> Self {
+        Self::new(DEFAULT_FAILURE_THRESHOLD, DEFAULT_COOL_DOWN)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Requests are issued as usual
+    Closed,
+    /// Requests are short-circuited until the cool-down period ends
+    Open,
+    /// The cool-down period has ended. A single trial request is let through,
+    /// its outcome decides whether the circuit is closed or opened again
+    HalfOpen,
+}
+
+#[derive(Debug)]
+struct Inner {
+    consecutive_failures: u32,
+    opened_at: Option<Instant>,
+    /// When the trial request of the half-open state was let through, if it has not completed yet
+    probe_started_at: Option<Instant>,
+}
+
+/// The state machine behind the circuit breaker.
+///
+/// Transport errors and server errors (`5xx` responses) count as failures,
+/// any other outcome closes the circuit and resets the failure count.
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    settings: CircuitBreakerSettings,
+    inner: Mutex<Inner>,
+}
+
+impl CircuitBreaker {
+    pub fn new(settings: CircuitBreakerSettings) -> Self {
+        Self {
+            settings,
+            inner: Mutex::new(Inner {
+                consecutive_failures: 0,
+                opened_at: None,
+                probe_started_at: None,
+            }),
+        }
+    }
+
+    pub fn settings(&self) -> CircuitBreakerSettings {
+        self.settings
+    }
+
+    pub fn state(&self) -> CircuitState {
+        self.state_at(Instant::now())
+    }
+
+    /// Returns `Ok(())` if a request can be issued, or how long the circuit
+    /// will remain open otherwise.
+    ///
+    /// In the half-open state, only one caller is let through. The outcome of its request
+    /// must be reported with [`CircuitBreaker::record_success`], [`CircuitBreaker::record_failure`]
+    /// or [`CircuitBreaker::record_inconclusive`].
+    pub fn check(&self) -> Result<(), Duration> {
+        self.check_at(Instant::now())
+    }
+
+    pub fn record_success(&self) {
+        let mut inner = self.lock();
+        inner.consecutive_failures = 0;
+        inner.opened_at = None;
+        inner.probe_started_at = None;
+    }
+
+    pub fn record_failure(&self) {
+        self.record_failure_at(Instant::now())
+    }
+
+    /// Records an outcome that says nothing about the state of the node, e.g. a request
+    /// that was not completed before a deadline. In the half-open state, this lets
+    /// another trial request through.
+    pub fn record_inconclusive(&self) {
+        self.lock().probe_started_at = None;
+    }
+
+    fn state_at(&self, now: Instant) -> CircuitState {
+        match self.lock().opened_at {
+            None => CircuitState::Closed,
+            Some(t) if now.duration_since(t) < self.settings.cool_down => CircuitState::Open,
+            Some(_) => CircuitState::HalfOpen,
+        }
+    }
+
+    fn check_at(&self, now: Instant) -> Result<(), Duration> {
+        let cool_down = self.settings.cool_down;
+        let mut inner = self.lock();
+        let Some(opened_at) = inner.opened_at else {
+            return Ok(());
+        };
+        let elapsed = now.duration_since(opened_at);
+        if elapsed < cool_down {
+            return Err(cool_down - elapsed);
+        }
+
+        // half-open: let a single trial request through. A trial request that has not
+        // completed within another cool-down period (e.g. because it was cancelled) is
+        // considered lost, so that the circuit cannot get stuck
+        match inner.probe_started_at {
+            Some(t) if now.duration_since(t) < cool_down => Err(cool_down - now.duration_since(t)),
+            _ => {
+                inner.probe_started_at = Some(now);
+                Ok(())
+            }
+        }
+    }
+
+    fn record_failure_at(&self, now: Instant) {
+        let mut inner = self.lock();
+        inner.probe_started_at = None;
+        inner.consecutive_failures = inner.consecutive_failures.saturating_add(1);
+        // a failure in the half-open state opens the circuit again right away
+        if inner.opened_at.is_some()
+            || inner.consecutive_failures >= self.settings.failure_threshold
+        {
+            inner.opened_at = Some(now);
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Inner> {
+        self.inner.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
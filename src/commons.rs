@@ -13,10 +13,16 @@
 // limitations under the License.
 use std::fmt;
 use std::fmt::Display;
+use std::str::FromStr;
 
+use crate::error::ConversionError;
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[serde(rename_all(serialize = "lowercase", deserialize = "PascalCase"))]
 pub enum SupportedProtocol {
     /// Represents the inter-node and CLI tool communication protocol
@@ -236,9 +242,128 @@ impl fmt::Display for SupportedProtocol {
     }
 }
 
+/// The protocol of a client connection, as reported by the HTTP API (e.g. "AMQP 0-9-1" or "MQTT 3.1.1").
+///
+/// Unlike [`SupportedProtocol`], which represents listeners, this type carries protocol versions
+/// where a plugin supports more than one.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(from = "String", into = "String")]
+pub enum ConnectionProtocol {
+    /// AMQP 0-9-1
+    AMQP091,
+    /// AMQP 1.0
+    AMQP10,
+    /// MQTT of the given version
+    MQTT(String),
+    /// MQTT of the given version over WebSockets
+    MQTTOverWebSockets(String),
+    /// STOMP of the given version
+    STOMP(String),
+    /// STOMP of the given version over WebSockets
+    STOMPOverWebSockets(String),
+    /// The RabbitMQ Stream protocol
+    Stream,
+    /// All other protocols, e.g. those coming from 3rd party plugins
+    Other(String),
+}
+
+impl ConnectionProtocol {
+    /// Returns true for AMQP 0-9-1 and AMQP 1.0 connections.
+    pub fn is_amqp(&self) -> bool {
+        matches!(
+            self,
+            ConnectionProtocol::AMQP091 | ConnectionProtocol::AMQP10
+        )
+    }
+
+    /// Returns true for MQTT connections, including those over WebSockets.
+    pub fn is_mqtt(&self) -> bool {
+        matches!(
+            self,
+            ConnectionProtocol::MQTT(_) | ConnectionProtocol::MQTTOverWebSockets(_)
+        )
+    }
+
+    /// Returns true for STOMP connections, including those over WebSockets.
+    pub fn is_stomp(&self) -> bool {
+        matches!(
+            self,
+            ConnectionProtocol::STOMP(_) | ConnectionProtocol::STOMPOverWebSockets(_)
+        )
+    }
+}
+
+impl From<&str> for ConnectionProtocol {
+    fn from(value: &str) -> Self {
+        match value {
+            "AMQP 0-9-1" => ConnectionProtocol::AMQP091,
+            "AMQP 1-0" | "AMQP 1.0" => ConnectionProtocol::AMQP10,
+            "stream" | "RabbitMQ Stream" => ConnectionProtocol::Stream,
+            other => {
+                if let Some(version) = other.strip_prefix("Web MQTT ") {
+                    ConnectionProtocol::MQTTOverWebSockets(version.to_owned())
+                } else if let Some(version) = other.strip_prefix("MQTT ") {
+                    ConnectionProtocol::MQTT(version.to_owned())
+                } else if let Some(version) = other.strip_prefix("Web STOMP ") {
+                    ConnectionProtocol::STOMPOverWebSockets(version.to_owned())
+                } else if let Some(version) = other.strip_prefix("STOMP ") {
+                    ConnectionProtocol::STOMP(version.to_owned())
+                } else {
+                    ConnectionProtocol::Other(other.to_owned())
+                }
+            }
+        }
+    }
+}
+
+impl From<String> for ConnectionProtocol {
+    fn from(value: String) -> Self {
+        ConnectionProtocol::from(value.as_str())
+    }
+}
+
+impl fmt::Display for ConnectionProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConnectionProtocol::AMQP091 => write!(f, "AMQP 0-9-1"),
+            // this is how the HTTP API reports AMQP 1.0 connections
+            ConnectionProtocol::AMQP10 => write!(f, "AMQP 1-0"),
+            ConnectionProtocol::MQTT(version) => write!(f, "MQTT {}", version),
+            ConnectionProtocol::MQTTOverWebSockets(version) => write!(f, "Web MQTT {}", version),
+            ConnectionProtocol::STOMP(version) => write!(f, "STOMP {}", version),
+            ConnectionProtocol::STOMPOverWebSockets(version) => write!(f, "Web STOMP {}", version),
+            ConnectionProtocol::Stream => write!(f, "RabbitMQ Stream"),
+            ConnectionProtocol::Other(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl From<ConnectionProtocol> for String {
+    fn from(value: ConnectionProtocol) -> Self {
+        value.to_string()
+    }
+}
+
+/// Returns the (non-TLS) listener protocol that accepts connections of the given protocol.
+impl From<&ConnectionProtocol> for SupportedProtocol {
+    fn from(value: &ConnectionProtocol) -> Self {
+        match value {
+            ConnectionProtocol::AMQP091 | ConnectionProtocol::AMQP10 => SupportedProtocol::AMQP,
+            ConnectionProtocol::MQTT(_) => SupportedProtocol::MQTT,
+            ConnectionProtocol::MQTTOverWebSockets(_) => SupportedProtocol::MQTTOverWebSockets,
+            ConnectionProtocol::STOMP(_) => SupportedProtocol::STOMP,
+            ConnectionProtocol::STOMPOverWebSockets(_) => SupportedProtocol::STOMPOverWebsockets,
+            ConnectionProtocol::Stream => SupportedProtocol::Stream,
+            ConnectionProtocol::Other(s) => SupportedProtocol::Other(s.clone()),
+        }
+    }
+}
+
 /// Exchange types. Most variants are for exchange types included with modern RabbitMQ distributions.
 /// For custom types provided by 3rd party plugins, use the `Plugin(String)` variant.
 #[derive(Eq, PartialEq, Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[serde(rename_all(serialize = "lowercase", deserialize = "PascalCase"))]
 pub enum ExchangeType {
     /// Fanout exchange
@@ -340,15 +465,17 @@ impl From<ExchangeType> for String {
 }
 
 #[derive(Eq, PartialEq, Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[serde(rename_all(serialize = "lowercase", deserialize = "PascalCase"))]
 pub enum QueueType {
     #[default]
     Classic,
     Quorum,
     Stream,
-    // Tanzu RabbitMQ-specific
+    /// Tanzu RabbitMQ-specific
     Delayed,
-    // A type this client is not aware of
+    /// A type this client is not aware of, e.g. one provided by a plugin
+    /// or introduced in a future RabbitMQ version
     #[serde(untagged)]
     Unsupported(String),
 }
@@ -380,13 +507,7 @@ impl From<&str> for QueueType {
 
 impl From<String> for QueueType {
     fn from(value: String) -> Self {
-        let val = value.to_ascii_lowercase();
-        match val.as_str() {
-            "classic" => QueueType::Classic,
-            "quorum" => QueueType::Quorum,
-            "stream" => QueueType::Stream,
-            _ => QueueType::Unsupported(value),
-        }
+        QueueType::from(value.as_str())
     }
 }
 
@@ -405,6 +526,7 @@ impl From<QueueType> for String {
 /// Binding destination can be either a queue or another exchange
 /// (in the case of [exchange-to-exchange bindings](https://rabbitmq.com/docs/e2e/)).
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum BindingDestinationType {
     Queue,
@@ -431,23 +553,32 @@ impl BindingDestinationType {
     }
 }
 
-impl From<&str> for BindingDestinationType {
-    fn from(value: &str) -> Self {
+impl FromStr for BindingDestinationType {
+    type Err = ConversionError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
         match value {
-            "queue" => BindingDestinationType::Queue,
-            "exchange" => BindingDestinationType::Exchange,
-            _ => BindingDestinationType::Queue,
+            "queue" => Ok(BindingDestinationType::Queue),
+            "exchange" => Ok(BindingDestinationType::Exchange),
+            _ => Err(ConversionError::UnsupportedValue {
+                type_name: "binding destination type".to_owned(),
+                value: value.to_owned(),
+            }),
         }
     }
 }
 
+/// Unknown values are mapped to [`BindingDestinationType::Queue`],
+/// use [`str::parse`] to detect them.
+impl From<&str> for BindingDestinationType {
+    fn from(value: &str) -> Self {
+        value.parse().unwrap_or(BindingDestinationType::Queue)
+    }
+}
+
 impl From<String> for BindingDestinationType {
     fn from(value: String) -> Self {
-        match value.as_str() {
-            "queue" => BindingDestinationType::Queue,
-            "exchange" => BindingDestinationType::Exchange,
-            _ => BindingDestinationType::Queue,
-        }
+        Self::from(value.as_str())
     }
 }
 
@@ -461,6 +592,7 @@ impl From<BindingDestinationType> for String {
 }
 
 #[derive(Eq, PartialEq, Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum PolicyTarget {
     Queues,
@@ -484,9 +616,9 @@ impl From<QueueType> for PolicyTarget {
 }
 
 impl PolicyTarget {
-    // Returns true if this policy target includes the target category.
-    // For example, [`PolicyTarget::Queue`] matches [`PolicyTarget::ClassicQueues`], [`PolicyTarget::QuorumQueues`],
-    // [`PolicyTarget::Stream`], [`PolicyTarget::Queue`] but not [`PolicyTarget::Exchanges`].
+    /// Returns true if this policy target includes the target category.
+    /// For example, [`PolicyTarget::Queues`] matches [`PolicyTarget::ClassicQueues`], [`PolicyTarget::QuorumQueues`],
+    /// [`PolicyTarget::Streams`], [`PolicyTarget::Queues`] but not [`PolicyTarget::Exchanges`].
     pub fn does_apply_to(&self, other: PolicyTarget) -> bool {
         match (self, other) {
             (PolicyTarget::All, _) => true,
@@ -504,6 +636,18 @@ impl PolicyTarget {
             _ => false,
         }
     }
+
+    /// Returns true if a policy with this target would apply to queues of the given type.
+    /// For example, [`PolicyTarget::QuorumQueues`] applies to [`QueueType::Quorum`] but not [`QueueType::Classic`],
+    /// and [`PolicyTarget::Queues`] applies to queues of all types.
+    pub fn does_apply_to_queue_type(&self, queue_type: QueueType) -> bool {
+        self.does_apply_to(PolicyTarget::from(queue_type))
+    }
+
+    /// Returns true if a policy with this target would apply to exchanges.
+    pub fn does_apply_to_exchanges(&self) -> bool {
+        self.does_apply_to(PolicyTarget::Exchanges)
+    }
 }
 
 impl fmt::Display for PolicyTarget {
@@ -514,41 +658,41 @@ impl fmt::Display for PolicyTarget {
     }
 }
 
-impl From<&str> for PolicyTarget {
-    fn from(value: &str) -> Self {
+impl FromStr for PolicyTarget {
+    type Err = ConversionError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
         match value {
-            "queues" => PolicyTarget::Queues,
-            "queue" => PolicyTarget::Queues,
-            "classic_queues" => PolicyTarget::ClassicQueues,
-            "classic_queue" => PolicyTarget::ClassicQueues,
-            "quorum_queues" => PolicyTarget::QuorumQueues,
-            "quorum_queue" => PolicyTarget::QuorumQueues,
-            "streams" => PolicyTarget::Streams,
-            "stream" => PolicyTarget::Streams,
-            "exchanges" => PolicyTarget::Exchanges,
-            "exchange" => PolicyTarget::Exchanges,
-            "all" => PolicyTarget::All,
-            _ => PolicyTarget::Queues,
+            "queues" => Ok(PolicyTarget::Queues),
+            "queue" => Ok(PolicyTarget::Queues),
+            "classic_queues" => Ok(PolicyTarget::ClassicQueues),
+            "classic_queue" => Ok(PolicyTarget::ClassicQueues),
+            "quorum_queues" => Ok(PolicyTarget::QuorumQueues),
+            "quorum_queue" => Ok(PolicyTarget::QuorumQueues),
+            "streams" => Ok(PolicyTarget::Streams),
+            "stream" => Ok(PolicyTarget::Streams),
+            "exchanges" => Ok(PolicyTarget::Exchanges),
+            "exchange" => Ok(PolicyTarget::Exchanges),
+            "all" => Ok(PolicyTarget::All),
+            _ => Err(ConversionError::UnsupportedValue {
+                type_name: "policy target".to_owned(),
+                value: value.to_owned(),
+            }),
         }
     }
 }
 
+/// Unknown values are mapped to [`PolicyTarget::Queues`],
+/// use [`str::parse`] to detect them.
+impl From<&str> for PolicyTarget {
+    fn from(value: &str) -> Self {
+        value.parse().unwrap_or(PolicyTarget::Queues)
+    }
+}
+
 impl From<String> for PolicyTarget {
     fn from(value: String) -> Self {
-        match value.as_str() {
-            "queues" => PolicyTarget::Queues,
-            "queue" => PolicyTarget::Queues,
-            "classic_queues" => PolicyTarget::ClassicQueues,
-            "classic_queue" => PolicyTarget::ClassicQueues,
-            "quorum_queues" => PolicyTarget::QuorumQueues,
-            "quorum_queue" => PolicyTarget::QuorumQueues,
-            "streams" => PolicyTarget::Streams,
-            "stream" => PolicyTarget::Streams,
-            "exchanges" => PolicyTarget::Exchanges,
-            "exchange" => PolicyTarget::Exchanges,
-            "all" => PolicyTarget::All,
-            _ => PolicyTarget::Queues,
-        }
+        Self::from(value.as_str())
     }
 }
 
@@ -566,6 +710,7 @@ impl From<PolicyTarget> for String {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[serde(rename_all = "kebab-case")]
 pub enum VirtualHostLimitTarget {
     MaxConnections,
@@ -581,23 +726,34 @@ impl AsRef<str> for VirtualHostLimitTarget {
     }
 }
 
-impl From<&str> for VirtualHostLimitTarget {
-    fn from(value: &str) -> Self {
+impl FromStr for VirtualHostLimitTarget {
+    type Err = ConversionError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
         match value {
-            "max-connections" => VirtualHostLimitTarget::MaxConnections,
-            "max-queues" => VirtualHostLimitTarget::MaxQueues,
-            _ => VirtualHostLimitTarget::MaxConnections,
+            "max-connections" => Ok(VirtualHostLimitTarget::MaxConnections),
+            "max-queues" => Ok(VirtualHostLimitTarget::MaxQueues),
+            _ => Err(ConversionError::UnsupportedValue {
+                type_name: "virtual host limit".to_owned(),
+                value: value.to_owned(),
+            }),
         }
     }
 }
 
+/// Unknown values are mapped to [`VirtualHostLimitTarget::MaxConnections`],
+/// use [`str::parse`] to detect them.
+impl From<&str> for VirtualHostLimitTarget {
+    fn from(value: &str) -> Self {
+        value
+            .parse()
+            .unwrap_or(VirtualHostLimitTarget::MaxConnections)
+    }
+}
+
 impl From<String> for VirtualHostLimitTarget {
     fn from(value: String) -> Self {
-        match value.as_str() {
-            "max-connections" => VirtualHostLimitTarget::MaxConnections,
-            "max-queues" => VirtualHostLimitTarget::MaxQueues,
-            _ => VirtualHostLimitTarget::MaxConnections,
-        }
+        Self::from(value.as_str())
     }
 }
 
@@ -608,6 +764,7 @@ impl From<VirtualHostLimitTarget> for String {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[serde(untagged, rename_all = "kebab-case")]
 pub enum UserLimitTarget {
     MaxConnections,
@@ -623,23 +780,32 @@ impl AsRef<str> for UserLimitTarget {
     }
 }
 
-impl From<&str> for UserLimitTarget {
-    fn from(value: &str) -> Self {
+impl FromStr for UserLimitTarget {
+    type Err = ConversionError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
         match value {
-            "max-connections" => UserLimitTarget::MaxConnections,
-            "max-channels" => UserLimitTarget::MaxChannels,
-            _ => UserLimitTarget::MaxConnections,
+            "max-connections" => Ok(UserLimitTarget::MaxConnections),
+            "max-channels" => Ok(UserLimitTarget::MaxChannels),
+            _ => Err(ConversionError::UnsupportedValue {
+                type_name: "user limit".to_owned(),
+                value: value.to_owned(),
+            }),
         }
     }
 }
 
+/// Unknown values are mapped to [`UserLimitTarget::MaxConnections`],
+/// use [`str::parse`] to detect them.
+impl From<&str> for UserLimitTarget {
+    fn from(value: &str) -> Self {
+        value.parse().unwrap_or(UserLimitTarget::MaxConnections)
+    }
+}
+
 impl From<String> for UserLimitTarget {
     fn from(value: String) -> Self {
-        match value.as_str() {
-            "max-connections" => UserLimitTarget::MaxConnections,
-            "max-channels" => UserLimitTarget::MaxChannels,
-            _ => UserLimitTarget::MaxConnections,
-        }
+        Self::from(value.as_str())
     }
 }
 
@@ -650,6 +816,7 @@ impl From<UserLimitTarget> for String {
 }
 
 #[derive(Default, Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub enum MessageTransferAcknowledgementMode {
     #[serde(rename = "no-ack")]
     Immediate,
@@ -692,3 +859,167 @@ impl From<MessageTransferAcknowledgementMode> for String {
         value.to_string()
     }
 }
+
+/// Management plugin [statistics collection mode](https://www.rabbitmq.com/docs/management#fine-grained-stats).
+#[derive(Default, Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum RatesMode {
+    /// Only message rates of individual objects are collected
+    #[default]
+    Basic,
+    /// Message rates are also collected for every channel, exchange and queue combination
+    Detailed,
+    /// Message rates are not collected
+    None,
+}
+
+impl From<&str> for RatesMode {
+    fn from(value: &str) -> Self {
+        match value {
+            "basic" => RatesMode::Basic,
+            "detailed" => RatesMode::Detailed,
+            "none" => RatesMode::None,
+            _ => RatesMode::default(),
+        }
+    }
+}
+
+impl From<String> for RatesMode {
+    fn from(value: String) -> Self {
+        Self::from(value.as_str())
+    }
+}
+
+impl Display for RatesMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RatesMode::Basic => write!(f, "basic"),
+            RatesMode::Detailed => write!(f, "detailed"),
+            RatesMode::None => write!(f, "none"),
+        }
+    }
+}
+
+impl From<RatesMode> for String {
+    fn from(value: RatesMode) -> Self {
+        value.to_string()
+    }
+}
+
+/// Which quorum queues get a new replica when quorum queues are grown onto a node,
+/// see `Client#grow_quorum_queue`.
+#[derive(Default, Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum QuorumQueueGrowthStrategy {
+    /// All matching quorum queues
+    #[default]
+    All,
+    /// Only matching quorum queues with an even number of replicas
+    Even,
+}
+
+impl From<&str> for QuorumQueueGrowthStrategy {
+    fn from(value: &str) -> Self {
+        match value {
+            "all" => QuorumQueueGrowthStrategy::All,
+            "even" => QuorumQueueGrowthStrategy::Even,
+            _ => QuorumQueueGrowthStrategy::default(),
+        }
+    }
+}
+
+impl From<String> for QuorumQueueGrowthStrategy {
+    fn from(value: String) -> Self {
+        Self::from(value.as_str())
+    }
+}
+
+impl Display for QuorumQueueGrowthStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QuorumQueueGrowthStrategy::All => write!(f, "all"),
+            QuorumQueueGrowthStrategy::Even => write!(f, "even"),
+        }
+    }
+}
+
+impl From<QuorumQueueGrowthStrategy> for String {
+    fn from(value: QuorumQueueGrowthStrategy) -> Self {
+        value.to_string()
+    }
+}
+
+/// How a message payload is encoded in HTTP API requests and responses.
+#[derive(Default, Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum PayloadEncoding {
+    /// The payload is a UTF-8 string
+    #[default]
+    String,
+    /// The payload is binary and Base64-encoded
+    Base64,
+}
+
+impl From<&str> for PayloadEncoding {
+    fn from(value: &str) -> Self {
+        match value {
+            "string" => PayloadEncoding::String,
+            "base64" => PayloadEncoding::Base64,
+            _ => PayloadEncoding::default(),
+        }
+    }
+}
+
+impl From<String> for PayloadEncoding {
+    fn from(value: String) -> Self {
+        Self::from(value.as_str())
+    }
+}
+
+impl Display for PayloadEncoding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PayloadEncoding::String => write!(f, "string"),
+            PayloadEncoding::Base64 => write!(f, "base64"),
+        }
+    }
+}
+
+impl From<PayloadEncoding> for String {
+    fn from(value: PayloadEncoding) -> Self {
+        value.to_string()
+    }
+}
+
+/// The unit of the time window used by the certificate expiration health check,
+/// see `Client#health_check_certificate_expiration`.
+#[derive(Default, Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum TimeUnit {
+    #[default]
+    Days,
+    Weeks,
+    Months,
+    Years,
+}
+
+impl Display for TimeUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimeUnit::Days => write!(f, "days"),
+            TimeUnit::Weeks => write!(f, "weeks"),
+            TimeUnit::Months => write!(f, "months"),
+            TimeUnit::Years => write!(f, "years"),
+        }
+    }
+}
+
+impl From<TimeUnit> for String {
+    fn from(value: TimeUnit) -> Self {
+        value.to_string()
+    }
+}
@@ -0,0 +1,207 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Encoding, decoding and validation of definition files,
+//! shared by the backup and restore functions of both clients.
+#![allow(clippy::result_large_err)]
+
+use crate::error::{Error, HttpClientError};
+use serde_json::Value;
+use std::ffi::{OsStr, OsString};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use tokio::io::AsyncWriteExt;
+
+type Result<T> = std::result::Result<T, HttpClientError>;
+
+const GZIP_EXTENSION: &str = "gz";
+const GZIP_MAGIC_BYTES: [u8; 2] = [0x1f, 0x8b];
+
+/// Makes temporary file names unique within the process,
+/// the process ID makes them unique across processes.
+static TEMPORARY_FILE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Top-level keys of a definitions document, at least one of them
+/// must be present for a document to be considered valid.
+const KNOWN_TOP_LEVEL_KEYS: [&str; 12] = [
+    "rabbit_version",
+    "rabbitmq_version",
+    "users",
+    "vhosts",
+    "permissions",
+    "topic_permissions",
+    "parameters",
+    "global_parameters",
+    "policies",
+    "queues",
+    "exchanges",
+    "bindings",
+];
+
+/// Validates exported definitions and encodes them for writing to `path`.
+/// Paths with the `.gz` extension are compressed with gzip.
+pub(crate) fn encode(path: &Path, definitions: &str) -> Result<Vec<u8>> {
+    let parsed = parse(definitions.as_bytes())?;
+    validate(&parsed)?;
+
+    if is_gzip_path(path) {
+        compress(definitions.as_bytes())
+    } else {
+        Ok(definitions.as_bytes().to_vec())
+    }
+}
+
+/// Decompresses (if necessary), parses and validates the contents of a definitions file.
+pub(crate) fn decode(bytes: &[u8]) -> Result<Value> {
+    let parsed = if bytes.starts_with(&GZIP_MAGIC_BYTES) {
+        parse(&decompress(bytes)?)?
+    } else {
+        parse(bytes)?
+    };
+    validate(&parsed)?;
+
+    Ok(parsed)
+}
+
+/// Fails early if writing definitions to `path` requires a feature that is not enabled,
+/// e.g. before the definitions are exported.
+pub(crate) fn check_path(path: &Path) -> Result<()> {
+    if is_gzip_path(path) && !cfg!(feature = "gzip") {
+        Err(gzip_not_enabled("Writing a .gz definitions file"))
+    } else {
+        Ok(())
+    }
+}
+
+/// Writes `bytes` to a temporary file in the same directory as `path`, flushes it to disk
+/// and renames it to `path`, so that `path` never contains a partially written file,
+/// even after a crash.
+pub(crate) async fn write_atomically(path: &Path, bytes: &[u8]) -> Result<()> {
+    let tmp_path = temporary_path(path);
+    let result = write_and_rename(&tmp_path, path, bytes).await;
+    if result.is_err() {
+        let _ = tokio::fs::remove_file(&tmp_path).await;
+    }
+    result
+}
+
+/// Returns a unique path in the same directory as `path` that can be written to
+/// and then atomically renamed to `path`.
+pub(crate) fn temporary_path(path: &Path) -> PathBuf {
+    let mut name = OsString::from(".");
+    name.push(path.file_name().unwrap_or(OsStr::new("definitions")));
+    name.push(format!(
+        ".{}.{}.tmp",
+        std::process::id(),
+        TEMPORARY_FILE_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    path.with_file_name(name)
+}
+
+async fn write_and_rename(tmp_path: &Path, path: &Path, bytes: &[u8]) -> Result<()> {
+    let mut file = tokio::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(tmp_path)
+        .await?;
+    file.write_all(bytes).await?;
+    file.sync_all().await?;
+    drop(file);
+
+    tokio::fs::rename(tmp_path, path).await?;
+    sync_parent_directory(path).await
+}
+
+/// Makes a rename durable by flushing the directory entry to disk.
+#[cfg(unix)]
+async fn sync_parent_directory(path: &Path) -> Result<()> {
+    let dir = match path.parent() {
+        Some(p) if !p.as_os_str().is_empty() => p,
+        _ => Path::new("."),
+    };
+    tokio::fs::File::open(dir).await?.sync_all().await?;
+    Ok(())
+}
+
+/// Directories cannot be opened (and flushed) on this platform.
+#[cfg(not(unix))]
+async fn sync_parent_directory(_path: &Path) -> Result<()> {
+    Ok(())
+}
+
+fn gzip_not_enabled(operation: &str) -> HttpClientError {
+    Error::FeatureNotEnabled {
+        feature: "gzip".to_owned(),
+        operation: operation.to_owned(),
+    }
+}
+
+fn validate(definitions: &Value) -> Result<()> {
+    let m = definitions
+        .as_object()
+        .ok_or_else(|| Error::InvalidDefinitions {
+            reason: "expected a JSON object".to_owned(),
+        })?;
+
+    if KNOWN_TOP_LEVEL_KEYS.iter().any(|k| m.contains_key(*k)) {
+        Ok(())
+    } else {
+        Err(Error::InvalidDefinitions {
+            reason: "none of the known top-level keys (such as 'vhosts' or 'queues') are present"
+                .to_owned(),
+        })
+    }
+}
+
+fn parse(bytes: &[u8]) -> Result<Value> {
+    serde_json::from_slice(bytes).map_err(|e| Error::InvalidDefinitions {
+        reason: e.to_string(),
+    })
+}
+
+fn is_gzip_path(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == GZIP_EXTENSION)
+}
+
+#[cfg(feature = "gzip")]
+fn compress(bytes: &[u8]) -> Result<Vec<u8>> {
+    use flate2::{write::GzEncoder, Compression};
+    use std::io::Write;
+
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(bytes)?;
+    Ok(encoder.finish()?)
+}
+
+#[cfg(not(feature = "gzip"))]
+fn compress(_bytes: &[u8]) -> Result<Vec<u8>> {
+    Err(gzip_not_enabled("Writing a .gz definitions file"))
+}
+
+#[cfg(feature = "gzip")]
+fn decompress(bytes: &[u8]) -> Result<Vec<u8>> {
+    use flate2::read::GzDecoder;
+    use std::io::Read;
+
+    let mut out = Vec::new();
+    GzDecoder::new(bytes).read_to_end(&mut out)?;
+    Ok(out)
+}
+
+#[cfg(not(feature = "gzip"))]
+fn decompress(_bytes: &[u8]) -> Result<Vec<u8>> {
+    Err(gzip_not_enabled(
+        "Reading a gzip-compressed definitions file",
+    ))
+}
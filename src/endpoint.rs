@@ -0,0 +1,299 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A typed HTTP API endpoint that is validated when it is constructed, so that
+//! a malformed endpoint fails early with a clear error instead of on the first request.
+//!
+//! [`Endpoint`] implements `Display`, so it can be passed to `ClientBuilder#with_endpoint`
+//! or `Client::new` in place of a string:
+//!
+//! ```rust,ignore
+//! use rabbitmq_http_client::endpoint::Endpoint;
+//!
+//! let endpoint = Endpoint::builder()
+//!     .with_tls()
+//!     .with_host("rabbitmq.eng.example.com")
+//!     .build()?;
+//! let rc = ClientBuilder::new().with_endpoint(endpoint).build();
+//! ```
+
+use std::fmt;
+use std::net::Ipv6Addr;
+use std::str::FromStr;
+
+use reqwest::Url;
+use thiserror::Error;
+
+/// The default HTTP API port.
+pub const DEFAULT_HTTP_PORT: u16 = 15672;
+/// The default HTTP API port when TLS is enabled.
+pub const DEFAULT_HTTPS_PORT: u16 = 15671;
+/// The default HTTP API path prefix.
+pub const DEFAULT_PATH_PREFIX: &str = "/api";
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum EndpointError {
+    #[error("'{0}' is not a supported scheme, supported schemes are: http, https")]
+    UnsupportedScheme(String),
+    #[error("endpoint host cannot be empty")]
+    EmptyHost,
+    #[error("'{0}' is not a valid host")]
+    InvalidHost(String),
+    #[error("endpoint port cannot be 0")]
+    InvalidPort,
+    #[error("'{0}' is not a valid path prefix: it cannot contain a query string or a fragment")]
+    InvalidPathPrefix(String),
+    #[error("'{endpoint}' is not a valid endpoint: {reason}")]
+    Malformed { endpoint: String, reason: String },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Scheme {
+    #[default]
+    Http,
+    /// HTTP over TLS
+    Https,
+}
+
+impl Scheme {
+    pub fn default_port(&self) -> u16 {
+        match self {
+            Scheme::Http => DEFAULT_HTTP_PORT,
+            Scheme::Https => DEFAULT_HTTPS_PORT,
+        }
+    }
+}
+
+impl fmt::Display for Scheme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Scheme::Http => write!(f, "http"),
+            Scheme::Https => write!(f, "https"),
+        }
+    }
+}
+
+impl FromStr for Scheme {
+    type Err = EndpointError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "http" => Ok(Scheme::Http),
+            "https" => Ok(Scheme::Https),
+            _ => Err(EndpointError::UnsupportedScheme(s.to_owned())),
+        }
+    }
+}
+
+/// A validated HTTP API endpoint, e.g. `https://rabbitmq.eng.example.com:15671/api`.
+///
+/// Use [`Endpoint::builder`] to construct one from parts or parse one from a string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Endpoint {
+    scheme: Scheme,
+    host: String,
+    port: u16,
+    path_prefix: String,
+}
+
+impl Endpoint {
+    pub fn builder() -> EndpointBuilder {
+        EndpointBuilder::default()
+    }
+
+    pub fn scheme(&self) -> Scheme {
+        self.scheme
+    }
+
+    pub fn host(&self) -> &str {
+        &self.host
+    }
+
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    pub fn path_prefix(&self) -> &str {
+        &self.path_prefix
+    }
+
+    pub fn uses_tls(&self) -> bool {
+        self.scheme == Scheme::Https
+    }
+}
+
+impl fmt::Display for Endpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.host.parse::<Ipv6Addr>().is_ok() {
+            write!(f, "{}://[{}]:{}", self.scheme, self.host, self.port)?;
+        } else {
+            write!(f, "{}://{}:{}", self.scheme, self.host, self.port)?;
+        }
+        write!(f, "{}", self.path_prefix)
+    }
+}
+
+/// Parses and validates an endpoint such as `http://localhost:15672/api`.
+///
+/// If the port is omitted, the default HTTP API port for the scheme is used, see [`Scheme::default_port`].
+/// An explicitly provided port is always kept, even if it is the standard one for the scheme, e.g. `:443`.
+/// If the path is omitted, [`DEFAULT_PATH_PREFIX`] is used.
+impl FromStr for Endpoint {
+    type Err = EndpointError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let url = Url::parse(s).map_err(|e| EndpointError::Malformed {
+            endpoint: s.to_owned(),
+            reason: e.to_string(),
+        })?;
+        let scheme = Scheme::from_str(url.scheme())?;
+        if url.query().is_some() || url.fragment().is_some() {
+            return Err(EndpointError::InvalidPathPrefix(s.to_owned()));
+        }
+        if !url.username().is_empty() || url.password().is_some() {
+            return Err(EndpointError::Malformed {
+                endpoint: s.to_owned(),
+                reason: "credentials must be provided separately".to_owned(),
+            });
+        }
+        let host = url
+            .host_str()
+            .map(|h| h.trim_start_matches('[').trim_end_matches(']'))
+            .unwrap_or_default();
+        let mut builder = Endpoint::builder().with_scheme(scheme).with_host(host);
+        // `Url::port` is `None` for the default port of the scheme, e.g. 443 for https,
+        // while the default HTTP API ports are different
+        let port = match url.port() {
+            Some(port) => Some(port),
+            None if has_explicit_port(s) => url.port_or_known_default(),
+            None => None,
+        };
+        if let Some(port) = port {
+            builder = builder.with_port(port);
+        }
+        if url.path() != "/" {
+            builder = builder.with_path_prefix(url.path());
+        }
+        builder.build()
+    }
+}
+
+/// Returns true if the authority part of `s` ends with a port, e.g. `:443`
+fn has_explicit_port(s: &str) -> bool {
+    let rest = s.split_once("://").map_or(s, |(_, rest)| rest);
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    let host_and_port = authority.rsplit_once('@').map_or(authority, |(_, hp)| hp);
+    match host_and_port.rsplit_once(':') {
+        Some((_, port)) => !port.is_empty() && port.bytes().all(|b| b.is_ascii_digit()),
+        None => false,
+    }
+}
+
+impl TryFrom<&str> for Endpoint {
+    type Error = EndpointError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Endpoint::from_str(s)
+    }
+}
+
+/// Constructs an [`Endpoint`] from parts. Defaults to `http://localhost:15672/api`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EndpointBuilder {
+    scheme: Scheme,
+    host: String,
+    port: Option<u16>,
+    path_prefix: String,
+}
+
+impl Default for EndpointBuilder {
+    fn default() -> Self {
+        Self {
+            scheme: Scheme::Http,
+            host: "localhost".to_owned(),
+            port: None,
+            path_prefix: DEFAULT_PATH_PREFIX.to_owned(),
+        }
+    }
+}
+
+impl EndpointBuilder {
+    pub fn with_scheme(mut self, scheme: Scheme) -> Self {
+        self.scheme = scheme;
+        self
+    }
+
+    /// Uses HTTPS. Unless a port is set explicitly, [`DEFAULT_HTTPS_PORT`] will be used.
+    pub fn with_tls(self) -> Self {
+        self.with_scheme(Scheme::Https)
+    }
+
+    /// A hostname, an IPv4 or an IPv6 address (without the brackets).
+    pub fn with_host(mut self, host: &str) -> Self {
+        self.host = host.to_owned();
+        self
+    }
+
+    /// Unless set, the default port for the scheme is used, see [`Scheme::default_port`].
+    pub fn with_port(mut self, port: u16) -> Self {
+        self.port = Some(port);
+        self
+    }
+
+    /// The path the HTTP API is served under, e.g. when it is behind a reverse proxy.
+    /// Defaults to [`DEFAULT_PATH_PREFIX`].
+    pub fn with_path_prefix(mut self, prefix: &str) -> Self {
+        self.path_prefix = prefix.to_owned();
+        self
+    }
+
+    /// Validates the parts and returns an [`Endpoint`].
+    pub fn build(self) -> Result<Endpoint, EndpointError> {
+        let host = self.host.trim();
+        if host.is_empty() {
+            return Err(EndpointError::EmptyHost);
+        }
+        if host.contains(|c: char| c.is_whitespace() || "/?#@[]".contains(c))
+            || (host.contains(':') && host.parse::<Ipv6Addr>().is_err())
+        {
+            return Err(EndpointError::InvalidHost(host.to_owned()));
+        }
+
+        let port = self.port.unwrap_or_else(|| self.scheme.default_port());
+        if port == 0 {
+            return Err(EndpointError::InvalidPort);
+        }
+
+        if self.path_prefix.contains(['?', '#']) {
+            return Err(EndpointError::InvalidPathPrefix(self.path_prefix));
+        }
+        let path_prefix = match self.path_prefix.trim_matches('/') {
+            "" => String::new(),
+            p => format!("/{}", p),
+        };
+
+        let endpoint = Endpoint {
+            scheme: self.scheme,
+            host: host.to_owned(),
+            port,
+            path_prefix,
+        };
+        // catches what the checks above do not, e.g. invalid characters in a domain name
+        Url::parse(&endpoint.to_string()).map_err(|e| EndpointError::Malformed {
+            endpoint: endpoint.to_string(),
+            reason: e.to_string(),
+        })?;
+        Ok(endpoint)
+    }
+}
@@ -13,10 +13,18 @@
 // limitations under the License.
 #![allow(clippy::result_large_err)]
 
-use crate::responses;
 use thiserror::Error;
 
+#[cfg(any(feature = "async", feature = "blocking"))]
+use crate::responses;
+#[cfg(any(feature = "async", feature = "blocking"))]
+use crate::utils::{redact_request_error, redact_url};
+#[cfg(any(feature = "async", feature = "blocking"))]
+use std::time::Duration;
+
+#[cfg(any(feature = "async", feature = "blocking"))]
 use backtrace::Backtrace;
+#[cfg(any(feature = "async", feature = "blocking"))]
 use reqwest::{
     header::{HeaderMap, InvalidHeaderValue},
     StatusCode, Url,
@@ -28,8 +36,15 @@ pub enum ConversionError {
     UnsupportedPropertyValue { property: String },
     #[error("Missing required argument")]
     MissingProperty { argument: String },
+    #[error("Message payload could not be decoded: {reason}")]
+    InvalidPayload { reason: String },
+    #[error("'{value}' is not a valid {type_name}")]
+    UnsupportedValue { type_name: String, value: String },
+    #[error("Metrics could not be parsed, line {line}: {reason}")]
+    InvalidMetrics { line: usize, reason: String },
 }
 
+#[cfg(any(feature = "async", feature = "blocking"))]
 #[derive(Error, Debug)]
 pub enum Error<U, S, E, BT> {
     #[error("API responded with a client error: status code of {status_code}")]
@@ -38,6 +53,8 @@ pub enum Error<U, S, E, BT> {
         status_code: S,
         body: Option<String>,
         headers: Option<HeaderMap>,
+        /// The ID sent with the request, see `ClientBuilder#with_request_ids`
+        request_id: Option<String>,
         backtrace: BT,
     },
     #[error("API responded with a server error: status code of {status_code}")]
@@ -46,6 +63,8 @@ pub enum Error<U, S, E, BT> {
         status_code: S,
         body: Option<String>,
         headers: Option<HeaderMap>,
+        /// The ID sent with the request, see `ClientBuilder#with_request_ids`
+        request_id: Option<String>,
         backtrace: BT,
     },
     #[error("Health check failed")]
@@ -56,6 +75,8 @@ pub enum Error<U, S, E, BT> {
     },
     #[error("API responded with a 404 Not Found")]
     NotFound,
+    #[error("Node did not become ready within {timeout:?}")]
+    NotReady { timeout: Duration },
     #[error("Cannot delete a binding: multiple matching bindings were found, provide additional properties")]
     MultipleMatchingBindings,
     #[error("could not convert provided value into an HTTP header value")]
@@ -64,50 +85,163 @@ pub enum Error<U, S, E, BT> {
     UnsupportedArgumentValue { property: String },
     #[error("Missing required argument")]
     MissingProperty { argument: String },
+    #[error("'{value}' is not a valid {type_name}")]
+    UnsupportedValue { type_name: String, value: String },
     #[error("Response is incompatible with the target data type")]
     IncompatibleBody {
         error: ConversionError,
         backtrace: BT,
     },
     #[error("encountered an error when performing an HTTP request")]
-    RequestError { error: E, backtrace: BT },
+    RequestError {
+        error: E,
+        /// The ID sent with the request, see `ClientBuilder#with_request_ids`
+        request_id: Option<String>,
+        backtrace: BT,
+    },
+    #[error("encountered an I/O error")]
+    IoError {
+        error: std::io::Error,
+        backtrace: BT,
+    },
+    #[error("Definitions are invalid: {reason}")]
+    InvalidDefinitions { reason: String },
+    #[error("{operation} requires the '{feature}' feature to be enabled")]
+    FeatureNotEnabled { feature: String, operation: String },
+    #[error("Message file is invalid: {reason}")]
+    InvalidMessageFile { reason: String },
+    #[error("Message published to exchange '{exchange}' with routing key '{routing_key}' was not routed to any queue")]
+    MessageNotRouted {
+        exchange: String,
+        routing_key: String,
+    },
+    #[error("Message replay was interrupted after {} message(s), {} fetched message(s) were not republished", .replayed.len(), .unreplayed.len())]
+    MessageReplayInterrupted {
+        /// Messages that were republished before the failure
+        replayed: Vec<responses::GetMessage>,
+        /// Messages that were fetched (and thus removed from the source queue)
+        /// but not republished. It is up to the caller to republish or persist them
+        unreplayed: Vec<responses::GetMessage>,
+        /// The publishing failure, e.g. [`Error::MessageNotRouted`]
+        cause: Box<Self>,
+    },
+    #[error("Message payload of {size} bytes exceeds the limit of {limit} bytes")]
+    PayloadTooLarge { size: usize, limit: usize },
+    #[error("Response body exceeds the limit of {limit} bytes, consider listing fewer objects (e.g. in a single virtual host) or requesting fewer columns")]
+    ResponseTooLarge {
+        url: Option<U>,
+        /// The size advertised by the server, if known upfront
+        content_length: Option<u64>,
+        limit: u64,
+        /// The ID sent with the request, see `ClientBuilder#with_request_ids`
+        request_id: Option<String>,
+    },
+    #[error("The deadline set with `Client#with_deadline` has passed")]
+    DeadlineExceeded,
+    #[error("The circuit breaker is open after consecutive failures, requests are short-circuited for another {retry_after:?}")]
+    CircuitOpen { retry_after: Duration },
+    #[error("The blocking client cannot be used here: {reason}")]
+    BlockingNotSupported { reason: String },
+    #[error("Client settings are invalid: {reason}")]
+    InvalidClientSettings { reason: String },
+    #[error("{kind} '{name}' is protected from destructive operations")]
+    ProtectedEntity {
+        kind: String,
+        vhost: Option<String>,
+        name: String,
+    },
     #[error("an unspecified error")]
     Other,
 }
 
+#[cfg(any(feature = "async", feature = "blocking"))]
+impl<U, S, E, BT> Error<U, S, E, BT> {
+    /// The ID sent with the failed request, if the client was configured
+    /// to send request IDs and the request was issued.
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            Error::ClientErrorResponse { request_id, .. }
+            | Error::ServerErrorResponse { request_id, .. }
+            | Error::RequestError { request_id, .. }
+            | Error::ResponseTooLarge { request_id, .. } => request_id.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn with_request_id(mut self, id: Option<String>) -> Self {
+        if let Error::ClientErrorResponse { request_id, .. }
+        | Error::ServerErrorResponse { request_id, .. }
+        | Error::RequestError { request_id, .. }
+        | Error::ResponseTooLarge { request_id, .. } = &mut self
+        {
+            *request_id = id;
+        }
+        self
+    }
+}
+
+#[cfg(any(feature = "async", feature = "blocking"))]
 #[allow(unused)]
 pub type HttpClientError = Error<Url, StatusCode, reqwest::Error, Backtrace>;
 
+#[cfg(any(feature = "async", feature = "blocking"))]
+impl HttpClientError {
+    /// Rewrites the request URL carried by the error, if any, e.g. to redact a part of it
+    pub(crate) fn map_url<F>(mut self, f: F) -> Self
+    where
+        F: Fn(Url) -> Url,
+    {
+        match &mut self {
+            Error::ClientErrorResponse { url, .. }
+            | Error::ServerErrorResponse { url, .. }
+            | Error::ResponseTooLarge { url, .. } => *url = url.take().map(f),
+            Error::RequestError { error, .. } => {
+                if let Some(url) = error.url_mut() {
+                    *url = f(url.clone());
+                }
+            }
+            _ => {}
+        }
+        self
+    }
+}
+
+#[cfg(any(feature = "async", feature = "blocking"))]
 impl From<reqwest::Error> for HttpClientError {
     fn from(req_err: reqwest::Error) -> Self {
+        let req_err = redact_request_error(req_err);
         match req_err.status() {
             None => HttpClientError::RequestError {
                 error: req_err,
+                request_id: None,
                 backtrace: Backtrace::new(),
             },
             Some(status_code) => {
                 if status_code.is_client_error() {
                     return HttpClientError::ClientErrorResponse {
-                        url: req_err.url().cloned(),
+                        url: req_err.url().cloned().map(redact_url),
                         status_code,
                         body: None,
                         headers: None,
+                        request_id: None,
                         backtrace: Backtrace::new(),
                     };
                 };
 
                 if status_code.is_server_error() {
                     return HttpClientError::ServerErrorResponse {
-                        url: req_err.url().cloned(),
+                        url: req_err.url().cloned().map(redact_url),
                         status_code,
                         body: None,
                         headers: None,
+                        request_id: None,
                         backtrace: Backtrace::new(),
                     };
                 };
 
                 HttpClientError::RequestError {
                     error: req_err,
+                    request_id: None,
                     backtrace: Backtrace::new(),
                 }
             }
@@ -115,12 +249,24 @@ impl From<reqwest::Error> for HttpClientError {
     }
 }
 
+#[cfg(any(feature = "async", feature = "blocking"))]
+impl From<std::io::Error> for HttpClientError {
+    fn from(err: std::io::Error) -> Self {
+        HttpClientError::IoError {
+            error: err,
+            backtrace: Backtrace::new(),
+        }
+    }
+}
+
+#[cfg(any(feature = "async", feature = "blocking"))]
 impl From<reqwest::header::InvalidHeaderValue> for HttpClientError {
     fn from(err: reqwest::header::InvalidHeaderValue) -> Self {
         HttpClientError::InvalidHeaderValue { error: err }
     }
 }
 
+#[cfg(any(feature = "async", feature = "blocking"))]
 impl From<ConversionError> for HttpClientError {
     fn from(value: ConversionError) -> Self {
         match value {
@@ -130,6 +276,14 @@ impl From<ConversionError> for HttpClientError {
             ConversionError::MissingProperty { argument } => {
                 HttpClientError::MissingProperty { argument }
             }
+            ConversionError::UnsupportedValue { type_name, value } => {
+                HttpClientError::UnsupportedValue { type_name, value }
+            }
+            error @ (ConversionError::InvalidPayload { .. }
+            | ConversionError::InvalidMetrics { .. }) => HttpClientError::IncompatibleBody {
+                error,
+                backtrace: Backtrace::new(),
+            },
         }
     }
 }
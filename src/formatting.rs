@@ -11,6 +11,7 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
+use crate::requests::DefinitionOperation;
 use crate::responses::*;
 use serde_json::Map;
 use std::fmt;
@@ -68,6 +69,16 @@ impl Display for QueueTotals {
 
 impl Display for MessageStats {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "messages published: {}",
+            display_option(&self.publishing_count)
+        )?;
+        writeln!(
+            f,
+            "messages delivered plus polled: {}",
+            display_option(&self.delivery_count)
+        )?;
         writeln!(
             f,
             "publishing (ingress) rate: {}",
@@ -83,6 +94,75 @@ impl Display for MessageStats {
     }
 }
 
+impl Display for DefinitionSetSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "server version: {}",
+            display_option(&self.server_version)
+        )?;
+        writeln!(f, "users: {}", self.user_count)?;
+        writeln!(f, "virtual hosts: {}", self.virtual_host_count)?;
+        writeln!(f, "permissions: {}", self.permission_count)?;
+        writeln!(f, "runtime parameters: {}", self.runtime_parameter_count)?;
+        writeln!(f, "policies: {}", self.policy_count)?;
+        writeln!(f, "exchanges: {}", self.exchange_count)?;
+        writeln!(f, "classic queues: {}", self.classic_queue_count)?;
+        writeln!(f, "quorum queues: {}", self.quorum_queue_count)?;
+        writeln!(f, "streams: {}", self.stream_count)?;
+        writeln!(f, "queues of other types: {}", self.other_queue_count)?;
+        writeln!(f, "bindings: {}", self.binding_count)?;
+        writeln!(
+            f,
+            "deprecated features used: {}",
+            self.deprecated_features.join(", ")
+        )?;
+
+        Ok(())
+    }
+}
+
+impl Display for DefinitionOperation<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DefinitionOperation::CreateVirtualHost(params) => {
+                write!(f, "create virtual host '{}'", params.name)
+            }
+            DefinitionOperation::CreateUser(user) => write!(f, "create user '{}'", user.name),
+            DefinitionOperation::DeclarePermissions(params) => write!(
+                f,
+                "declare permissions of user '{}' in virtual host '{}'",
+                params.user, params.vhost
+            ),
+            DefinitionOperation::UpsertRuntimeParameter(param) => write!(
+                f,
+                "set runtime parameter '{}' of component '{}' in virtual host '{}'",
+                param.name, param.component, param.vhost
+            ),
+            DefinitionOperation::DeclarePolicy(params) => write!(
+                f,
+                "declare policy '{}' in virtual host '{}'",
+                params.name, params.vhost
+            ),
+            DefinitionOperation::DeclareExchange { vhost, params } => write!(
+                f,
+                "declare exchange '{}' in virtual host '{}'",
+                params.name, vhost
+            ),
+            DefinitionOperation::DeclareQueue { vhost, params } => write!(
+                f,
+                "declare queue '{}' in virtual host '{}'",
+                params.name, vhost
+            ),
+            DefinitionOperation::DeclareBinding(b) => write!(
+                f,
+                "bind {} '{}' to exchange '{}' in virtual host '{}'",
+                b.destination_type, b.destination, b.source, b.vhost
+            ),
+        }
+    }
+}
+
 impl Display for TagList {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt_comma_separated_list(f, &self.0)
@@ -170,7 +250,10 @@ impl Display for MessageRouted {
 
 impl Display for MessageProperties {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt_map_as_colon_separated_pairs(f, &self.0)
+        match serde_json::to_value(self) {
+            Ok(serde_json::Value::Object(m)) => fmt_map_as_colon_separated_pairs(f, &m),
+            _ => Ok(()),
+        }
     }
 }
 
@@ -328,6 +411,16 @@ pub fn display_arg_table(xs: &XArguments) -> String {
     s.clone()
 }
 
+pub fn display_string_list(xs: &[String]) -> String {
+    let mut s = String::new();
+    for x in xs {
+        let line = format!("{}\n", x);
+        s += line.as_str()
+    }
+
+    s
+}
+
 pub fn display_tag_map_option(opt: &Option<TagMap>) -> String {
     match opt {
         Some(val) => {
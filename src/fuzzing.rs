@@ -0,0 +1,299 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! [`Arbitrary`] implementations for commonly used request and response types,
+//! for property-based testing and fuzzing of code that handles topologies.
+//!
+//! Generated values satisfy the constraints RabbitMQ imposes: names are non-empty,
+//! at most 255 bytes long and never use the reserved `amq.` prefix, optional arguments
+//! are well-known ones with values of the expected type, and quorum queues and
+//! streams are always durable.
+//!
+//! Request types are generated in their owned form, e.g. [`OwnedQueueParams`],
+//! use their `as_params` functions to get the borrowed counterpart.
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+use serde_json::{json, Map, Value};
+
+use crate::commons::{BindingDestinationType, ExchangeType, PolicyTarget, QueueType};
+use crate::requests::{
+    OwnedExchangeParams, OwnedPolicyParams, OwnedQueueParams, OwnedVirtualHostParams,
+};
+use crate::responses::{BindingInfo, ExchangeInfo, QueueDefinition, XArguments};
+
+/// The longest name generated by [`arbitrary_name`]. RabbitMQ allows up to 255 bytes.
+pub const MAX_GENERATED_NAME_LENGTH: usize = 64;
+
+const NAME_CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789.-_:";
+const RESERVED_PREFIX: &str = "amq.";
+
+/// Generates a valid queue, exchange, virtual host, user or policy name.
+pub fn arbitrary_name(u: &mut Unstructured<'_>) -> Result<String> {
+    let len = u.int_in_range(1..=MAX_GENERATED_NAME_LENGTH)?;
+    let mut name = String::with_capacity(len);
+    for _ in 0..len {
+        name.push(char::from(*u.choose(NAME_CHARS)?));
+    }
+    if name.starts_with(RESERVED_PREFIX) {
+        name.insert(0, 'x');
+    }
+    Ok(name)
+}
+
+/// Generates optional queue arguments that are valid for the given queue type.
+/// The `x-queue-type` argument is not included.
+pub fn arbitrary_queue_arguments(
+    u: &mut Unstructured<'_>,
+    queue_type: &QueueType,
+) -> Result<Map<String, Value>> {
+    let mut m = Map::new();
+    if *queue_type == QueueType::Stream {
+        if u.arbitrary()? {
+            m.insert(
+                "x-max-length-bytes".to_owned(),
+                json!(u.int_in_range(1..=u32::MAX)?),
+            );
+        }
+        if u.arbitrary()? {
+            let age = format!(
+                "{}{}",
+                u.int_in_range(1..=365u16)?,
+                u.choose(&["s", "m", "h", "D"])?
+            );
+            m.insert("x-max-age".to_owned(), json!(age));
+        }
+        return Ok(m);
+    }
+
+    if u.arbitrary()? {
+        m.insert(
+            "x-max-length".to_owned(),
+            json!(u.int_in_range(1..=u32::MAX)?),
+        );
+    }
+    if u.arbitrary()? {
+        m.insert(
+            "x-message-ttl".to_owned(),
+            json!(u.int_in_range(0..=u32::MAX)?),
+        );
+    }
+    if u.arbitrary()? {
+        m.insert("x-expires".to_owned(), json!(u.int_in_range(1..=u32::MAX)?));
+    }
+    if u.arbitrary()? {
+        let overflow = u.choose(&["drop-head", "reject-publish"])?;
+        m.insert("x-overflow".to_owned(), json!(overflow));
+    }
+    if u.arbitrary()? {
+        m.insert(
+            "x-dead-letter-exchange".to_owned(),
+            json!(arbitrary_name(u)?),
+        );
+        if u.arbitrary()? {
+            m.insert(
+                "x-dead-letter-routing-key".to_owned(),
+                json!(arbitrary_name(u)?),
+            );
+        }
+    }
+    if *queue_type == QueueType::Classic && u.arbitrary()? {
+        m.insert(
+            "x-max-priority".to_owned(),
+            json!(u.int_in_range(1..=255u8)?),
+        );
+    }
+    Ok(m)
+}
+
+/// Generates optional exchange arguments.
+pub fn arbitrary_exchange_arguments(u: &mut Unstructured<'_>) -> Result<Map<String, Value>> {
+    let mut m = Map::new();
+    if u.arbitrary()? {
+        m.insert("alternate-exchange".to_owned(), json!(arbitrary_name(u)?));
+    }
+    Ok(m)
+}
+
+/// Generates a policy definition with a few well-known keys.
+pub fn arbitrary_policy_definition(u: &mut Unstructured<'_>) -> Result<Map<String, Value>> {
+    let mut m = Map::new();
+    m.insert(
+        "max-length".to_owned(),
+        json!(u.int_in_range(1..=u32::MAX)?),
+    );
+    if u.arbitrary()? {
+        m.insert(
+            "message-ttl".to_owned(),
+            json!(u.int_in_range(0..=u32::MAX)?),
+        );
+    }
+    if u.arbitrary()? {
+        m.insert("dead-letter-exchange".to_owned(), json!(arbitrary_name(u)?));
+    }
+    Ok(m)
+}
+
+fn optional(m: Map<String, Value>) -> Option<Map<String, Value>> {
+    if m.is_empty() {
+        None
+    } else {
+        Some(m)
+    }
+}
+
+impl<'a> Arbitrary<'a> for QueueType {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let queue_type = u.choose(&[QueueType::Classic, QueueType::Quorum, QueueType::Stream])?;
+        Ok(queue_type.clone())
+    }
+}
+
+impl<'a> Arbitrary<'a> for ExchangeType {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let exchange_type = u.choose(&[
+            ExchangeType::Fanout,
+            ExchangeType::Topic,
+            ExchangeType::Direct,
+            ExchangeType::Headers,
+        ])?;
+        Ok(exchange_type.clone())
+    }
+}
+
+impl<'a> Arbitrary<'a> for PolicyTarget {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let target = u.choose(&[
+            PolicyTarget::Queues,
+            PolicyTarget::ClassicQueues,
+            PolicyTarget::QuorumQueues,
+            PolicyTarget::Streams,
+            PolicyTarget::Exchanges,
+            PolicyTarget::All,
+        ])?;
+        Ok(target.clone())
+    }
+}
+
+impl<'a> Arbitrary<'a> for BindingDestinationType {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        if u.arbitrary()? {
+            Ok(BindingDestinationType::Queue)
+        } else {
+            Ok(BindingDestinationType::Exchange)
+        }
+    }
+}
+
+impl<'a> Arbitrary<'a> for OwnedQueueParams {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let queue_type = QueueType::arbitrary(u)?;
+        // quorum queues and streams must be durable and cannot be auto-delete
+        let replicated = queue_type != QueueType::Classic;
+        let durable = replicated || u.arbitrary()?;
+        let auto_delete = !replicated && u.arbitrary()?;
+        let args = arbitrary_queue_arguments(u, &queue_type)?;
+
+        Ok(OwnedQueueParams::new(
+            arbitrary_name(u)?,
+            queue_type,
+            durable,
+            auto_delete,
+            optional(args),
+        ))
+    }
+}
+
+impl<'a> Arbitrary<'a> for OwnedExchangeParams {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(OwnedExchangeParams::new(
+            arbitrary_name(u)?,
+            ExchangeType::arbitrary(u)?,
+            u.arbitrary()?,
+            u.arbitrary()?,
+            optional(arbitrary_exchange_arguments(u)?),
+        ))
+    }
+}
+
+impl<'a> Arbitrary<'a> for OwnedVirtualHostParams {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut params = OwnedVirtualHostParams::named(arbitrary_name(u)?);
+        if u.arbitrary()? {
+            params.description = Some(arbitrary_name(u)?);
+        }
+        if u.arbitrary()? {
+            let n = u.int_in_range(0..=3)?;
+            params.tags = Some((0..n).map(|_| arbitrary_name(u)).collect::<Result<_>>()?);
+        }
+        if u.arbitrary()? {
+            params.default_queue_type = Some(QueueType::arbitrary(u)?);
+        }
+        params.tracing = u.arbitrary()?;
+        Ok(params)
+    }
+}
+
+impl<'a> Arbitrary<'a> for OwnedPolicyParams {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(OwnedPolicyParams::new(
+            arbitrary_name(u)?,
+            arbitrary_name(u)?,
+            format!("^{}", regex::escape(&arbitrary_name(u)?)),
+            PolicyTarget::arbitrary(u)?,
+            u.int_in_range(0..=100)?,
+            arbitrary_policy_definition(u)?,
+        ))
+    }
+}
+
+impl<'a> Arbitrary<'a> for QueueDefinition {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let params = OwnedQueueParams::arbitrary(u)?;
+        Ok(QueueDefinition {
+            name: params.name,
+            vhost: arbitrary_name(u)?,
+            durable: params.durable,
+            auto_delete: params.auto_delete,
+            arguments: XArguments(params.arguments.unwrap_or_default()),
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for ExchangeInfo {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let params = OwnedExchangeParams::arbitrary(u)?;
+        Ok(ExchangeInfo {
+            name: params.name,
+            vhost: arbitrary_name(u)?,
+            exchange_type: String::from(params.exchange_type),
+            durable: params.durable,
+            auto_delete: params.auto_delete,
+            arguments: XArguments(params.arguments.unwrap_or_default()),
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for BindingInfo {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(BindingInfo {
+            vhost: arbitrary_name(u)?,
+            source: arbitrary_name(u)?,
+            destination: arbitrary_name(u)?,
+            destination_type: BindingDestinationType::arbitrary(u)?,
+            routing_key: arbitrary_name(u)?,
+            arguments: XArguments(Map::new()),
+            properties_key: None,
+        })
+    }
+}
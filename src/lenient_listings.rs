@@ -0,0 +1,87 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Lenient listing deserialization: a client configured with [`LenientListings`]
+//! (see `ClientBuilder#with_lenient_listings`) deserializes every element of a listing
+//! independently and skips the elements that fail to parse instead of failing the entire call.
+//!
+//! This keeps listings usable when, for example, a plugin adds a field of an unexpected type
+//! to a single object.
+
+use std::fmt;
+use std::sync::Arc;
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+/// A listing element that could not be deserialized and was skipped.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SkippedElement {
+    /// The position of the element in the listing
+    pub index: usize,
+    /// The element as returned by the API
+    pub element: Value,
+    /// Why the element could not be deserialized
+    pub error: String,
+}
+
+type Reporter = Arc<dyn Fn(&SkippedElement) + Send + Sync>;
+
+/// Enables lenient listing deserialization and determines how skipped elements are reported.
+#[derive(Clone)]
+pub struct LenientListings {
+    reporter: Reporter,
+}
+
+impl LenientListings {
+    /// Skips malformed elements silently.
+    pub fn skip_silently() -> Self {
+        Self::reporting_to(|_| {})
+    }
+
+    /// Passes every skipped element to the given function, e.g. to log it.
+    pub fn reporting_to<F>(f: F) -> Self
+    where
+        F: Fn(&SkippedElement) + Send + Sync + 'static,
+    {
+        Self {
+            reporter: Arc::new(f),
+        }
+    }
+
+    /// Deserializes the elements one by one, reporting and skipping those that fail to parse.
+    pub fn deserialize_elements<T>(&self, elements: Vec<Value>) -> Vec<T>
+    where
+        T: DeserializeOwned,
+    {
+        let mut result = Vec::with_capacity(elements.len());
+        for (index, element) in elements.into_iter().enumerate() {
+            match T::deserialize(&element) {
+                Ok(val) => result.push(val),
+                Err(e) => (self.reporter)(&SkippedElement {
+                    index,
+                    element,
+                    error: e.to_string(),
+                }),
+            }
+        }
+        result
+    }
+}
+
+impl fmt::Debug for LenientListings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LenientListings").finish_non_exhaustive()
+    }
+}
@@ -28,25 +28,72 @@
 //! This library is double licensed under the Apache 2.0 and MIT licenses.
 //! This means that the user can choose either of the licenses.
 
+/// Periodic alarm and quorum criticality health checks
+pub mod alarm_watcher;
+/// Conversions to and from the types of the `amqprs` AMQP 0-9-1 client
+#[cfg(feature = "amqprs")]
+pub mod amqprs_interop;
 /// The primary API: a async HTTP API client
 #[cfg(feature = "async")]
 pub mod api;
+/// Typed change audit records for mutating operations
+pub mod audit;
 /// The primary API: a blocking HTTP API client
 #[cfg(feature = "blocking")]
 pub mod blocking_api;
+/// Connection churn analysis
+pub mod churn_analyzer;
+/// Short-circuits requests after consecutive failures
+pub mod circuit_breaker;
 /// Types commonly used by API requests and responses
 pub mod commons;
+/// A typed, validated HTTP API endpoint
+#[cfg(any(feature = "async", feature = "blocking"))]
+pub mod endpoint;
 /// Formatting helpers
 pub mod formatting;
+/// `Arbitrary` implementations for property-based testing and fuzzing
+#[cfg(feature = "arbitrary")]
+pub mod fuzzing;
+/// Lenient listing deserialization that skips malformed elements
+pub mod lenient_listings;
 /// Providers password hashing utilities for user pre-seeding.
 pub mod password_hashing;
+/// Client-side payload size limits for published messages
+pub mod payload_limits;
+/// Re-exports of the clients and the most commonly used types
+pub mod prelude;
+/// A client for the Prometheus plugin endpoints
+#[cfg(feature = "prometheus")]
+pub mod prometheus;
+/// Guard rails for destructive operations
+pub mod protection;
+/// Renders listings as tables, JSON, YAML or plain text
+#[cfg(feature = "render")]
+pub mod render;
+/// Cluster summary reports
+pub mod report;
+/// Request ID injection
+pub mod request_ids;
 /// Types used to issues API requests (such as `PUT`, `POST`, `DELETE`)
 pub mod requests;
 /// API response types
 pub mod responses;
+/// Generates synthetic topologies, e.g. for benchmarking
+pub mod topology_generator;
+/// A change feed for listings, built on polling and diffing
+pub mod watch;
 
 /// Error
-#[cfg(any(feature = "async", feature = "blocking"))]
 pub mod error;
 pub mod transformers;
 mod utils;
+
+// used by exported macros, e.g. x_args!
+#[doc(hidden)]
+pub use serde_json;
+
+#[cfg(any(feature = "async", feature = "blocking"))]
+mod definition_files;
+#[cfg(any(feature = "async", feature = "blocking"))]
+mod message_files;
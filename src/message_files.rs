@@ -0,0 +1,61 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Encoding and decoding of message files (JSON Lines, one message per line),
+//! shared by the queue draining and republishing functions of both clients.
+#![allow(clippy::result_large_err)]
+
+use crate::error::{Error, HttpClientError};
+use crate::responses::GetMessage;
+
+type Result<T> = std::result::Result<T, HttpClientError>;
+
+/// The name the HTTP API uses for the default exchange.
+pub(crate) const DEFAULT_EXCHANGE: &str = "amq.default";
+
+/// Encodes a batch of messages, one JSON document per line.
+pub(crate) fn encode(messages: &[GetMessage]) -> Result<Vec<u8>> {
+    let mut out = Vec::new();
+    for msg in messages {
+        serde_json::to_writer(&mut out, msg).map_err(|e| Error::InvalidMessageFile {
+            reason: e.to_string(),
+        })?;
+        out.push(b'\n');
+    }
+    Ok(out)
+}
+
+/// Decodes the contents of a message file. Blank lines are ignored.
+pub(crate) fn decode(bytes: &[u8]) -> Result<Vec<GetMessage>> {
+    bytes
+        .split(|b| *b == b'\n')
+        .enumerate()
+        .filter(|(_, line)| !line.trim_ascii().is_empty())
+        .map(|(i, line)| {
+            serde_json::from_slice(line).map_err(|e| Error::InvalidMessageFile {
+                reason: format!("line {}: {}", i + 1, e),
+            })
+        })
+        .collect()
+}
+
+/// Messages fetched from a queue report an empty exchange name
+/// if they were published to the default exchange.
+pub(crate) fn exchange_name(exchange: &str) -> &str {
+    if exchange.is_empty() {
+        DEFAULT_EXCHANGE
+    } else {
+        exchange
+    }
+}
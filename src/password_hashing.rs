@@ -11,13 +11,75 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
+use crate::error::ConversionError;
 use rand::RngCore;
-use ring::digest::{Context, SHA256};
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+use ring::digest::{Algorithm, Context, SHA256, SHA512};
 
 const SALT_LENGTH: usize = 4;
 
+/// A password hashing algorithm supported by RabbitMQ.
+///
+/// Unless specified with [`crate::requests::UserParams::hashing_algorithm`], the hashing algorithm
+/// used for a user is the one configured on the cluster (`password_hashing_module` in `rabbitmq.conf`).
+/// SHA-256 is the default.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub enum HashingAlgorithm {
+    #[default]
+    #[serde(rename = "rabbit_password_hashing_sha256")]
+    Sha256,
+    #[serde(rename = "rabbit_password_hashing_sha512")]
+    Sha512,
+}
+
+impl HashingAlgorithm {
+    /// Produces a salted password hash using this algorithm.
+    pub fn salt_and_hash(&self, salt: &[u8], password: &str) -> Vec<u8> {
+        match self {
+            HashingAlgorithm::Sha256 => salted_password_hash_sha256(salt, password),
+            HashingAlgorithm::Sha512 => salted_password_hash_sha512(salt, password),
+        }
+    }
+
+    /// Produces a Base64-encoded salted password hash using this algorithm.
+    pub fn base64_encoded_salted_password_hash(&self, salt: &[u8], password: &str) -> String {
+        rbase64::encode(self.salt_and_hash(salt, password).as_slice())
+    }
+}
+
+impl fmt::Display for HashingAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HashingAlgorithm::Sha256 => write!(f, "rabbit_password_hashing_sha256"),
+            HashingAlgorithm::Sha512 => write!(f, "rabbit_password_hashing_sha512"),
+        }
+    }
+}
+
+impl FromStr for HashingAlgorithm {
+    type Err = ConversionError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "rabbit_password_hashing_sha256" => Ok(HashingAlgorithm::Sha256),
+            "rabbit_password_hashing_sha512" => Ok(HashingAlgorithm::Sha512),
+            _ => Err(ConversionError::UnsupportedValue {
+                type_name: "password hashing algorithm".to_owned(),
+                value: value.to_owned(),
+            }),
+        }
+    }
+}
+
 /// Generates and returns a 32-bit salt.
-/// Used in combination with [`base64_encoded_salted_password_hash_sha256`].
+/// Used in combination with [`base64_encoded_salted_password_hash_sha256`]
+/// and [`base64_encoded_salted_password_hash_sha512`].
 /// See the [Credentials and Passwords guide](https://rabbitmq.com/docs/passwords/).
 pub fn salt() -> Vec<u8> {
     // salts are 32 bit long
@@ -32,14 +94,15 @@ pub fn salt() -> Vec<u8> {
 ///
 /// See the [Credentials and Passwords guide](https://rabbitmq.com/docs/passwords/).
 pub fn salted_password_hash_sha256(salt: &[u8], password: &str) -> Vec<u8> {
-    let mut ctx = Context::new(&SHA256);
-    let vec = [salt, password.as_bytes()].concat();
-
-    ctx.update(&vec);
-    let digest = ctx.finish();
-    let digest_vec = Vec::from(digest.as_ref());
+    salted_password_hash(&SHA256, salt, password)
+}
 
-    [salt, &digest_vec[..]].concat()
+/// Produces a SHA-512 hashed, salted password hash.
+/// Prefer [`base64_encoded_salted_password_hash_sha512`].
+///
+/// See the [Credentials and Passwords guide](https://rabbitmq.com/docs/passwords/).
+pub fn salted_password_hash_sha512(salt: &[u8], password: &str) -> Vec<u8> {
+    salted_password_hash(&SHA512, salt, password)
 }
 
 ///
@@ -51,3 +114,25 @@ pub fn base64_encoded_salted_password_hash_sha256(salt: &[u8], password: &str) -
     let salted = salted_password_hash_sha256(salt, password);
     rbase64::encode(salted.as_slice())
 }
+
+///
+/// Produces a Base64-encoded, SHA-512 hashed, salted password hash that can be passed
+/// as [`crate::requests::UserParams::password_hash`] when adding a user with [`crate::blocking_api::Client::create_user`]
+/// to a cluster configured to use `rabbit_password_hashing_sha512`.
+///
+/// See the [Credentials and Passwords guide](https://rabbitmq.com/docs/passwords/).
+pub fn base64_encoded_salted_password_hash_sha512(salt: &[u8], password: &str) -> String {
+    let salted = salted_password_hash_sha512(salt, password);
+    rbase64::encode(salted.as_slice())
+}
+
+fn salted_password_hash(algorithm: &'static Algorithm, salt: &[u8], password: &str) -> Vec<u8> {
+    let mut ctx = Context::new(algorithm);
+    let vec = [salt, password.as_bytes()].concat();
+
+    ctx.update(&vec);
+    let digest = ctx.finish();
+    let digest_vec = Vec::from(digest.as_ref());
+
+    [salt, &digest_vec[..]].concat()
+}
@@ -0,0 +1,140 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Client-side payload size limits for published messages.
+//!
+//! The HTTP API responds to messages larger than the broker's `max_message_size` with
+//! a generic server error. A client configured with a [`PayloadSizeGuard`]
+//! (see `ClientBuilder#with_payload_size_guard`) rejects such messages before sending them,
+//! returning an `Error::PayloadTooLarge` instead, and can warn about payloads
+//! that are close to the limit.
+
+use std::fmt;
+use std::sync::Arc;
+
+/// The default `max_message_size` of RabbitMQ 4.x nodes, 16 MiB.
+pub const DEFAULT_BROKER_MAX_MESSAGE_SIZE: usize = 16 * 1024 * 1024;
+
+/// The share of the limit above which payloads are reported as close to the limit by default.
+pub const DEFAULT_WARNING_RATIO: f64 = 0.9;
+
+/// Reported for payloads that are within the limit but close to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PayloadSizeWarning {
+    /// The payload size in bytes (after decoding, for Base64-encoded payloads)
+    pub size: usize,
+    pub limit: usize,
+}
+
+/// The outcome of a payload size check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PayloadSizeCheck {
+    WithinLimit,
+    NearLimit(PayloadSizeWarning),
+    TooLarge { size: usize, limit: usize },
+}
+
+type WarningCallback = Arc<dyn Fn(PayloadSizeWarning) + Send + Sync>;
+
+/// A maximum payload size for published messages, plus an optional warning threshold.
+#[derive(Clone)]
+pub struct PayloadSizeGuard {
+    max_size: usize,
+    warning_ratio: f64,
+    on_warning: Option<WarningCallback>,
+}
+
+impl PayloadSizeGuard {
+    /// Rejects payloads larger than `max_size` bytes, which should not exceed
+    /// the `max_message_size` configured on the nodes.
+    pub fn new(max_size: usize) -> Self {
+        Self {
+            max_size,
+            warning_ratio: DEFAULT_WARNING_RATIO,
+            on_warning: None,
+        }
+    }
+
+    /// Payloads larger than `ratio * max_size` are reported as close to the limit.
+    pub fn with_warning_ratio(mut self, ratio: f64) -> Self {
+        self.warning_ratio = ratio.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Invokes `f` for every published payload that is close to the limit.
+    pub fn with_warning_callback<F>(mut self, f: F) -> Self
+    where
+        F: Fn(PayloadSizeWarning) + Send + Sync + 'static,
+    {
+        self.on_warning = Some(Arc::new(f));
+        self
+    }
+
+    pub fn max_size(&self) -> usize {
+        self.max_size
+    }
+
+    /// The size above which payloads are reported as close to the limit.
+    pub fn warning_threshold(&self) -> usize {
+        (self.max_size as f64 * self.warning_ratio) as usize
+    }
+
+    pub fn check(&self, size: usize) -> PayloadSizeCheck {
+        if size > self.max_size {
+            PayloadSizeCheck::TooLarge {
+                size,
+                limit: self.max_size,
+            }
+        } else if size > self.warning_threshold() {
+            PayloadSizeCheck::NearLimit(PayloadSizeWarning {
+                size,
+                limit: self.max_size,
+            })
+        } else {
+            PayloadSizeCheck::WithinLimit
+        }
+    }
+
+    /// Checks the size and invokes the warning callback, if any, for payloads
+    /// close to the limit. Returns `Err((size, limit))` for payloads that are too large.
+    #[cfg(any(feature = "async", feature = "blocking"))]
+    pub(crate) fn enforce(&self, size: usize) -> Result<(), (usize, usize)> {
+        match self.check(size) {
+            PayloadSizeCheck::WithinLimit => Ok(()),
+            PayloadSizeCheck::NearLimit(warning) => {
+                if let Some(f) = &self.on_warning {
+                    f(warning);
+                }
+                Ok(())
+            }
+            PayloadSizeCheck::TooLarge { size, limit } => Err((size, limit)),
+        }
+    }
+}
+
+impl Default for PayloadSizeGuard {
+    fn default() -> Self {
+        Self::new(DEFAULT_BROKER_MAX_MESSAGE_SIZE)
+    }
+}
+
+impl fmt::Debug for PayloadSizeGuard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PayloadSizeGuard")
+            .field("max_size", &self.max_size)
+            .field("warning_ratio", &self.warning_ratio)
+            .field("on_warning", &self.on_warning.is_some())
+            .finish()
+    }
+}
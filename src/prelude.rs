@@ -0,0 +1,55 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Re-exports the clients and the most commonly used types, so that a typical program
+//! can start with a single import:
+//!
+//! ```rust
+//! use rabbitmq_http_client::prelude::*;
+//! ```
+//!
+//! Since both clients are named `Client` in their respective modules, they are re-exported
+//! as `AsyncClient` and `BlockingClient`.
+//!
+//! A few type names, e.g. `Permissions`, are used by both request and response types. These
+//! are not re-exported individually, use `requests::Permissions` or `responses::Permissions`.
+
+#[cfg(feature = "async")]
+pub use crate::api::{Client as AsyncClient, ClientBuilder as AsyncClientBuilder};
+#[cfg(feature = "blocking")]
+pub use crate::blocking_api::{Client as BlockingClient, ClientBuilder as BlockingClientBuilder};
+pub use crate::error::ConversionError;
+#[cfg(any(feature = "async", feature = "blocking"))]
+pub use crate::error::HttpClientError;
+
+pub use crate::{commons, requests, responses};
+
+pub use crate::commons::{
+    BindingDestinationType, ConnectionProtocol, ExchangeType, MessageTransferAcknowledgementMode,
+    PayloadEncoding, PolicyTarget, QueueType, RatesMode, SupportedProtocol, UserLimitTarget,
+    VirtualHostLimitTarget,
+};
+
+pub use crate::requests::{
+    x_arguments, EnforcedLimitParams, ExchangeParams, PolicyParams, QueueParams, StreamParams,
+    UserParams, UserParamsWithPassword, VirtualHostChanges, VirtualHostParams,
+};
+pub use crate::x_args;
+
+pub use crate::responses::{
+    BindingInfo, BindingInfoFilters, Channel, ClusterDefinitionSet, ClusterNode, Connection,
+    Consumer, ExchangeInfo, NamedPolicyTargetObject, Overview, Policy, QueueInfo, QueueInfoFilters,
+    QueueOps, QueueSummary, TopChannels, TopConnections, TopQueues, User, VirtualHost,
+    VirtualHostDefinitionSet,
+};
@@ -0,0 +1,552 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A small client for the endpoints served by the `rabbitmq_prometheus` plugin
+//! (by default, on port 15692).
+//!
+//! The plugin exposes a number of per-object metrics that the HTTP API does not,
+//! for example, per-queue metrics when the management plugin's metric collection
+//! is disabled. Scraped metrics are parsed from the Prometheus text exposition format
+//! into [`Metrics`], optionally keeping only selected metric families.
+//!
+//! The clients require the `async` or `blocking` feature: with `prometheus` alone,
+//! only the parser is available.
+#![allow(clippy::result_large_err)]
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::error::ConversionError;
+
+/// The default port of the Prometheus plugin endpoint.
+pub const DEFAULT_PROMETHEUS_PORT: u16 = 15692;
+
+/// The endpoints served by the Prometheus plugin.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum MetricsEndpoint {
+    /// `GET /metrics`: metrics aggregated across all objects
+    #[default]
+    Aggregated,
+    /// `GET /metrics/per-object`: metrics of every individual object (queue, connection, etc).
+    /// Can be very large on clusters with many objects
+    PerObject,
+    /// `GET /metrics/detailed`: selected metric groups (e.g. `queue_coarse_metrics`),
+    /// optionally limited to specific virtual hosts
+    Detailed {
+        families: Vec<String>,
+        vhosts: Vec<String>,
+    },
+}
+
+impl MetricsEndpoint {
+    pub fn path(&self) -> &'static str {
+        match self {
+            MetricsEndpoint::Aggregated => "metrics",
+            MetricsEndpoint::PerObject => "metrics/per-object",
+            MetricsEndpoint::Detailed { .. } => "metrics/detailed",
+        }
+    }
+
+    pub fn query(&self) -> Vec<(&'static str, &str)> {
+        match self {
+            MetricsEndpoint::Detailed { families, vhosts } => families
+                .iter()
+                .map(|f| ("family", f.as_str()))
+                .chain(vhosts.iter().map(|v| ("vhost", v.as_str())))
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MetricType {
+    Counter,
+    Gauge,
+    Histogram,
+    Summary,
+    #[default]
+    Untyped,
+}
+
+impl From<&str> for MetricType {
+    fn from(value: &str) -> Self {
+        match value {
+            "counter" => MetricType::Counter,
+            "gauge" => MetricType::Gauge,
+            "histogram" => MetricType::Histogram,
+            "summary" => MetricType::Summary,
+            _ => MetricType::Untyped,
+        }
+    }
+}
+
+impl fmt::Display for MetricType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MetricType::Counter => write!(f, "counter"),
+            MetricType::Gauge => write!(f, "gauge"),
+            MetricType::Histogram => write!(f, "histogram"),
+            MetricType::Summary => write!(f, "summary"),
+            MetricType::Untyped => write!(f, "untyped"),
+        }
+    }
+}
+
+/// A single sample, e.g. `rabbitmq_queue_messages{vhost="/",queue="qq.1"} 10`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sample {
+    /// The sample name. Differs from the family name for histograms and summaries,
+    /// e.g. `rabbitmq_raft_entry_commit_latency_seconds_count`
+    pub name: String,
+    pub labels: BTreeMap<String, String>,
+    pub value: f64,
+}
+
+impl Sample {
+    pub fn label(&self, name: &str) -> Option<&str> {
+        self.labels.get(name).map(String::as_str)
+    }
+
+    /// Returns true if the sample has all the given label values.
+    pub fn has_labels(&self, labels: &[(&str, &str)]) -> bool {
+        labels.iter().all(|(k, v)| self.label(k) == Some(*v))
+    }
+}
+
+/// A metric family: all the samples of a metric, with its type and description.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MetricFamily {
+    pub name: String,
+    pub help: Option<String>,
+    pub metric_type: MetricType,
+    pub samples: Vec<Sample>,
+}
+
+impl MetricFamily {
+    fn named(name: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            ..Self::default()
+        }
+    }
+
+    /// The sum of all samples, e.g. a cluster-wide total of a per-node metric.
+    pub fn sum(&self) -> f64 {
+        self.samples.iter().map(|s| s.value).sum()
+    }
+
+    /// The value of the first sample with the given label values.
+    pub fn value(&self, labels: &[(&str, &str)]) -> Option<f64> {
+        self.samples
+            .iter()
+            .find(|s| s.has_labels(labels))
+            .map(|s| s.value)
+    }
+
+    /// Sample values keyed by the value of the given label. Samples without
+    /// the label are skipped.
+    pub fn values_by_label(&self, label: &str) -> BTreeMap<String, f64> {
+        self.samples
+            .iter()
+            .filter_map(|s| s.label(label).map(|l| (l.to_owned(), s.value)))
+            .collect()
+    }
+}
+
+/// A per-queue metric value, see [`Metrics::queue_values`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueueMetricValue {
+    pub vhost: String,
+    pub queue: String,
+    pub value: f64,
+}
+
+/// Metric families parsed from the Prometheus text exposition format.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Metrics {
+    pub families: BTreeMap<String, MetricFamily>,
+}
+
+impl Metrics {
+    /// Parses all metric families.
+    pub fn parse(text: &str) -> Result<Self, ConversionError> {
+        parse_families(text, |_| true)
+    }
+
+    /// Parses only the metric families with the given names, skipping the rest.
+    pub fn parse_selected(text: &str, names: &[&str]) -> Result<Self, ConversionError> {
+        parse_families(text, |name| names.contains(&name))
+    }
+
+    pub fn family(&self, name: &str) -> Option<&MetricFamily> {
+        self.families.get(name)
+    }
+
+    /// The value of the first sample of a family with the given label values.
+    pub fn value(&self, family: &str, labels: &[(&str, &str)]) -> Option<f64> {
+        self.family(family).and_then(|f| f.value(labels))
+    }
+
+    /// Values of a per-queue metric family (one that uses the `vhost` and `queue` labels),
+    /// e.g. `rabbitmq_detailed_queue_messages`.
+    pub fn queue_values(&self, family: &str) -> Vec<QueueMetricValue> {
+        let Some(family) = self.family(family) else {
+            return Vec::new();
+        };
+        family
+            .samples
+            .iter()
+            .filter_map(|s| {
+                Some(QueueMetricValue {
+                    vhost: s.label("vhost")?.to_owned(),
+                    queue: s.label("queue")?.to_owned(),
+                    value: s.value,
+                })
+            })
+            .collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.families.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.families.len()
+    }
+}
+
+fn parse_families<F>(text: &str, include: F) -> Result<Metrics, ConversionError>
+where
+    F: Fn(&str) -> bool,
+{
+    let mut families: BTreeMap<String, MetricFamily> = BTreeMap::new();
+    // the family most recently described by a HELP or TYPE line
+    let mut current: Option<String> = None;
+
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let invalid = |reason: &str| ConversionError::InvalidMetrics {
+            line: i + 1,
+            reason: reason.to_owned(),
+        };
+
+        if let Some(comment) = line.strip_prefix('#') {
+            let mut parts = comment.trim_start().splitn(3, ' ');
+            let (keyword, name, rest) = (parts.next(), parts.next(), parts.next());
+            let (Some(keyword @ ("HELP" | "TYPE")), Some(name)) = (keyword, name) else {
+                // other comments are ignored
+                continue;
+            };
+            current = Some(name.to_owned());
+            if !include(name) {
+                continue;
+            }
+            let family = families
+                .entry(name.to_owned())
+                .or_insert_with(|| MetricFamily::named(name));
+            let rest = rest.unwrap_or_default();
+            if keyword == "HELP" {
+                family.help = Some(unescape(rest, false));
+            } else {
+                family.metric_type = MetricType::from(rest.trim());
+            }
+            continue;
+        }
+
+        let sample = parse_sample(line).map_err(invalid)?;
+        let family_name = match &current {
+            Some(name) if belongs_to(&sample.name, name) => name.clone(),
+            _ => sample.name.clone(),
+        };
+        if include(&family_name) {
+            families
+                .entry(family_name.clone())
+                .or_insert_with(|| MetricFamily::named(&family_name))
+                .samples
+                .push(sample);
+        }
+    }
+
+    Ok(Metrics { families })
+}
+
+fn belongs_to(sample_name: &str, family_name: &str) -> bool {
+    match sample_name.strip_prefix(family_name) {
+        Some("") => true,
+        Some(suffix) => matches!(
+            suffix,
+            "_bucket" | "_sum" | "_count" | "_total" | "_created"
+        ),
+        None => false,
+    }
+}
+
+fn parse_sample(line: &str) -> Result<Sample, &'static str> {
+    let name_end = line
+        .find(|c: char| c == '{' || c.is_whitespace())
+        .ok_or("missing sample value")?;
+    let name = &line[..name_end];
+    if name.is_empty() {
+        return Err("missing metric name");
+    }
+
+    let mut labels = BTreeMap::new();
+    let mut rest = &line[name_end..];
+    if let Some(after_brace) = rest.strip_prefix('{') {
+        rest = after_brace;
+        loop {
+            rest = rest.trim_start_matches([' ', ',']);
+            if let Some(after) = rest.strip_prefix('}') {
+                rest = after;
+                break;
+            }
+            let eq = rest.find('=').ok_or("malformed label")?;
+            let key = rest[..eq].trim();
+            rest = rest[eq + 1..]
+                .trim_start()
+                .strip_prefix('"')
+                .ok_or("label values must be quoted")?;
+            let end = closing_quote(rest).ok_or("unterminated label value")?;
+            labels.insert(key.to_owned(), unescape(&rest[..end], true));
+            rest = &rest[end + 1..];
+        }
+    }
+
+    // an optional timestamp can follow the value
+    let value = rest
+        .split_whitespace()
+        .next()
+        .ok_or("missing sample value")?;
+    let value = value.parse::<f64>().map_err(|_| "invalid sample value")?;
+
+    Ok(Sample {
+        name: name.to_owned(),
+        labels,
+        value,
+    })
+}
+
+fn closing_quote(s: &str) -> Option<usize> {
+    let mut escaped = false;
+    for (i, c) in s.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '"' => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+fn unescape(s: &str, quotes: bool) -> String {
+    let mut result = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => result.push('\n'),
+            Some('\\') => result.push('\\'),
+            Some('"') if quotes => result.push('"'),
+            Some(other) => {
+                result.push('\\');
+                result.push(other);
+            }
+            None => result.push('\\'),
+        }
+    }
+    result
+}
+
+#[cfg(feature = "blocking")]
+pub use blocking_client::BlockingPrometheusClient;
+
+#[cfg(feature = "blocking")]
+mod blocking_client {
+    use std::fmt;
+
+    use reqwest::Client as HttpClient;
+    use tokio::runtime::Handle;
+
+    use super::{Metrics, MetricsEndpoint, PrometheusClient};
+    use crate::blocking_api::ClientRuntime;
+    use crate::error::HttpClientError;
+
+    /// A blocking client for the Prometheus plugin endpoints.
+    ///
+    /// Like [`crate::blocking_api::Client`], it runs [`PrometheusClient`] on a Tokio runtime:
+    /// a small owned one by default, or the one set with
+    /// [`BlockingPrometheusClient::with_runtime_handle`]. It can be used from within
+    /// a multi-threaded runtime, on a single-threaded one, functions return
+    /// [`crate::error::Error::BlockingNotSupported`] instead of panicking.
+    ///
+    /// Example
+    /// ```rust
+    /// use rabbitmq_http_client::prometheus::{BlockingPrometheusClient, MetricsEndpoint};
+    ///
+    /// let pc = BlockingPrometheusClient::new("http://localhost:15692");
+    /// let metrics = pc.scrape_selected(&MetricsEndpoint::PerObject, &["rabbitmq_queue_messages"]);
+    /// ```
+    pub struct BlockingPrometheusClient {
+        client: PrometheusClient,
+        runtime: ClientRuntime,
+    }
+
+    impl fmt::Debug for BlockingPrometheusClient {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("BlockingPrometheusClient")
+                .field("client", &self.client)
+                .finish_non_exhaustive()
+        }
+    }
+
+    impl BlockingPrometheusClient {
+        /// Instantiates a client for the given endpoint, e.g. `http://localhost:15692`.
+        pub fn new(endpoint: &str) -> Self {
+            Self::from_http_client(HttpClient::new(), endpoint)
+        }
+
+        pub fn from_http_client(client: HttpClient, endpoint: &str) -> Self {
+            Self {
+                client: PrometheusClient::from_http_client(client, endpoint),
+                runtime: ClientRuntime::owned(),
+            }
+        }
+
+        /// Makes the client run requests on the given multi-threaded runtime
+        /// instead of a runtime it owns.
+        pub fn with_runtime_handle(self, handle: Handle) -> Self {
+            Self {
+                runtime: ClientRuntime::Borrowed(handle),
+                ..self
+            }
+        }
+
+        /// Scrapes the given endpoint and parses all metric families.
+        pub fn scrape(&self, endpoint: &MetricsEndpoint) -> Result<Metrics, HttpClientError> {
+            self.runtime.block_on(self.client.scrape(endpoint))
+        }
+
+        /// Scrapes the given endpoint and parses only the metric families with the given names.
+        pub fn scrape_selected(
+            &self,
+            endpoint: &MetricsEndpoint,
+            names: &[&str],
+        ) -> Result<Metrics, HttpClientError> {
+            self.runtime
+                .block_on(self.client.scrape_selected(endpoint, names))
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+pub use async_client::PrometheusClient;
+
+#[cfg(feature = "async")]
+mod async_client {
+    use backtrace::Backtrace;
+    use reqwest::{Client as HttpClient, StatusCode};
+
+    use super::{Metrics, MetricsEndpoint};
+    use crate::error::{Error, HttpClientError};
+    use crate::utils::redact_url;
+
+    /// An async client for the Prometheus plugin endpoints.
+    ///
+    /// Unsuccessful responses are reported the same way [`crate::api::Client`] reports them:
+    /// a 404 as [`Error::NotFound`], other 4xx and 5xx responses as
+    /// [`Error::ClientErrorResponse`] and [`Error::ServerErrorResponse`] that include the body.
+    #[derive(Debug)]
+    pub struct PrometheusClient {
+        endpoint: String,
+        client: HttpClient,
+    }
+
+    impl PrometheusClient {
+        /// Instantiates a client for the given endpoint, e.g. `http://localhost:15692`.
+        pub fn new(endpoint: &str) -> Self {
+            Self::from_http_client(HttpClient::new(), endpoint)
+        }
+
+        pub fn from_http_client(client: HttpClient, endpoint: &str) -> Self {
+            Self {
+                endpoint: endpoint.trim_end_matches('/').to_owned(),
+                client,
+            }
+        }
+
+        /// Scrapes the given endpoint and parses all metric families.
+        pub async fn scrape(&self, endpoint: &MetricsEndpoint) -> Result<Metrics, HttpClientError> {
+            let text = self.fetch(endpoint).await?;
+            Ok(Metrics::parse(&text)?)
+        }
+
+        /// Scrapes the given endpoint and parses only the metric families with the given names.
+        pub async fn scrape_selected(
+            &self,
+            endpoint: &MetricsEndpoint,
+            names: &[&str],
+        ) -> Result<Metrics, HttpClientError> {
+            let text = self.fetch(endpoint).await?;
+            Ok(Metrics::parse_selected(&text, names)?)
+        }
+
+        async fn fetch(&self, endpoint: &MetricsEndpoint) -> Result<String, HttpClientError> {
+            let response = self
+                .client
+                .get(format!("{}/{}", self.endpoint, endpoint.path()))
+                .query(&endpoint.query())
+                .send()
+                .await?;
+
+            let status = response.status();
+            if status == StatusCode::NOT_FOUND {
+                return Err(Error::NotFound);
+            }
+            if status.is_client_error() || status.is_server_error() {
+                let url = redact_url(response.url().clone());
+                let headers = response.headers().clone();
+                let body = response.text().await?;
+                return Err(if status.is_client_error() {
+                    Error::ClientErrorResponse {
+                        url: Some(url),
+                        body: Some(body),
+                        headers: Some(headers),
+                        status_code: status,
+                        request_id: None,
+                        backtrace: Backtrace::new(),
+                    }
+                } else {
+                    Error::ServerErrorResponse {
+                        url: Some(url),
+                        body: Some(body),
+                        headers: Some(headers),
+                        status_code: status,
+                        request_id: None,
+                        backtrace: Backtrace::new(),
+                    }
+                });
+            }
+
+            Ok(response.text().await?)
+        }
+    }
+}
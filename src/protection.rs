@@ -0,0 +1,98 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Guard rails for destructive operations.
+//!
+//! A client configured with [`ProtectedEntities`] (see `ClientBuilder#with_protected_entities`)
+//! refuses to delete, purge or close the matching entities, returning an
+//! `Error::ProtectedEntity` instead of issuing the request.
+
+/// Virtual hosts and object names that destructive operations must not target.
+///
+/// Patterns are matched against the entire value and can use `*` as a wildcard
+/// that matches any sequence of characters, e.g. `prod-*`.
+///
+/// * Virtual host patterns protect the virtual hosts themselves and all queues, streams and
+///   exchanges in them
+/// * Name patterns protect queues, streams, exchanges and users with matching names, as well as
+///   connections of matching users
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProtectedEntities {
+    virtual_hosts: Vec<String>,
+    names: Vec<String>,
+}
+
+impl ProtectedEntities {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Protects virtual hosts that match the given pattern.
+    pub fn with_virtual_host(mut self, pattern: &str) -> Self {
+        self.virtual_hosts.push(pattern.to_owned());
+        self
+    }
+
+    /// Protects queues, streams, exchanges and users with names that match the given pattern.
+    pub fn with_name(mut self, pattern: &str) -> Self {
+        self.names.push(pattern.to_owned());
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.virtual_hosts.is_empty() && self.names.is_empty()
+    }
+
+    pub fn is_virtual_host_protected(&self, vhost: &str) -> bool {
+        self.virtual_hosts
+            .iter()
+            .any(|pattern| matches_pattern(pattern, vhost))
+    }
+
+    pub fn is_name_protected(&self, name: &str) -> bool {
+        self.names
+            .iter()
+            .any(|pattern| matches_pattern(pattern, name))
+    }
+
+    /// Returns true if an object (e.g. a queue) with the given name in the given
+    /// virtual host is protected.
+    pub fn is_object_protected(&self, vhost: &str, name: &str) -> bool {
+        self.is_virtual_host_protected(vhost) || self.is_name_protected(name)
+    }
+}
+
+/// Matches a value against a pattern where `*` matches any sequence of characters,
+/// including an empty one. All other characters match literally.
+pub fn matches_pattern(pattern: &str, value: &str) -> bool {
+    let mut parts = pattern.split('*');
+    // split always yields at least one part
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = value.strip_prefix(first) else {
+        return false;
+    };
+
+    let middle_and_last: Vec<&str> = parts.collect();
+    let Some((last, middle)) = middle_and_last.split_last() else {
+        // no wildcards
+        return rest.is_empty();
+    };
+    for part in middle {
+        match rest.find(part) {
+            Some(i) => rest = &rest[i + part.len()..],
+            None => return false,
+        }
+    }
+    rest.ends_with(last)
+}
@@ -0,0 +1,126 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Renders listings, e.g. the result of `Client#list_queues`, in one of several
+//! output formats, so that command line tools built on this library can offer
+//! a `--format` switch without type-specific formatting code:
+//!
+//! ```rust,ignore
+//! use rabbitmq_http_client::render::{OutputFormat, Render};
+//!
+//! let format: OutputFormat = "json".parse()?;
+//! println!("{}", client.list_queues()?.render(format)?);
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::Serialize;
+use tabled::{Table, Tabled};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum RenderError {
+    #[error(
+        "'{0}' is not a supported output format, supported formats are: table, json, yaml, plain"
+    )]
+    UnsupportedFormat(String),
+    #[error("failed to serialize the value: {0}")]
+    Serialization(#[from] serde_json::Error),
+    #[error("failed to serialize the value as YAML: {0}")]
+    YamlSerialization(#[from] serde_yaml_ng::Error),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    /// A table with a header row
+    #[default]
+    Table,
+    /// A pretty-printed JSON array
+    Json,
+    /// A YAML sequence
+    Yaml,
+    /// One tab-separated line per item without a header, convenient for scripting.
+    /// Backslashes, tabs and line breaks within values are escaped as `\\`, `\t`, `\n` and `\r`
+    Plain,
+}
+
+impl fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OutputFormat::Table => write!(f, "table"),
+            OutputFormat::Json => write!(f, "json"),
+            OutputFormat::Yaml => write!(f, "yaml"),
+            OutputFormat::Plain => write!(f, "plain"),
+        }
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = RenderError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "table" => Ok(OutputFormat::Table),
+            "json" => Ok(OutputFormat::Json),
+            "yaml" | "yml" => Ok(OutputFormat::Yaml),
+            "plain" | "tsv" => Ok(OutputFormat::Plain),
+            _ => Err(RenderError::UnsupportedFormat(s.to_owned())),
+        }
+    }
+}
+
+/// Implemented by listing results, that is, slices and vectors of response types.
+pub trait Render {
+    fn render(&self, format: OutputFormat) -> Result<String, RenderError>;
+}
+
+impl<T: Tabled + Serialize> Render for [T] {
+    fn render(&self, format: OutputFormat) -> Result<String, RenderError> {
+        match format {
+            OutputFormat::Table => Ok(Table::new(self).to_string()),
+            OutputFormat::Json => Ok(serde_json::to_string_pretty(self)?),
+            OutputFormat::Yaml => Ok(serde_yaml_ng::to_string(self)?),
+            OutputFormat::Plain => {
+                let lines: Vec<String> = self
+                    .iter()
+                    .map(|item| {
+                        let fields: Vec<String> = item
+                            .fields()
+                            .iter()
+                            .map(|f| escape_plain_field(f))
+                            .collect();
+                        fields.join("\t")
+                    })
+                    .collect();
+                Ok(lines.join("\n"))
+            }
+        }
+    }
+}
+
+/// Escapes the characters that would break the line and column structure of plain output.
+fn escape_plain_field(field: &str) -> String {
+    let mut out = String::with_capacity(field.len());
+    for c in field.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '\t' => out.push_str("\\t"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            other => out.push(other),
+        }
+    }
+    out
+}
@@ -0,0 +1,356 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A cluster summary report for support tickets and health reviews, see `Client#cluster_report`.
+//!
+//! The report is assembled from several endpoints (overview, nodes, virtual hosts, users,
+//! policies and queues) that are queried concurrently.
+//!
+//! This module also provides a per-queue consumer utilisation report,
+//! see `Client#consumer_utilisation_report`.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+use serde::Serialize;
+#[cfg(feature = "tabled")]
+use tabled::Tabled;
+
+use crate::responses::{ClusterNode, Consumer, Overview, QueueInfo, QueueSummary};
+
+/// The number of queues [`ClusterReport::top_queues`] includes by default.
+pub const DEFAULT_TOP_QUEUE_COUNT: usize = 10;
+
+/// The consumer utilisation below which the consumers of a queue with messages ready
+/// for delivery are considered saturated by default.
+pub const DEFAULT_CONSUMER_SATURATION_THRESHOLD: f64 = 0.9;
+
+/// The state of a cluster node and its resource alarms.
+#[derive(Debug, Serialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "tabled", derive(Tabled))]
+pub struct NodeStatus {
+    pub name: String,
+    pub running: bool,
+    pub being_drained: bool,
+    pub memory_alarm: bool,
+    pub free_disk_space_alarm: bool,
+}
+
+impl NodeStatus {
+    pub fn has_alarms(&self) -> bool {
+        self.memory_alarm || self.free_disk_space_alarm
+    }
+}
+
+impl From<&ClusterNode> for NodeStatus {
+    fn from(node: &ClusterNode) -> Self {
+        Self {
+            name: node.name.clone(),
+            running: node.running,
+            being_drained: node.being_drained,
+            memory_alarm: node.has_memory_alarm_in_effect,
+            free_disk_space_alarm: node.has_free_disk_space_alarm_in_effect,
+        }
+    }
+}
+
+/// The number of entities of every type in the cluster.
+#[derive(Debug, Serialize, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "tabled", derive(Tabled))]
+pub struct EntityCounts {
+    pub virtual_hosts: u64,
+    pub users: u64,
+    pub policies: u64,
+    pub connections: u64,
+    pub channels: u64,
+    pub consumers: u64,
+    pub queues: u64,
+    pub exchanges: u64,
+}
+
+/// Listener ports by node and protocol.
+#[derive(Debug, Serialize, Clone, Default, PartialEq, Eq)]
+pub struct ListenerMatrix(pub BTreeMap<String, BTreeMap<String, Vec<u32>>>);
+
+impl ListenerMatrix {
+    /// The ports a node listens on for the given protocol, e.g. `amqp` or `http`.
+    pub fn ports(&self, node: &str, protocol: &str) -> &[u32] {
+        self.0
+            .get(node)
+            .and_then(|protocols| protocols.get(protocol))
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// All protocols at least one node listens on.
+    pub fn protocols(&self) -> Vec<&str> {
+        let mut result: Vec<&str> = self
+            .0
+            .values()
+            .flat_map(|protocols| protocols.keys().map(String::as_str))
+            .collect();
+        result.sort_unstable();
+        result.dedup();
+        result
+    }
+}
+
+impl fmt::Display for ListenerMatrix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (node, protocols) in &self.0 {
+            let listeners: Vec<String> = protocols
+                .iter()
+                .map(|(protocol, ports)| {
+                    let ports: Vec<String> = ports.iter().map(u32::to_string).collect();
+                    format!("{}: {}", protocol, ports.join(", "))
+                })
+                .collect();
+            writeln!(f, "{}: {}", node, listeners.join("; "))?;
+        }
+        Ok(())
+    }
+}
+
+/// A cluster summary: versions, nodes and their alarms, listeners,
+/// entity counts and the queues with the most messages.
+#[derive(Debug, Serialize, Clone)]
+pub struct ClusterReport {
+    pub cluster_name: String,
+    pub rabbitmq_version: String,
+    pub erlang_version: String,
+    pub nodes: Vec<NodeStatus>,
+    pub listeners: ListenerMatrix,
+    pub entity_counts: EntityCounts,
+    /// Queues with the most messages, in descending order of message count
+    pub top_queues: Vec<QueueSummary>,
+}
+
+impl ClusterReport {
+    /// Assembles a report from the responses of the individual endpoints.
+    /// `queues` does not have to be sorted.
+    pub fn new(
+        overview: &Overview,
+        nodes: &[ClusterNode],
+        entity_counts: EntityCounts,
+        mut queues: Vec<QueueSummary>,
+        top_queue_count: usize,
+    ) -> Self {
+        let mut listeners = ListenerMatrix::default();
+        for l in &overview.listeners {
+            let ports = listeners
+                .0
+                .entry(l.node.clone())
+                .or_default()
+                .entry(l.protocol.clone())
+                .or_default();
+            if !ports.contains(&l.port) {
+                ports.push(l.port);
+            }
+        }
+
+        queues.sort_by_key(|q| std::cmp::Reverse(q.message_count));
+        queues.truncate(top_queue_count);
+
+        Self {
+            cluster_name: overview.cluster_name.clone(),
+            rabbitmq_version: overview.rabbitmq_version.clone(),
+            erlang_version: overview.erlang_version.clone(),
+            nodes: nodes.iter().map(NodeStatus::from).collect(),
+            listeners,
+            entity_counts,
+            top_queues: queues,
+        }
+    }
+
+    /// Returns the nodes that have at least one resource alarm in effect.
+    pub fn nodes_with_alarms(&self) -> Vec<&NodeStatus> {
+        self.nodes.iter().filter(|n| n.has_alarms()).collect()
+    }
+}
+
+impl fmt::Display for ClusterReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Cluster: {}", self.cluster_name)?;
+        writeln!(f, "RabbitMQ version: {}", self.rabbitmq_version)?;
+        writeln!(f, "Erlang version: {}", self.erlang_version)?;
+
+        writeln!(f, "\nNodes:")?;
+        for n in &self.nodes {
+            let mut notes = Vec::new();
+            if !n.running {
+                notes.push("not running");
+            }
+            if n.being_drained {
+                notes.push("being drained");
+            }
+            if n.memory_alarm {
+                notes.push("memory alarm");
+            }
+            if n.free_disk_space_alarm {
+                notes.push("free disk space alarm");
+            }
+            if notes.is_empty() {
+                notes.push("ok");
+            }
+            writeln!(f, "  {}: {}", n.name, notes.join(", "))?;
+        }
+
+        writeln!(f, "\nListeners:")?;
+        for line in self.listeners.to_string().lines() {
+            writeln!(f, "  {}", line)?;
+        }
+
+        let c = &self.entity_counts;
+        writeln!(f, "\nEntity counts:")?;
+        writeln!(f, "  virtual hosts: {}", c.virtual_hosts)?;
+        writeln!(f, "  users: {}", c.users)?;
+        writeln!(f, "  policies: {}", c.policies)?;
+        writeln!(f, "  connections: {}", c.connections)?;
+        writeln!(f, "  channels: {}", c.channels)?;
+        writeln!(f, "  consumers: {}", c.consumers)?;
+        writeln!(f, "  queues: {}", c.queues)?;
+        writeln!(f, "  exchanges: {}", c.exchanges)?;
+
+        writeln!(f, "\nTop queues by message count:")?;
+        for q in &self.top_queues {
+            writeln!(
+                f,
+                "  {} in virtual host {}: {} messages",
+                q.name, q.vhost, q.message_count
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Consumers of a single queue and how well they keep up with deliveries.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+#[cfg_attr(feature = "tabled", derive(Tabled))]
+pub struct QueueConsumerUtilisation {
+    pub vhost: String,
+    pub name: String,
+    pub consumer_count: u32,
+    /// Consumers that acknowledge deliveries manually
+    pub manual_ack_consumer_count: u32,
+    /// The sum of consumer prefetch values. Consumers with an unlimited prefetch
+    /// (a value of 0) are not included
+    pub total_prefetch: u64,
+    /// Consumers with an unlimited prefetch
+    pub unlimited_prefetch_consumer_count: u32,
+    /// The share of time the queue was able to deliver messages to consumers immediately,
+    /// from 0.0 to 1.0
+    pub utilisation: f64,
+    pub messages_ready: u64,
+    /// Messages delivered but not yet acknowledged by consumers
+    pub ack_backlog: u64,
+    pub saturated: bool,
+}
+
+impl QueueConsumerUtilisation {
+    /// Combines a queue with the consumers listed for it.
+    ///
+    /// Consumers are saturated when there are messages ready for delivery but the queue
+    /// cannot deliver them because the utilisation is below `saturation_threshold`, or when
+    /// the ack backlog has reached the combined prefetch of all (prefetch-limited) consumers.
+    pub fn new(queue: &QueueInfo, consumers: &[&Consumer], saturation_threshold: f64) -> Self {
+        let consumer_count = if consumers.is_empty() {
+            u32::from(queue.consumer_count)
+        } else {
+            consumers.len() as u32
+        };
+        let manual_ack_consumer_count = consumers.iter().filter(|c| c.manual_ack).count() as u32;
+        let unlimited_prefetch_consumer_count =
+            consumers.iter().filter(|c| c.prefetch_count == 0).count() as u32;
+        let total_prefetch = consumers
+            .iter()
+            .map(|c| u64::from(c.prefetch_count))
+            .sum::<u64>();
+
+        let messages_ready = queue
+            .message_count
+            .saturating_sub(queue.unacknowledged_message_count);
+        let ack_backlog = queue.unacknowledged_message_count;
+        let utilisation = f64::from(queue.consumer_utilisation);
+
+        let delivery_bound = messages_ready > 0 && utilisation < saturation_threshold;
+        let prefetch_bound = unlimited_prefetch_consumer_count == 0
+            && total_prefetch > 0
+            && ack_backlog >= total_prefetch;
+
+        Self {
+            vhost: queue.vhost.clone(),
+            name: queue.name.clone(),
+            consumer_count,
+            manual_ack_consumer_count,
+            total_prefetch,
+            unlimited_prefetch_consumer_count,
+            utilisation,
+            messages_ready,
+            ack_backlog,
+            saturated: consumer_count > 0 && (delivery_bound || prefetch_bound),
+        }
+    }
+}
+
+/// A per-queue consumer utilisation report, see `Client#consumer_utilisation_report`.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct ConsumerUtilisationReport {
+    /// Queues sorted by virtual host and name
+    pub queues: Vec<QueueConsumerUtilisation>,
+    pub saturation_threshold: f64,
+}
+
+impl ConsumerUtilisationReport {
+    /// Joins queues with the consumers listed for them.
+    pub fn new(queues: &[QueueInfo], consumers: &[Consumer], saturation_threshold: f64) -> Self {
+        let mut consumers_by_queue: HashMap<(&str, &str), Vec<&Consumer>> = HashMap::new();
+        for c in consumers {
+            consumers_by_queue
+                .entry((c.queue.vhost.as_str(), c.queue.name.as_str()))
+                .or_default()
+                .push(c);
+        }
+
+        let mut result: Vec<QueueConsumerUtilisation> = queues
+            .iter()
+            .map(|q| {
+                let consumers = consumers_by_queue
+                    .get(&(q.vhost.as_str(), q.name.as_str()))
+                    .map(Vec::as_slice)
+                    .unwrap_or_default();
+                QueueConsumerUtilisation::new(q, consumers, saturation_threshold)
+            })
+            .collect();
+        result.sort_by(|a, b| (&a.vhost, &a.name).cmp(&(&b.vhost, &b.name)));
+
+        Self {
+            queues: result,
+            saturation_threshold,
+        }
+    }
+
+    /// Queues whose consumers cannot keep up.
+    pub fn saturated(&self) -> Vec<&QueueConsumerUtilisation> {
+        self.queues.iter().filter(|q| q.saturated).collect()
+    }
+
+    /// Queues that have messages ready for delivery but no consumers.
+    pub fn without_consumers(&self) -> Vec<&QueueConsumerUtilisation> {
+        self.queues
+            .iter()
+            .filter(|q| q.consumer_count == 0 && q.messages_ready > 0)
+            .collect()
+    }
+}
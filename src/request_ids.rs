@@ -0,0 +1,97 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Request ID injection: a client configured with [`RequestIds`]
+//! (see `ClientBuilder#with_request_ids`) sends an ID with every request
+//! and includes it in the errors it returns, so that client failures can be
+//! correlated with reverse proxy and node logs.
+
+use std::fmt;
+use std::sync::Arc;
+
+use rand::RngCore;
+
+/// The default request ID header.
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+/// The [W3C Trace Context](https://www.w3.org/TR/trace-context/) header.
+pub const TRACEPARENT_HEADER: &str = "traceparent";
+
+type Generator = Arc<dyn Fn() -> String + Send + Sync>;
+
+/// Which header to send and how to produce its value for every request.
+#[derive(Clone)]
+pub struct RequestIds {
+    header: String,
+    generator: Generator,
+}
+
+impl RequestIds {
+    /// Sends a random 128-bit ID, hex-encoded, in the `X-Request-Id` header.
+    pub fn random() -> Self {
+        Self::custom(|| random_hex(16))
+    }
+
+    /// Sends a `traceparent` header with a random trace ID and a random parent ID.
+    pub fn trace_parent() -> Self {
+        Self::custom(|| format!("00-{}-{}-01", random_hex(16), random_hex(8)))
+            .with_header(TRACEPARENT_HEADER)
+    }
+
+    /// Sends the same ID with every request, e.g. the ID of the operation
+    /// that the client was created for.
+    pub fn fixed(id: &str) -> Self {
+        let id = id.to_owned();
+        Self::custom(move || id.clone())
+    }
+
+    /// Sends an ID produced by the given function in the `X-Request-Id` header.
+    pub fn custom<F>(f: F) -> Self
+    where
+        F: Fn() -> String + Send + Sync + 'static,
+    {
+        Self {
+            header: REQUEST_ID_HEADER.to_owned(),
+            generator: Arc::new(f),
+        }
+    }
+
+    /// Uses a different header.
+    pub fn with_header(mut self, header: &str) -> Self {
+        self.header = header.to_owned();
+        self
+    }
+
+    pub fn header(&self) -> &str {
+        &self.header
+    }
+
+    /// Produces an ID for the next request.
+    pub fn next_id(&self) -> String {
+        (self.generator)()
+    }
+}
+
+impl fmt::Debug for RequestIds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RequestIds")
+            .field("header", &self.header)
+            .finish_non_exhaustive()
+    }
+}
+
+fn random_hex(n_bytes: usize) -> String {
+    let mut buf = vec![0u8; n_bytes];
+    rand::rng().fill_bytes(&mut buf);
+    buf.iter().map(|b| format!("{:02x}", b)).collect()
+}
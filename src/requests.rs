@@ -11,14 +11,25 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
-use crate::commons::{ExchangeType, MessageTransferAcknowledgementMode, PolicyTarget, QueueType};
+use crate::commons::{
+    ExchangeType, MessageTransferAcknowledgementMode, PolicyTarget, QueueType,
+    X_ARGUMENT_KEY_X_QUEUE_TYPE,
+};
+use crate::password_hashing::HashingAlgorithm;
 use crate::responses;
-use crate::responses::{Policy, PolicyDefinition as PolDef};
-use serde::{Deserialize, Serialize};
+use crate::responses::{
+    BindingDefinition, ExchangeInfo, ExchangeInfoWithoutVirtualHost, Policy,
+    PolicyDefinition as PolDef, QueueDefinition, QueueInfo, QueueOps, RuntimeParameter, User,
+    VirtualHost,
+};
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Deserializer, Serialize};
 use serde_json::{json, Map, Value};
 
 /// Properties of a [virtual host](https://rabbitmq.com/docs/vhosts/) to be created or updated.
 #[derive(Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct VirtualHostParams<'a> {
     /// Virtual host name
     pub name: &'a str,
@@ -46,9 +57,100 @@ impl<'a> VirtualHostParams<'a> {
     }
 }
 
+/// Makes it possible to re-create an existing virtual host, for example, in a different cluster,
+/// with the same description, tags, default queue type and tracing setting.
+impl<'a> From<&'a VirtualHost> for VirtualHostParams<'a> {
+    fn from(vh: &'a VirtualHost) -> Self {
+        let description = vh
+            .description
+            .as_deref()
+            .or(vh.metadata.description.as_deref());
+        let tags = vh
+            .tags
+            .as_ref()
+            .or(vh.metadata.tags.as_ref())
+            .map(|tl| tl.0.iter().map(String::as_str).collect());
+        let default_queue_type = vh
+            .default_queue_type
+            .as_deref()
+            .or(vh.metadata.default_queue_type.as_deref())
+            .map(QueueType::from);
+
+        VirtualHostParams {
+            name: &vh.name,
+            description,
+            tags,
+            default_queue_type,
+            tracing: vh.tracing,
+        }
+    }
+}
+
+/// Virtual host metadata changes to be merged into the current metadata,
+/// see `Client#amend_vhost`. Fields that are `None` are left unchanged.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct VirtualHostChanges<'a> {
+    pub description: Option<&'a str>,
+    /// Replaces the current list of tags
+    pub tags: Option<Vec<&'a str>>,
+    pub default_queue_type: Option<QueueType>,
+    pub tracing: Option<bool>,
+}
+
+impl<'a> VirtualHostChanges<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_description(mut self, description: &'a str) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    pub fn with_tags(mut self, tags: Vec<&'a str>) -> Self {
+        self.tags = Some(tags);
+        self
+    }
+
+    pub fn with_default_queue_type(mut self, queue_type: QueueType) -> Self {
+        self.default_queue_type = Some(queue_type);
+        self
+    }
+
+    pub fn with_tracing(mut self, enabled: bool) -> Self {
+        self.tracing = Some(enabled);
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.description.is_none()
+            && self.tags.is_none()
+            && self.default_queue_type.is_none()
+            && self.tracing.is_none()
+    }
+
+    /// Merges the changes into the given virtual host properties.
+    pub fn apply_to<'b>(&self, params: VirtualHostParams<'b>) -> VirtualHostParams<'b>
+    where
+        'a: 'b,
+    {
+        VirtualHostParams {
+            name: params.name,
+            description: self.description.or(params.description),
+            tags: self.tags.clone().or(params.tags),
+            default_queue_type: self
+                .default_queue_type
+                .clone()
+                .or(params.default_queue_type),
+            tracing: self.tracing.unwrap_or(params.tracing),
+        }
+    }
+}
+
 /// Represents resource usage a limit to be enforced
 /// on a [virtual host](https://rabbitmq.com/docs/vhosts/) or a user.
 #[derive(Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct EnforcedLimitParams<T> {
     pub kind: T,
     pub value: i64,
@@ -62,6 +164,7 @@ impl<T> EnforcedLimitParams<T> {
 
 /// Properties of a [user](https://rabbitmq.com/docs/access-control/#user-management) to be created or updated.
 #[derive(Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct UserParams<'a> {
     /// Username
     pub name: &'a str,
@@ -70,14 +173,124 @@ pub struct UserParams<'a> {
     pub password_hash: &'a str,
     /// A comma-separate list of user tags
     pub tags: &'a str,
+    /// The algorithm `password_hash` was produced with. When `None`, the server
+    /// assumes the algorithm configured on the cluster
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hashing_algorithm: Option<HashingAlgorithm>,
+}
+
+impl<'a> UserParams<'a> {
+    pub fn with_name(mut self, name: &'a str) -> Self {
+        self.name = name;
+        self
+    }
+
+    pub fn with_password_hash(mut self, password_hash: &'a str) -> Self {
+        self.password_hash = password_hash;
+        self
+    }
+
+    pub fn with_tags(mut self, tags: &'a str) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    pub fn with_hashing_algorithm(mut self, hashing_algorithm: HashingAlgorithm) -> Self {
+        self.hashing_algorithm = Some(hashing_algorithm);
+        self
+    }
+}
+
+/// A user with an empty name, password hash and no tags.
+impl Default for UserParams<'_> {
+    fn default() -> Self {
+        Self {
+            name: "",
+            password_hash: "",
+            tags: "",
+            hashing_algorithm: None,
+        }
+    }
+}
+
+/// Properties of a [user](https://rabbitmq.com/docs/access-control/#user-management) to be created or updated
+/// with a plaintext password, which the server will salt and hash.
+///
+/// Prefer [`UserParams`] with a password hash produced by the functions in [`crate::password_hashing`]
+/// when the password should not be sent over the network.
+#[derive(Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct UserParamsWithPassword<'a> {
+    /// Username
+    pub name: &'a str,
+    /// Plaintext password of the user
+    pub password: &'a str,
+    /// A comma-separate list of user tags
+    pub tags: &'a str,
 }
 
+impl<'a> UserParamsWithPassword<'a> {
+    pub fn new(name: &'a str, password: &'a str, tags: &'a str) -> Self {
+        Self {
+            name,
+            password,
+            tags,
+        }
+    }
+}
+
+/// Optional arguments, e.g. of a queue, stream or exchange.
+/// Use the [`x_args!`](crate::x_args) macro or [`x_arguments`] to construct them.
 pub type XArguments = Option<Map<String, Value>>;
 
+/// Constructs [`XArguments`] from an iterator of key-value pairs, e.g. a `HashMap<&str, Value>`.
+///
+/// Since [`XArguments`] is an alias for a standard library type, `From` and `FromIterator`
+/// cannot be implemented for it, so this function serves the same purpose.
+pub fn x_arguments<I, K, V>(pairs: I) -> XArguments
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: Into<String>,
+    V: Into<Value>,
+{
+    Some(
+        pairs
+            .into_iter()
+            .map(|(k, v)| (k.into(), v.into()))
+            .collect(),
+    )
+}
+
+/// Constructs [`XArguments`] from a list of key-value pairs. Values can be
+/// of any type that can be serialized to JSON, including `serde_json::json!` expressions:
+///
+/// ```rust
+/// use rabbitmq_http_client::x_args;
+/// use rabbitmq_http_client::requests::QueueParams;
+///
+/// let args = x_args! {
+///     "x-max-length" => 10_000,
+///     "x-overflow" => "reject-publish"
+/// };
+/// let params = QueueParams::new_quorum_queue("qq.1", args);
+/// ```
+#[macro_export]
+macro_rules! x_args {
+    ($($key:expr => $value:expr),* $(,)?) => {{
+        #[allow(unused_mut)]
+        let mut args = $crate::serde_json::Map::new();
+        $(
+            args.insert(::std::string::String::from($key), $crate::serde_json::json!($value));
+        )*
+        ::std::option::Option::Some(args)
+    }};
+}
+
 /// [Queue](https://rabbitmq.com/docs/queues/) properties used at declaration time.
 /// Prefer constructor functions, they correctly put [`QueueType`] to the optional
 /// argument map.
 #[derive(Serialize, Debug)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct QueueParams<'a> {
     /// The name of the queue to declare.
     /// Must be no longer than 255 bytes in length.
@@ -170,8 +383,48 @@ impl<'a> QueueParams<'a> {
     }
 }
 
+/// Makes it possible to re-declare an existing queue, for example, in a different virtual host or cluster.
+///
+/// The queue type is taken from the queue's reported type rather than its `x-queue-type` argument,
+/// which the server may have omitted or normalized.
+impl<'a> From<&'a QueueInfo> for QueueParams<'a> {
+    fn from(info: &'a QueueInfo) -> Self {
+        let queue_type = info.queue_type();
+        let mut optional_args = info.arguments.0.clone();
+        optional_args.remove(X_ARGUMENT_KEY_X_QUEUE_TYPE);
+        let arguments = Self::combined_args(Some(optional_args), &queue_type);
+
+        Self {
+            name: &info.name,
+            queue_type,
+            durable: info.durable,
+            auto_delete: info.auto_delete,
+            exclusive: info.exclusive,
+            arguments,
+        }
+    }
+}
+
+/// Used to import queues from definitions one by one.
+///
+/// Unlike the [`QueueInfo`] conversion, the arguments are used as is: a queue
+/// without the `x-queue-type` argument will use the default queue type of its virtual host.
+impl<'a> From<&'a QueueDefinition> for QueueParams<'a> {
+    fn from(def: &'a QueueDefinition) -> Self {
+        Self {
+            name: &def.name,
+            queue_type: def.queue_type(),
+            durable: def.durable,
+            auto_delete: def.auto_delete,
+            exclusive: false,
+            arguments: Some(def.arguments.0.clone()),
+        }
+    }
+}
+
 /// [Stream](https://rabbitmq.com/docs/streams/) properties used at declaration time
 #[derive(Serialize, Debug)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct StreamParams<'a> {
     /// The name of the stream to declare.
     /// Must be no longer than 255 bytes in length.
@@ -211,6 +464,7 @@ impl<'a> StreamParams<'a> {
 
 /// Exchange properties used at queue declaration time
 #[derive(Debug, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct ExchangeParams<'a> {
     #[serde(skip_serializing)]
     pub name: &'a str,
@@ -363,9 +617,75 @@ impl<'a> ExchangeParams<'a> {
             arguments: optional_args,
         }
     }
+
+    pub fn with_name(mut self, name: &'a str) -> Self {
+        self.name = name;
+        self
+    }
+
+    pub fn with_exchange_type(mut self, exchange_type: ExchangeType) -> Self {
+        self.exchange_type = exchange_type;
+        self
+    }
+
+    pub fn with_durable(mut self, durable: bool) -> Self {
+        self.durable = durable;
+        self
+    }
+
+    pub fn with_auto_delete(mut self, auto_delete: bool) -> Self {
+        self.auto_delete = auto_delete;
+        self
+    }
+
+    pub fn with_arguments(mut self, arguments: XArguments) -> Self {
+        self.arguments = arguments;
+        self
+    }
+
+    fn optional_args_of(arguments: &responses::XArguments) -> XArguments {
+        if arguments.0.is_empty() {
+            None
+        } else {
+            Some(arguments.0.clone())
+        }
+    }
+}
+
+/// A durable direct exchange with an empty name and no optional arguments.
+impl Default for ExchangeParams<'_> {
+    fn default() -> Self {
+        Self::new("", ExchangeType::Direct, true, false, None)
+    }
+}
+
+/// Makes it possible to re-declare an exported exchange, for example, in a different virtual host or cluster.
+impl<'a> From<&'a ExchangeInfo> for ExchangeParams<'a> {
+    fn from(info: &'a ExchangeInfo) -> Self {
+        Self::new(
+            &info.name,
+            ExchangeType::from(info.exchange_type.as_str()),
+            info.durable,
+            info.auto_delete,
+            Self::optional_args_of(&info.arguments),
+        )
+    }
+}
+
+impl<'a> From<&'a ExchangeInfoWithoutVirtualHost> for ExchangeParams<'a> {
+    fn from(info: &'a ExchangeInfoWithoutVirtualHost) -> Self {
+        Self::new(
+            &info.name,
+            ExchangeType::from(info.exchange_type.as_str()),
+            info.durable,
+            info.auto_delete,
+            Self::optional_args_of(&info.arguments),
+        )
+    }
 }
 
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct BulkUserDelete<'a> {
     #[serde(borrow, rename = "users")]
     pub usernames: Vec<&'a str>,
@@ -375,6 +695,7 @@ pub type RuntimeParameterValue = Map<String, Value>;
 
 /// Represents a [runtime parameter](https://rabbitmq.com/docs/parameters/).
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct RuntimeParameterDefinition<'a> {
     pub name: &'a str,
     pub vhost: &'a str,
@@ -382,8 +703,20 @@ pub struct RuntimeParameterDefinition<'a> {
     pub value: RuntimeParameterValue,
 }
 
+impl<'a> From<&'a RuntimeParameter> for RuntimeParameterDefinition<'a> {
+    fn from(param: &'a RuntimeParameter) -> Self {
+        Self {
+            name: &param.name,
+            vhost: &param.vhost,
+            component: &param.component,
+            value: param.value.0.clone(),
+        }
+    }
+}
+
 /// Represents a [global runtime parameter](https://rabbitmq.com/docs/parameters/).
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct GlobalRuntimeParameterDefinition<'a> {
     pub name: &'a str,
     pub value: RuntimeParameterValue,
@@ -405,6 +738,7 @@ impl From<PolDef> for PolicyDefinition {
 
 /// Represents a [policy](https://rabbitmq.com/docs/parameters/#policies).
 #[derive(Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct PolicyParams<'a> {
     pub vhost: &'a str,
     pub name: &'a str,
@@ -415,6 +749,53 @@ pub struct PolicyParams<'a> {
     pub definition: PolicyDefinition,
 }
 
+impl<'a> PolicyParams<'a> {
+    pub fn with_vhost(mut self, vhost: &'a str) -> Self {
+        self.vhost = vhost;
+        self
+    }
+
+    pub fn with_name(mut self, name: &'a str) -> Self {
+        self.name = name;
+        self
+    }
+
+    pub fn with_pattern(mut self, pattern: &'a str) -> Self {
+        self.pattern = pattern;
+        self
+    }
+
+    pub fn with_apply_to(mut self, apply_to: PolicyTarget) -> Self {
+        self.apply_to = apply_to;
+        self
+    }
+
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    pub fn with_definition(mut self, definition: PolicyDefinition) -> Self {
+        self.definition = definition;
+        self
+    }
+}
+
+/// A policy in the default virtual host with an empty name, pattern and definition
+/// that applies to queues with priority 0.
+impl Default for PolicyParams<'_> {
+    fn default() -> Self {
+        Self {
+            vhost: "/",
+            name: "",
+            pattern: "",
+            apply_to: PolicyTarget::Queues,
+            priority: 0,
+            definition: PolicyDefinition::new(),
+        }
+    }
+}
+
 impl<'a> From<&'a Policy> for PolicyParams<'a> {
     fn from(policy: &'a Policy) -> Self {
         PolicyParams {
@@ -430,6 +811,7 @@ impl<'a> From<&'a Policy> for PolicyParams<'a> {
 
 /// Represents a user's [permission in a particular virtual host](https://rabbitmq.com/docs/access-control/).
 #[derive(Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct Permissions<'a> {
     pub user: &'a str,
     pub vhost: &'a str,
@@ -438,7 +820,99 @@ pub struct Permissions<'a> {
     pub write: &'a str,
 }
 
+impl<'a> From<&'a responses::Permissions> for Permissions<'a> {
+    fn from(p: &'a responses::Permissions) -> Self {
+        Self {
+            user: &p.user,
+            vhost: &p.vhost,
+            configure: &p.configure,
+            read: &p.read,
+            write: &p.write,
+        }
+    }
+}
+
+/// A single step of importing a [`responses::ClusterDefinitionSet`] one object at a time,
+/// see [`responses::ClusterDefinitionSet::operations`].
+///
+/// Applied with `Client#apply_definition_operation`.
+pub enum DefinitionOperation<'a> {
+    CreateVirtualHost(VirtualHostParams<'a>),
+    /// Users are imported with their existing password hashes
+    CreateUser(&'a User),
+    DeclarePermissions(Permissions<'a>),
+    UpsertRuntimeParameter(RuntimeParameterDefinition<'a>),
+    DeclarePolicy(PolicyParams<'a>),
+    DeclareExchange {
+        vhost: &'a str,
+        params: ExchangeParams<'a>,
+    },
+    DeclareQueue {
+        vhost: &'a str,
+        params: QueueParams<'a>,
+    },
+    DeclareBinding(&'a BindingDefinition),
+}
+
+impl responses::ClusterDefinitionSet {
+    /// Decomposes this definition set into individual operations that can be applied
+    /// one by one, e.g. to report progress or handle errors for every object.
+    ///
+    /// The operations are ordered the same way RabbitMQ imports definitions:
+    /// virtual hosts, users, permissions, runtime parameters, policies,
+    /// exchanges, queues, and finally bindings.
+    pub fn operations(&self) -> Vec<DefinitionOperation<'_>> {
+        let mut ops = Vec::new();
+
+        ops.extend(
+            self.virtual_hosts
+                .iter()
+                .map(|vh| DefinitionOperation::CreateVirtualHost(vh.into())),
+        );
+        ops.extend(self.users.iter().map(DefinitionOperation::CreateUser));
+        ops.extend(
+            self.permissions
+                .iter()
+                .map(|p| DefinitionOperation::DeclarePermissions(p.into())),
+        );
+        ops.extend(
+            self.parameters
+                .iter()
+                .map(|p| DefinitionOperation::UpsertRuntimeParameter(p.into())),
+        );
+        ops.extend(
+            self.policies
+                .iter()
+                .map(|p| DefinitionOperation::DeclarePolicy(p.into())),
+        );
+        ops.extend(
+            self.exchanges
+                .iter()
+                .map(|x| DefinitionOperation::DeclareExchange {
+                    vhost: &x.vhost,
+                    params: x.into(),
+                }),
+        );
+        ops.extend(
+            self.queues
+                .iter()
+                .map(|q| DefinitionOperation::DeclareQueue {
+                    vhost: &q.vhost,
+                    params: q.into(),
+                }),
+        );
+        ops.extend(
+            self.bindings
+                .iter()
+                .map(DefinitionOperation::DeclareBinding),
+        );
+
+        ops
+    }
+}
+
 #[derive(Default, Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum FederationResourceCleanupMode {
     #[default]
@@ -522,7 +996,7 @@ const DEFAULT_FEDERATION_RECONNECT_DELAY: u16 = 5;
 ///
 /// A federation upstream is declared as a runtime parameter,
 /// therefore this type implements a conversion that is used
-/// by [`crate::api::Client#declare_federation_upstream`] and [`crate::blocking_api::Client#declare_federation_upstream`]
+/// by [`crate::api::Client::declare_federation_upstream`] and [`crate::blocking_api::Client::declare_federation_upstream`]
 pub struct FederationUpstreamParams<'a> {
     pub name: &'a str,
     pub vhost: &'a str,
@@ -588,6 +1062,7 @@ impl<'a> From<FederationUpstreamParams<'a>> for RuntimeParameterDefinition<'a> {
         value.insert("trust-user-id".to_owned(), json!(params.trust_user_id));
         value.insert("reconnect-delay".to_owned(), json!(params.reconnect_delay));
         value.insert("ack-mode".to_owned(), json!(params.ack_mode));
+        value.insert("bind-nowait".to_owned(), json!(params.bind_using_nowait));
 
         if let Some(qf) = params.queue_federation {
             value.insert("queue".to_owned(), json!(qf.queue));
@@ -626,6 +1101,7 @@ pub const SHOVEL_COMPONENT: &str = "shovel";
 
 /// Represents a dynamic AMQP 0-9-1 shovel definition.
 #[derive(Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct Amqp091ShovelParams<'a> {
     pub name: &'a str,
     pub vhost: &'a str,
@@ -694,6 +1170,7 @@ impl<'a> From<Amqp091ShovelParams<'a>> for RuntimeParameterDefinition<'a> {
 }
 
 #[derive(Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct Amqp091ShovelSourceParams<'a> {
     pub source_uri: &'a str,
 
@@ -764,6 +1241,7 @@ impl<'a> Amqp091ShovelSourceParams<'a> {
 }
 
 #[derive(Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct Amqp091ShovelDestinationParams<'a> {
     pub destination_uri: &'a str,
 
@@ -837,6 +1315,7 @@ impl<'a> Amqp091ShovelDestinationParams<'a> {
 
 /// Represents a dynamic shovel definition.
 #[derive(Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct Amqp10ShovelParams<'a> {
     pub name: &'a str,
     pub vhost: &'a str,
@@ -849,6 +1328,7 @@ pub struct Amqp10ShovelParams<'a> {
 }
 
 #[derive(Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct Amqp10ShovelSourceParams<'a> {
     pub source_uri: &'a str,
     pub source_address: &'a str,
@@ -900,6 +1380,7 @@ impl<'a> From<Amqp10ShovelParams<'a>> for RuntimeParameterDefinition<'a> {
 }
 
 #[derive(Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct Amqp10ShovelDestinationParams<'a> {
     pub destination_uri: &'a str,
     pub destination_address: &'a str,
@@ -914,9 +1395,128 @@ impl<'a> Amqp10ShovelDestinationParams<'a> {
     }
 }
 
+/// A dynamic shovel definition that uses either AMQP 0-9-1 or AMQP 1.0,
+/// for code that handles shovels of both kinds.
+#[derive(Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(untagged)]
+pub enum ShovelParams<'a> {
+    Amqp091(Amqp091ShovelParams<'a>),
+    Amqp10(Amqp10ShovelParams<'a>),
+}
+
+impl ShovelParams<'_> {
+    pub fn name(&self) -> &str {
+        match self {
+            ShovelParams::Amqp091(p) => p.name,
+            ShovelParams::Amqp10(p) => p.name,
+        }
+    }
+
+    pub fn vhost(&self) -> &str {
+        match self {
+            ShovelParams::Amqp091(p) => p.vhost,
+            ShovelParams::Amqp10(p) => p.vhost,
+        }
+    }
+}
+
+impl<'a> From<Amqp091ShovelParams<'a>> for ShovelParams<'a> {
+    fn from(params: Amqp091ShovelParams<'a>) -> Self {
+        ShovelParams::Amqp091(params)
+    }
+}
+
+impl<'a> From<Amqp10ShovelParams<'a>> for ShovelParams<'a> {
+    fn from(params: Amqp10ShovelParams<'a>) -> Self {
+        ShovelParams::Amqp10(params)
+    }
+}
+
+impl<'a> From<ShovelParams<'a>> for RuntimeParameterDefinition<'a> {
+    fn from(params: ShovelParams<'a>) -> Self {
+        match params {
+            ShovelParams::Amqp091(p) => p.into(),
+            ShovelParams::Amqp10(p) => p.into(),
+        }
+    }
+}
+
 pub type MessageProperties = Map<String, Value>;
 
+impl From<&responses::MessageProperties> for MessageProperties {
+    fn from(props: &responses::MessageProperties) -> Self {
+        match serde_json::to_value(props) {
+            Ok(Value::Object(m)) => m,
+            _ => Map::new(),
+        }
+    }
+}
+
+/// How many messages [`MessageReplayParams`] fetches at a time by default.
+pub const DEFAULT_MESSAGE_REPLAY_BATCH_SIZE: u32 = 100;
+
+/// How many messages [`MessageReplayParams`] replays at most by default.
+pub const DEFAULT_MESSAGE_REPLAY_MAX_MESSAGES: u32 = 10_000;
+
+/// Parameters of a message replay (move): messages are fetched from a source queue
+/// and republished to an exchange with their original properties.
+///
+/// The typical use case is replaying messages from a dead-letter queue.
+///
+/// Used by [`crate::api::Client::replay_messages`] and [`crate::blocking_api::Client::replay_messages`].
+pub struct MessageReplayParams<'a> {
+    pub vhost: &'a str,
+    pub source_queue: &'a str,
+    pub destination_exchange: &'a str,
+    /// When `None`, every message is republished using its original routing key
+    pub destination_routing_key: Option<&'a str>,
+    /// How many messages to fetch at a time
+    pub batch_size: u32,
+    /// The maximum number of messages to replay, [`DEFAULT_MESSAGE_REPLAY_MAX_MESSAGES`] by default
+    pub max_messages: u32,
+    /// When `true`, up to one batch of messages is fetched and requeued,
+    /// nothing is republished
+    pub dry_run: bool,
+}
+
+impl<'a> MessageReplayParams<'a> {
+    /// Republishes messages to another queue via the default exchange.
+    pub fn to_queue(vhost: &'a str, source_queue: &'a str, destination_queue: &'a str) -> Self {
+        Self {
+            vhost,
+            source_queue,
+            destination_exchange: "",
+            destination_routing_key: Some(destination_queue),
+            batch_size: DEFAULT_MESSAGE_REPLAY_BATCH_SIZE,
+            max_messages: DEFAULT_MESSAGE_REPLAY_MAX_MESSAGES,
+            dry_run: false,
+        }
+    }
+
+    /// Republishes messages to an exchange using their original routing keys.
+    pub fn to_exchange(vhost: &'a str, source_queue: &'a str, exchange: &'a str) -> Self {
+        Self {
+            vhost,
+            source_queue,
+            destination_exchange: exchange,
+            destination_routing_key: None,
+            batch_size: DEFAULT_MESSAGE_REPLAY_BATCH_SIZE,
+            max_messages: DEFAULT_MESSAGE_REPLAY_MAX_MESSAGES,
+            dry_run: false,
+        }
+    }
+
+    /// How many messages to fetch in the next batch, given how many were already replayed.
+    pub fn next_batch_size(&self, replayed_so_far: u32) -> u32 {
+        self.max_messages
+            .saturating_sub(replayed_so_far)
+            .min(self.batch_size)
+    }
+}
+
 #[derive(Serialize, Default)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct EmptyPayload;
 
 impl EmptyPayload {
@@ -924,3 +1524,374 @@ impl EmptyPayload {
         Self
     }
 }
+
+/// An owned counterpart of [`VirtualHostParams`], convenient when the parameters are built
+/// dynamically, e.g. from a configuration file, or have to be moved across threads or tasks.
+///
+/// Use [`OwnedVirtualHostParams::as_params`] to pass it to the client.
+///
+/// Can be deserialized, e.g. from a configuration file. Only the name is required.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OwnedVirtualHostParams {
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub tags: Option<Vec<String>>,
+    #[serde(default, deserialize_with = "deserialize_optional_queue_type")]
+    pub default_queue_type: Option<QueueType>,
+    #[serde(default)]
+    pub tracing: bool,
+}
+
+impl OwnedVirtualHostParams {
+    pub fn named(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+            tags: None,
+            default_queue_type: None,
+            tracing: false,
+        }
+    }
+
+    pub fn as_params(&self) -> VirtualHostParams<'_> {
+        VirtualHostParams {
+            name: &self.name,
+            description: self.description.as_deref(),
+            tags: self
+                .tags
+                .as_ref()
+                .map(|tags| tags.iter().map(String::as_str).collect()),
+            default_queue_type: self.default_queue_type.clone(),
+            tracing: self.tracing,
+        }
+    }
+}
+
+impl From<&VirtualHostParams<'_>> for OwnedVirtualHostParams {
+    fn from(params: &VirtualHostParams<'_>) -> Self {
+        Self {
+            name: params.name.to_owned(),
+            description: params.description.map(str::to_owned),
+            tags: params
+                .tags
+                .as_ref()
+                .map(|tags| tags.iter().map(|t| (*t).to_owned()).collect()),
+            default_queue_type: params.default_queue_type.clone(),
+            tracing: params.tracing,
+        }
+    }
+}
+
+/// An owned counterpart of [`UserParams`].
+///
+/// Use [`OwnedUserParams::as_params`] to pass it to the client.
+///
+/// Can be deserialized, e.g. from a configuration file. Tags are optional.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OwnedUserParams {
+    pub name: String,
+    pub password_hash: String,
+    #[serde(default)]
+    pub tags: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hashing_algorithm: Option<HashingAlgorithm>,
+}
+
+impl OwnedUserParams {
+    pub fn new(
+        name: impl Into<String>,
+        password_hash: impl Into<String>,
+        tags: impl Into<String>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            password_hash: password_hash.into(),
+            tags: tags.into(),
+            hashing_algorithm: None,
+        }
+    }
+
+    pub fn as_params(&self) -> UserParams<'_> {
+        UserParams {
+            name: &self.name,
+            password_hash: &self.password_hash,
+            tags: &self.tags,
+            hashing_algorithm: self.hashing_algorithm,
+        }
+    }
+}
+
+impl From<&UserParams<'_>> for OwnedUserParams {
+    fn from(params: &UserParams<'_>) -> Self {
+        Self {
+            hashing_algorithm: params.hashing_algorithm,
+            ..Self::new(params.name, params.password_hash, params.tags)
+        }
+    }
+}
+
+/// An owned counterpart of [`QueueParams`].
+///
+/// Use [`OwnedQueueParams::as_params`] to pass it to the client.
+///
+/// Can be deserialized, e.g. from a configuration file. Only the name is required,
+/// queues are durable classic queues by default.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OwnedQueueParams {
+    pub name: String,
+    #[serde(default, deserialize_with = "deserialize_queue_type")]
+    pub queue_type: QueueType,
+    #[serde(default = "durable_by_default")]
+    pub durable: bool,
+    #[serde(default)]
+    pub auto_delete: bool,
+    #[serde(default)]
+    pub exclusive: bool,
+    #[serde(default)]
+    pub arguments: XArguments,
+}
+
+impl OwnedQueueParams {
+    /// Like [`QueueParams::new`], puts the queue type to the optional argument map.
+    pub fn new(
+        name: impl Into<String>,
+        queue_type: QueueType,
+        durable: bool,
+        auto_delete: bool,
+        optional_args: XArguments,
+    ) -> Self {
+        let arguments = QueueParams::combined_args(optional_args, &queue_type);
+        Self {
+            name: name.into(),
+            queue_type,
+            durable,
+            auto_delete,
+            exclusive: false,
+            arguments,
+        }
+    }
+
+    /// Puts the queue type to the optional argument map, unless it is already there.
+    pub fn as_params(&self) -> QueueParams<'_> {
+        QueueParams {
+            name: &self.name,
+            queue_type: self.queue_type.clone(),
+            durable: self.durable,
+            auto_delete: self.auto_delete,
+            exclusive: self.exclusive,
+            arguments: QueueParams::combined_args(self.arguments.clone(), &self.queue_type),
+        }
+    }
+}
+
+impl From<&QueueParams<'_>> for OwnedQueueParams {
+    fn from(params: &QueueParams<'_>) -> Self {
+        Self {
+            name: params.name.to_owned(),
+            queue_type: params.queue_type.clone(),
+            durable: params.durable,
+            auto_delete: params.auto_delete,
+            exclusive: params.exclusive,
+            arguments: params.arguments.clone(),
+        }
+    }
+}
+
+/// An owned counterpart of [`StreamParams`].
+///
+/// Use [`OwnedStreamParams::as_params`] to pass it to the client.
+///
+/// Can be deserialized, e.g. from a configuration file. The name and the expiration are required.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OwnedStreamParams {
+    pub name: String,
+    pub expiration: String,
+    #[serde(default)]
+    pub max_length_bytes: Option<u64>,
+    #[serde(default)]
+    pub max_segment_length_bytes: Option<u64>,
+    #[serde(default)]
+    pub arguments: XArguments,
+}
+
+impl OwnedStreamParams {
+    pub fn new(name: impl Into<String>, expiration: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            expiration: expiration.into(),
+            max_length_bytes: None,
+            max_segment_length_bytes: None,
+            arguments: None,
+        }
+    }
+
+    pub fn as_params(&self) -> StreamParams<'_> {
+        StreamParams {
+            name: &self.name,
+            expiration: &self.expiration,
+            max_length_bytes: self.max_length_bytes,
+            max_segment_length_bytes: self.max_segment_length_bytes,
+            arguments: self.arguments.clone(),
+        }
+    }
+}
+
+impl From<&StreamParams<'_>> for OwnedStreamParams {
+    fn from(params: &StreamParams<'_>) -> Self {
+        Self {
+            name: params.name.to_owned(),
+            expiration: params.expiration.to_owned(),
+            max_length_bytes: params.max_length_bytes,
+            max_segment_length_bytes: params.max_segment_length_bytes,
+            arguments: params.arguments.clone(),
+        }
+    }
+}
+
+/// An owned counterpart of [`ExchangeParams`].
+///
+/// Use [`OwnedExchangeParams::as_params`] to pass it to the client.
+///
+/// Can be deserialized, e.g. from a configuration file. The name and the type are required,
+/// exchanges are durable by default.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OwnedExchangeParams {
+    pub name: String,
+    #[serde(deserialize_with = "deserialize_exchange_type")]
+    pub exchange_type: ExchangeType,
+    #[serde(default = "durable_by_default")]
+    pub durable: bool,
+    #[serde(default)]
+    pub auto_delete: bool,
+    #[serde(default)]
+    pub arguments: XArguments,
+}
+
+impl OwnedExchangeParams {
+    pub fn new(
+        name: impl Into<String>,
+        exchange_type: ExchangeType,
+        durable: bool,
+        auto_delete: bool,
+        optional_args: XArguments,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            exchange_type,
+            durable,
+            auto_delete,
+            arguments: optional_args,
+        }
+    }
+
+    pub fn as_params(&self) -> ExchangeParams<'_> {
+        ExchangeParams {
+            name: &self.name,
+            exchange_type: self.exchange_type.clone(),
+            durable: self.durable,
+            auto_delete: self.auto_delete,
+            arguments: self.arguments.clone(),
+        }
+    }
+}
+
+impl From<&ExchangeParams<'_>> for OwnedExchangeParams {
+    fn from(params: &ExchangeParams<'_>) -> Self {
+        Self {
+            name: params.name.to_owned(),
+            exchange_type: params.exchange_type.clone(),
+            durable: params.durable,
+            auto_delete: params.auto_delete,
+            arguments: params.arguments.clone(),
+        }
+    }
+}
+
+/// An owned counterpart of [`PolicyParams`].
+///
+/// Use [`OwnedPolicyParams::as_params`] to pass it to the client.
+///
+/// Can be deserialized, e.g. from a configuration file. The priority is optional.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OwnedPolicyParams {
+    pub vhost: String,
+    pub name: String,
+    pub pattern: String,
+    pub apply_to: PolicyTarget,
+    #[serde(default)]
+    pub priority: i32,
+    pub definition: PolicyDefinition,
+}
+
+impl OwnedPolicyParams {
+    pub fn new(
+        vhost: impl Into<String>,
+        name: impl Into<String>,
+        pattern: impl Into<String>,
+        apply_to: PolicyTarget,
+        priority: i32,
+        definition: PolicyDefinition,
+    ) -> Self {
+        Self {
+            vhost: vhost.into(),
+            name: name.into(),
+            pattern: pattern.into(),
+            apply_to,
+            priority,
+            definition,
+        }
+    }
+
+    pub fn as_params(&self) -> PolicyParams<'_> {
+        PolicyParams {
+            vhost: &self.vhost,
+            name: &self.name,
+            pattern: &self.pattern,
+            apply_to: self.apply_to.clone(),
+            priority: self.priority,
+            definition: self.definition.clone(),
+        }
+    }
+}
+
+impl From<&PolicyParams<'_>> for OwnedPolicyParams {
+    fn from(params: &PolicyParams<'_>) -> Self {
+        Self {
+            vhost: params.vhost.to_owned(),
+            name: params.name.to_owned(),
+            pattern: params.pattern.to_owned(),
+            apply_to: params.apply_to.clone(),
+            priority: params.priority,
+            definition: params.definition.clone(),
+        }
+    }
+}
+
+fn durable_by_default() -> bool {
+    true
+}
+
+// queue and exchange types are deserialized from their names as used by RabbitMQ,
+// e.g. "quorum" or "x-consistent-hash"
+fn deserialize_queue_type<'de, D>(deserializer: D) -> Result<QueueType, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    String::deserialize(deserializer).map(QueueType::from)
+}
+
+fn deserialize_optional_queue_type<'de, D>(deserializer: D) -> Result<Option<QueueType>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::<String>::deserialize(deserializer).map(|val| val.map(QueueType::from))
+}
+
+fn deserialize_exchange_type<'de, D>(deserializer: D) -> Result<ExchangeType, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    String::deserialize(deserializer).map(ExchangeType::from)
+}
@@ -14,12 +14,16 @@
 use std::{fmt, ops};
 
 use crate::commons::{
-    BindingDestinationType, MessageTransferAcknowledgementMode, PolicyTarget, QueueType,
+    BindingDestinationType, ConnectionProtocol, MessageTransferAcknowledgementMode,
+    PayloadEncoding, PolicyTarget, QueueType, RatesMode, SupportedProtocol,
     X_ARGUMENT_KEY_X_QUEUE_TYPE,
 };
 use crate::error::ConversionError;
 use crate::formatting::*;
+use crate::password_hashing::HashingAlgorithm;
 use crate::utils::{percentage, percentage_as_text};
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
 use serde::{
     de::{MapAccess, Visitor},
     Deserialize, Serialize,
@@ -27,7 +31,10 @@ use serde::{
 use serde_aux::prelude::*;
 use serde_json::{json, Map};
 
-use time::OffsetDateTime;
+use std::collections::HashMap;
+use std::time::Duration;
+use time::format_description::{self, well_known::Rfc3339};
+use time::{OffsetDateTime, PrimitiveDateTime};
 
 use crate::transformers::{TransformerFn, TransformerFnOnce};
 use regex::Regex;
@@ -38,12 +45,15 @@ use std::fmt::Formatter;
 use tabled::Tabled;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct TagList(pub Vec<String>);
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct PluginList(pub Vec<String>);
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct XArguments(pub Map<String, serde_json::Value>);
 
 impl XArguments {
@@ -60,7 +70,8 @@ impl XArguments {
     }
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 #[allow(dead_code)]
 pub struct StreamPublisher {
@@ -74,7 +85,8 @@ pub struct StreamPublisher {
     pub errored: u64,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 #[allow(dead_code)]
 pub struct StreamConsumer {
@@ -91,6 +103,7 @@ pub struct StreamConsumer {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[serde(transparent)]
 pub struct RuntimeParameterValue(pub Map<String, serde_json::Value>);
 
@@ -109,6 +122,7 @@ impl fmt::Display for RuntimeParameterValue {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[serde(transparent)]
 pub struct GlobalRuntimeParameterValue(pub serde_json::Value);
 
@@ -120,7 +134,8 @@ impl fmt::Display for GlobalRuntimeParameterValue {
     }
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct NodeList(Vec<String>);
 
 impl fmt::Display for NodeList {
@@ -129,7 +144,8 @@ impl fmt::Display for NodeList {
     }
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 #[allow(dead_code)]
 pub struct NodeMemoryFootprint {
@@ -137,7 +153,23 @@ pub struct NodeMemoryFootprint {
     pub breakdown: NodeMemoryBreakdown,
 }
 
+impl NodeMemoryFootprint {
+    /// Computes the share of every memory breakdown category in the total.
+    pub fn percentages(&self) -> NodeMemoryPercentages {
+        NodeMemoryPercentages::from(&self.breakdown)
+    }
+}
+
 #[derive(Debug, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[allow(dead_code)]
+pub struct NodeMemoryRelativeFootprint {
+    #[serde(rename = "memory")]
+    pub breakdown: NodeMemoryPercentages,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 #[allow(dead_code)]
 pub struct NodeMemoryTotals {
@@ -165,7 +197,8 @@ impl fmt::Display for NodeMemoryTotals {
     }
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 #[allow(dead_code)]
 pub struct NodeMemoryBreakdown {
@@ -210,6 +243,93 @@ pub struct NodeMemoryBreakdown {
     pub total: NodeMemoryTotals,
 }
 
+/// The node memory breakdown as percentages of the total, as returned by `Client#get_node_memory_footprint_relative`
+/// or computed from a [`NodeMemoryBreakdown`].
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "tabled", derive(Tabled))]
+#[serde(default)]
+#[allow(dead_code)]
+pub struct NodeMemoryPercentages {
+    pub connection_readers: f64,
+    pub connection_writers: f64,
+    pub connection_channels: f64,
+    pub connection_other: f64,
+    #[serde(rename = "queue_procs")]
+    pub classic_queue_procs: f64,
+    pub quorum_queue_procs: f64,
+    pub stream_queue_procs: f64,
+    pub stream_queue_replica_reader_procs: f64,
+    pub stream_queue_coordinator_procs: f64,
+    pub plugins: f64,
+    pub metadata_store: f64,
+    #[serde(rename = "other_proc")]
+    pub other_procs: f64,
+    pub metrics: f64,
+    #[serde(rename = "mgmt_db")]
+    pub management_db: f64,
+    pub mnesia: f64,
+    #[serde(rename = "quorum_ets")]
+    pub quorum_queue_ets_tables: f64,
+    #[serde(rename = "metadata_store_ets")]
+    pub metadata_store_ets_tables: f64,
+    #[serde(rename = "other_ets")]
+    pub other_ets_tables: f64,
+    #[serde(rename = "binary")]
+    pub binary_heap: f64,
+    #[serde(rename = "msg_index")]
+    pub message_indices: f64,
+    pub code: f64,
+    #[serde(rename = "atom")]
+    pub atom_table: f64,
+    pub other_system: f64,
+    #[serde(rename = "allocated_unused")]
+    pub allocated_but_unused: f64,
+    #[serde(rename = "reserved_unallocated")]
+    pub reserved_but_unallocated: f64,
+}
+
+impl From<&NodeMemoryBreakdown> for NodeMemoryPercentages {
+    fn from(b: &NodeMemoryBreakdown) -> Self {
+        let total = b.grand_total();
+        let of_total = |val: u64| {
+            if total == 0 {
+                0.0
+            } else {
+                percentage(val, total)
+            }
+        };
+
+        NodeMemoryPercentages {
+            connection_readers: of_total(b.connection_readers),
+            connection_writers: of_total(b.connection_writers),
+            connection_channels: of_total(b.connection_channels),
+            connection_other: of_total(b.connection_other),
+            classic_queue_procs: of_total(b.classic_queue_procs),
+            quorum_queue_procs: of_total(b.quorum_queue_procs),
+            stream_queue_procs: of_total(b.stream_queue_procs),
+            stream_queue_replica_reader_procs: of_total(b.stream_queue_replica_reader_procs),
+            stream_queue_coordinator_procs: of_total(b.stream_queue_coordinator_procs),
+            plugins: of_total(b.plugins),
+            metadata_store: of_total(b.metadata_store),
+            other_procs: of_total(b.other_procs),
+            metrics: of_total(b.metrics),
+            management_db: of_total(b.management_db),
+            mnesia: of_total(b.mnesia),
+            quorum_queue_ets_tables: of_total(b.quorum_queue_ets_tables),
+            metadata_store_ets_tables: of_total(b.metadata_store_ets_tables),
+            other_ets_tables: of_total(b.other_ets_tables),
+            binary_heap: of_total(b.binary_heap),
+            message_indices: of_total(b.message_indices),
+            code: of_total(b.code),
+            atom_table: of_total(b.atom_table),
+            other_system: of_total(b.other_system),
+            allocated_but_unused: of_total(b.allocated_but_unused),
+            reserved_but_unallocated: of_total(b.reserved_but_unallocated),
+        }
+    }
+}
+
 macro_rules! percentage_fn {
     ($fn_name:ident, $field:ident) => {
         pub fn $fn_name(&mut self) -> f64 {
@@ -399,7 +519,8 @@ impl fmt::Display for NodeMemoryBreakdown {
 }
 
 /// Represents a number of key OAuth 2 configuration settings.
-#[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 pub struct OAuthConfiguration {
     pub oauth_enabled: bool,
@@ -410,6 +531,7 @@ pub struct OAuthConfiguration {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[allow(dead_code)]
 pub struct VirtualHostMetadata {
     /// Optional tags
@@ -423,6 +545,7 @@ pub struct VirtualHostMetadata {
 
 /// Represents a [RabbitMQ virtual host](https://rabbitmq.com/docs/vhosts/).
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 #[allow(dead_code)]
 pub struct VirtualHost {
@@ -441,9 +564,41 @@ pub struct VirtualHost {
     /// All virtual host metadata combined
     #[cfg_attr(feature = "tabled", tabled(skip))]
     pub metadata: VirtualHostMetadata,
+    /// Is message tracing enabled in this virtual host?
+    #[serde(default)]
+    #[cfg_attr(feature = "tabled", tabled(skip))]
+    pub tracing: bool,
+    /// Is this virtual host protected from deletion?
+    /// Always `false` on RabbitMQ versions prior to 4.1.
+    #[serde(default)]
+    #[cfg_attr(feature = "tabled", tabled(skip))]
+    pub protected_from_deletion: bool,
+
+    // Message statistics are only reported when statistics are enabled
+    // and are never a part of definitions
+    /// Total number of messages in all queues in this virtual host
+    #[serde(default, skip_serializing)]
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub messages: Option<u64>,
+    #[serde(default, rename(deserialize = "messages_ready"), skip_serializing)]
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub messages_ready_for_delivery: Option<u64>,
+    #[serde(
+        default,
+        rename(deserialize = "messages_unacknowledged"),
+        skip_serializing
+    )]
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub messages_delivered_but_unacknowledged_by_consumers: Option<u64>,
+    /// Message publishing, delivery, acknowledgement and so on counters and rates
+    /// in this virtual host
+    #[serde(default, skip_serializing)]
+    #[cfg_attr(feature = "tabled", tabled(skip))]
+    pub message_stats: Option<MessageStats>,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct EnforcedLimits(pub Map<String, serde_json::Value>);
 
 impl ops::Deref for EnforcedLimits {
@@ -460,7 +615,8 @@ impl fmt::Display for EnforcedLimits {
     }
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 #[allow(dead_code)]
 pub struct VirtualHostLimits {
@@ -469,7 +625,8 @@ pub struct VirtualHostLimits {
     pub limits: EnforcedLimits,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 #[allow(dead_code)]
 pub struct UserLimits {
@@ -480,70 +637,209 @@ pub struct UserLimits {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 #[allow(dead_code)]
 pub struct User {
     pub name: String,
     pub tags: TagList,
     pub password_hash: String,
+    /// The algorithm `password_hash` was produced with, e.g. `rabbit_password_hashing_sha256`.
+    /// Kept as reported by the server since it can be one this client cannot produce hashes with,
+    /// see [`crate::password_hashing::HashingAlgorithm`]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub hashing_algorithm: Option<String>,
 }
 
 impl User {
     pub fn with_name(&self, name: String) -> Self {
         Self {
             name,
-            tags: self.tags.clone(),
-            password_hash: self.password_hash.clone(),
+            ..self.clone()
         }
     }
 
     pub fn with_tags(&self, tags: TagList) -> Self {
         Self {
-            name: self.name.clone(),
             tags,
-            password_hash: self.password_hash.clone(),
+            ..self.clone()
         }
     }
 
+    /// Note that the hashing algorithm is reset since the new hash
+    /// is not necessarily produced with the same one
     pub fn with_password_hash(&self, password_hash: String) -> Self {
         Self {
-            name: self.name.clone(),
-            tags: self.tags.clone(),
             password_hash,
+            hashing_algorithm: None,
+            ..self.clone()
+        }
+    }
+
+    /// Parses [`User::hashing_algorithm`]. Returns `Ok(None)` if the server did not report it.
+    pub fn parsed_hashing_algorithm(&self) -> Result<Option<HashingAlgorithm>, ConversionError> {
+        self.hashing_algorithm
+            .as_deref()
+            .map(str::parse)
+            .transpose()
+    }
+}
+
+/// A password hash computed by the server, see `Client#hash_password`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[allow(dead_code)]
+pub struct HashedPassword {
+    #[serde(rename(deserialize = "ok"))]
+    pub password_hash: String,
+}
+
+/// The user the client is authenticated as, see `Client#current_user`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "tabled", derive(Tabled))]
+#[allow(dead_code)]
+pub struct CurrentUser {
+    pub name: String,
+    pub tags: TagList,
+}
+
+impl CurrentUser {
+    /// Returns true if the user has the given tag, e.g. `monitoring` or `policymaker`.
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.0.iter().any(|t| t == tag)
+    }
+
+    /// Returns true if the user is tagged as an `administrator`.
+    pub fn is_administrator(&self) -> bool {
+        self.has_tag("administrator")
+    }
+}
+
+/// Client connection state.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ConnectionState {
+    /// The connection is being opened
+    Starting,
+    /// Connection parameters are being negotiated
+    Tuning,
+    /// The client is opening the connection
+    Opening,
+    /// The connection is open and operational
+    Running,
+    /// Publishers on this connection are [throttled](https://www.rabbitmq.com/docs/flow-control)
+    Flow,
+    /// The connection will be blocked as soon as it publishes, because a resource alarm is in effect
+    Blocking,
+    /// The connection is blocked because a [resource alarm](https://www.rabbitmq.com/docs/alarms) is in effect
+    Blocked,
+    /// The connection is being closed
+    Closing,
+    /// The connection is closed
+    Closed,
+    /// The state was not reported
+    #[default]
+    Unknown,
+    /// A state this client is not aware of
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl From<&str> for ConnectionState {
+    fn from(value: &str) -> Self {
+        match value {
+            "starting" => ConnectionState::Starting,
+            "tuning" => ConnectionState::Tuning,
+            "opening" => ConnectionState::Opening,
+            "running" => ConnectionState::Running,
+            "flow" => ConnectionState::Flow,
+            "blocking" => ConnectionState::Blocking,
+            "blocked" => ConnectionState::Blocked,
+            "closing" => ConnectionState::Closing,
+            "closed" => ConnectionState::Closed,
+            "unknown" => ConnectionState::Unknown,
+            other => ConnectionState::Other(other.to_owned()),
         }
     }
 }
 
+impl From<String> for ConnectionState {
+    fn from(value: String) -> Self {
+        Self::from(value.as_str())
+    }
+}
+
+impl fmt::Display for ConnectionState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConnectionState::Starting => write!(f, "starting"),
+            ConnectionState::Tuning => write!(f, "tuning"),
+            ConnectionState::Opening => write!(f, "opening"),
+            ConnectionState::Running => write!(f, "running"),
+            ConnectionState::Flow => write!(f, "flow"),
+            ConnectionState::Blocking => write!(f, "blocking"),
+            ConnectionState::Blocked => write!(f, "blocked"),
+            ConnectionState::Closing => write!(f, "closing"),
+            ConnectionState::Closed => write!(f, "closed"),
+            ConnectionState::Unknown => write!(f, "unknown"),
+            ConnectionState::Other(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl From<ConnectionState> for String {
+    fn from(value: ConnectionState) -> Self {
+        value.to_string()
+    }
+}
+
+impl ConnectionState {
+    /// Returns true if publishers on this connection are blocked (or about to be)
+    /// because of a resource alarm.
+    pub fn is_blocked(&self) -> bool {
+        matches!(self, ConnectionState::Blocked | ConnectionState::Blocking)
+    }
+}
+
 /// Represents a client connection.
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 #[allow(dead_code)]
 pub struct Connection {
-    /// Connection name. Use it to close this connection.
+    /// Connection name. Use it to close this connection with `Client#close_connection`.
     pub name: String,
     /// To what node the client is connected
     pub node: String,
     /// Connection state
-    #[serde(default = "undefined")]
-    pub state: String,
+    #[serde(default)]
+    pub state: ConnectionState,
     /// What protocol the connection uses
-    pub protocol: String,
+    pub protocol: ConnectionProtocol,
     /// The name of the authenticated user
     #[serde(rename(deserialize = "user"))]
     pub username: String,
     /// When was this connection opened (a timestamp).
+    #[serde(default)]
     pub connected_at: u64,
     /// The hostname used to connect.
     #[serde(rename(deserialize = "host"))]
+    #[serde(default, deserialize_with = "deserialize_default_from_null")]
     pub server_hostname: String,
     /// The port used to connect.
     #[serde(rename(deserialize = "port"))]
+    #[serde(default, deserialize_with = "deserialize_default_from_null")]
     pub server_port: u32,
     /// Client hostname.
     #[serde(rename(deserialize = "peer_host"))]
+    #[serde(default, deserialize_with = "deserialize_default_from_null")]
     pub client_hostname: String,
     /// Ephemeral client port.
     #[serde(rename(deserialize = "peer_port"))]
+    #[serde(default, deserialize_with = "deserialize_default_from_null")]
     pub client_port: u32,
     /// Maximum number of channels that can be opened on this connection.
     #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
@@ -552,12 +848,77 @@ pub struct Connection {
     #[serde(rename(deserialize = "channels"))]
     #[serde(default)]
     pub channel_count: u16,
+    /// Total number of bytes received on this connection.
+    #[serde(rename(deserialize = "recv_oct"))]
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub bytes_received: Option<u64>,
+    /// The rate of bytes received on this connection.
+    #[serde(rename(deserialize = "recv_oct_details"))]
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option_details_rate"))]
+    pub bytes_received_details: Option<Rate>,
+    /// Total number of bytes sent on this connection.
+    #[serde(rename(deserialize = "send_oct"))]
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub bytes_sent: Option<u64>,
+    /// The rate of bytes sent on this connection.
+    #[serde(rename(deserialize = "send_oct_details"))]
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option_details_rate"))]
+    pub bytes_sent_details: Option<Rate>,
+    /// Total number of packets (socket reads) received on this connection.
+    #[serde(rename(deserialize = "recv_cnt"))]
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub packets_received: Option<u64>,
+    /// The rate of packets received on this connection.
+    #[serde(rename(deserialize = "recv_cnt_details"))]
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option_details_rate"))]
+    pub packets_received_details: Option<Rate>,
+    /// Total number of packets (socket writes) sent on this connection.
+    #[serde(rename(deserialize = "send_cnt"))]
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub packets_sent: Option<u64>,
+    /// The rate of packets sent on this connection.
+    #[serde(rename(deserialize = "send_cnt_details"))]
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option_details_rate"))]
+    pub packets_sent_details: Option<Rate>,
     /// Client-provided properties (metadata and capabilities).
+    /// MQTT, STOMP and other non-AMQP clients usually do not provide any.
+    #[serde(default)]
     #[cfg_attr(feature = "tabled", tabled(skip))]
     pub client_properties: ClientProperties,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+/// Accessors that take the differences between protocols into account: e.g.
+/// only AMQP 0-9-1 connections have channels, and MQTT and STOMP clients
+/// do not provide any client properties.
+impl Connection {
+    /// Returns true if this connection can have channels. AMQP 0-9-1 connections
+    /// have channels, AMQP 1.0 connections have sessions (see `Client#list_connection_sessions`),
+    /// other protocols have neither.
+    pub fn has_channels(&self) -> bool {
+        self.protocol == ConnectionProtocol::AMQP091
+    }
+
+    /// The client's address as `host:port`, if the peer details are known.
+    pub fn client_address(&self) -> Option<String> {
+        if self.client_hostname.is_empty() {
+            return None;
+        }
+        Some(format!("{}:{}", self.client_hostname, self.client_port))
+    }
+
+    /// The client-provided connection name (the `connection_name` client property), if any.
+    pub fn client_provided_name(&self) -> Option<&str> {
+        Some(self.client_properties.connection_name.as_str()).filter(|s| !s.is_empty())
+    }
+
+    /// Client capabilities, only provided by AMQP 0-9-1 clients.
+    pub fn capabilities(&self) -> Option<&ClientCapabilities> {
+        self.client_properties.capabilities.as_ref()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[allow(dead_code)]
 pub struct ClientProperties {
     #[serde(default)]
@@ -571,7 +932,9 @@ pub struct ClientProperties {
     pub capabilities: Option<ClientCapabilities>,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(default)]
 #[allow(dead_code)]
 pub struct ClientCapabilities {
     pub authentication_failure_close: bool,
@@ -586,7 +949,132 @@ pub struct ClientCapabilities {
     pub publisher_confirms: bool,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+/// The maximum message size of an AMQP 1.0 link.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MaxMessageSize {
+    /// No limit, reported as `"unlimited"`
+    Unlimited,
+    Bytes(u64),
+}
+
+impl Serialize for MaxMessageSize {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            MaxMessageSize::Unlimited => serializer.serialize_str("unlimited"),
+            MaxMessageSize::Bytes(n) => serializer.serialize_u64(*n),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for MaxMessageSize {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Bytes(u64),
+            Named(String),
+        }
+
+        match Raw::deserialize(deserializer)? {
+            Raw::Bytes(n) => Ok(MaxMessageSize::Bytes(n)),
+            Raw::Named(s) if s == "unlimited" => Ok(MaxMessageSize::Unlimited),
+            Raw::Named(s) => Err(serde::de::Error::custom(format!(
+                "'{}' is not a valid maximum message size",
+                s
+            ))),
+        }
+    }
+}
+
+/// An AMQP 1.0 session. AMQP 1.0 connections have sessions
+/// (and links within them) instead of channels.
+///
+/// Returned by `Client#list_connection_sessions`, RabbitMQ 4.0 and later only.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct AmqpSession {
+    pub channel_number: u16,
+    #[serde(default)]
+    pub handle_max: Option<u32>,
+    #[serde(default)]
+    pub next_incoming_id: Option<u32>,
+    #[serde(default)]
+    pub incoming_window: Option<u32>,
+    #[serde(default)]
+    pub next_outgoing_id: Option<u32>,
+    #[serde(default)]
+    pub remote_incoming_window: Option<u32>,
+    #[serde(default)]
+    pub remote_outgoing_window: Option<u32>,
+    /// Deliveries sent to the client that it has not settled yet
+    #[serde(default)]
+    pub outgoing_unsettled_deliveries: Option<u64>,
+    /// Links the client publishes on
+    #[serde(default)]
+    pub incoming_links: Vec<AmqpIncomingLink>,
+    /// Links the client consumes from
+    #[serde(default)]
+    pub outgoing_links: Vec<AmqpOutgoingLink>,
+}
+
+/// A link the client sends messages on (from the server's perspective, an incoming link).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct AmqpIncomingLink {
+    pub handle: u32,
+    pub link_name: String,
+    /// E.g. `/queues/orders` or `/exchanges/events/order.created`.
+    /// Not set for anonymous terminus links, where every message carries its own address.
+    #[serde(default)]
+    pub target_address: Option<String>,
+    #[serde(default)]
+    pub delivery_count: Option<u32>,
+    #[serde(default)]
+    pub credit: Option<u32>,
+    /// E.g. `settled`, `unsettled` or `mixed`
+    #[serde(default)]
+    pub snd_settle_mode: Option<String>,
+    #[serde(default)]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<serde_json::Value>"))]
+    pub max_message_size: Option<MaxMessageSize>,
+    /// Messages not yet confirmed by the target queues
+    #[serde(default)]
+    pub unconfirmed_messages: Option<u64>,
+}
+
+/// A link the client receives messages on (from the server's perspective, an outgoing link).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct AmqpOutgoingLink {
+    pub handle: u32,
+    pub link_name: String,
+    /// E.g. `/queues/orders`
+    #[serde(default)]
+    pub source_address: Option<String>,
+    #[serde(default)]
+    pub queue_name: Option<String>,
+    #[serde(default)]
+    pub queue_type: Option<String>,
+    /// Whether messages are sent pre-settled, that is, without acknowledgements
+    #[serde(default)]
+    pub send_settled: Option<bool>,
+    #[serde(default)]
+    pub delivery_count: Option<u32>,
+    #[serde(default)]
+    pub credit: Option<u32>,
+    #[serde(default)]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<serde_json::Value>"))]
+    pub max_message_size: Option<MaxMessageSize>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 #[allow(dead_code)]
 pub struct UserConnection {
@@ -597,7 +1085,67 @@ pub struct UserConnection {
     pub vhost: String,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+/// Channel state.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ChannelState {
+    /// The channel is being opened
+    Starting,
+    /// The channel is open and operational
+    Running,
+    /// Publishers on this channel are [throttled](https://www.rabbitmq.com/docs/flow-control)
+    Flow,
+    /// The channel is being closed
+    Closing,
+    /// The state was not reported
+    #[default]
+    Unknown,
+    /// A state this client is not aware of
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl From<&str> for ChannelState {
+    fn from(value: &str) -> Self {
+        match value {
+            "starting" => ChannelState::Starting,
+            "running" => ChannelState::Running,
+            "flow" => ChannelState::Flow,
+            "closing" => ChannelState::Closing,
+            "unknown" => ChannelState::Unknown,
+            other => ChannelState::Other(other.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChannelState {
+    fn from(value: String) -> Self {
+        Self::from(value.as_str())
+    }
+}
+
+impl fmt::Display for ChannelState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChannelState::Starting => write!(f, "starting"),
+            ChannelState::Running => write!(f, "running"),
+            ChannelState::Flow => write!(f, "flow"),
+            ChannelState::Closing => write!(f, "closing"),
+            ChannelState::Unknown => write!(f, "unknown"),
+            ChannelState::Other(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl From<ChannelState> for String {
+    fn from(value: ChannelState) -> Self {
+        value.to_string()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 #[allow(dead_code)]
 pub struct Channel {
@@ -606,8 +1154,11 @@ pub struct Channel {
     pub name: String,
     #[cfg_attr(feature = "tabled", tabled(skip))]
     pub connection_details: ConnectionDetails,
+    /// The node the channel's connection is connected to
+    pub node: String,
     pub vhost: String,
-    pub state: String,
+    #[serde(default)]
+    pub state: ChannelState,
     pub consumer_count: u32,
     #[serde(rename(deserialize = "confirm"))]
     pub has_publisher_confirms_enabled: bool,
@@ -616,7 +1167,8 @@ pub struct Channel {
     pub messages_unconfirmed: u32,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 #[allow(dead_code)]
 pub struct ConnectionDetails {
@@ -627,7 +1179,8 @@ pub struct ConnectionDetails {
     pub client_port: u32,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[allow(dead_code)]
 pub struct ChannelDetails {
     #[serde(rename(deserialize = "number"))]
@@ -643,7 +1196,8 @@ pub struct ChannelDetails {
     pub username: String,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[allow(dead_code)]
 pub struct Consumer {
     pub consumer_tag: String,
@@ -697,7 +1251,8 @@ impl Tabled for Consumer {
     }
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 #[allow(dead_code)]
 pub struct NameAndVirtualHost {
@@ -706,89 +1261,503 @@ pub struct NameAndVirtualHost {
     pub vhost: String,
 }
 
-impl fmt::Display for NameAndVirtualHost {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        writeln!(f, "'{}' in virtual host '{}'", self.name, self.vhost)
+impl fmt::Display for NameAndVirtualHost {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "'{}' in virtual host '{}'", self.name, self.vhost)
+    }
+}
+
+pub trait QueueOps {
+    fn queue_type(&self) -> QueueType;
+
+    fn policy_target_type(&self) -> PolicyTarget;
+}
+
+/// Queue state.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum QueueState {
+    /// The queue is operational
+    Running,
+    /// The queue has been inactive for a while and has hibernated
+    Idle,
+    /// Publishers to this queue are [throttled](https://www.rabbitmq.com/docs/flow-control)
+    Flow,
+    /// A quorum queue or stream that has lost the majority of its replicas
+    Minority,
+    /// The queue is not available, e.g. its node is down
+    Down,
+    /// The queue process has crashed
+    Crashed,
+    /// The queue has been stopped
+    Stopped,
+    /// The queue has been terminated
+    Terminated,
+    /// The state was not reported
+    #[default]
+    Unknown,
+    /// A state this client is not aware of
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl From<&str> for QueueState {
+    fn from(value: &str) -> Self {
+        match value {
+            "running" => QueueState::Running,
+            "idle" => QueueState::Idle,
+            "flow" => QueueState::Flow,
+            "minority" => QueueState::Minority,
+            "down" => QueueState::Down,
+            "crashed" => QueueState::Crashed,
+            "stopped" => QueueState::Stopped,
+            "terminated" => QueueState::Terminated,
+            "unknown" => QueueState::Unknown,
+            other => QueueState::Other(other.to_owned()),
+        }
+    }
+}
+
+impl From<String> for QueueState {
+    fn from(value: String) -> Self {
+        Self::from(value.as_str())
+    }
+}
+
+impl fmt::Display for QueueState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QueueState::Running => write!(f, "running"),
+            QueueState::Idle => write!(f, "idle"),
+            QueueState::Flow => write!(f, "flow"),
+            QueueState::Minority => write!(f, "minority"),
+            QueueState::Down => write!(f, "down"),
+            QueueState::Crashed => write!(f, "crashed"),
+            QueueState::Stopped => write!(f, "stopped"),
+            QueueState::Terminated => write!(f, "terminated"),
+            QueueState::Unknown => write!(f, "unknown"),
+            QueueState::Other(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl From<QueueState> for String {
+    fn from(value: QueueState) -> Self {
+        value.to_string()
+    }
+}
+
+/// The number of messages a classic queue aims to keep in memory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TargetRamCount {
+    /// No limit, reported as `"infinity"`
+    Infinity,
+    Count(u64),
+}
+
+impl Serialize for TargetRamCount {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            TargetRamCount::Infinity => serializer.serialize_str("infinity"),
+            TargetRamCount::Count(n) => serializer.serialize_u64(*n),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for TargetRamCount {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Count(u64),
+            Named(String),
+        }
+
+        match Raw::deserialize(deserializer)? {
+            Raw::Count(n) => Ok(TargetRamCount::Count(n)),
+            Raw::Named(s) if s == "infinity" => Ok(TargetRamCount::Infinity),
+            Raw::Named(s) => Err(serde::de::Error::custom(format!(
+                "'{}' is not a valid target RAM count",
+                s
+            ))),
+        }
+    }
+}
+
+/// Internal state of a classic queue, useful when debugging its paging behavior.
+/// Other queue types do not report it.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct BackingQueueStatus {
+    /// E.g. `default` or `lazy`
+    #[serde(default)]
+    pub mode: Option<String>,
+    /// The version of the classic queue storage implementation
+    #[serde(default)]
+    pub version: Option<u8>,
+    #[serde(default)]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<serde_json::Value>"))]
+    pub target_ram_count: Option<TargetRamCount>,
+    #[serde(default, rename = "len")]
+    pub length: Option<u64>,
+    #[serde(default)]
+    pub next_seq_id: Option<u64>,
+    #[serde(default)]
+    pub next_deliver_seq_id: Option<u64>,
+    #[serde(default)]
+    pub num_pending_acks: Option<u64>,
+    #[serde(default)]
+    pub num_unconfirmed: Option<u64>,
+    /// Messages per second
+    #[serde(default)]
+    pub avg_ingress_rate: f64,
+    /// Messages per second
+    #[serde(default)]
+    pub avg_egress_rate: f64,
+    /// Acknowledgements per second
+    #[serde(default)]
+    pub avg_ack_ingress_rate: f64,
+    /// Acknowledgements per second
+    #[serde(default)]
+    pub avg_ack_egress_rate: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "tabled", derive(Tabled))]
+#[allow(dead_code)]
+pub struct QueueInfo {
+    pub name: String,
+    pub vhost: String,
+    #[serde(rename(deserialize = "type"))]
+    pub queue_type: String,
+    pub durable: bool,
+    pub auto_delete: bool,
+    pub exclusive: bool,
+    #[cfg_attr(feature = "tabled", tabled(display = "display_arg_table"))]
+    pub arguments: XArguments,
+
+    #[serde(default = "undefined")]
+    pub node: String,
+    #[serde(default)]
+    pub state: QueueState,
+    // only quorum queues and streams will have this
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub leader: Option<String>,
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub members: Option<NodeList>,
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub online: Option<NodeList>,
+
+    #[serde(default)]
+    pub memory: u64,
+    #[serde(rename(deserialize = "consumers"))]
+    #[serde(default)]
+    pub consumer_count: u16,
+    #[serde(default)]
+    pub consumer_utilisation: f32,
+    #[cfg_attr(feature = "tabled", tabled(skip))]
+    pub exclusive_consumer_tag: Option<String>,
+    // only returned when a single queue is fetched, e.g. using `get_queue_info`
+    #[serde(default)]
+    #[cfg_attr(feature = "tabled", tabled(skip))]
+    pub consumer_details: Option<Vec<Consumer>>,
+
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub policy: Option<String>,
+    #[serde(default)]
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub operator_policy: Option<String>,
+    /// The combination of the policy and the operator policy that the broker
+    /// actually applies to the queue
+    #[serde(
+        default = "empty_policy_definition",
+        deserialize_with = "deserialize_policy_definition"
+    )]
+    #[cfg_attr(feature = "tabled", tabled(skip))]
+    pub effective_policy_definition: PolicyDefinition,
+
+    #[serde(default)]
+    pub message_bytes: u64,
+    #[serde(default)]
+    #[cfg_attr(feature = "tabled", tabled(skip))]
+    pub message_bytes_persistent: u64,
+    #[serde(default)]
+    #[cfg_attr(feature = "tabled", tabled(skip))]
+    pub message_bytes_ram: u64,
+    #[serde(default)]
+    #[cfg_attr(feature = "tabled", tabled(skip))]
+    pub message_bytes_ready: u64,
+    #[serde(default)]
+    #[cfg_attr(feature = "tabled", tabled(skip))]
+    pub message_bytes_unacknowledged: u64,
+
+    #[serde(rename(deserialize = "messages"))]
+    #[serde(default)]
+    pub message_count: u64,
+    #[serde(rename(deserialize = "messages_persistent"))]
+    #[serde(default)]
+    #[cfg_attr(feature = "tabled", tabled(skip))]
+    pub on_disk_message_count: u64,
+    #[serde(rename(deserialize = "messages_ram"))]
+    #[serde(default)]
+    #[cfg_attr(feature = "tabled", tabled(skip))]
+    pub in_memory_message_count: u64,
+    #[serde(rename(deserialize = "messages_unacknowledged"))]
+    #[serde(default)]
+    pub unacknowledged_message_count: u64,
+
+    // only classic queues report this, and only while they are idle
+    #[serde(default)]
+    #[cfg_attr(feature = "tabled", tabled(skip))]
+    pub idle_since: Option<String>,
+
+    // only quorum queues report these, and not all RabbitMQ versions do
+    /// The number of open (segment and WAL) files per cluster member
+    #[serde(default)]
+    #[cfg_attr(feature = "tabled", tabled(skip))]
+    pub open_files: Option<HashMap<String, u32>>,
+    /// The current Raft term
+    #[serde(default, rename(deserialize = "term"))]
+    #[cfg_attr(feature = "tabled", tabled(skip))]
+    pub raft_term: Option<u64>,
+    /// The index of the latest Raft log entry committed by a majority of members
+    #[serde(default)]
+    #[cfg_attr(feature = "tabled", tabled(skip))]
+    pub commit_index: Option<u64>,
+    /// The index of the latest Raft log entry applied to the queue state
+    #[serde(default, rename(deserialize = "last_applied"))]
+    #[cfg_attr(feature = "tabled", tabled(skip))]
+    pub last_applied_index: Option<u64>,
+    /// The size of the Raft log in bytes
+    #[serde(default)]
+    #[cfg_attr(feature = "tabled", tabled(skip))]
+    pub log_size: Option<u64>,
+
+    // only classic queues report this
+    #[serde(default)]
+    #[cfg_attr(feature = "tabled", tabled(skip))]
+    pub backing_queue_status: Option<BackingQueueStatus>,
+}
+
+impl QueueInfo {
+    pub fn has_consumers(&self) -> bool {
+        self.consumer_count > 0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.message_count == 0
+    }
+
+    /// Returns the number of committed Raft log entries that are yet to be applied
+    /// to the queue state. Only quorum queues on RabbitMQ versions that report
+    /// both indices have a value.
+    pub fn applied_index_lag(&self) -> Option<u64> {
+        match (self.commit_index, self.last_applied_index) {
+            (Some(committed), Some(applied)) => Some(committed.saturating_sub(applied)),
+            _ => None,
+        }
+    }
+
+    /// Returns the total number of open files across all quorum queue members.
+    pub fn total_open_files(&self) -> Option<u64> {
+        self.open_files
+            .as_ref()
+            .map(|m| m.values().map(|n| u64::from(*n)).sum())
+    }
+
+    /// Parses the `idle_since` timestamp. Returns `None` if the queue
+    /// is not idle or the timestamp is in an unknown format.
+    pub fn idle_since_timestamp(&self) -> Option<OffsetDateTime> {
+        let val = self.idle_since.as_deref()?;
+        if let Ok(ts) = OffsetDateTime::parse(val, &Rfc3339) {
+            return Some(ts);
+        }
+
+        // older RabbitMQ versions use a format such as "2025-05-16 10:20:30"
+        let legacy_format = format_description::parse_borrowed::<2>(
+            "[year]-[month]-[day] [hour]:[minute]:[second]",
+        )
+        .ok()?;
+        PrimitiveDateTime::parse(val, &legacy_format)
+            .ok()
+            .map(|ts| ts.assume_utc())
+    }
+
+    /// Returns true if the queue has been idle for longer than the given duration.
+    pub fn is_idle_longer_than(&self, duration: Duration) -> bool {
+        match self.idle_since_timestamp() {
+            Some(ts) => OffsetDateTime::now_utc() - ts > duration,
+            None => false,
+        }
+    }
+}
+
+/// Filtering helpers for queue listings, such as those returned by `list_queues`,
+/// meant to be chained, e.g. `queues.of_type(QueueType::Classic).without_consumers().empty()`.
+pub trait QueueInfoFilters {
+    /// Keeps queues that have no consumers.
+    fn without_consumers(self) -> Self;
+    /// Keeps queues that have been idle for longer than the given duration.
+    fn idle_longer_than(self, duration: Duration) -> Self;
+    /// Keeps queues that have no messages.
+    fn empty(self) -> Self;
+    /// Keeps queues to which the given policy applies.
+    fn matching_policy(self, name: &str) -> Self;
+    /// Keeps queues of the given type.
+    fn of_type(self, queue_type: QueueType) -> Self;
+}
+
+impl QueueInfoFilters for Vec<QueueInfo> {
+    fn without_consumers(self) -> Self {
+        self.into_iter().filter(|q| !q.has_consumers()).collect()
+    }
+
+    fn idle_longer_than(self, duration: Duration) -> Self {
+        self.into_iter()
+            .filter(|q| q.is_idle_longer_than(duration))
+            .collect()
+    }
+
+    fn empty(self) -> Self {
+        self.into_iter().filter(|q| q.is_empty()).collect()
+    }
+
+    fn matching_policy(self, name: &str) -> Self {
+        self.into_iter()
+            .filter(|q| q.policy.as_deref() == Some(name))
+            .collect()
+    }
+
+    fn of_type(self, queue_type: QueueType) -> Self {
+        self.into_iter()
+            .filter(|q| q.queue_type() == queue_type)
+            .collect()
+    }
+}
+
+/// Ordering helpers for "top N" queue reports.
+/// Every function returns at most `n` queues, in descending order.
+pub trait TopQueues {
+    fn top_by_message_count(self, n: usize) -> Self;
+    fn top_by_unacknowledged_message_count(self, n: usize) -> Self;
+    fn top_by_memory(self, n: usize) -> Self;
+    fn top_by_consumer_count(self, n: usize) -> Self;
+}
+
+impl TopQueues for Vec<QueueInfo> {
+    fn top_by_message_count(self, n: usize) -> Self {
+        top_by(self, n, |q| q.message_count)
+    }
+
+    fn top_by_unacknowledged_message_count(self, n: usize) -> Self {
+        top_by(self, n, |q| q.unacknowledged_message_count)
+    }
+
+    fn top_by_memory(self, n: usize) -> Self {
+        top_by(self, n, |q| q.memory)
+    }
+
+    fn top_by_consumer_count(self, n: usize) -> Self {
+        top_by(self, n, |q| q.consumer_count)
+    }
+}
+
+/// Ordering helpers for "top N" connection reports.
+/// Every function returns at most `n` connections, in descending order.
+pub trait TopConnections {
+    fn top_by_channel_count(self, n: usize) -> Self;
+    fn top_by_bytes_received(self, n: usize) -> Self;
+    fn top_by_bytes_sent(self, n: usize) -> Self;
+}
+
+impl TopConnections for Vec<Connection> {
+    fn top_by_channel_count(self, n: usize) -> Self {
+        top_by(self, n, |c| c.channel_count)
+    }
+
+    fn top_by_bytes_received(self, n: usize) -> Self {
+        top_by(self, n, |c| c.bytes_received)
+    }
+
+    fn top_by_bytes_sent(self, n: usize) -> Self {
+        top_by(self, n, |c| c.bytes_sent)
+    }
+}
+
+/// Ordering helpers for "top N" channel reports.
+/// Every function returns at most `n` channels, in descending order.
+pub trait TopChannels {
+    fn top_by_unacknowledged_message_count(self, n: usize) -> Self;
+    fn top_by_unconfirmed_message_count(self, n: usize) -> Self;
+    fn top_by_consumer_count(self, n: usize) -> Self;
+}
+
+impl TopChannels for Vec<Channel> {
+    fn top_by_unacknowledged_message_count(self, n: usize) -> Self {
+        top_by(self, n, |ch| ch.messages_unacknowledged)
+    }
+
+    fn top_by_unconfirmed_message_count(self, n: usize) -> Self {
+        top_by(self, n, |ch| ch.messages_unconfirmed)
+    }
+
+    fn top_by_consumer_count(self, n: usize) -> Self {
+        top_by(self, n, |ch| ch.consumer_count)
     }
 }
 
-pub trait QueueOps {
-    fn queue_type(&self) -> QueueType;
+impl QueueOps for QueueInfo {
+    fn queue_type(&self) -> QueueType {
+        QueueType::from(self.queue_type.as_str())
+    }
 
-    fn policy_target_type(&self) -> PolicyTarget;
+    fn policy_target_type(&self) -> PolicyTarget {
+        PolicyTarget::from(self.queue_type())
+    }
 }
 
-#[derive(Debug, Deserialize, Clone)]
+/// A lightweight alternative to [`QueueInfo`] that only includes the queue identity
+/// and message counts. Returned by `list_queue_summaries` and `list_queue_summaries_in`,
+/// which are meant to be used by monitoring loops that poll frequently.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
-#[allow(dead_code)]
-pub struct QueueInfo {
+pub struct QueueSummary {
     pub name: String,
     pub vhost: String,
     #[serde(rename(deserialize = "type"))]
     pub queue_type: String,
-    pub durable: bool,
-    pub auto_delete: bool,
-    pub exclusive: bool,
-    #[cfg_attr(feature = "tabled", tabled(display = "display_arg_table"))]
-    pub arguments: XArguments,
-
-    #[serde(default = "undefined")]
-    pub node: String,
-    #[serde(default)]
-    pub state: String,
-    // only quorum queues and streams will have this
-    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
-    pub leader: Option<String>,
-    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
-    pub members: Option<NodeList>,
-    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
-    pub online: Option<NodeList>,
-
-    #[serde(default)]
-    pub memory: u64,
-    #[serde(rename(deserialize = "consumers"))]
-    #[serde(default)]
-    pub consumer_count: u16,
-    #[serde(default)]
-    pub consumer_utilisation: f32,
-    #[cfg_attr(feature = "tabled", tabled(skip))]
-    pub exclusive_consumer_tag: Option<String>,
-
-    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
-    pub policy: Option<String>,
-
-    #[serde(default)]
-    pub message_bytes: u64,
-    #[serde(default)]
-    #[cfg_attr(feature = "tabled", tabled(skip))]
-    pub message_bytes_persistent: u64,
-    #[serde(default)]
-    #[cfg_attr(feature = "tabled", tabled(skip))]
-    pub message_bytes_ram: u64,
-    #[serde(default)]
-    #[cfg_attr(feature = "tabled", tabled(skip))]
-    pub message_bytes_ready: u64,
-    #[serde(default)]
-    #[cfg_attr(feature = "tabled", tabled(skip))]
-    pub message_bytes_unacknowledged: u64,
-
     #[serde(rename(deserialize = "messages"))]
     #[serde(default)]
     pub message_count: u64,
-    #[serde(rename(deserialize = "messages_persistent"))]
-    #[serde(default)]
-    #[cfg_attr(feature = "tabled", tabled(skip))]
-    pub on_disk_message_count: u64,
-    #[serde(rename(deserialize = "messages_ram"))]
+    #[serde(rename(deserialize = "messages_ready"))]
     #[serde(default)]
-    #[cfg_attr(feature = "tabled", tabled(skip))]
-    pub in_memory_message_count: u64,
+    pub ready_message_count: u64,
     #[serde(rename(deserialize = "messages_unacknowledged"))]
     #[serde(default)]
     pub unacknowledged_message_count: u64,
 }
 
-impl QueueOps for QueueInfo {
+impl QueueSummary {
+    #[cfg(any(feature = "async", feature = "blocking"))]
+    /// The columns requested from the HTTP API to populate this struct.
+    pub(crate) const COLUMNS: &'static str =
+        "name,vhost,type,messages,messages_ready,messages_unacknowledged";
+
+    pub fn is_empty(&self) -> bool {
+        self.message_count == 0
+    }
+}
+
+impl QueueOps for QueueSummary {
     fn queue_type(&self) -> QueueType {
         QueueType::from(self.queue_type.as_str())
     }
@@ -798,6 +1767,21 @@ impl QueueOps for QueueInfo {
     }
 }
 
+impl From<QueueInfo> for QueueSummary {
+    fn from(value: QueueInfo) -> Self {
+        QueueSummary {
+            ready_message_count: value
+                .message_count
+                .saturating_sub(value.unacknowledged_message_count),
+            name: value.name,
+            vhost: value.vhost,
+            queue_type: value.queue_type,
+            message_count: value.message_count,
+            unacknowledged_message_count: value.unacknowledged_message_count,
+        }
+    }
+}
+
 impl NamedPolicyTargetObject for QueueInfo {
     fn vhost(&self) -> String {
         self.vhost.clone()
@@ -817,6 +1801,7 @@ impl NamedPolicyTargetObject for QueueInfo {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 #[allow(dead_code)]
 pub struct QueueDefinition {
@@ -876,6 +1861,7 @@ impl QueueDefinition {
 /// The virtual host is omitted so that such objects can
 /// be imported into an arbitrary virtual host.
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 #[allow(dead_code)]
 pub struct QueueDefinitionWithoutVirtualHost {
@@ -903,6 +1889,7 @@ impl QueueOps for QueueDefinitionWithoutVirtualHost {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 #[allow(dead_code)]
 pub struct ExchangeInfo {
@@ -940,6 +1927,7 @@ impl NamedPolicyTargetObject for ExchangeDefinition {
 /// The virtual host is omitted so that such objects can
 /// be imported into an arbitrary virtual host.
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 #[allow(dead_code)]
 pub struct ExchangeInfoWithoutVirtualHost {
@@ -955,6 +1943,7 @@ pub struct ExchangeInfoWithoutVirtualHost {
 pub type ExchangeDefinitionWithoutVirtualHost = ExchangeInfoWithoutVirtualHost;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 #[allow(dead_code)]
 pub struct BindingInfo {
@@ -971,10 +1960,38 @@ pub struct BindingInfo {
 
 pub type BindingDefinition = BindingInfo;
 
+/// Filtering combinators for binding listings, e.g. the result of `Client#list_bindings`.
+pub trait BindingInfoFilters {
+    /// Keeps bindings with the given destination type (queues or exchanges).
+    fn with_destination_type(self, destination_type: BindingDestinationType) -> Self;
+    /// Keeps bindings of the given source exchange. Use an empty name
+    /// for the default exchange.
+    fn with_source(self, exchange: &str) -> Self;
+    /// Keeps bindings of the given destination queue or exchange.
+    fn with_destination(self, name: &str) -> Self;
+}
+
+impl BindingInfoFilters for Vec<BindingInfo> {
+    fn with_destination_type(self, destination_type: BindingDestinationType) -> Self {
+        self.into_iter()
+            .filter(|b| b.destination_type == destination_type)
+            .collect()
+    }
+
+    fn with_source(self, exchange: &str) -> Self {
+        self.into_iter().filter(|b| b.source == exchange).collect()
+    }
+
+    fn with_destination(self, name: &str) -> Self {
+        self.into_iter().filter(|b| b.destination == name).collect()
+    }
+}
+
 /// Used in virtual host-specific definitions.
 /// The virtual host is omitted so that such objects can
 /// be imported into an arbitrary virtual host.
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 #[allow(dead_code)]
 pub struct BindingInfoWithoutVirtualHost {
@@ -990,11 +2007,13 @@ pub struct BindingInfoWithoutVirtualHost {
 
 pub type BindingDefinitionWithoutVirtualHost = BindingInfoWithoutVirtualHost;
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 #[allow(dead_code)]
 pub struct ClusterNode {
     pub name: String,
+    pub running: bool,
     pub uptime: u32,
     pub run_queue: u32,
     pub processors: u32,
@@ -1011,12 +2030,24 @@ pub struct ClusterNode {
     pub free_disk_space_low_watermark: u64,
     #[serde(rename(deserialize = "disk_free_alarm"))]
     pub has_free_disk_space_alarm_in_effect: bool,
-    pub rates_mode: String,
+    pub rates_mode: RatesMode,
     pub enabled_plugins: PluginList,
     pub being_drained: bool,
 }
 
+impl ClusterNode {
+    /// Returns the hostname part of the node name, e.g. `node1.eng.megacorp.local`
+    /// for `rabbit@node1.eng.megacorp.local`.
+    pub fn hostname(&self) -> &str {
+        match self.name.split_once('@') {
+            Some((_, hostname)) => hostname,
+            None => &self.name,
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 #[allow(dead_code)]
 pub struct RuntimeParameter {
@@ -1031,6 +2062,7 @@ pub struct RuntimeParameter {
 /// The virtual host is omitted so that such objects can
 /// be imported into an arbitrary virtual host.
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 #[allow(dead_code)]
 pub struct RuntimeParameterWithoutVirtualHost {
@@ -1041,6 +2073,7 @@ pub struct RuntimeParameterWithoutVirtualHost {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 #[allow(dead_code)]
 pub struct GlobalRuntimeParameter {
@@ -1049,12 +2082,14 @@ pub struct GlobalRuntimeParameter {
 }
 
 #[derive(Debug, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[allow(dead_code)]
 pub struct ClusterIdentity {
     pub name: String,
 }
 
 #[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[allow(dead_code)]
 pub struct ClusterTags(pub Map<String, serde_json::Value>);
 
@@ -1064,6 +2099,26 @@ impl From<GlobalRuntimeParameterValue> for ClusterTags {
     }
 }
 
+/// Mappings of client certificate distinguished names (or usernames) to the virtual hosts
+/// MQTT clients connect to, stored in the `mqtt_default_vhosts` global runtime parameter.
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[allow(dead_code)]
+pub struct MqttDefaultVirtualHosts(pub Map<String, serde_json::Value>);
+
+impl MqttDefaultVirtualHosts {
+    /// The virtual host mapped to the given distinguished name or username, if any.
+    pub fn vhost_for(&self, name: &str) -> Option<&str> {
+        self.0.get(name).and_then(|v| v.as_str())
+    }
+}
+
+impl From<GlobalRuntimeParameterValue> for MqttDefaultVirtualHosts {
+    fn from(value: GlobalRuntimeParameterValue) -> Self {
+        MqttDefaultVirtualHosts(value.0.as_object().cloned().unwrap_or_default())
+    }
+}
+
 pub trait PolicyDefinitionOps {
     fn has_cmq_keys(&self) -> bool;
 
@@ -1074,7 +2129,8 @@ pub trait PolicyDefinitionOps {
     fn without_cmq_keys(&self) -> Self;
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct PolicyDefinition(pub Option<Map<String, serde_json::Value>>);
 
 impl PolicyDefinition {
@@ -1156,6 +2212,7 @@ impl fmt::Display for PolicyDefinition {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 #[allow(dead_code)]
 pub struct Policy {
@@ -1280,6 +2337,7 @@ impl PolicyWithoutVirtualHost {
 /// The virtual host is omitted so that such objects can
 /// be imported into an arbitrary virtual host.
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 #[allow(dead_code)]
 pub struct PolicyWithoutVirtualHost {
@@ -1300,6 +2358,7 @@ pub trait NamedPolicyTargetObject {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 #[allow(dead_code)]
 pub struct Permissions {
@@ -1322,8 +2381,52 @@ impl Permissions {
     }
 }
 
+/// Name of the deprecated feature used by policies with classic queue mirroring keys.
+pub const DEPRECATED_FEATURE_CLASSIC_QUEUE_MIRRORING: &str = "classic_queue_mirroring";
+/// Name of the deprecated feature used by non-durable queues. Exclusive queues
+/// are never exported, so all non-durable queues in a definition set are non-exclusive.
+pub const DEPRECATED_FEATURE_TRANSIENT_NON_EXCLUSIVE_QUEUES: &str = "transient_nonexcl_queues";
+
+/// A summary of a [`ClusterDefinitionSet`], see [`ClusterDefinitionSet::summary`].
+#[derive(Debug, Serialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "tabled", derive(Tabled))]
+pub struct DefinitionSetSummary {
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub server_version: Option<String>,
+    pub user_count: usize,
+    pub virtual_host_count: usize,
+    pub permission_count: usize,
+    pub runtime_parameter_count: usize,
+    pub policy_count: usize,
+    pub exchange_count: usize,
+    pub classic_queue_count: usize,
+    pub quorum_queue_count: usize,
+    pub stream_count: usize,
+    /// Queues of types other than classic, quorum, and stream
+    pub other_queue_count: usize,
+    pub binding_count: usize,
+    /// Names of the [deprecated features](https://www.rabbitmq.com/docs/deprecated-features) used by the definitions
+    #[cfg_attr(feature = "tabled", tabled(display = "display_string_list"))]
+    pub deprecated_features: Vec<String>,
+}
+
+impl DefinitionSetSummary {
+    pub fn queue_count(&self) -> usize {
+        self.classic_queue_count
+            + self.quorum_queue_count
+            + self.stream_count
+            + self.other_queue_count
+    }
+
+    pub fn uses_deprecated_features(&self) -> bool {
+        !self.deprecated_features.is_empty()
+    }
+}
+
 /// Represents definitions of an entire cluster (all virtual hosts).
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[allow(dead_code)]
 pub struct ClusterDefinitionSet {
     #[serde(rename(deserialize = "rabbitmq_version"))]
@@ -1342,6 +2445,47 @@ pub struct ClusterDefinitionSet {
 }
 
 impl ClusterDefinitionSet {
+    /// Summarizes this definition set, e.g. for a quick review before importing it.
+    pub fn summary(&self) -> DefinitionSetSummary {
+        let mut summary = DefinitionSetSummary {
+            server_version: self.server_version.clone(),
+            user_count: self.users.len(),
+            virtual_host_count: self.virtual_hosts.len(),
+            permission_count: self.permissions.len(),
+            runtime_parameter_count: self.parameters.len(),
+            policy_count: self.policies.len(),
+            exchange_count: self.exchanges.len(),
+            classic_queue_count: 0,
+            quorum_queue_count: 0,
+            stream_count: 0,
+            other_queue_count: 0,
+            binding_count: self.bindings.len(),
+            deprecated_features: Vec::new(),
+        };
+
+        for q in &self.queues {
+            match q.queue_type() {
+                QueueType::Classic => summary.classic_queue_count += 1,
+                QueueType::Quorum => summary.quorum_queue_count += 1,
+                QueueType::Stream => summary.stream_count += 1,
+                _ => summary.other_queue_count += 1,
+            }
+        }
+
+        if self.policies.iter().any(|p| p.has_cmq_keys()) {
+            summary
+                .deprecated_features
+                .push(DEPRECATED_FEATURE_CLASSIC_QUEUE_MIRRORING.to_owned());
+        }
+        if self.queues.iter().any(|q| !q.durable) {
+            summary
+                .deprecated_features
+                .push(DEPRECATED_FEATURE_TRANSIENT_NON_EXCLUSIVE_QUEUES.to_owned());
+        }
+
+        summary
+    }
+
     pub fn find_policy(&self, vhost: &str, name: &str) -> Option<&Policy> {
         self.policies
             .iter()
@@ -1448,8 +2592,24 @@ impl ClusterDefinitionSet {
     }
 }
 
+/// The topology of a single virtual host: its queues, exchanges, bindings,
+/// policies and runtime parameters, as returned by `Client#get_vhost_topology`.
+///
+/// Unlike [`VirtualHostDefinitionSet`], the objects include their runtime state
+/// (e.g. queue message counts).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct VirtualHostTopology {
+    pub vhost: String,
+    pub queues: Vec<QueueInfo>,
+    pub exchanges: Vec<ExchangeInfo>,
+    pub bindings: Vec<BindingInfo>,
+    pub policies: Vec<Policy>,
+    pub parameters: Vec<RuntimeParameter>,
+}
+
 /// Represents definitions of a single virtual host.
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[allow(dead_code)]
 pub struct VirtualHostDefinitionSet {
     #[serde(rename(deserialize = "rabbitmq_version"))]
@@ -1465,13 +2625,40 @@ pub struct VirtualHostDefinitionSet {
     pub bindings: Vec<BindingDefinitionWithoutVirtualHost>,
 }
 
+/// The result of an aliveness test, see `Client#aliveness_test`.
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct AlivenessTestResult {
+    /// `ok` or `failed`
+    pub status: String,
+    /// Why the test has failed
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+impl AlivenessTestResult {
+    pub fn is_ok(&self) -> bool {
+        self.status == "ok"
+    }
+}
+
 #[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[serde(untagged)]
 pub enum HealthCheckFailureDetails {
     AlarmCheck(ClusterAlarmCheckDetails),
     NodeIsQuorumCritical(QuorumCriticalityCheckDetails),
+    NodeIsMirrorSyncCritical(MirrorSyncCriticalityCheckDetails),
+    CertificateExpiration(CertificateExpirationCheckDetails),
+    VirtualHostsDown(VirtualHostsCheckDetails),
     NoActivePortListener(NoActivePortListenerDetails),
     NoActiveProtocolListener(NoActiveProtocolListenerDetails),
+    /// Only produced by `Client#health_check_quorum_queues_without_elected_leaders`
+    /// and its variants: the response has the same shape as that of the quorum criticality check.
+    QuorumQueuesWithoutElectedLeaders(QuorumQueuesWithoutElectedLeadersCheckDetails),
+    /// Failure details of a check this library does not know about, or that
+    /// could not be parsed into one of the variants above. Must remain the last variant.
+    Raw(Map<String, serde_json::Value>),
 }
 
 impl HealthCheckFailureDetails {
@@ -1479,40 +2666,130 @@ impl HealthCheckFailureDetails {
         match self {
             HealthCheckFailureDetails::AlarmCheck(details) => details.reason.clone(),
             HealthCheckFailureDetails::NodeIsQuorumCritical(details) => details.reason.clone(),
+            HealthCheckFailureDetails::NodeIsMirrorSyncCritical(details) => details.reason.clone(),
             HealthCheckFailureDetails::NoActivePortListener(details) => details.reason.clone(),
             HealthCheckFailureDetails::NoActiveProtocolListener(details) => details.reason.clone(),
+            HealthCheckFailureDetails::CertificateExpiration(details) => details.reason.clone(),
+            HealthCheckFailureDetails::VirtualHostsDown(details) => details.reason.clone(),
+            HealthCheckFailureDetails::QuorumQueuesWithoutElectedLeaders(details) => {
+                details.reason.clone()
+            }
+            HealthCheckFailureDetails::Raw(body) => body
+                .get("reason")
+                .and_then(serde_json::Value::as_str)
+                .unwrap_or_default()
+                .to_owned(),
         }
     }
+
+    /// Parses a failure response body. Bodies that do not match any of the known
+    /// failure detail types are returned as [`HealthCheckFailureDetails::Raw`].
+    pub fn from_body(body: &str) -> Self {
+        serde_json::from_str(body).unwrap_or_else(|_| Self::raw(body))
+    }
+
+    /// Wraps a failure response body that is not a JSON object, e.g. a plain text one.
+    pub fn raw(body: &str) -> Self {
+        let mut m = Map::new();
+        m.insert(
+            "reason".to_owned(),
+            serde_json::Value::String(body.to_owned()),
+        );
+        HealthCheckFailureDetails::Raw(m)
+    }
 }
 
 #[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct ClusterAlarmCheckDetails {
     pub reason: String,
     pub alarms: Vec<ResourceAlarm>,
 }
 
 #[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct ResourceAlarm {
     pub node: String,
     pub resource: String,
 }
 
 #[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct QuorumCriticalityCheckDetails {
     pub reason: String,
     pub queues: Vec<QuorumEndangeredQueue>,
 }
 
 #[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct QuorumQueuesWithoutElectedLeadersCheckDetails {
+    pub reason: String,
+    /// Quorum queues that currently do not have an elected leader
+    pub queues: Vec<QueueWithoutElectedLeader>,
+}
+
+#[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct QueueWithoutElectedLeader {
+    pub name: String,
+    #[serde(default)]
+    pub readable_name: Option<String>,
+    #[serde(rename(deserialize = "virtual_host"))]
+    pub vhost: String,
+    #[serde(rename(deserialize = "type"), default)]
+    pub queue_type: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct MirrorSyncCriticalityCheckDetails {
+    pub reason: String,
+    pub queues: Vec<MirrorSyncEndangeredQueue>,
+}
+
+#[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct CertificateExpirationCheckDetails {
+    pub reason: String,
+    /// Listeners with certificates that expire within the requested time window
+    pub expired: Vec<ListenerWithExpiringCertificate>,
+}
+
+#[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct ListenerWithExpiringCertificate {
+    pub node: String,
+    pub protocol: String,
+    #[serde(default)]
+    pub interface: Option<String>,
+    pub port: u16,
+    #[serde(default)]
+    pub certfile: Option<String>,
+    #[serde(default)]
+    pub cacertfile: Option<String>,
+    #[serde(default)]
+    pub expiry_date: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct VirtualHostsCheckDetails {
+    pub reason: String,
+    #[serde(rename(deserialize = "virtual-hosts"))]
+    pub virtual_hosts: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct NoActivePortListenerDetails {
     pub status: String,
     pub reason: String,
     #[serde(rename(deserialize = "missing"))]
-    #[serde(default)]
     pub inactive_port: u16,
 }
 
 #[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct NoActiveProtocolListenerDetails {
     pub status: String,
     pub reason: String,
@@ -1524,6 +2801,7 @@ pub struct NoActiveProtocolListenerDetails {
 }
 
 #[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct QuorumEndangeredQueue {
     pub name: String,
     pub readable_name: String,
@@ -1533,7 +2811,17 @@ pub struct QuorumEndangeredQueue {
     pub queue_type: String,
 }
 
+#[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct MirrorSyncEndangeredQueue {
+    pub name: String,
+    pub readable_name: String,
+    #[serde(rename(deserialize = "virtual_host"))]
+    pub vhost: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 #[allow(dead_code)]
 pub struct GetMessage {
@@ -1545,10 +2833,25 @@ pub struct GetMessage {
     #[serde(deserialize_with = "deserialize_message_properties")]
     pub properties: MessageProperties,
     pub payload: String,
-    pub payload_encoding: String,
+    pub payload_encoding: PayloadEncoding,
+}
+
+impl GetMessage {
+    /// Returns the payload as bytes, decoding it first if it is Base64-encoded.
+    pub fn decoded_payload(&self) -> Result<Vec<u8>, ConversionError> {
+        match self.payload_encoding {
+            PayloadEncoding::String => Ok(self.payload.as_bytes().to_vec()),
+            PayloadEncoding::Base64 => {
+                rbase64::decode(&self.payload).map_err(|e| ConversionError::InvalidPayload {
+                    reason: e.to_string(),
+                })
+            }
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[serde(transparent)]
 pub struct MessageList(pub Vec<GetMessage>);
 
@@ -1563,35 +2866,102 @@ impl PartialEq for MessageList {
     }
 }
 
-#[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 pub struct MessageRouted {
     pub routed: bool,
 }
 
+/// Properties of a message fetched using [`GetMessage`].
+///
+/// Well-known AMQP 0-9-1 properties are exposed as typed fields,
+/// any other keys are collected into `extras`.
 #[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq, Default)]
-#[serde(transparent)]
-pub struct MessageProperties(pub Map<String, serde_json::Value>);
-
-#[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct MessageProperties {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_encoding: Option<String>,
+    /// 1 for transient messages, 2 for persistent ones
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_mode: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correlation_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiration: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headers: Option<Map<String, serde_json::Value>>,
+    /// Properties that do not have a dedicated field, e.g. `app_id` or `type`
+    #[serde(flatten)]
+    pub extras: Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 pub struct ChurnRates {
     pub connection_created: u32,
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option_details_rate"))]
+    pub connection_created_details: Option<Rate>,
     pub connection_closed: u32,
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option_details_rate"))]
+    pub connection_closed_details: Option<Rate>,
     pub queue_declared: u32,
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option_details_rate"))]
+    pub queue_declared_details: Option<Rate>,
     pub queue_created: u32,
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option_details_rate"))]
+    pub queue_created_details: Option<Rate>,
     pub queue_deleted: u32,
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option_details_rate"))]
+    pub queue_deleted_details: Option<Rate>,
     pub channel_created: u32,
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option_details_rate"))]
+    pub channel_created_details: Option<Rate>,
     pub channel_closed: u32,
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option_details_rate"))]
+    pub channel_closed_details: Option<Rate>,
 }
 
-#[derive(Debug, Deserialize, Clone, PartialEq, PartialOrd)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 pub struct Rate {
     pub rate: f64,
+    /// Only included when samples were requested, e.g. using the `msg_rates_age`
+    /// and `msg_rates_incr` query parameters
+    #[serde(default)]
+    #[cfg_attr(feature = "tabled", tabled(skip))]
+    pub samples: Option<Vec<RateSample>>,
+    #[serde(default)]
+    #[cfg_attr(feature = "tabled", tabled(skip))]
+    pub avg: Option<f64>,
+    #[serde(default)]
+    #[cfg_attr(feature = "tabled", tabled(skip))]
+    pub avg_rate: Option<f64>,
 }
 
-#[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+/// A single data point of a [`Rate`].
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct RateSample {
+    pub sample: u64,
+    /// A timestamp in milliseconds
+    pub timestamp: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 pub struct ObjectTotals {
     pub connections: u64,
@@ -1601,7 +2971,8 @@ pub struct ObjectTotals {
     pub consumers: u64,
 }
 
-#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 pub struct QueueTotals {
     pub messages: u64,
@@ -1616,9 +2987,43 @@ pub struct QueueTotals {
     pub messages_delivered_but_unacknowledged_by_consumers_details: Rate,
 }
 
-#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 pub struct MessageStats {
+    /// Total number of messages delivered to consumers plus polled (via 'basic.get')
+    #[serde(rename = "deliver_get")]
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub delivery_count: Option<u64>,
+    /// Total number of messages published
+    #[serde(rename = "publish")]
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub publishing_count: Option<u64>,
+    /// Total number of messages delivered to consumers in automatic acknowledgement mode
+    #[serde(rename = "deliver_no_ack")]
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub delivery_with_automatic_acknowledgement_count: Option<u64>,
+    /// Total number of messages redelivered
+    #[serde(rename = "redeliver")]
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub redelivery_count: Option<u64>,
+    /// Total number of messages confirmed to publishers
+    #[serde(rename = "confirm")]
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub publisher_confirmation_count: Option<u64>,
+    /// Total number of messages acknowledged by consumers
+    #[serde(rename = "ack")]
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub consumer_acknowledgement_count: Option<u64>,
+    /// Total number of unroutable messages that were dropped
+    #[serde(rename = "drop_unroutable")]
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub unroutable_dropped_message_count: Option<u64>,
+    /// Total number of unroutable messages that were returned to publishers
+    #[serde(rename = "return_unroutable")]
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub unroutable_returned_message_count: Option<u64>,
+
     /// Consumer delivery rate plus polling (via 'basic.get') rate
     #[serde(rename = "deliver_get_details")]
     #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
@@ -1649,21 +3054,35 @@ pub struct MessageStats {
     pub unroutable_returned_message_details: Option<Rate>,
 }
 
-#[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 pub struct Listener {
-    node: String,
-    protocol: String,
-    port: u32,
+    /// The node this listener runs on
+    pub node: String,
+    /// The protocol as reported by the server, e.g. `amqp` or `http/prometheus`,
+    /// see [`Listener::supported_protocol`]
+    pub protocol: String,
+    pub port: u32,
+    /// The interface (IP address) this listener is bound to
     #[serde(rename(deserialize = "ip_address"))]
-    interface: String,
+    pub interface: String,
+}
+
+impl Listener {
+    /// Returns the protocol of this listener as a [`SupportedProtocol`].
+    pub fn supported_protocol(&self) -> SupportedProtocol {
+        SupportedProtocol::from(self.protocol.as_str())
+    }
 }
 
-#[derive(Debug, Deserialize, Clone, Eq, PartialEq, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq, Default)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[serde(transparent)]
 pub struct TagMap(pub Map<String, serde_json::Value>);
 
-#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 pub struct Overview {
     pub cluster_name: String,
@@ -1682,14 +3101,21 @@ pub struct Overview {
     pub node_tags: Option<TagMap>,
 
     pub statistics_db_event_queue: u64,
+    #[serde(default)]
+    pub rates_mode: RatesMode,
     pub churn_rates: ChurnRates,
 
     pub queue_totals: QueueTotals,
     pub object_totals: ObjectTotals,
     pub message_stats: MessageStats,
+
+    #[serde(default)]
+    #[cfg_attr(feature = "tabled", tabled(skip))]
+    pub listeners: Vec<Listener>,
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum FeatureFlagState {
     Enabled,
@@ -1745,6 +3171,7 @@ impl fmt::Display for FeatureFlagState {
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum FeatureFlagStability {
     Required,
@@ -1797,6 +3224,7 @@ impl fmt::Display for FeatureFlagStability {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 #[allow(dead_code)]
 pub struct FeatureFlag {
@@ -1810,11 +3238,13 @@ pub struct FeatureFlag {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[serde(transparent)]
 pub struct FeatureFlagList(pub Vec<FeatureFlag>);
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
-#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(from = "String", into = "String")]
 pub enum DeprecationPhase {
     PermittedByDefault,
     DeniedByDefault,
@@ -1862,11 +3292,11 @@ impl From<DeprecationPhase> for String {
 impl fmt::Display for DeprecationPhase {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            DeprecationPhase::PermittedByDefault => writeln!(f, "permitted_by_default")?,
-            DeprecationPhase::DeniedByDefault => writeln!(f, "denied_by_default")?,
-            DeprecationPhase::Disconnected => writeln!(f, "disconnected")?,
-            DeprecationPhase::Removed => writeln!(f, "removed")?,
-            DeprecationPhase::Undefined => writeln!(f, "undefined")?,
+            DeprecationPhase::PermittedByDefault => write!(f, "permitted_by_default")?,
+            DeprecationPhase::DeniedByDefault => write!(f, "denied_by_default")?,
+            DeprecationPhase::Disconnected => write!(f, "disconnected")?,
+            DeprecationPhase::Removed => write!(f, "removed")?,
+            DeprecationPhase::Undefined => write!(f, "undefined")?,
         }
 
         Ok(())
@@ -1874,6 +3304,7 @@ impl fmt::Display for DeprecationPhase {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 #[allow(dead_code)]
 pub struct DeprecatedFeature {
@@ -1886,10 +3317,12 @@ pub struct DeprecatedFeature {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[serde(transparent)]
 pub struct DeprecatedFeatureList(pub Vec<DeprecatedFeature>);
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum OperatingMode {
     Upstream,
@@ -1906,6 +3339,7 @@ impl From<OperatingMode> for String {
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum SchemaDefinitionSyncState {
     Recover,
@@ -1941,6 +3375,7 @@ impl From<SchemaDefinitionSyncState> for String {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[serde(transparent)]
 pub struct HostnamePortPairs(pub Vec<String>);
 
@@ -1951,6 +3386,7 @@ impl From<HostnamePortPairs> for String {
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub enum MessagingProtocol {
     #[serde(rename = "amqp091")]
     Amqp091,
@@ -1990,7 +3426,8 @@ impl From<MessagingProtocol> for String {
 // Federation
 //
 
-#[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 #[serde(rename_all = "kebab-case")]
 #[allow(dead_code)]
@@ -2004,6 +3441,11 @@ pub struct FederationUpstream {
     #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
     pub reconnect_delay: Option<u32>,
     #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub prefetch_count: Option<u32>,
+    #[serde(rename = "bind-nowait")]
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub bind_using_nowait: Option<bool>,
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
     pub queue: Option<String>,
     #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
     pub consumer_tag: Option<String>,
@@ -2026,7 +3468,8 @@ impl TryFrom<RuntimeParameter> for FederationUpstream {
         let uri = param
             .value
             .get("uri")
-            .map(|v| v.as_str().unwrap().to_owned())
+            .and_then(|v| v.as_str())
+            .map(str::to_owned)
             .ok_or_else(|| ConversionError::MissingProperty {
                 argument: "uri".to_owned(),
             })?;
@@ -2045,6 +3488,15 @@ impl TryFrom<RuntimeParameter> for FederationUpstream {
             .value
             .get("trust-user-id")
             .map(|v| v.as_bool().unwrap_or_default());
+        let prefetch_count = param
+            .value
+            .get("prefetch-count")
+            .and_then(|v| v.as_u64())
+            .map(|v| v as u32);
+        let bind_using_nowait = param
+            .value
+            .get("bind-nowait")
+            .map(|v| v.as_bool().unwrap_or_default());
 
         let exchange = param
             .value
@@ -2084,6 +3536,8 @@ impl TryFrom<RuntimeParameter> for FederationUpstream {
             ack_mode,
             trust_user_id,
             reconnect_delay,
+            prefetch_count,
+            bind_using_nowait,
             exchange,
             max_hops,
             expires,
@@ -2097,7 +3551,8 @@ impl TryFrom<RuntimeParameter> for FederationUpstream {
     }
 }
 
-#[derive(Debug, Deserialize, Clone, Eq, PartialEq, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq, Default)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum FederationType {
     #[default]
@@ -2124,7 +3579,8 @@ impl From<String> for FederationType {
     }
 }
 
-#[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 #[allow(dead_code)]
 pub struct FederationLink {
@@ -2146,6 +3602,7 @@ pub struct FederationLink {
 //
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum ShovelType {
     Dynamic,
@@ -2181,10 +3638,14 @@ impl From<ShovelType> for String {
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum ShovelState {
     Starting,
     Running,
+    /// The shovel has stopped, e.g. because it could not connect to the source
+    /// or destination. Such shovels can be restarted using `Client#restart_shovel`.
+    Terminated,
     Unknown,
 }
 
@@ -2193,6 +3654,7 @@ impl fmt::Display for ShovelState {
         match self {
             ShovelState::Starting => write!(f, "starting"),
             ShovelState::Running => write!(f, "running"),
+            ShovelState::Terminated => write!(f, "terminated"),
             ShovelState::Unknown => write!(f, "unknown"),
         }
     }
@@ -2203,6 +3665,7 @@ impl From<String> for ShovelState {
         match value.as_str() {
             "starting" => ShovelState::Starting,
             "running" => ShovelState::Running,
+            "terminated" => ShovelState::Terminated,
             _ => ShovelState::Unknown,
         }
     }
@@ -2213,15 +3676,19 @@ impl From<ShovelState> for String {
         match value {
             ShovelState::Starting => "starting".to_owned(),
             ShovelState::Running => "running".to_owned(),
+            ShovelState::Terminated => "terminated".to_owned(),
             ShovelState::Unknown => "unknown".to_owned(),
         }
     }
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
-#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(from = "String", into = "String")]
 pub enum ShovelPublishingState {
     Running,
+    /// Publishing is throttled by flow control
+    Flow,
     Blocked,
     Unknown,
 }
@@ -2230,6 +3697,7 @@ impl fmt::Display for ShovelPublishingState {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             ShovelPublishingState::Running => write!(f, "running"),
+            ShovelPublishingState::Flow => write!(f, "flow"),
             ShovelPublishingState::Blocked => write!(f, "blocked"),
             ShovelPublishingState::Unknown => write!(f, "unknown"),
         }
@@ -2240,6 +3708,7 @@ impl From<String> for ShovelPublishingState {
     fn from(value: String) -> Self {
         match value.as_str() {
             "running" => ShovelPublishingState::Running,
+            "flow" => ShovelPublishingState::Flow,
             "blocked" => ShovelPublishingState::Blocked,
             _ => ShovelPublishingState::Unknown,
         }
@@ -2250,13 +3719,15 @@ impl From<ShovelPublishingState> for String {
     fn from(value: ShovelPublishingState) -> Self {
         match value {
             ShovelPublishingState::Running => "running".to_owned(),
+            ShovelPublishingState::Flow => "flow".to_owned(),
             ShovelPublishingState::Blocked => "blocked".to_owned(),
             ShovelPublishingState::Unknown => "unknown".to_owned(),
         }
     }
 }
 
-#[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 #[allow(dead_code)]
 pub struct Shovel {
@@ -2295,9 +3766,23 @@ pub struct Shovel {
     #[serde(rename = "dest_protocol")]
     #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
     pub destination_protocol: Option<MessagingProtocol>,
+
+    /// Whether publishing to the destination is blocked, e.g. by a resource alarm
+    #[serde(default, rename = "blocked_status")]
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub publishing_state: Option<ShovelPublishingState>,
+    /// Why the shovel has terminated, only reported for terminated shovels
+    #[serde(default, rename = "reason")]
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub last_error: Option<String>,
+    /// When the shovel state last changed, e.g. `2025-05-20 10:15:00`
+    #[serde(default)]
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub timestamp: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 #[allow(dead_code)]
 pub struct SchemaDefinitionSyncStatus {
@@ -2315,6 +3800,7 @@ pub struct SchemaDefinitionSyncStatus {
         rename = "last_connection_completion_stamp",
         with = "time::serde::timestamp::option"
     )]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<i64>"))]
     pub last_connection_completion_timestamp: Option<OffsetDateTime>,
     #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
     #[serde(
@@ -2322,10 +3808,12 @@ pub struct SchemaDefinitionSyncStatus {
         rename = "last_sync_request_stamp",
         with = "time::serde::timestamp::option"
     )]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<i64>"))]
     pub last_sync_request_timestamp: Option<OffsetDateTime>,
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[serde(rename_all = "snake_case")]
 /// Represents a running WSR link state
 pub enum WarmStandbyReplicationStateOnUpstream {
@@ -2335,6 +3823,7 @@ pub enum WarmStandbyReplicationStateOnUpstream {
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[serde(rename_all = "snake_case")]
 /// Represents a running WSR link state
 pub enum WarmStandbyReplicationLinkStateOnDownstream {
@@ -2347,6 +3836,7 @@ pub enum WarmStandbyReplicationLinkStateOnDownstream {
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[serde(untagged)]
 /// Represents a running WSR link state
 pub enum WarmStandbyReplicationState {
@@ -2454,6 +3944,7 @@ impl From<WarmStandbyReplicationState> for String {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 #[allow(dead_code)]
 pub struct WarmStandbyReplicationInVirtualHost {
@@ -2486,15 +3977,54 @@ impl fmt::Display for WarmStandbyReplicationInVirtualHost {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[serde(transparent)]
 pub struct WarmStandbyReplicationStatus {
     pub virtual_hosts: Vec<WarmStandbyReplicationInVirtualHost>,
 }
 
+/// A deserialized response body along with the HTTP response metadata:
+/// status code, headers, and the time it took to receive the response.
+///
+/// Returned by the `*_with_response` client functions, which are useful when
+/// the caller needs to inspect things like deprecation warnings or correlation IDs
+/// set by proxies in front of the HTTP API.
+#[cfg(any(feature = "async", feature = "blocking"))]
+#[derive(Debug, Clone)]
+pub struct ResponseEnvelope<T> {
+    pub body: T,
+    pub status_code: reqwest::StatusCode,
+    pub headers: reqwest::header::HeaderMap,
+    pub duration: std::time::Duration,
+}
+
+#[cfg(any(feature = "async", feature = "blocking"))]
+impl<T> ResponseEnvelope<T> {
+    /// Returns the value of the given header, if it is present and is valid UTF-8.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|val| val.to_str().ok())
+    }
+
+    /// Discards the metadata and returns the response body.
+    pub fn into_body(self) -> T {
+        self.body
+    }
+}
+
 //
 // Implementation
 //
 
+fn top_by<T, K, F>(mut xs: Vec<T>, n: usize, key: F) -> Vec<T>
+where
+    K: Ord,
+    F: Fn(&T) -> K,
+{
+    xs.sort_by_key(|x| std::cmp::Reverse(key(x)));
+    xs.truncate(n);
+    xs
+}
+
 fn undefined() -> String {
     "?".to_string()
 }
@@ -2538,6 +4068,19 @@ where
     })
 }
 
+fn empty_policy_definition() -> PolicyDefinition {
+    PolicyDefinition(None)
+}
+
+// an empty definition can be returned as an empty list
+fn deserialize_policy_definition<'de, D>(deserializer: D) -> Result<PolicyDefinition, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let m = deserialize_map_or_seq::<Map<String, serde_json::Value>, D>(deserializer)?;
+    Ok(PolicyDefinition(if m.is_empty() { None } else { Some(m) }))
+}
+
 fn deserialize_message_properties<'de, D>(deserializer: D) -> Result<MessageProperties, D::Error>
 where
     D: serde::Deserializer<'de>,
@@ -0,0 +1,243 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Generates synthetic topologies of a given size, e.g. to benchmark a broker or
+//! to test monitoring tools against a cluster with many queues.
+//!
+//! The generated topology is a [`ClusterDefinitionSet`] that can be declared using
+//! `Client#declare_topology` and removed using `Client#delete_topology`.
+
+use serde_json::{json, Map, Value};
+
+use crate::commons::{BindingDestinationType, ExchangeType, PolicyTarget, QueueType};
+use crate::responses::{
+    BindingDefinition, ClusterDefinitionSet, ExchangeDefinition, Policy, PolicyDefinition,
+    QueueDefinition, XArguments,
+};
+
+/// The placeholder replaced with the object index in naming templates.
+pub const INDEX_PLACEHOLDER: &str = "{n}";
+
+pub const DEFAULT_QUEUE_NAME_TEMPLATE: &str = "generated.q.{n}";
+pub const DEFAULT_EXCHANGE_NAME_TEMPLATE: &str = "generated.x.{n}";
+pub const DEFAULT_POLICY_NAME_TEMPLATE: &str = "generated.policy.{n}";
+
+/// Generates queues, exchanges, bindings and policies in a single virtual host.
+///
+/// Object names are produced from templates where `{n}` is replaced with
+/// the index of the object, starting at 0.
+///
+/// Queue types and optional queue arguments are assigned in a round-robin fashion,
+/// so `with_queue_types(vec![QueueType::Quorum, QueueType::Classic])` produces
+/// an equal number of quorum and classic queues.
+///
+/// Every queue is bound to `bindings_per_queue` exchanges (queue `i` is bound to exchanges
+/// `i`, `i + 1`, and so on, wrapping around) using the queue name as the routing key.
+#[derive(Debug, Clone)]
+pub struct TopologyGenerator {
+    vhost: String,
+    queue_count: usize,
+    queue_name_template: String,
+    queue_types: Vec<QueueType>,
+    queue_arguments: Vec<Map<String, Value>>,
+    exchange_count: usize,
+    exchange_name_template: String,
+    exchange_type: ExchangeType,
+    bindings_per_queue: usize,
+    policy_count: usize,
+    policy_name_template: String,
+    policy_pattern_template: String,
+    policy_target: PolicyTarget,
+    policy_definition: Map<String, Value>,
+}
+
+impl TopologyGenerator {
+    /// Instantiates a generator that generates an empty topology in the given virtual host.
+    pub fn new(vhost: &str) -> Self {
+        Self {
+            vhost: vhost.to_owned(),
+            queue_count: 0,
+            queue_name_template: DEFAULT_QUEUE_NAME_TEMPLATE.to_owned(),
+            queue_types: vec![QueueType::Classic],
+            queue_arguments: Vec::new(),
+            exchange_count: 0,
+            exchange_name_template: DEFAULT_EXCHANGE_NAME_TEMPLATE.to_owned(),
+            exchange_type: ExchangeType::Direct,
+            bindings_per_queue: 0,
+            policy_count: 0,
+            policy_name_template: DEFAULT_POLICY_NAME_TEMPLATE.to_owned(),
+            policy_pattern_template: "^generated\\.".to_owned(),
+            policy_target: PolicyTarget::Queues,
+            policy_definition: Map::new(),
+        }
+    }
+
+    /// Generates `count` queues named using the given template.
+    pub fn with_queues(mut self, count: usize, name_template: &str) -> Self {
+        self.queue_count = count;
+        self.queue_name_template = name_template.to_owned();
+        self
+    }
+
+    /// The queue types to use, assigned to queues in a round-robin fashion.
+    pub fn with_queue_types(mut self, queue_types: Vec<QueueType>) -> Self {
+        if !queue_types.is_empty() {
+            self.queue_types = queue_types;
+        }
+        self
+    }
+
+    /// Optional queue argument maps, assigned to queues in a round-robin fashion.
+    /// Use repeated entries to skew the distribution.
+    pub fn with_queue_arguments(mut self, arguments: Vec<Map<String, Value>>) -> Self {
+        self.queue_arguments = arguments;
+        self
+    }
+
+    /// Generates `count` exchanges of the given type named using the given template.
+    pub fn with_exchanges(
+        mut self,
+        count: usize,
+        name_template: &str,
+        exchange_type: ExchangeType,
+    ) -> Self {
+        self.exchange_count = count;
+        self.exchange_name_template = name_template.to_owned();
+        self.exchange_type = exchange_type;
+        self
+    }
+
+    /// How many exchanges every queue is bound to. Capped at the number of exchanges.
+    pub fn with_bindings_per_queue(mut self, n: usize) -> Self {
+        self.bindings_per_queue = n;
+        self
+    }
+
+    /// Generates `count` policies. Both the name and the pattern are templates.
+    pub fn with_policies(
+        mut self,
+        count: usize,
+        name_template: &str,
+        pattern_template: &str,
+        apply_to: PolicyTarget,
+        definition: Map<String, Value>,
+    ) -> Self {
+        self.policy_count = count;
+        self.policy_name_template = name_template.to_owned();
+        self.policy_pattern_template = pattern_template.to_owned();
+        self.policy_target = apply_to;
+        self.policy_definition = definition;
+        self
+    }
+
+    pub fn queue_name(&self, i: usize) -> String {
+        render_template(&self.queue_name_template, i)
+    }
+
+    pub fn exchange_name(&self, i: usize) -> String {
+        render_template(&self.exchange_name_template, i)
+    }
+
+    pub fn policy_name(&self, i: usize) -> String {
+        render_template(&self.policy_name_template, i)
+    }
+
+    /// Generates the topology. Only the policies, queues, exchanges and bindings
+    /// of the returned definition set are populated.
+    pub fn generate(&self) -> ClusterDefinitionSet {
+        ClusterDefinitionSet {
+            server_version: None,
+            users: Vec::new(),
+            virtual_hosts: Vec::new(),
+            permissions: Vec::new(),
+            parameters: Vec::new(),
+            policies: self.policies(),
+            queues: self.queues(),
+            exchanges: self.exchanges(),
+            bindings: self.bindings(),
+        }
+    }
+
+    fn queues(&self) -> Vec<QueueDefinition> {
+        (0..self.queue_count)
+            .map(|i| {
+                let mut arguments = if self.queue_arguments.is_empty() {
+                    Map::new()
+                } else {
+                    self.queue_arguments[i % self.queue_arguments.len()].clone()
+                };
+                let queue_type = &self.queue_types[i % self.queue_types.len()];
+                arguments.insert("x-queue-type".to_owned(), json!(queue_type));
+
+                QueueDefinition {
+                    name: self.queue_name(i),
+                    vhost: self.vhost.clone(),
+                    durable: true,
+                    auto_delete: false,
+                    arguments: XArguments(arguments),
+                }
+            })
+            .collect()
+    }
+
+    fn exchanges(&self) -> Vec<ExchangeDefinition> {
+        (0..self.exchange_count)
+            .map(|i| ExchangeDefinition {
+                name: self.exchange_name(i),
+                vhost: self.vhost.clone(),
+                exchange_type: String::from(self.exchange_type.clone()),
+                durable: true,
+                auto_delete: false,
+                arguments: XArguments(Map::new()),
+            })
+            .collect()
+    }
+
+    fn bindings(&self) -> Vec<BindingDefinition> {
+        let n = self.bindings_per_queue.min(self.exchange_count);
+        let mut result = Vec::with_capacity(self.queue_count * n);
+        for i in 0..self.queue_count {
+            let queue = self.queue_name(i);
+            for k in 0..n {
+                result.push(BindingDefinition {
+                    vhost: self.vhost.clone(),
+                    source: self.exchange_name((i + k) % self.exchange_count),
+                    destination: queue.clone(),
+                    destination_type: BindingDestinationType::Queue,
+                    routing_key: queue.clone(),
+                    arguments: XArguments(Map::new()),
+                    properties_key: None,
+                });
+            }
+        }
+        result
+    }
+
+    fn policies(&self) -> Vec<Policy> {
+        (0..self.policy_count)
+            .map(|i| Policy {
+                name: self.policy_name(i),
+                vhost: self.vhost.clone(),
+                pattern: render_template(&self.policy_pattern_template, i),
+                apply_to: self.policy_target.clone(),
+                priority: 0,
+                definition: PolicyDefinition(Some(self.policy_definition.clone())),
+            })
+            .collect()
+    }
+}
+
+fn render_template(template: &str, i: usize) -> String {
+    template.replace(INDEX_PLACEHOLDER, &i.to_string())
+}
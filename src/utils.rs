@@ -12,6 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(any(feature = "async", feature = "blocking"))]
+use reqwest::Url;
+
+/// Used in place of credentials in `Debug` output, errors and URLs.
+#[cfg(any(feature = "async", feature = "blocking"))]
+pub(crate) const REDACTED: &str = "redacted";
+
 pub fn percentage(a: u64, b: u64) -> f64 {
     (a as f64 / b as f64) * 100.0
 }
@@ -46,3 +53,41 @@ macro_rules! path {
         url
     }}
 }
+
+/// Replaces the password in the user info part of a URL, if any,
+/// so that the URL can be safely logged or included into an error.
+#[cfg(any(feature = "async", feature = "blocking"))]
+pub(crate) fn redact_url(mut url: Url) -> Url {
+    if url.password().is_some() {
+        let _ = url.set_password(Some(REDACTED));
+    }
+    url
+}
+
+/// Replaces the last path segment, e.g. a password passed as a part of the path, with [`REDACTED`].
+#[cfg(any(feature = "async", feature = "blocking"))]
+pub(crate) fn redact_last_path_segment(mut url: Url) -> Url {
+    if let Ok(mut segments) = url.path_segments_mut() {
+        segments.pop().push(REDACTED);
+    }
+    url
+}
+
+/// Like [`redact_url`] but for endpoints that have not been parsed yet.
+#[cfg(any(feature = "async", feature = "blocking"))]
+pub(crate) fn redact_endpoint(endpoint: &str) -> String {
+    match Url::parse(endpoint) {
+        Ok(url) if url.password().is_some() => redact_url(url).to_string(),
+        _ => endpoint.to_owned(),
+    }
+}
+
+/// Redacts the password in the URL carried by a `reqwest` error, since
+/// the URL is a part of the error's `Display` output.
+#[cfg(any(feature = "async", feature = "blocking"))]
+pub(crate) fn redact_request_error(err: reqwest::Error) -> reqwest::Error {
+    match err.url().cloned() {
+        Some(url) if url.password().is_some() => err.with_url(redact_url(url)),
+        _ => err,
+    }
+}
@@ -0,0 +1,223 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A change feed for listings: polls a listing (e.g. queues, connections or policies)
+//! on an interval, compares it to the previous snapshot and produces
+//! [`WatchEvent`]s for the objects that were added, removed or changed.
+//!
+//! See `Client#watch_queues`, `Client#watch_connections` and `Client#watch_policies`.
+//! With the async client, the events are produced by a `Stream`, see [`watch_listing`].
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use crate::responses::{Connection, Policy, QueueInfo};
+
+/// The default interval between two polls.
+pub const DEFAULT_WATCH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// An object that can be watched for changes.
+pub trait Watchable: Clone {
+    /// Uniquely identifies the object in a listing
+    type Key: Ord + Clone;
+
+    fn watch_key(&self) -> Self::Key;
+
+    /// Returns true if the object has changed in a way that should be reported.
+    /// Metrics such as message counts or rates are not taken into account.
+    fn has_changed_from(&self, previous: &Self) -> bool;
+}
+
+impl Watchable for QueueInfo {
+    type Key = (String, String);
+
+    fn watch_key(&self) -> Self::Key {
+        (self.vhost.clone(), self.name.clone())
+    }
+
+    fn has_changed_from(&self, previous: &Self) -> bool {
+        self.state != previous.state
+            || self.node != previous.node
+            || self.leader != previous.leader
+            || self.members != previous.members
+            || self.policy != previous.policy
+            || self.arguments != previous.arguments
+            || self.consumer_count != previous.consumer_count
+    }
+}
+
+impl Watchable for Connection {
+    type Key = String;
+
+    fn watch_key(&self) -> Self::Key {
+        self.name.clone()
+    }
+
+    fn has_changed_from(&self, previous: &Self) -> bool {
+        self.state != previous.state || self.channel_count != previous.channel_count
+    }
+}
+
+impl Watchable for Policy {
+    type Key = (String, String);
+
+    fn watch_key(&self) -> Self::Key {
+        (self.vhost.clone(), self.name.clone())
+    }
+
+    fn has_changed_from(&self, previous: &Self) -> bool {
+        self.pattern != previous.pattern
+            || self.apply_to != previous.apply_to
+            || self.priority != previous.priority
+            || self.definition != previous.definition
+    }
+}
+
+/// A change observed between two snapshots of a listing.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WatchEvent<T> {
+    Added(T),
+    Removed(T),
+    Changed { before: T, after: T },
+}
+
+impl<T> WatchEvent<T> {
+    /// The most recently observed version of the object.
+    pub fn item(&self) -> &T {
+        match self {
+            WatchEvent::Added(item) => item,
+            WatchEvent::Removed(item) => item,
+            WatchEvent::Changed { after, .. } => after,
+        }
+    }
+}
+
+/// Watch configuration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WatchSettings {
+    /// How long to wait between two polls
+    pub interval: Duration,
+    /// Whether the objects in the first snapshot should be reported as added.
+    /// When false, the first snapshot only establishes a baseline
+    pub emit_initial: bool,
+}
+
+impl WatchSettings {
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_emit_initial(mut self, emit_initial: bool) -> Self {
+        self.emit_initial = emit_initial;
+        self
+    }
+}
+
+impl Default for WatchSettings {
+    fn default() -> Self {
+        Self {
+            interval: DEFAULT_WATCH_INTERVAL,
+            emit_initial: true,
+        }
+    }
+}
+
+/// Compares every snapshot of a listing with the previous one.
+///
+/// Events are produced in key order: additions and changes first, then removals.
+#[derive(Debug)]
+pub struct SnapshotDiffer<T: Watchable> {
+    previous: Option<BTreeMap<T::Key, T>>,
+    emit_initial: bool,
+}
+
+impl<T: Watchable> SnapshotDiffer<T> {
+    pub fn new(emit_initial: bool) -> Self {
+        Self {
+            previous: None,
+            emit_initial,
+        }
+    }
+
+    /// Records a snapshot and returns the changes since the previous one.
+    pub fn diff(&mut self, snapshot: Vec<T>) -> Vec<WatchEvent<T>> {
+        let current: BTreeMap<T::Key, T> = snapshot
+            .into_iter()
+            .map(|item| (item.watch_key(), item))
+            .collect();
+
+        let mut events = Vec::new();
+        match self.previous.take() {
+            None => {
+                if self.emit_initial {
+                    events.extend(current.values().cloned().map(WatchEvent::Added));
+                }
+            }
+            Some(mut previous) => {
+                for (key, item) in &current {
+                    match previous.remove(key) {
+                        None => events.push(WatchEvent::Added(item.clone())),
+                        Some(before) if item.has_changed_from(&before) => {
+                            events.push(WatchEvent::Changed {
+                                before,
+                                after: item.clone(),
+                            })
+                        }
+                        Some(_) => {}
+                    }
+                }
+                // what is left was not in the current snapshot
+                events.extend(previous.into_values().map(WatchEvent::Removed));
+            }
+        }
+
+        self.previous = Some(current);
+        events
+    }
+}
+
+/// Polls a listing using `list` every `settings.interval` and produces a stream
+/// of changes. The stream ends after the first error returned by `list`.
+///
+/// This is what `Client#watch_queues` and similar functions use, it can be used
+/// to watch other listings or filtered ones.
+#[cfg(feature = "async")]
+pub fn watch_listing<T, E, F, Fut>(
+    settings: WatchSettings,
+    list: F,
+) -> impl futures_util::Stream<Item = Result<WatchEvent<T>, E>>
+where
+    T: Watchable,
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Result<Vec<T>, E>>,
+{
+    use futures_util::stream::{self, TryStreamExt};
+
+    let differ = SnapshotDiffer::new(settings.emit_initial);
+    stream::try_unfold(
+        (differ, list, true),
+        move |(mut differ, mut list, is_first)| async move {
+            if !is_first {
+                tokio::time::sleep(settings.interval).await;
+            }
+            let events = differ.diff(list().await?);
+            let batch = stream::iter(events.into_iter().map(Ok));
+            Ok(Some((batch, (differ, list, false))))
+        },
+    )
+    .try_flatten()
+}
@@ -15,7 +15,7 @@ use amqprs::connection::{Connection, OpenConnectionArguments};
 use rabbitmq_http_client::api::Client;
 
 mod test_helpers;
-use crate::test_helpers::{endpoint, hostname, PASSWORD, USERNAME};
+use crate::test_helpers::{async_await_metric_emission, endpoint, hostname, PASSWORD, USERNAME};
 
 #[tokio::test]
 async fn test_list_channels() {
@@ -57,3 +57,63 @@ async fn test_list_virtual_host_channels() {
     ch.close().await.unwrap();
     conn.clone().close().await.unwrap();
 }
+
+#[tokio::test]
+async fn test_list_channels_on_node() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let args = OpenConnectionArguments::new(&hostname(), 5672, USERNAME, PASSWORD);
+    let conn = Connection::open(&args).await.unwrap();
+    assert!(conn.is_open());
+
+    let ch = conn.open_channel(None).await.unwrap();
+    assert!(ch.is_open());
+
+    let nodes = rc.list_nodes().await.unwrap();
+    let node = &nodes.first().unwrap().name;
+    let result1 = rc.list_channels_on_node(node).await;
+    assert!(
+        result1.is_ok(),
+        "list_channels_on_node returned {:?}",
+        result1
+    );
+    assert!(result1.unwrap().iter().all(|c| &c.node == node));
+
+    let result2 = rc.list_channels_on_node("rabbit@no-such-node").await;
+    assert!(result2.unwrap().is_empty());
+
+    ch.close().await.unwrap();
+    conn.clone().close().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_list_channels_on_connection() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let client_name = "rust.http.api.async.test_list_channels_on_connection";
+    let mut args = OpenConnectionArguments::new(&hostname(), 5672, USERNAME, PASSWORD);
+    args.connection_name(client_name);
+    let conn = Connection::open(&args).await.unwrap();
+    assert!(conn.is_open());
+
+    let ch = conn.open_channel(None).await.unwrap();
+    assert!(ch.is_open());
+    async_await_metric_emission(1000).await;
+
+    let connections = rc
+        .list_connections_with_client_name(client_name)
+        .await
+        .unwrap();
+    let name = &connections.first().unwrap().name;
+    let result1 = rc.list_channels_on(name).await;
+    assert!(result1.is_ok(), "list_channels_on returned {:?}", result1);
+    let channels = result1.unwrap();
+    assert!(!channels.is_empty());
+    assert!(channels.iter().all(|c| &c.connection_details.name == name));
+
+    // just to be explicit
+    ch.close().await.unwrap();
+    conn.clone().close().await.unwrap();
+}
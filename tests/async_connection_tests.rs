@@ -14,10 +14,11 @@ use std::time::Duration;
 // limitations under the License.
 use amqprs::connection::{Connection, OpenConnectionArguments};
 use rabbitmq_http_client::api::Client;
+use rabbitmq_http_client::churn_analyzer::ChurnAnalyzerSettings;
 use rabbitmq_http_client::requests::VirtualHostParams;
 
 mod test_helpers;
-use crate::test_helpers::{endpoint, hostname, PASSWORD, USERNAME};
+use crate::test_helpers::{async_await_metric_emission, endpoint, hostname, PASSWORD, USERNAME};
 
 #[tokio::test]
 async fn test_async_list_connections() {
@@ -34,6 +35,69 @@ async fn test_async_list_connections() {
     conn.close().await.unwrap();
 }
 
+#[tokio::test]
+async fn test_async_list_connections_with_client_name() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let client_name = "rust.http.api.async.test_list_connections_with_client_name";
+    let mut args = OpenConnectionArguments::new(&hostname(), 5672, USERNAME, PASSWORD);
+    args.connection_name(client_name);
+    let conn = Connection::open(&args).await.unwrap();
+    assert!(conn.is_open());
+    async_await_metric_emission(1000).await;
+
+    let result1 = rc.list_connections_with_client_name(client_name).await;
+    assert!(
+        result1.is_ok(),
+        "list_connections_with_client_name returned {:?}",
+        result1
+    );
+    let connections = result1.unwrap();
+    assert!(!connections.is_empty());
+    assert!(connections
+        .iter()
+        .all(|c| c.client_properties.connection_name == client_name));
+
+    let result2 = rc
+        .list_connections_with_client_name("rust.http.api.async.no_such_connection")
+        .await;
+    assert!(result2.unwrap().is_empty());
+
+    conn.close().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_async_get_connection_info() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let client_name = "rust.http.api.async.test_get_connection_info";
+    let mut args = OpenConnectionArguments::new(&hostname(), 5672, USERNAME, PASSWORD);
+    args.connection_name(client_name);
+    let conn = Connection::open(&args).await.unwrap();
+    assert!(conn.is_open());
+    async_await_metric_emission(1000).await;
+
+    let connections = rc
+        .list_connections_with_client_name(client_name)
+        .await
+        .unwrap();
+    let name = &connections.first().unwrap().name;
+
+    let result1 = rc.get_connection_info(name).await;
+    assert!(
+        result1.is_ok(),
+        "get_connection_info returned {:?}",
+        result1
+    );
+    let connection = result1.unwrap();
+    assert_eq!(name, &connection.name);
+    assert_eq!(client_name, connection.client_properties.connection_name);
+
+    conn.close().await.unwrap();
+}
+
 #[tokio::test]
 async fn test_async_list_user_connections() {
     let endpoint = endpoint();
@@ -119,3 +183,38 @@ async fn test_async_close_user_connections() {
     tokio::time::sleep(Duration::from_millis(50)).await;
     assert!(!conn.is_open());
 }
+
+#[tokio::test]
+async fn test_async_analyze_connection_churn() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let settings =
+        ChurnAnalyzerSettings::new(Duration::from_secs(2)).with_interval(Duration::from_secs(1));
+    let result1 = rc.analyze_connection_churn(&settings).await;
+    assert!(
+        result1.is_ok(),
+        "analyze_connection_churn returned {:?}",
+        result1
+    );
+    assert!(result1.unwrap().elapsed >= Duration::from_secs(2));
+}
+
+#[tokio::test]
+async fn test_async_list_connections_on_node() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let nodes = rc.list_nodes().await.unwrap();
+    let node = &nodes.first().unwrap().name;
+    let result1 = rc.list_connections_on_node(node).await;
+    assert!(
+        result1.is_ok(),
+        "list_connections_on_node returned {:?}",
+        result1
+    );
+    assert!(result1.unwrap().iter().all(|c| &c.node == node));
+
+    let result2 = rc.list_connections_on_node("rabbit@no-such-node").await;
+    assert!(result2.unwrap().is_empty());
+}
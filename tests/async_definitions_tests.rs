@@ -17,10 +17,11 @@ mod test_helpers;
 use crate::test_helpers::{
     await_metric_emission, await_queue_metric_emission, endpoint, PASSWORD, USERNAME,
 };
-use rabbitmq_http_client::commons::PolicyTarget;
+use rabbitmq_http_client::commons::{ExchangeType, PolicyTarget, QueueType};
 use rabbitmq_http_client::requests::{
     ExchangeParams, PolicyParams, QueueParams, VirtualHostParams,
 };
+use rabbitmq_http_client::topology_generator::TopologyGenerator;
 use serde_json::{json, Map, Value};
 
 #[tokio::test]
@@ -295,3 +296,96 @@ async fn test_async_import_vhost_definitions() {
 
     rc.delete_vhost(vh, true).await.unwrap();
 }
+
+#[tokio::test]
+async fn test_async_backup_and_restore_definitions_using_a_file() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let vh = "rust/http/api/async/definitions/backup";
+    rc.delete_vhost(vh, true).await.unwrap();
+    let vh_params = VirtualHostParams::named(vh);
+    rc.create_vhost(&vh_params).await.unwrap();
+
+    let path = std::env::temp_dir().join("rust.http.api.async.definitions.backup.json");
+    let result1 = rc.backup_definitions_to_file(&path).await;
+    assert!(
+        result1.is_ok(),
+        "backup_definitions_to_file returned {:?}",
+        result1
+    );
+
+    rc.delete_vhost(vh, false).await.unwrap();
+
+    let result2 = rc.restore_definitions_from_file(&path).await;
+    assert!(
+        result2.is_ok(),
+        "restore_definitions_from_file returned {:?}",
+        result2
+    );
+    assert!(rc.get_vhost(vh).await.is_ok());
+
+    rc.delete_vhost(vh, true).await.unwrap();
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test]
+async fn test_async_concurrent_definition_backups_to_the_same_file() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let path = std::env::temp_dir().join("rust.http.api.async.definitions.concurrent.backup.json");
+    let (result1, result2) = tokio::join!(
+        rc.backup_definitions_to_file(&path),
+        rc.backup_definitions_to_file(&path)
+    );
+    assert!(
+        result1.is_ok(),
+        "backup_definitions_to_file returned {:?}",
+        result1
+    );
+    assert!(
+        result2.is_ok(),
+        "backup_definitions_to_file returned {:?}",
+        result2
+    );
+
+    let result3 = rc.restore_definitions_from_file(&path).await;
+    assert!(
+        result3.is_ok(),
+        "restore_definitions_from_file returned {:?}",
+        result3
+    );
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test]
+async fn test_async_declare_and_delete_generated_topology() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let vh = "/";
+    let topology = TopologyGenerator::new(vh)
+        .with_queues(20, "rust.tests.async.generated.q.{n}")
+        .with_queue_types(vec![QueueType::Classic, QueueType::Quorum])
+        .with_exchanges(4, "rust.tests.async.generated.x.{n}", ExchangeType::Direct)
+        .with_bindings_per_queue(2)
+        .generate();
+
+    let result1 = rc.declare_topology(&topology, 4).await;
+    assert!(result1.is_ok(), "declare_topology returned {:?}", result1);
+
+    let result2 = rc
+        .get_queue_info(vh, "rust.tests.async.generated.q.19")
+        .await;
+    assert!(result2.is_ok(), "get_queue_info returned {:?}", result2);
+
+    let result3 = rc.delete_topology(&topology, 4).await;
+    assert!(result3.is_ok(), "delete_topology returned {:?}", result3);
+
+    let result4 = rc
+        .get_queue_info(vh, "rust.tests.async.generated.q.19")
+        .await;
+    assert!(result4.is_err());
+}
@@ -13,7 +13,9 @@
 // limitations under the License.
 use rabbitmq_http_client::{
     api::Client,
-    requests::{self, QueueParams},
+    commons::PayloadEncoding,
+    error::Error as APIClientError,
+    requests::{self, MessageReplayParams, QueueParams},
     responses::{GetMessage, MessageProperties, MessageRouted},
 };
 use serde_json::{json, Map, Value};
@@ -68,14 +70,17 @@ async fn test_async_publish_and_get() {
             message_count: 1,
             properties: MessageProperties::default(),
             payload: "rust test 1".to_owned(),
-            payload_encoding: "string".to_owned()
+            payload_encoding: PayloadEncoding::String
         }]
     );
 
     let result7 = rc.get_messages(vhost, queue, 1, "ack_requeue_false").await;
     assert!(result7.is_ok(), "get_messages returned {:?}", result7);
 
-    let props = MessageProperties(props);
+    let props = MessageProperties {
+        timestamp: Some(123456789),
+        ..Default::default()
+    };
     let msg_list2 = result7.unwrap();
     assert_eq!(
         msg_list2,
@@ -87,9 +92,270 @@ async fn test_async_publish_and_get() {
             message_count: 0,
             properties: props,
             payload: "rust test 2".to_owned(),
-            payload_encoding: "string".to_owned()
+            payload_encoding: PayloadEncoding::String
         }]
     );
 
     rc.delete_queue(vhost, queue, false).await.unwrap();
 }
+
+#[tokio::test]
+async fn test_async_replay_messages() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+    let vhost = "/";
+    let source = "rust.tests.async.cq.replay_messages.dlq";
+    let destination = "rust.tests.async.cq.replay_messages";
+
+    let _ = rc.delete_queue(vhost, source, false).await;
+    let _ = rc.delete_queue(vhost, destination, false).await;
+
+    for q in [source, destination] {
+        let params = QueueParams::new_durable_classic_queue(q, None);
+        rc.declare_queue(vhost, &params).await.unwrap();
+    }
+
+    let mut props = Map::<String, Value>::new();
+    props.insert(String::from("correlation_id"), json!("c-1"));
+    for payload in ["m1", "m2", "m3"] {
+        rc.publish_message(vhost, "", source, payload, props.clone())
+            .await
+            .unwrap();
+    }
+
+    let mut params = MessageReplayParams::to_queue(vhost, source, destination);
+    params.batch_size = 2;
+    params.dry_run = true;
+    let result1 = rc.replay_messages(&params).await;
+    assert!(result1.is_ok(), "replay_messages returned {:?}", result1);
+    assert_eq!(2, result1.unwrap().len());
+
+    params.dry_run = false;
+    let result2 = rc.replay_messages(&params).await;
+    assert!(result2.is_ok(), "replay_messages returned {:?}", result2);
+    assert_eq!(3, result2.unwrap().len());
+
+    let msgs = rc
+        .get_messages(vhost, destination, 3, "ack_requeue_false")
+        .await
+        .unwrap();
+    assert_eq!(3, msgs.len());
+    assert_eq!("m1", msgs[0].payload);
+    assert_eq!(Some("c-1".to_owned()), msgs[0].properties.correlation_id);
+
+    rc.delete_queue(vhost, source, false).await.unwrap();
+    rc.delete_queue(vhost, destination, false).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_async_replay_messages_returns_unrouted_messages() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+    let vhost = "/";
+    let source = "rust.tests.async.cq.replay_messages.unrouted.dlq";
+
+    let _ = rc.delete_queue(vhost, source, false).await;
+    let params = QueueParams::new_durable_classic_queue(source, None);
+    rc.declare_queue(vhost, &params).await.unwrap();
+
+    for payload in ["m1", "m2"] {
+        rc.publish_message(vhost, "", source, payload, Map::new())
+            .await
+            .unwrap();
+    }
+
+    // amq.direct has no binding for this routing key
+    let mut params = MessageReplayParams::to_exchange(vhost, source, "amq.direct");
+    params.destination_routing_key = Some("rust.tests.async.replay_messages.unrouted");
+    let result = rc.replay_messages(&params).await;
+    match result {
+        Err(APIClientError::MessageReplayInterrupted {
+            replayed,
+            unreplayed,
+            cause,
+        }) => {
+            assert!(replayed.is_empty());
+            assert_eq!(2, unreplayed.len());
+            assert_eq!("m1", unreplayed[0].payload);
+            assert!(matches!(*cause, APIClientError::MessageNotRouted { .. }));
+        }
+        other => panic!("expected MessageReplayInterrupted, got {:?}", other),
+    }
+
+    rc.delete_queue(vhost, source, false).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_async_replay_messages_into_the_source_queue_terminates() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+    let vhost = "/";
+    let queue = "rust.tests.async.cq.replay_messages.loop";
+
+    let _ = rc.delete_queue(vhost, queue, false).await;
+    let params = QueueParams::new_durable_classic_queue(queue, None);
+    rc.declare_queue(vhost, &params).await.unwrap();
+
+    for payload in ["m1", "m2", "m3"] {
+        rc.publish_message(vhost, "", queue, payload, Map::new())
+            .await
+            .unwrap();
+    }
+
+    let mut params = MessageReplayParams::to_queue(vhost, queue, queue);
+    params.batch_size = 3;
+    let result = rc.replay_messages(&params).await;
+    assert!(result.is_ok(), "replay_messages returned {:?}", result);
+    assert_eq!(6, result.unwrap().len());
+
+    let msgs = rc
+        .get_messages(vhost, queue, 10, "ack_requeue_false")
+        .await
+        .unwrap();
+    assert_eq!(3, msgs.len());
+
+    rc.delete_queue(vhost, queue, false).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_async_drain_queue_to_file_and_publish_messages_from_file() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+    let vhost = "/";
+    let source = "rust.tests.async.cq.drain_to_file";
+    let destination = "rust.tests.async.cq.drain_to_file.restored";
+    let path = std::env::temp_dir().join("rust.http.api.async.messages.drain.jsonl");
+
+    let _ = rc.delete_queue(vhost, source, false).await;
+    let _ = rc.delete_queue(vhost, destination, false).await;
+    let _ = std::fs::remove_file(&path);
+
+    for q in [source, destination] {
+        let params = QueueParams::new_durable_classic_queue(q, None);
+        rc.declare_queue(vhost, &params).await.unwrap();
+    }
+
+    let mut props = Map::<String, Value>::new();
+    props.insert(String::from("correlation_id"), json!("c-1"));
+    for payload in ["m1", "m2", "m3"] {
+        rc.publish_message(vhost, "", source, payload, props.clone())
+            .await
+            .unwrap();
+    }
+
+    let mut progress = Vec::new();
+    let result1 = rc
+        .drain_queue_to_file(vhost, source, &path, 2, |n| progress.push(n))
+        .await;
+    assert!(
+        result1.is_ok(),
+        "drain_queue_to_file returned {:?}",
+        result1
+    );
+    assert_eq!(3, result1.unwrap());
+    assert_eq!(vec![2, 3], progress);
+
+    let result2 = rc
+        .publish_messages_from_file(vhost, &path, None, Some(destination), |_| {})
+        .await;
+    assert!(
+        result2.is_ok(),
+        "publish_messages_from_file returned {:?}",
+        result2
+    );
+    assert_eq!(3, result2.unwrap());
+
+    let msgs = rc
+        .get_messages(vhost, destination, 3, "ack_requeue_false")
+        .await
+        .unwrap();
+    assert_eq!(3, msgs.len());
+    assert_eq!("m1", msgs[0].payload);
+    assert_eq!(Some("c-1".to_owned()), msgs[0].properties.correlation_id);
+
+    rc.delete_queue(vhost, source, false).await.unwrap();
+    rc.delete_queue(vhost, destination, false).await.unwrap();
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test]
+async fn test_async_publish_messages_from_file_stops_at_unrouted_messages() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+    let vhost = "/";
+    let source = "rust.tests.async.cq.drain_to_file.unrouted";
+    let path = std::env::temp_dir().join("rust.http.api.async.messages.drain.unrouted.jsonl");
+
+    let _ = rc.delete_queue(vhost, source, false).await;
+    let _ = std::fs::remove_file(&path);
+    let params = QueueParams::new_durable_classic_queue(source, None);
+    rc.declare_queue(vhost, &params).await.unwrap();
+    for payload in ["m1", "m2"] {
+        rc.publish_message(vhost, "", source, payload, Map::new())
+            .await
+            .unwrap();
+    }
+    rc.drain_queue_to_file(vhost, source, &path, 10, |_| {})
+        .await
+        .unwrap();
+
+    // amq.direct has no binding for this routing key
+    let mut published = 0;
+    let result = rc
+        .publish_messages_from_file(
+            vhost,
+            &path,
+            Some("amq.direct"),
+            Some("rust.tests.async.drain_to_file.unrouted"),
+            |n| published = n,
+        )
+        .await;
+    assert!(
+        matches!(result, Err(APIClientError::MessageNotRouted { .. })),
+        "publish_messages_from_file returned {:?}",
+        result
+    );
+    assert_eq!(0, published);
+
+    rc.delete_queue(vhost, source, false).await.unwrap();
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test]
+async fn test_async_publish_binary_message() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+    let vhost = "/";
+    let queue = "rust.tests.async.cq.publish_binary_message";
+
+    let _ = rc.delete_queue(vhost, queue, false).await;
+
+    let params = QueueParams::new_durable_classic_queue(queue, None);
+    let result1 = rc.declare_queue(vhost, &params).await;
+    assert!(result1.is_ok(), "declare_queue returned {:?}", result1);
+
+    let payload: Vec<u8> = vec![0x00, 0xff, 0x10, 0x80, 0x7f];
+    let result2 = rc
+        .publish_binary_message(
+            vhost,
+            "",
+            queue,
+            &payload,
+            requests::MessageProperties::default(),
+        )
+        .await;
+    assert!(
+        result2.is_ok(),
+        "publish_binary_message returned {:?}",
+        result2
+    );
+    assert_eq!(result2.unwrap(), MessageRouted { routed: true });
+
+    let result3 = rc.get_messages(vhost, queue, 1, "ack_requeue_false").await;
+    assert!(result3.is_ok(), "get_messages returned {:?}", result3);
+    let msg_list = result3.unwrap();
+    assert_eq!(PayloadEncoding::Base64, msg_list[0].payload_encoding);
+    assert_eq!(payload, msg_list[0].decoded_payload().unwrap());
+
+    rc.delete_queue(vhost, queue, false).await.unwrap();
+}
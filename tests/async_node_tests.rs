@@ -39,3 +39,20 @@ async fn test_async_get_node_info() {
     assert!(node.uptime >= 1);
     assert!(node.total_erlang_processes >= 1);
 }
+
+#[tokio::test]
+async fn test_async_discover_node_clients() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+    // the test suite uses a single node, reachable via the default endpoint
+    let clients = rc
+        .discover_node_clients(|_| endpoint.clone())
+        .await
+        .unwrap();
+
+    assert!(!clients.is_empty());
+    for (node, client) in clients {
+        assert!(node.starts_with("rabbit@"));
+        assert!(client.health_check_local_alarms().await.is_ok());
+    }
+}
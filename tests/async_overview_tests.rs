@@ -12,10 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use rabbitmq_http_client::api::Client;
+use rabbitmq_http_client::api::{Client, ClientBuilder};
+use rabbitmq_http_client::report::DEFAULT_CONSUMER_SATURATION_THRESHOLD;
+use std::time::Duration;
 
 mod test_helpers;
-use crate::test_helpers::{endpoint, generate_activity, PASSWORD, USERNAME};
+use crate::test_helpers::{endpoint, generate_activity, hostname, PASSWORD, USERNAME};
 
 #[tokio::test]
 async fn test_async_overview() {
@@ -30,3 +32,125 @@ async fn test_async_overview() {
     let ov = result1.unwrap();
     assert!(ov.object_totals.exchanges > 0);
 }
+
+#[tokio::test]
+async fn test_async_await_ready() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let result1 = rc.await_ready(Duration::from_secs(10)).await;
+    assert!(result1.is_ok(), "await_ready returned {:?}", result1);
+}
+
+#[tokio::test]
+async fn test_async_await_ready_times_out_with_wrong_credentials() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, "definitely-not-the-password");
+
+    let result1 = rc.await_ready(Duration::from_millis(500)).await;
+    assert!(result1.is_err());
+}
+
+#[tokio::test]
+async fn test_async_overview_after_credentials_update() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, "definitely-not-the-password");
+    assert!(rc.overview().await.is_err());
+
+    rc.update_credentials(USERNAME, PASSWORD);
+    let result1 = rc.overview().await;
+    assert!(result1.is_ok(), "overview returned {:?}", result1);
+}
+
+#[tokio::test]
+async fn test_async_overview_with_api_prefix() {
+    let endpoint = format!("http://{}:15672/", hostname());
+    let rc = ClientBuilder::new()
+        .with_endpoint(&endpoint)
+        .with_basic_auth_credentials(USERNAME, PASSWORD)
+        .with_api_prefix("/api/")
+        .build();
+
+    let result1 = rc.overview().await;
+    assert!(result1.is_ok(), "overview returned {:?}", result1);
+}
+
+#[cfg(feature = "http2")]
+#[tokio::test]
+async fn test_async_overview_over_http2() {
+    let endpoint = endpoint();
+    let rc = ClientBuilder::new()
+        .with_endpoint(&endpoint)
+        .with_basic_auth_credentials(USERNAME, PASSWORD)
+        .with_http2_prior_knowledge()
+        .build();
+
+    let result1 = rc.overview().await;
+    assert!(result1.is_ok(), "overview returned {:?}", result1);
+}
+
+#[tokio::test]
+async fn test_async_overview_with_response() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let result1 = rc.overview_with_response().await;
+    assert!(
+        result1.is_ok(),
+        "overview_with_response returned {:?}",
+        result1
+    );
+
+    let envelope = result1.unwrap();
+    assert!(envelope.status_code.is_success());
+    assert!(envelope.header("content-type").is_some());
+    assert!(envelope.body.object_totals.exchanges > 0);
+}
+
+#[tokio::test]
+async fn test_async_list_listeners() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let result1 = rc.list_listeners().await;
+    assert!(result1.is_ok(), "list_listeners returned {:?}", result1);
+    let listeners = result1.unwrap();
+    assert!(listeners.iter().any(|l| l.protocol == "amqp"));
+
+    let node = &listeners.first().unwrap().node;
+    let result2 = rc.listeners_of(node).await;
+    assert!(result2.is_ok(), "listeners_of returned {:?}", result2);
+    assert!(result2.unwrap().iter().all(|l| &l.node == node));
+}
+
+#[tokio::test]
+async fn test_async_cluster_report() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let result1 = rc.cluster_report(5).await;
+    assert!(result1.is_ok(), "cluster_report returned {:?}", result1);
+
+    let report = result1.unwrap();
+    assert!(!report.nodes.is_empty());
+    assert!(report.entity_counts.virtual_hosts >= 1);
+    assert!(report.entity_counts.exchanges > 0);
+    assert!(report.top_queues.len() <= 5);
+    assert!(!report.listeners.protocols().is_empty());
+    assert!(report.to_string().contains(&report.cluster_name));
+}
+
+#[tokio::test]
+async fn test_async_consumer_utilisation_report() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let result1 = rc
+        .consumer_utilisation_report(Some("/"), DEFAULT_CONSUMER_SATURATION_THRESHOLD)
+        .await;
+    assert!(
+        result1.is_ok(),
+        "consumer_utilisation_report returned {:?}",
+        result1
+    );
+}
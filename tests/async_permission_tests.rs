@@ -171,3 +171,30 @@ async fn test_async_grant_permissions() {
 
     rc.delete_vhost(vh_params.name, false).await.unwrap();
 }
+
+#[tokio::test]
+async fn test_async_clear_permissions() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let vh_params = VirtualHostParams::named("test_async_clear_permissions");
+    let _ = rc.delete_vhost(vh_params.name, false).await;
+    let result1 = rc.create_vhost(&vh_params).await;
+    assert!(result1.is_ok());
+
+    let result2 = rc.get_permissions(vh_params.name, "guest").await;
+    assert!(result2.is_ok(), "get_permissions returned {:?}", result2);
+
+    let result3 = rc.clear_permissions(vh_params.name, "guest", false).await;
+    assert!(result3.is_ok(), "clear_permissions returned {:?}", result3);
+
+    let result4 = rc.get_permissions(vh_params.name, "guest").await;
+    assert!(result4.is_err(), "permissions found after clearing");
+    let result5 = rc.list_permissions_of("guest").await.unwrap();
+    assert!(!result5.iter().any(|p| p.vhost == vh_params.name));
+
+    let result6 = rc.clear_permissions(vh_params.name, "guest", true).await;
+    assert!(result6.is_ok(), "clear_permissions returned {:?}", result6);
+
+    rc.delete_vhost(vh_params.name, false).await.unwrap();
+}
@@ -17,7 +17,10 @@ use rabbitmq_http_client::{
     requests::{PolicyParams, VirtualHostParams},
 };
 
+use futures_util::{pin_mut, StreamExt};
+use rabbitmq_http_client::watch::{WatchEvent, WatchSettings};
 use serde_json::{json, Map, Value};
+use std::time::Duration;
 mod test_helpers;
 use crate::test_helpers::{endpoint, PASSWORD, USERNAME};
 
@@ -147,3 +150,39 @@ async fn test_an_operator_policy(rc: &Client<&str, &str, &str>, policy: &PolicyP
     let policies = rc.list_operator_policies().await.unwrap();
     assert!(!policies.iter().any(|p| p.name == policy.name));
 }
+
+#[tokio::test]
+async fn test_async_watch_policies() {
+    let endpoint = endpoint();
+    let rc = Client::new(endpoint.as_str(), USERNAME, PASSWORD);
+
+    let vh_params = VirtualHostParams::named("test_async_watch_policies");
+    let _ = rc.delete_vhost(vh_params.name, false).await;
+    let result1 = rc.create_vhost(&vh_params).await;
+    assert!(result1.is_ok());
+
+    let mut definition = Map::<String, Value>::new();
+    definition.insert("max-length".to_owned(), json!(100));
+    let params = PolicyParams {
+        vhost: vh_params.name,
+        name: "watched_policy",
+        pattern: ".*",
+        apply_to: PolicyTarget::Queues,
+        priority: 0,
+        definition,
+    };
+    let result2 = rc.declare_policy(&params).await;
+    assert!(result2.is_ok());
+
+    let settings = WatchSettings::new(Duration::from_millis(500));
+    let stream = rc.watch_policies(Some(vh_params.name), settings);
+    pin_mut!(stream);
+    let event = stream.next().await.unwrap();
+    assert!(
+        matches!(&event, Ok(WatchEvent::Added(p)) if p.name == "watched_policy"),
+        "watch_policies produced {:?}",
+        event
+    );
+
+    let _ = rc.delete_vhost(vh_params.name, false).await;
+}
@@ -11,7 +11,11 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
-use rabbitmq_http_client::{api::Client, commons::QueueType, requests::QueueParams};
+use rabbitmq_http_client::{
+    api::Client,
+    commons::QueueType,
+    requests::{self, QueueParams},
+};
 use serde_json::{json, Map, Value};
 
 mod test_helpers;
@@ -146,3 +150,72 @@ async fn test_async_list_queues_in_a_virtual_host() {
 
     rc.delete_queue(vh_name, params.name, false).await.unwrap();
 }
+
+#[tokio::test]
+async fn test_async_list_queue_summaries() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let vh_name = "/";
+
+    let params = QueueParams::new_durable_classic_queue("rust.tests.cq.list_queue_summaries", None);
+    let result1 = rc.declare_queue(vh_name, &params).await;
+    assert!(result1.is_ok(), "declare_queue returned {:?}", result1);
+
+    test_helpers::async_await_queue_metric_emission().await;
+
+    let result2 = rc.list_queue_summaries().await;
+    assert!(
+        result2.is_ok(),
+        "list_queue_summaries returned {:?}",
+        result2
+    );
+    assert!(result2.unwrap().iter().any(|q| q.name == params.name));
+
+    let result3 = rc.list_queue_summaries_in(vh_name).await;
+    assert!(
+        result3.is_ok(),
+        "list_queue_summaries_in returned {:?}",
+        result3
+    );
+    assert!(result3.unwrap().iter().any(|q| q.name == params.name));
+
+    rc.delete_queue(vh_name, params.name, false).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_async_purge_queue() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+    let vhost = "/";
+    let name = "rust.tests.async.cq.purge_queue";
+
+    let _ = rc.delete_queue(vhost, name, false).await;
+
+    let params = QueueParams::new_durable_classic_queue(name, None);
+    let result1 = rc.declare_queue(vhost, &params).await;
+    assert!(result1.is_ok(), "declare_queue returned {:?}", result1);
+
+    for i in 0..3 {
+        let payload = format!("message {}", i);
+        let result2 = rc
+            .publish_message(
+                vhost,
+                "",
+                name,
+                &payload,
+                requests::MessageProperties::default(),
+            )
+            .await;
+        assert!(result2.is_ok(), "publish_message returned {:?}", result2);
+    }
+
+    let result3 = rc.purge_queue(vhost, name).await;
+    assert!(result3.is_ok(), "purge_queue returned {:?}", result3);
+
+    let result4 = rc.get_messages(vhost, name, 1, "ack_requeue_false").await;
+    assert!(result4.is_ok(), "get_messages returned {:?}", result4);
+    assert!(result4.unwrap().is_empty());
+
+    let _ = rc.delete_queue(vhost, name, false).await;
+}
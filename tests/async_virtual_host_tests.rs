@@ -11,10 +11,16 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
-use rabbitmq_http_client::{api::Client, commons::QueueType, requests::VirtualHostParams};
+use rabbitmq_http_client::{
+    api::Client,
+    commons::QueueType,
+    requests::{QueueParams, VirtualHostChanges, VirtualHostParams},
+};
 
 mod test_helpers;
-use crate::test_helpers::{endpoint, PASSWORD, USERNAME};
+use crate::test_helpers::{
+    async_testing_against_3_13_x, async_testing_against_4_0_x, endpoint, PASSWORD, USERNAME,
+};
 
 #[tokio::test]
 async fn test_async_list_vhosts() {
@@ -161,3 +167,137 @@ async fn test_async_delete_vhost() {
     let result3 = rc.get_vhost(name).await;
     assert!(result3.is_err());
 }
+
+#[tokio::test]
+async fn test_async_amend_vhost() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+    let name = "rust_test_async_amend_vhost";
+
+    let _ = rc.delete_vhost(name, false).await;
+
+    let params = VirtualHostParams {
+        name,
+        description: Some("original description"),
+        tags: Some(vec!["tag-a", "tag-b"]),
+        default_queue_type: Some(QueueType::Quorum),
+        tracing: false,
+    };
+    let result1 = rc.create_vhost(&params).await;
+    assert!(result1.is_ok());
+
+    let changes = VirtualHostChanges::new().with_description("amended description");
+    let result2 = rc.amend_vhost(name, &changes).await;
+    assert!(result2.is_ok(), "amend_vhost returned {:?}", result2);
+
+    let vh = rc.get_vhost(name).await.unwrap();
+    assert_eq!(Some("amended description".to_owned()), vh.description);
+    let tags = vh.tags.unwrap();
+    assert!(tags.0.contains(&"tag-a".to_owned()));
+    assert!(tags.0.contains(&"tag-b".to_owned()));
+    assert_eq!(Some("quorum".to_owned()), vh.default_queue_type);
+
+    let _ = rc.delete_vhost(name, false).await;
+}
+
+#[tokio::test]
+async fn test_async_start_vhost_on_node() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+    let name = "rust_test_async_start_vhost_on_node";
+
+    let _ = rc.delete_vhost(name, false).await;
+
+    let params = VirtualHostParams {
+        name,
+        description: None,
+        tags: None,
+        default_queue_type: None,
+        tracing: false,
+    };
+    let result1 = rc.create_vhost(&params).await;
+    assert!(result1.is_ok());
+
+    let nodes = rc.list_nodes().await.unwrap();
+    let node = &nodes.first().unwrap().name;
+    let result2 = rc.start_vhost_on_node(name, node).await;
+    assert!(
+        result2.is_ok(),
+        "start_vhost_on_node returned {:?}",
+        result2
+    );
+
+    let _ = rc.delete_vhost(name, false).await;
+}
+
+#[tokio::test]
+async fn test_async_vhost_deletion_protection() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    if async_testing_against_3_13_x().await || async_testing_against_4_0_x().await {
+        return;
+    }
+
+    let name = "rust_test_async_vhost_deletion_protection";
+
+    let _ = rc.disable_vhost_deletion_protection(name).await;
+    let _ = rc.delete_vhost(name, true).await;
+
+    let params = VirtualHostParams::named(name);
+    let result1 = rc.create_vhost(&params).await;
+    assert!(result1.is_ok());
+    assert!(!rc.get_vhost(name).await.unwrap().protected_from_deletion);
+
+    let result2 = rc.enable_vhost_deletion_protection(name).await;
+    assert!(
+        result2.is_ok(),
+        "enable_vhost_deletion_protection returned {:?}",
+        result2
+    );
+    assert!(rc.get_vhost(name).await.unwrap().protected_from_deletion);
+    assert!(rc.delete_vhost(name, false).await.is_err());
+
+    let result3 = rc.disable_vhost_deletion_protection(name).await;
+    assert!(
+        result3.is_ok(),
+        "disable_vhost_deletion_protection returned {:?}",
+        result3
+    );
+    assert!(!rc.get_vhost(name).await.unwrap().protected_from_deletion);
+
+    let result4 = rc.delete_vhost(name, false).await;
+    assert!(result4.is_ok());
+}
+
+#[tokio::test]
+async fn test_async_get_vhost_topology() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+    let name = "rust_test_async_get_vhost_topology";
+    let q = "rust.tests.topology.q";
+
+    let _ = rc.delete_vhost(name, false).await;
+
+    let params = VirtualHostParams::named(name);
+    let result1 = rc.create_vhost(&params).await;
+    assert!(result1.is_ok());
+
+    let qp = QueueParams::new_durable_classic_queue(q, None);
+    let _ = rc.declare_queue(name, &qp).await;
+    let _ = rc.bind_queue(name, q, "amq.fanout", None, None).await;
+
+    let result2 = rc.get_vhost_topology(name).await;
+    assert!(result2.is_ok(), "get_vhost_topology returned {:?}", result2);
+    let topology = result2.unwrap();
+    assert_eq!(name, topology.vhost);
+    assert!(topology.queues.iter().any(|x| x.name == q));
+    assert!(topology.exchanges.iter().any(|x| x.name == "amq.fanout"));
+    assert!(topology
+        .bindings
+        .iter()
+        .any(|b| b.source == "amq.fanout" && b.destination == q));
+    assert!(topology.parameters.iter().all(|p| p.vhost == name));
+
+    let _ = rc.delete_vhost(name, false).await;
+}
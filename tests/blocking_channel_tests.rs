@@ -34,3 +34,28 @@ fn test_blocking_list_virtual_host_channels() {
     let result1 = rc.list_channels_in(vh_name);
     assert!(result1.is_ok(), "list_channels_in returned {:?}", result1);
 }
+
+#[test]
+fn test_blocking_list_channels_on_node() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let nodes = rc.list_nodes().unwrap();
+    let node = &nodes.first().unwrap().name;
+    let result1 = rc.list_channels_on_node(node);
+    assert!(
+        result1.is_ok(),
+        "list_channels_on_node returned {:?}",
+        result1
+    );
+    assert!(result1.unwrap().iter().all(|c| &c.node == node));
+}
+
+#[test]
+fn test_blocking_list_channels_on_unknown_connection() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let result1 = rc.list_channels_on("127.0.0.1:1 -> 127.0.0.1:5672");
+    assert!(result1.is_err());
+}
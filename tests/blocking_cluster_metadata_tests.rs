@@ -72,3 +72,33 @@ fn test_blocking_set_cluster_tags() {
     let result3 = rc.clear_cluster_tags();
     assert!(result3.is_ok());
 }
+
+#[test]
+fn test_blocking_set_mqtt_default_vhosts() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let mut mappings: Map<String, Value> = Map::new();
+    mappings.insert("O=client,CN=sensor-1".to_owned(), json!("/"));
+
+    let result1 = rc.set_mqtt_default_vhosts(mappings);
+    assert!(
+        result1.is_ok(),
+        "set_mqtt_default_vhosts returned {:?}",
+        result1
+    );
+
+    let result2 = rc.get_mqtt_default_vhosts();
+    assert!(
+        result2.is_ok(),
+        "get_mqtt_default_vhosts returned {:?}",
+        result2
+    );
+    assert_eq!(
+        Some("/"),
+        result2.unwrap().vhost_for("O=client,CN=sensor-1")
+    );
+
+    let result3 = rc.clear_mqtt_default_vhosts();
+    assert!(result3.is_ok());
+}
@@ -12,7 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 use rabbitmq_http_client::blocking_api::Client;
+use rabbitmq_http_client::churn_analyzer::ChurnAnalyzerSettings;
 use rabbitmq_http_client::requests::VirtualHostParams;
+use std::time::Duration;
 
 mod test_helpers;
 use crate::test_helpers::{endpoint, PASSWORD, USERNAME};
@@ -26,6 +28,29 @@ fn test_blocking_list_connections() {
     assert!(result1.is_ok(), "list_connections returned {:?}", result1);
 }
 
+#[test]
+fn test_blocking_list_connections_with_client_name() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let result1 = rc.list_connections_with_client_name("rust.http.api.blocking.no_such_connection");
+    assert!(
+        result1.is_ok(),
+        "list_connections_with_client_name returned {:?}",
+        result1
+    );
+    assert!(result1.unwrap().is_empty());
+}
+
+#[test]
+fn test_blocking_get_connection_info_of_unknown_connection() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let result1 = rc.get_connection_info("127.0.0.1:1 -> 127.0.0.1:5672");
+    assert!(result1.is_err());
+}
+
 #[test]
 fn test_blocking_list_user_connections() {
     let endpoint = endpoint();
@@ -86,3 +111,38 @@ fn test_blocking_list_virtual_host_stream_connections() {
         result1
     );
 }
+
+#[test]
+fn test_blocking_analyze_connection_churn() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let settings =
+        ChurnAnalyzerSettings::new(Duration::from_secs(2)).with_interval(Duration::from_secs(1));
+    let result1 = rc.analyze_connection_churn(&settings);
+    assert!(
+        result1.is_ok(),
+        "analyze_connection_churn returned {:?}",
+        result1
+    );
+    assert!(result1.unwrap().elapsed >= Duration::from_secs(2));
+}
+
+#[test]
+fn test_blocking_list_connections_on_node() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let nodes = rc.list_nodes().unwrap();
+    let node = &nodes.first().unwrap().name;
+    let result1 = rc.list_connections_on_node(node);
+    assert!(
+        result1.is_ok(),
+        "list_connections_on_node returned {:?}",
+        result1
+    );
+    assert!(result1.unwrap().iter().all(|c| &c.node == node));
+
+    let result2 = rc.list_connections_on_node("rabbit@no-such-node");
+    assert!(result2.unwrap().is_empty());
+}
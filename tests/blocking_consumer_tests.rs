@@ -39,3 +39,23 @@ fn test_blocking_list_vhost_consumers() {
 
     rc.delete_vhost(vh_params.name, true).unwrap();
 }
+
+#[test]
+fn test_blocking_list_queue_consumers() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let vh_params = VirtualHostParams::named("test_blocking_list_queue_consumers");
+    let result1 = rc.create_vhost(&vh_params);
+    assert!(result1.is_ok());
+
+    let result2 = rc.list_consumers_on_queue(vh_params.name, "a.queue");
+    assert!(
+        result2.is_ok(),
+        "list_consumers_on_queue returned {:?}",
+        result2
+    );
+    assert!(result2.unwrap().is_empty());
+
+    rc.delete_vhost(vh_params.name, true).unwrap();
+}
@@ -17,10 +17,11 @@ mod test_helpers;
 use crate::test_helpers::{
     await_metric_emission, await_queue_metric_emission, endpoint, PASSWORD, USERNAME,
 };
-use rabbitmq_http_client::commons::PolicyTarget;
+use rabbitmq_http_client::commons::{ExchangeType, PolicyTarget, QueueType};
 use rabbitmq_http_client::requests::{
     ExchangeParams, PolicyParams, QueueParams, VirtualHostParams,
 };
+use rabbitmq_http_client::topology_generator::TopologyGenerator;
 use serde_json::{json, Map, Value};
 
 #[test]
@@ -286,3 +287,65 @@ fn test_blocking_import_vhost_definitions() {
 
     rc.delete_vhost(vh, true).unwrap();
 }
+
+#[test]
+fn test_blocking_backup_and_restore_definitions_using_a_file() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let vh = "rust/http/api/blocking/definitions/backup";
+    rc.delete_vhost(vh, true).unwrap();
+    let vh_params = VirtualHostParams::named(vh);
+    rc.create_vhost(&vh_params).unwrap();
+
+    let path = std::env::temp_dir().join("rust.http.api.blocking.definitions.backup.json");
+    let result1 = rc.backup_definitions_to_file(&path);
+    assert!(
+        result1.is_ok(),
+        "backup_definitions_to_file returned {:?}",
+        result1
+    );
+
+    rc.delete_vhost(vh, false).unwrap();
+
+    let result2 = rc.restore_definitions_from_file(&path);
+    assert!(
+        result2.is_ok(),
+        "restore_definitions_from_file returned {:?}",
+        result2
+    );
+    assert!(rc.get_vhost(vh).is_ok());
+
+    rc.delete_vhost(vh, true).unwrap();
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_blocking_declare_and_delete_generated_topology() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let vh = "/";
+    let topology = TopologyGenerator::new(vh)
+        .with_queues(20, "rust.tests.blocking.generated.q.{n}")
+        .with_queue_types(vec![QueueType::Classic, QueueType::Quorum])
+        .with_exchanges(
+            4,
+            "rust.tests.blocking.generated.x.{n}",
+            ExchangeType::Direct,
+        )
+        .with_bindings_per_queue(2)
+        .generate();
+
+    let result1 = rc.declare_topology(&topology, 4);
+    assert!(result1.is_ok(), "declare_topology returned {:?}", result1);
+
+    let result2 = rc.get_queue_info(vh, "rust.tests.blocking.generated.q.19");
+    assert!(result2.is_ok(), "get_queue_info returned {:?}", result2);
+
+    let result3 = rc.delete_topology(&topology, 4);
+    assert!(result3.is_ok(), "delete_topology returned {:?}", result3);
+
+    let result4 = rc.get_queue_info(vh, "rust.tests.blocking.generated.q.19");
+    assert!(result4.is_err());
+}
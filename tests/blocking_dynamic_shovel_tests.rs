@@ -15,6 +15,7 @@ use rabbitmq_http_client::commons::MessageTransferAcknowledgementMode;
 use rabbitmq_http_client::requests::{
     Amqp091ShovelDestinationParams, Amqp091ShovelParams, Amqp091ShovelSourceParams,
     Amqp10ShovelDestinationParams, Amqp10ShovelParams, Amqp10ShovelSourceParams, QueueParams,
+    ShovelParams,
 };
 use rabbitmq_http_client::{blocking_api::Client, requests::VirtualHostParams};
 
@@ -235,3 +236,88 @@ fn test_blocking_delete_a_dynamic_amqp091_shovel() {
 
     let _ = rc.delete_vhost(vh_params.name, false);
 }
+
+#[test]
+fn test_blocking_restart_a_dynamic_amqp091_shovel() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    if testing_against_3_13_x() {
+        return;
+    }
+
+    let vh = "rust.http.api.blocking.test_restart_a_dynamic_amqp091_shovel";
+    let sh = "test_restart_a_dynamic_amqp091_shovel";
+
+    let vh_params = VirtualHostParams::named(vh);
+    let result1 = rc.create_vhost(&vh_params);
+    assert!(result1.is_ok());
+
+    let src_q = format!("{0}.src.q", sh);
+    let dest_q = format!("{0}.dest.q", sh);
+
+    let amqp_endpoint = amqp_endpoint_with_vhost(vh);
+    let shovel_params = Amqp091ShovelParams {
+        vhost: vh,
+        name: sh,
+        acknowledgement_mode: MessageTransferAcknowledgementMode::WhenConfirmed,
+        reconnect_delay: Some(5),
+        source: Amqp091ShovelSourceParams::queue_source(&amqp_endpoint, &src_q),
+        destination: Amqp091ShovelDestinationParams::queue_destination(&amqp_endpoint, &dest_q),
+    };
+    let result2 = rc.declare_amqp091_shovel(shovel_params);
+    assert!(result2.is_ok());
+
+    await_metric_emission(200);
+    let result3 = rc.restart_shovel(vh, sh);
+    assert!(result3.is_ok(), "restart_shovel returned {:?}", result3);
+
+    let result4 = rc.delete_shovel(vh, sh, false);
+    assert!(result4.is_ok());
+
+    let result5 = rc.restart_shovel(vh, sh);
+    assert!(result5.is_err());
+
+    let _ = rc.delete_vhost(vh_params.name, false);
+}
+
+#[test]
+fn test_blocking_declare_a_shovel_and_list_shovels_in_vhost() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    if testing_against_3_13_x() {
+        return;
+    }
+
+    let vh = "rust.http.api.blocking.test_declare_a_shovel_and_list_shovels_in_vhost";
+    let sh = "test_declare_a_shovel_and_list_shovels_in_vhost";
+
+    let vh_params = VirtualHostParams::named(vh);
+    let result1 = rc.create_vhost(&vh_params);
+    assert!(result1.is_ok());
+
+    let src_q = format!("{0}.src.q", sh);
+    let dest_q = format!("{0}.dest.q", sh);
+
+    let amqp_endpoint = amqp_endpoint_with_vhost(vh);
+    let shovel_params = ShovelParams::from(Amqp091ShovelParams {
+        vhost: vh,
+        name: sh,
+        acknowledgement_mode: MessageTransferAcknowledgementMode::WhenConfirmed,
+        reconnect_delay: Some(5),
+        source: Amqp091ShovelSourceParams::queue_source(&amqp_endpoint, &src_q),
+        destination: Amqp091ShovelDestinationParams::queue_destination(&amqp_endpoint, &dest_q),
+    });
+    let result2 = rc.declare_shovel(shovel_params);
+    assert!(result2.is_ok(), "declare_shovel returned {:?}", result2);
+
+    await_metric_emission(300);
+    let result3 = rc.list_shovels_in(vh);
+    assert!(result3.is_ok(), "list_shovels_in returned {:?}", result3);
+    let shovels = result3.unwrap();
+    assert!(shovels.iter().all(|s| s.vhost == vh));
+    assert!(shovels.iter().any(|s| s.name == sh));
+
+    let _ = rc.delete_vhost(vh_params.name, false);
+}
@@ -11,7 +11,10 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
-use rabbitmq_http_client::{blocking_api::Client, commons::SupportedProtocol};
+use rabbitmq_http_client::{
+    blocking_api::Client,
+    commons::{SupportedProtocol, TimeUnit},
+};
 
 mod test_helpers;
 use crate::test_helpers::{endpoint, PASSWORD, USERNAME};
@@ -43,6 +46,15 @@ fn test_blocking_health_check_node_is_quorum_critical() {
     assert!(result1.is_ok());
 }
 
+#[test]
+fn test_blocking_health_check_node_is_mirror_sync_critical() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let result1 = rc.health_check_if_node_is_mirror_sync_critical();
+    assert!(result1.is_ok());
+}
+
 #[test]
 fn test_blocking_health_check_port_listener_succeeds() {
     let endpoint = endpoint();
@@ -88,3 +100,58 @@ fn test_blocking_health_check_protocol_listener_fails() {
     let result2 = rc.health_check_protocol_listener(SupportedProtocol::STOMPOverWebsocketsWithTLS);
     assert!(result2.is_err());
 }
+
+#[test]
+fn test_blocking_health_check_certificate_expiration() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let result1 = rc.health_check_certificate_expiration(4, TimeUnit::Weeks);
+    assert!(result1.is_ok());
+}
+
+#[test]
+fn test_blocking_health_check_virtual_hosts() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let result1 = rc.health_check_virtual_hosts();
+    assert!(result1.is_ok());
+}
+
+#[test]
+fn test_blocking_health_check_metadata_store_initialized() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let result1 = rc.health_check_metadata_store_initialized();
+    assert!(result1.is_ok());
+
+    let result2 = rc.health_check_metadata_store_initialized_with_data();
+    assert!(result2.is_ok());
+}
+
+#[test]
+fn test_blocking_health_check_quorum_queues_without_elected_leaders() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let result1 = rc.health_check_quorum_queues_without_elected_leaders();
+    assert!(result1.is_ok());
+
+    let result2 = rc.health_check_quorum_queues_without_elected_leaders_matching("^qq\\.");
+    assert!(result2.is_ok());
+
+    let result3 = rc.health_check_quorum_queues_without_elected_leaders_in("/", None);
+    assert!(result3.is_ok());
+}
+
+#[test]
+fn test_blocking_aliveness_test() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let result = rc.aliveness_test("/");
+    assert!(result.is_ok(), "aliveness_test returned {:?}", result);
+    assert!(result.unwrap().is_ok());
+}
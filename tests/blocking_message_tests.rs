@@ -13,7 +13,8 @@
 // limitations under the License.
 use rabbitmq_http_client::{
     blocking_api::Client,
-    requests::{self, QueueParams},
+    commons::PayloadEncoding,
+    requests::{self, MessageReplayParams, QueueParams},
     responses::{GetMessage, MessageProperties, MessageRouted},
 };
 use serde_json::{json, Map, Value};
@@ -64,14 +65,17 @@ fn test_blocking_publish_and_get() {
             message_count: 1,
             properties: MessageProperties::default(),
             payload: "rust test 1".to_owned(),
-            payload_encoding: "string".to_owned()
+            payload_encoding: PayloadEncoding::String
         }]
     );
 
     let result7 = rc.get_messages(vhost, queue, 1, "ack_requeue_false");
     assert!(result7.is_ok(), "get_messages returned {:?}", result7);
 
-    let props = MessageProperties(props);
+    let props = MessageProperties {
+        timestamp: Some(123456789),
+        ..Default::default()
+    };
     let msg_list2 = result7.unwrap();
     assert_eq!(
         msg_list2,
@@ -83,9 +87,147 @@ fn test_blocking_publish_and_get() {
             message_count: 0,
             properties: props,
             payload: "rust test 2".to_owned(),
-            payload_encoding: "string".to_owned()
+            payload_encoding: PayloadEncoding::String
         }]
     );
 
     rc.delete_queue(vhost, queue, false).unwrap();
 }
+
+#[test]
+fn test_blocking_replay_messages() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+    let vhost = "/";
+    let source = "rust.tests.blocking.cq.replay_messages.dlq";
+    let destination = "rust.tests.blocking.cq.replay_messages";
+
+    let _ = rc.delete_queue(vhost, source, false);
+    let _ = rc.delete_queue(vhost, destination, false);
+
+    for q in [source, destination] {
+        let params = QueueParams::new_durable_classic_queue(q, None);
+        rc.declare_queue(vhost, &params).unwrap();
+    }
+
+    let mut props = Map::<String, Value>::new();
+    props.insert(String::from("correlation_id"), json!("c-1"));
+    for payload in ["m1", "m2", "m3"] {
+        rc.publish_message(vhost, "", source, payload, props.clone())
+            .unwrap();
+    }
+
+    let mut params = MessageReplayParams::to_queue(vhost, source, destination);
+    params.batch_size = 2;
+    params.dry_run = true;
+    let result1 = rc.replay_messages(&params);
+    assert!(result1.is_ok(), "replay_messages returned {:?}", result1);
+    assert_eq!(2, result1.unwrap().len());
+
+    params.dry_run = false;
+    let result2 = rc.replay_messages(&params);
+    assert!(result2.is_ok(), "replay_messages returned {:?}", result2);
+    assert_eq!(3, result2.unwrap().len());
+
+    let msgs = rc
+        .get_messages(vhost, destination, 3, "ack_requeue_false")
+        .unwrap();
+    assert_eq!(3, msgs.len());
+    assert_eq!("m1", msgs[0].payload);
+    assert_eq!(Some("c-1".to_owned()), msgs[0].properties.correlation_id);
+
+    rc.delete_queue(vhost, source, false).unwrap();
+    rc.delete_queue(vhost, destination, false).unwrap();
+}
+
+#[test]
+fn test_blocking_drain_queue_to_file_and_publish_messages_from_file() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+    let vhost = "/";
+    let source = "rust.tests.blocking.cq.drain_to_file";
+    let destination = "rust.tests.blocking.cq.drain_to_file.restored";
+    let path = std::env::temp_dir().join("rust.http.api.blocking.messages.drain.jsonl");
+
+    let _ = rc.delete_queue(vhost, source, false);
+    let _ = rc.delete_queue(vhost, destination, false);
+    let _ = std::fs::remove_file(&path);
+
+    for q in [source, destination] {
+        let params = QueueParams::new_durable_classic_queue(q, None);
+        rc.declare_queue(vhost, &params).unwrap();
+    }
+
+    let mut props = Map::<String, Value>::new();
+    props.insert(String::from("correlation_id"), json!("c-1"));
+    for payload in ["m1", "m2", "m3"] {
+        rc.publish_message(vhost, "", source, payload, props.clone())
+            .unwrap();
+    }
+
+    let mut progress = Vec::new();
+    let result1 = rc.drain_queue_to_file(vhost, source, &path, 2, |n| progress.push(n));
+    assert!(
+        result1.is_ok(),
+        "drain_queue_to_file returned {:?}",
+        result1
+    );
+    assert_eq!(3, result1.unwrap());
+    assert_eq!(vec![2, 3], progress);
+
+    let result2 = rc.publish_messages_from_file(vhost, &path, None, Some(destination), |_| {});
+    assert!(
+        result2.is_ok(),
+        "publish_messages_from_file returned {:?}",
+        result2
+    );
+    assert_eq!(3, result2.unwrap());
+
+    let msgs = rc
+        .get_messages(vhost, destination, 3, "ack_requeue_false")
+        .unwrap();
+    assert_eq!(3, msgs.len());
+    assert_eq!("m1", msgs[0].payload);
+    assert_eq!(Some("c-1".to_owned()), msgs[0].properties.correlation_id);
+
+    rc.delete_queue(vhost, source, false).unwrap();
+    rc.delete_queue(vhost, destination, false).unwrap();
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_blocking_publish_binary_message() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+    let vhost = "/";
+    let queue = "rust.tests.blocking.cq.publish_binary_message";
+
+    let _ = rc.delete_queue(vhost, queue, false);
+
+    let params = QueueParams::new_durable_classic_queue(queue, None);
+    let result1 = rc.declare_queue(vhost, &params);
+    assert!(result1.is_ok(), "declare_queue returned {:?}", result1);
+
+    let payload: Vec<u8> = vec![0x00, 0xff, 0x10, 0x80, 0x7f];
+    let result2 = rc.publish_binary_message(
+        vhost,
+        "",
+        queue,
+        &payload,
+        requests::MessageProperties::default(),
+    );
+    assert!(
+        result2.is_ok(),
+        "publish_binary_message returned {:?}",
+        result2
+    );
+    assert_eq!(result2.unwrap(), MessageRouted { routed: true });
+
+    let result3 = rc.get_messages(vhost, queue, 1, "ack_requeue_false");
+    assert!(result3.is_ok(), "get_messages returned {:?}", result3);
+    let msg_list = result3.unwrap();
+    assert_eq!(PayloadEncoding::Base64, msg_list[0].payload_encoding);
+    assert_eq!(payload, msg_list[0].decoded_payload().unwrap());
+
+    rc.delete_queue(vhost, queue, false).unwrap();
+}
@@ -42,3 +42,21 @@ fn test_blocking_get_node_memory_footprint() {
     let code_percentage_s = footprint.breakdown.code_percentage_as_text();
     assert!(regex.is_match(&code_percentage_s));
 }
+
+#[test]
+fn test_blocking_get_node_memory_footprint_relative() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+    let nodes = rc.list_nodes().unwrap();
+    let name = nodes.first().unwrap().name.clone();
+
+    let result = rc.get_node_memory_footprint_relative(&name);
+    assert!(
+        result.is_ok(),
+        "get_node_memory_footprint_relative returned {:?}",
+        result
+    );
+    let percentages = result.unwrap().breakdown;
+    assert!(percentages.code > 0.0);
+    assert!(percentages.code <= 100.0);
+}
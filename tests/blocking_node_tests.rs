@@ -39,3 +39,17 @@ fn test_blocking_get_node_info() {
     assert!(node.uptime >= 1);
     assert!(node.total_erlang_processes >= 1);
 }
+
+#[test]
+fn test_blocking_discover_node_clients() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+    // the test suite uses a single node, reachable via the default endpoint
+    let clients = rc.discover_node_clients(|_| endpoint.clone()).unwrap();
+
+    assert!(!clients.is_empty());
+    for (node, client) in clients {
+        assert!(node.starts_with("rabbit@"));
+        assert!(client.health_check_local_alarms().is_ok());
+    }
+}
@@ -171,3 +171,30 @@ fn test_blocking_grant_permissions() {
 
     rc.delete_vhost(vh_params.name, false).unwrap();
 }
+
+#[test]
+fn test_blocking_clear_permissions() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let vh_params = VirtualHostParams::named("test_blocking_clear_permissions");
+    let _ = rc.delete_vhost(vh_params.name, false);
+    let result1 = rc.create_vhost(&vh_params);
+    assert!(result1.is_ok());
+
+    let result2 = rc.get_permissions(vh_params.name, "guest");
+    assert!(result2.is_ok(), "get_permissions returned {:?}", result2);
+
+    let result3 = rc.clear_permissions(vh_params.name, "guest", false);
+    assert!(result3.is_ok(), "clear_permissions returned {:?}", result3);
+
+    let result4 = rc.get_permissions(vh_params.name, "guest");
+    assert!(result4.is_err(), "permissions found after clearing");
+    let result5 = rc.list_permissions_of("guest").unwrap();
+    assert!(!result5.iter().any(|p| p.vhost == vh_params.name));
+
+    let result6 = rc.clear_permissions(vh_params.name, "guest", true);
+    assert!(result6.is_ok(), "clear_permissions returned {:?}", result6);
+
+    rc.delete_vhost(vh_params.name, false).unwrap();
+}
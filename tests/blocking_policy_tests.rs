@@ -17,7 +17,10 @@ use rabbitmq_http_client::{
     requests::{PolicyParams, VirtualHostParams},
 };
 
+use rabbitmq_http_client::watch::{WatchEvent, WatchSettings};
 use serde_json::{json, Map, Value};
+use std::ops::ControlFlow;
+use std::time::Duration;
 mod test_helpers;
 use crate::test_helpers::{endpoint, PASSWORD, USERNAME};
 
@@ -141,3 +144,40 @@ fn test_an_operator_policy(rc: &Client<&str, &str, &str>, policy: &PolicyParams)
     let policies = rc.list_operator_policies().unwrap();
     assert!(!policies.iter().any(|p| p.name == policy.name));
 }
+
+#[test]
+fn test_blocking_watch_policies() {
+    let endpoint = endpoint();
+    let rc = Client::new(endpoint.as_str(), USERNAME, PASSWORD);
+
+    let vh_params = VirtualHostParams::named("test_blocking_watch_policies");
+    let _ = rc.delete_vhost(vh_params.name, false);
+    let result1 = rc.create_vhost(&vh_params);
+    assert!(result1.is_ok());
+
+    let mut definition = Map::<String, Value>::new();
+    definition.insert("max-length".to_owned(), json!(100));
+    let params = PolicyParams {
+        vhost: vh_params.name,
+        name: "watched_policy",
+        pattern: ".*",
+        apply_to: PolicyTarget::Queues,
+        priority: 0,
+        definition,
+    };
+    let result2 = rc.declare_policy(&params);
+    assert!(result2.is_ok());
+
+    let mut added = Vec::new();
+    let settings = WatchSettings::new(Duration::from_millis(500));
+    let result3 = rc.watch_policies(Some(vh_params.name), &settings, |event| {
+        if let WatchEvent::Added(p) = event {
+            added.push(p.name);
+        }
+        ControlFlow::Break(())
+    });
+    assert!(result3.is_ok(), "watch_policies returned {:?}", result3);
+    assert_eq!(vec!["watched_policy".to_owned()], added);
+
+    let _ = rc.delete_vhost(vh_params.name, false);
+}
@@ -34,6 +34,7 @@ fn test_blocking_list_all_user_limits() {
         name: "test_list_all_user_limits",
         password_hash: &password_hash,
         tags: "management",
+        ..Default::default()
     };
     let result1 = rc.create_user(&params);
     assert!(result1.is_ok());
@@ -72,6 +73,7 @@ fn test_blocking_list_user_limits() {
         name: "test_list_user_limits",
         password_hash: &password_hash,
         tags: "management",
+        ..Default::default()
     };
     let result1 = rc.create_user(&params);
     assert!(result1.is_ok());
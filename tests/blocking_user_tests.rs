@@ -11,7 +11,11 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
-use rabbitmq_http_client::{blocking_api::Client, password_hashing, requests::UserParams};
+use rabbitmq_http_client::{
+    blocking_api::Client,
+    password_hashing::{self, HashingAlgorithm},
+    requests::{UserParams, UserParamsWithPassword},
+};
 
 mod test_helpers;
 use crate::test_helpers::{endpoint, PASSWORD, USERNAME};
@@ -46,6 +50,7 @@ fn test_blocking_list_users_without_permissions() {
         name: &username,
         password_hash: &password_hash,
         tags: "",
+        ..Default::default()
     };
     rc.create_user(&params).expect("failed to create a user");
 
@@ -83,11 +88,75 @@ fn test_blocking_user_creation() {
         name: "rust3",
         password_hash: &password_hash,
         tags: "management",
+        ..Default::default()
     };
     let result = rc.create_user(&params);
     assert!(result.is_ok());
 }
 
+#[test]
+fn test_blocking_user_creation_with_password() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let name = "rust_test_blocking_user_creation_with_password";
+    let password = "rust4_t0p_sEkr37";
+    let _ = rc.delete_user(name, true);
+
+    let params = UserParamsWithPassword::new(name, password, "management");
+    let result1 = rc.create_user_with_password(&params);
+    assert!(
+        result1.is_ok(),
+        "create_user_with_password returned {:?}",
+        result1
+    );
+
+    let rc2 = Client::new(&endpoint, name, password);
+    let result2 = rc2.current_user();
+    assert!(result2.is_ok(), "current_user returned {:?}", result2);
+    assert_eq!(name, result2.unwrap().name);
+
+    let _ = rc.delete_user(name, false);
+}
+
+#[test]
+fn test_blocking_user_update_preserves_password_hash() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let salt = password_hashing::salt();
+    let password_hash =
+        password_hashing::base64_encoded_salted_password_hash_sha512(&salt, "upd4te_me");
+
+    let name = "upd4te_me";
+    let params = UserParams {
+        name,
+        password_hash: &password_hash,
+        tags: "management",
+        hashing_algorithm: Some(HashingAlgorithm::Sha512),
+    };
+    let result1 = rc.create_user(&params);
+    assert!(result1.is_ok());
+
+    let changes = UserParams {
+        name,
+        password_hash: "",
+        tags: "monitoring",
+        ..Default::default()
+    };
+    let result2 = rc.update_user(&changes);
+    assert!(result2.is_ok());
+
+    let user = rc.get_user(name).unwrap();
+    assert_eq!(password_hash, user.password_hash);
+    assert_eq!(
+        Some(HashingAlgorithm::Sha512),
+        user.parsed_hashing_algorithm().unwrap()
+    );
+
+    let _ = rc.delete_user(name, false);
+}
+
 #[test]
 fn test_blocking_user_deletion() {
     let endpoint = endpoint();
@@ -102,6 +171,7 @@ fn test_blocking_user_deletion() {
         name,
         password_hash: &password_hash,
         tags: "management",
+        ..Default::default()
     };
     let result1 = rc.create_user(&params);
     assert!(result1.is_ok());
@@ -124,6 +194,7 @@ fn test_blocking_bulk_user_deletion() {
         name: name1,
         password_hash: &password_hash,
         tags: "management",
+        ..Default::default()
     };
     let result1 = rc.create_user(&params1);
     assert!(result1.is_ok());
@@ -133,6 +204,7 @@ fn test_blocking_bulk_user_deletion() {
         name: name2,
         password_hash: &password_hash,
         tags: "management",
+        ..Default::default()
     };
     let result2 = rc.create_user(&params2);
     assert!(result2.is_ok());
@@ -140,3 +212,15 @@ fn test_blocking_bulk_user_deletion() {
     let result2 = rc.delete_users(vec![name1, name2]);
     assert!(result2.is_ok());
 }
+
+#[test]
+fn test_blocking_current_user() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let result = rc.current_user();
+    assert!(result.is_ok(), "current_user returned {:?}", result);
+    let user = result.unwrap();
+    assert_eq!(USERNAME, user.name);
+    assert!(user.is_administrator());
+}
@@ -15,6 +15,9 @@
 
 use rabbitmq_http_client::blocking_api::Client as BlockingClient;
 use std::env;
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::thread::{self, JoinHandle};
 use std::time::Duration;
 
 use amqprs::channel::BasicPublishArguments;
@@ -147,3 +150,126 @@ pub fn cluster_tags(tags: Map<String, Value>) -> Map<String, Value> {
     val.insert(String::from("cluster_tags"), json!(tags));
     val
 }
+
+//
+// One-shot HTTP server
+//
+
+/// A local HTTP server that accepts a single connection. Used by unit tests
+/// that need a canned response but not a RabbitMQ node.
+pub struct OneShotServer {
+    address: SocketAddr,
+    handle: JoinHandle<String>,
+}
+
+impl OneShotServer {
+    pub fn endpoint(&self) -> String {
+        format!("http://{}/api", self.address)
+    }
+
+    pub fn port(&self) -> u16 {
+        self.address.port()
+    }
+
+    /// Waits for the server to finish and returns the raw request it received
+    pub fn request(self) -> String {
+        self.handle.join().unwrap()
+    }
+}
+
+/// Responds to a single request with the given status line (e.g. `"200 OK"`),
+/// headers and body. `content-length` is added unless the headers
+/// set `transfer-encoding`, in which case the body must already be encoded.
+pub fn serve_once(status: &str, headers: &[(&str, &str)], body: &str) -> OneShotServer {
+    let response = response_bytes(status, headers, body);
+    spawn_one_shot_server(move |stream| {
+        let _ = stream.write_all(&response);
+    })
+}
+
+/// Like [`serve_once`] but holds the connection open for `duration` after responding,
+/// e.g. to serve a chunked body that never completes
+pub fn serve_once_and_hang(
+    status: &str,
+    headers: &[(&str, &str)],
+    body: &str,
+    duration: Duration,
+) -> OneShotServer {
+    let response = response_bytes(status, headers, body);
+    spawn_one_shot_server(move |stream| {
+        let _ = stream.write_all(&response);
+        let _ = stream.flush();
+        thread::sleep(duration);
+    })
+}
+
+/// Accepts a single connection, reads the request and holds the connection
+/// open for `duration` without responding
+pub fn serve_nothing_once(duration: Duration) -> OneShotServer {
+    spawn_one_shot_server(move |_| thread::sleep(duration))
+}
+
+/// Encodes `body` as a single chunk followed by the terminating chunk
+pub fn chunked(body: &str) -> String {
+    format!("{:x}\r\n{}\r\n0\r\n\r\n", body.len(), body)
+}
+
+fn response_bytes(status: &str, headers: &[(&str, &str)], body: &str) -> Vec<u8> {
+    let mut response = format!("HTTP/1.1 {}\r\nconnection: close\r\n", status);
+    for (name, value) in headers {
+        response.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    if !headers
+        .iter()
+        .any(|(name, _)| name.eq_ignore_ascii_case("transfer-encoding"))
+    {
+        response.push_str(&format!("content-length: {}\r\n", body.len()));
+    }
+    response.push_str("\r\n");
+    response.push_str(body);
+    response.into_bytes()
+}
+
+fn spawn_one_shot_server<F>(respond: F) -> OneShotServer
+where
+    F: FnOnce(&mut TcpStream) + Send + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    let handle = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let request = read_request(&mut stream);
+        respond(&mut stream);
+        request
+    });
+    OneShotServer { address, handle }
+}
+
+// reads the request line, headers and a body delimited either by
+// content-length or by the terminating chunk
+fn read_request(stream: &mut TcpStream) -> String {
+    let mut request = Vec::new();
+    let mut buf = [0u8; 4096];
+    loop {
+        let n = stream.read(&mut buf).unwrap_or(0);
+        request.extend_from_slice(&buf[..n]);
+        let text = String::from_utf8_lossy(&request);
+        if n == 0 {
+            break;
+        }
+        if let Some((head, body)) = text.split_once("\r\n\r\n") {
+            let head = head.to_lowercase();
+            let content_length = head
+                .lines()
+                .find_map(|l| l.strip_prefix("content-length:"))
+                .map_or(0, |v| v.trim().parse::<usize>().unwrap());
+            let is_chunked = head.contains("transfer-encoding: chunked");
+            if (is_chunked && body.ends_with("0\r\n\r\n"))
+                || (!is_chunked && body.len() >= content_length)
+            {
+                break;
+            }
+        }
+    }
+    String::from_utf8_lossy(&request).into_owned()
+}
@@ -0,0 +1,132 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod test_helpers;
+
+use rabbitmq_http_client::alarm_watcher::{
+    AlarmEvent, AlarmStateTracker, AlarmWatcherSettings, WatchedCheck,
+};
+use rabbitmq_http_client::blocking_api::Client;
+use rabbitmq_http_client::responses::{ClusterAlarmCheckDetails, HealthCheckFailureDetails};
+use std::ops::ControlFlow;
+use std::time::Duration;
+
+fn alarm_details() -> HealthCheckFailureDetails {
+    HealthCheckFailureDetails::AlarmCheck(ClusterAlarmCheckDetails {
+        reason: "There are alarms in effect in the cluster".to_owned(),
+        alarms: Vec::new(),
+    })
+}
+
+#[test]
+fn test_unit_alarm_state_tracker_reports_transitions() {
+    let check = WatchedCheck::ClusterWideAlarms;
+    let mut tracker = AlarmStateTracker::new(1);
+
+    assert_eq!(None, tracker.observe(check, None));
+    assert_eq!(
+        Some(AlarmEvent::Raised {
+            check,
+            details: alarm_details()
+        }),
+        tracker.observe(check, Some(alarm_details()))
+    );
+    assert!(tracker.is_raised(check));
+    // no transition
+    assert_eq!(None, tracker.observe(check, Some(alarm_details())));
+
+    assert_eq!(
+        Some(AlarmEvent::Cleared { check }),
+        tracker.observe(check, None)
+    );
+    assert!(!tracker.is_raised(check));
+}
+
+#[test]
+fn test_unit_alarm_state_tracker_debounces_transitions() {
+    let check = WatchedCheck::NodeIsQuorumCritical;
+    let mut tracker = AlarmStateTracker::new(3);
+
+    assert_eq!(None, tracker.observe(check, Some(alarm_details())));
+    assert_eq!(None, tracker.observe(check, Some(alarm_details())));
+    // a flap resets the counter
+    assert_eq!(None, tracker.observe(check, None));
+    assert_eq!(None, tracker.observe(check, Some(alarm_details())));
+    assert_eq!(None, tracker.observe(check, Some(alarm_details())));
+    let event = tracker.observe(check, Some(alarm_details())).unwrap();
+    assert!(matches!(event, AlarmEvent::Raised { .. }));
+    assert_eq!(check, event.check());
+
+    assert_eq!(None, tracker.observe(check, None));
+    assert_eq!(None, tracker.observe(check, None));
+    assert_eq!(
+        Some(AlarmEvent::Cleared { check }),
+        tracker.observe(check, None)
+    );
+}
+
+#[test]
+fn test_unit_alarm_state_tracker_tracks_checks_independently() {
+    let mut tracker = AlarmStateTracker::new(1);
+
+    assert!(tracker
+        .observe(WatchedCheck::LocalAlarms, Some(alarm_details()))
+        .is_some());
+    assert!(tracker.is_raised(WatchedCheck::LocalAlarms));
+    assert!(!tracker.is_raised(WatchedCheck::ClusterWideAlarms));
+    assert_eq!(None, tracker.observe(WatchedCheck::ClusterWideAlarms, None));
+}
+
+#[test]
+fn test_unit_alarm_watcher_settings() {
+    let settings = AlarmWatcherSettings::default();
+    assert_eq!(1, settings.debounce);
+    assert_eq!(
+        vec![
+            WatchedCheck::ClusterWideAlarms,
+            WatchedCheck::NodeIsQuorumCritical
+        ],
+        settings.checks
+    );
+
+    let settings = AlarmWatcherSettings::new(Duration::from_secs(30))
+        .with_debounce(2)
+        .with_checks(vec![WatchedCheck::LocalAlarms]);
+    assert_eq!(Duration::from_secs(30), settings.interval);
+    assert_eq!(2, settings.debounce);
+    assert_eq!(vec![WatchedCheck::LocalAlarms], settings.checks);
+}
+
+#[test]
+fn test_unit_watch_alarms_keeps_polling_unreachable_nodes() {
+    // nothing listens on this port
+    let rc = Client::new("http://127.0.0.1:1/api", "guest", "guest");
+    let settings = AlarmWatcherSettings::new(Duration::from_millis(10))
+        .with_checks(vec![WatchedCheck::LocalAlarms]);
+
+    let mut failures = 0;
+    let result = rc.watch_alarms(&settings, |event| match event {
+        AlarmEvent::CheckFailed { check, .. } => {
+            assert_eq!(WatchedCheck::LocalAlarms, check);
+            failures += 1;
+            if failures < 3 {
+                ControlFlow::Continue(())
+            } else {
+                ControlFlow::Break(())
+            }
+        }
+        other => panic!("expected CheckFailed, got {:?}", other),
+    });
+    assert!(result.is_ok(), "watch_alarms returned {:?}", result);
+    assert_eq!(3, failures);
+}
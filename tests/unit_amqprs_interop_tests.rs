@@ -0,0 +1,124 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg(feature = "amqprs")]
+
+mod test_helpers;
+
+use amqprs::channel::{ExchangeDeclareArguments, QueueDeclareArguments};
+use amqprs::{BasicProperties, FieldName, FieldTable, FieldValue};
+use rabbitmq_http_client::amqprs_interop::{
+    field_table_from_json, json_from_field_table, x_arguments_from_field_table,
+};
+use rabbitmq_http_client::commons::ExchangeType;
+use rabbitmq_http_client::requests::{ExchangeParams, QueueParams};
+use rabbitmq_http_client::responses::MessageProperties;
+use rabbitmq_http_client::x_args;
+use serde_json::{json, Map};
+
+fn field_name(s: &str) -> FieldName {
+    FieldName::try_from(s).unwrap()
+}
+
+#[test]
+fn test_unit_json_to_field_table_round_trip() {
+    let val = json!({
+        "x-max-length": 10000,
+        "x-overflow": "reject-publish",
+        "x-single-active-consumer": true,
+        "ratio": 0.5,
+        "nothing": null,
+        "nodes": ["rabbit@node1", "rabbit@node2"],
+        "nested": {"a": 1}
+    });
+    let m = val.as_object().unwrap();
+
+    let table = field_table_from_json(m);
+    assert_eq!(
+        Some(&FieldValue::l(10000)),
+        table.get(&field_name("x-max-length"))
+    );
+    assert_eq!(
+        Some(&FieldValue::t(true)),
+        table.get(&field_name("x-single-active-consumer"))
+    );
+    assert_eq!(Some(&FieldValue::V), table.get(&field_name("nothing")));
+
+    assert_eq!(m, &json_from_field_table(&table));
+}
+
+#[test]
+fn test_unit_empty_field_table_to_x_arguments() {
+    assert_eq!(None, x_arguments_from_field_table(&FieldTable::new()));
+}
+
+#[test]
+fn test_unit_queue_params_to_queue_declare_arguments() {
+    let params = QueueParams::new_quorum_queue("qq.1", None);
+    let args = QueueDeclareArguments::from(&params);
+
+    // QueueDeclareArguments does not expose its fields, and field tables with
+    // more than one key are formatted in an undefined order
+    let expected = QueueDeclareArguments::durable_client_named("qq.1")
+        .arguments(field_table_from_json(
+            json!({"x-queue-type": "quorum"}).as_object().unwrap(),
+        ))
+        .finish();
+    assert_eq!(format!("{:?}", expected), format!("{:?}", args));
+}
+
+#[test]
+fn test_unit_exchange_params_to_exchange_declare_arguments_and_back() {
+    let params = ExchangeParams::durable(
+        "events.topic",
+        ExchangeType::Topic,
+        x_args! {"alternate-exchange" => "unroutable"},
+    );
+    let args = ExchangeDeclareArguments::from(&params);
+
+    assert_eq!("events.topic", args.exchange);
+    assert_eq!("topic", args.exchange_type);
+    assert!(args.durable);
+    assert!(!args.auto_delete);
+
+    let params2 = ExchangeParams::from(&args);
+    assert_eq!(params.name, params2.name);
+    assert_eq!(params.exchange_type, params2.exchange_type);
+    assert_eq!(params.durable, params2.durable);
+    assert_eq!(params.arguments, params2.arguments);
+}
+
+#[test]
+fn test_unit_message_properties_to_basic_properties_and_back() {
+    let mut extras = Map::new();
+    extras.insert("app_id".to_owned(), json!("billing"));
+    extras.insert("type".to_owned(), json!("orders.created"));
+    let props = MessageProperties {
+        content_type: Some("application/json".to_owned()),
+        delivery_mode: Some(2),
+        priority: Some(5),
+        correlation_id: Some("abc-123".to_owned()),
+        timestamp: Some(1747411200),
+        headers: Some(json!({"x-retries": 3}).as_object().unwrap().clone()),
+        extras,
+        ..Default::default()
+    };
+
+    let bp = BasicProperties::from(&props);
+    assert_eq!(Some(&"application/json".to_owned()), bp.content_type());
+    assert_eq!(Some(2), bp.delivery_mode());
+    assert_eq!(Some(&"billing".to_owned()), bp.app_id());
+    assert_eq!(Some(&"orders.created".to_owned()), bp.message_type());
+
+    assert_eq!(props, MessageProperties::from(&bp));
+}
@@ -0,0 +1,79 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod test_helpers;
+
+use std::sync::{Arc, Mutex};
+
+use rabbitmq_http_client::audit::{AuditHook, Mutation};
+use rabbitmq_http_client::blocking_api::ClientBuilder;
+use rabbitmq_http_client::commons::QueueType;
+use rabbitmq_http_client::requests::QueueParams;
+use test_helpers::serve_once;
+
+fn recording_hook() -> (AuditHook, Arc<Mutex<Vec<Mutation>>>) {
+    let recorded = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&recorded);
+    let hook = AuditHook::new(move |m| sink.lock().unwrap().push(m.clone()));
+    (hook, recorded)
+}
+
+#[test]
+fn test_unit_audit_hook_is_invoked_after_successful_mutations() {
+    let server = serve_once("204 No Content", &[], "");
+    let (hook, recorded) = recording_hook();
+
+    let rc = ClientBuilder::new()
+        .with_endpoint(server.endpoint())
+        .with_audit_hook(hook)
+        .build();
+    let params = QueueParams::new("orders", QueueType::Quorum, true, false, None);
+    rc.declare_queue("events", &params).unwrap();
+    server.request();
+
+    assert_eq!(
+        vec![Mutation::DeclaredQueue {
+            vhost: "events".to_owned(),
+            name: "orders".to_owned()
+        }],
+        *recorded.lock().unwrap()
+    );
+}
+
+#[test]
+fn test_unit_audit_hook_is_not_invoked_for_failed_mutations() {
+    let (hook, recorded) = recording_hook();
+
+    let rc = ClientBuilder::new()
+        .with_endpoint("http://127.0.0.1:1/api")
+        .with_audit_hook(hook)
+        .build();
+    assert!(rc.delete_queue("events", "orders", false).is_err());
+
+    assert!(recorded.lock().unwrap().is_empty());
+}
+
+#[test]
+fn test_unit_audit_hook_is_not_invoked_for_reads() {
+    let server = serve_once("204 No Content", &[], "");
+    let (hook, recorded) = recording_hook();
+
+    let rc = ClientBuilder::new()
+        .with_endpoint(server.endpoint())
+        .with_audit_hook(hook)
+        .build();
+    let _ = rc.list_vhosts();
+    server.request();
+
+    assert!(recorded.lock().unwrap().is_empty());
+}
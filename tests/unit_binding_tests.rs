@@ -0,0 +1,60 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod test_helpers;
+
+use rabbitmq_http_client::commons::BindingDestinationType;
+use rabbitmq_http_client::responses::{BindingInfo, BindingInfoFilters};
+use serde_json::json;
+
+fn bindings() -> Vec<BindingInfo> {
+    serde_json::from_value(json!([
+        {"vhost": "/", "source": "", "destination": "orders", "destination_type": "queue",
+         "routing_key": "orders", "arguments": {}, "properties_key": "orders"},
+        {"vhost": "/", "source": "events", "destination": "orders", "destination_type": "queue",
+         "routing_key": "order.created", "arguments": {}, "properties_key": "order.created"},
+        {"vhost": "/", "source": "events", "destination": "events.audit", "destination_type": "exchange",
+         "routing_key": "#", "arguments": {}, "properties_key": "%23"},
+        {"vhost": "other", "source": "amq.fanout", "destination": "logs", "destination_type": "queue",
+         "routing_key": "", "arguments": {}, "properties_key": "~"}
+    ]))
+    .unwrap()
+}
+
+#[test]
+fn test_unit_binding_filters_by_destination_type() {
+    let xs = bindings().with_destination_type(BindingDestinationType::Exchange);
+    assert_eq!(1, xs.len());
+    assert_eq!("events.audit", xs[0].destination);
+
+    let xs = bindings().with_destination_type(BindingDestinationType::Queue);
+    assert_eq!(3, xs.len());
+}
+
+#[test]
+fn test_unit_binding_filters_by_source_and_destination() {
+    let xs = bindings().with_source("events");
+    assert_eq!(2, xs.len());
+
+    // the default exchange
+    let xs = bindings().with_source("");
+    assert_eq!(1, xs.len());
+    assert_eq!("orders", xs[0].routing_key);
+
+    let xs = bindings()
+        .with_source("events")
+        .with_destination_type(BindingDestinationType::Queue)
+        .with_destination("orders");
+    assert_eq!(1, xs.len());
+    assert_eq!("order.created", xs[0].routing_key);
+}
@@ -0,0 +1,77 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod test_helpers;
+
+use rabbitmq_http_client::blocking_api::{Client, ClientBuilder};
+use rabbitmq_http_client::error::Error;
+
+const UNREACHABLE_ENDPOINT: &str = "http://127.0.0.1:1/api";
+
+#[test]
+fn test_unit_blocking_client_with_an_owned_runtime() {
+    let rc = Client::new(UNREACHABLE_ENDPOINT, "guest", "guest");
+
+    let result = rc.list_nodes();
+    assert!(matches!(result, Err(Error::RequestError { .. })));
+}
+
+#[test]
+fn test_unit_blocking_client_with_a_multi_threaded_runtime_handle() {
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(1)
+        .enable_all()
+        .build()
+        .unwrap();
+    let rc = ClientBuilder::new()
+        .with_endpoint(UNREACHABLE_ENDPOINT)
+        .with_runtime_handle(runtime.handle().clone())
+        .build();
+
+    let result = rc.list_nodes();
+    assert!(matches!(result, Err(Error::RequestError { .. })));
+}
+
+#[test]
+fn test_unit_blocking_client_with_a_single_threaded_runtime_handle() {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    let rc = ClientBuilder::new()
+        .with_endpoint(UNREACHABLE_ENDPOINT)
+        .with_runtime_handle(runtime.handle().clone())
+        .build();
+
+    let result = rc.list_nodes();
+    assert!(matches!(result, Err(Error::BlockingNotSupported { .. })));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_unit_blocking_client_used_in_a_multi_threaded_runtime() {
+    let rc = Client::new(UNREACHABLE_ENDPOINT, "guest", "guest");
+
+    let result = rc.list_nodes();
+    assert!(matches!(result, Err(Error::RequestError { .. })));
+    // the owned runtime must not panic when dropped in an async context
+    drop(rc);
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_unit_blocking_client_used_in_a_single_threaded_runtime() {
+    let rc = Client::new(UNREACHABLE_ENDPOINT, "guest", "guest");
+
+    let result = rc.list_nodes();
+    assert!(matches!(result, Err(Error::BlockingNotSupported { .. })));
+    drop(rc);
+}
@@ -0,0 +1,145 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod test_helpers;
+
+use std::time::Duration;
+
+use rabbitmq_http_client::churn_analyzer::{
+    ChurnAnalyzerSettings, ConnectionChurnTracker, ConnectionOrigin,
+    DEFAULT_CHURN_THRESHOLD_PER_MINUTE,
+};
+use rabbitmq_http_client::responses::{ChurnRates, Connection};
+use serde_json::json;
+
+fn connection(name: &str, user: &str, client_provided_name: &str) -> Connection {
+    serde_json::from_value(json!({
+        "name": name,
+        "node": "rabbit@sunnyside",
+        "state": "running",
+        "protocol": "AMQP 0-9-1",
+        "user": user,
+        "connected_at": 1747411200000u64,
+        "host": "127.0.0.1",
+        "port": 5672,
+        "peer_host": "127.0.0.1",
+        "peer_port": 61012,
+        "channel_max": 2047,
+        "channels": 1,
+        "client_properties": {"connection_name": client_provided_name}
+    }))
+    .unwrap()
+}
+
+fn churn_rates(created: u32, closed: u32) -> ChurnRates {
+    serde_json::from_value(json!({
+        "connection_created": created,
+        "connection_closed": closed,
+        "queue_declared": 0,
+        "queue_created": 0,
+        "queue_deleted": 0,
+        "channel_created": 0,
+        "channel_closed": 0
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_unit_churn_tracker_first_listing_is_a_baseline() {
+    let mut tracker = ConnectionChurnTracker::new();
+    tracker.observe_connections(&[
+        connection("c1", "guest", "orders"),
+        connection("c2", "guest", "orders"),
+    ]);
+
+    let report = tracker.report(Duration::from_secs(60), 1.0);
+    assert!(report.applications.is_empty());
+    assert!(!report.has_offenders());
+}
+
+#[test]
+fn test_unit_churn_tracker_counts_opened_and_closed_connections_per_application() {
+    let mut tracker = ConnectionChurnTracker::new();
+    tracker.observe_connections(&[
+        connection("c1", "guest", "orders"),
+        connection("c2", "billing", ""),
+    ]);
+    tracker.observe_connections(&[
+        connection("c2", "billing", ""),
+        connection("c3", "guest", "orders"),
+        connection("c4", "guest", "orders"),
+    ]);
+    tracker.observe_connections(&[connection("c4", "guest", "orders")]);
+
+    let report = tracker.report(Duration::from_secs(30), 3.0);
+    assert_eq!(2, report.applications.len());
+
+    let orders = &report.applications[0];
+    assert_eq!(
+        ConnectionOrigin {
+            username: "guest".to_owned(),
+            client_provided_name: Some("orders".to_owned()),
+        },
+        orders.origin
+    );
+    assert_eq!(2, orders.opened);
+    assert_eq!(2, orders.closed);
+    assert_eq!(4.0, orders.opened_per_minute);
+
+    let billing = &report.applications[1];
+    assert_eq!(None, billing.origin.client_provided_name);
+    assert_eq!(0, billing.opened);
+    assert_eq!(1, billing.closed);
+
+    let offenders = report.offenders();
+    assert_eq!(1, offenders.len());
+    assert_eq!("orders (user: guest)", offenders[0].origin.to_string());
+}
+
+#[test]
+fn test_unit_churn_tracker_cluster_totals_from_churn_rates() {
+    let mut tracker = ConnectionChurnTracker::new();
+    tracker.observe_churn_rates(&churn_rates(100, 90));
+    tracker.observe_churn_rates(&churn_rates(130, 115));
+    tracker.observe_churn_rates(&churn_rates(160, 150));
+
+    let report = tracker.report(Duration::from_secs(60), 1.0);
+    assert_eq!(60, report.cluster_connections_opened);
+    assert_eq!(60, report.cluster_connections_closed);
+}
+
+#[test]
+fn test_unit_churn_tracker_tolerates_counter_resets() {
+    let mut tracker = ConnectionChurnTracker::new();
+    tracker.observe_churn_rates(&churn_rates(100, 90));
+    tracker.observe_churn_rates(&churn_rates(5, 2));
+
+    let report = tracker.report(Duration::from_secs(60), 1.0);
+    assert_eq!(0, report.cluster_connections_opened);
+    assert_eq!(0, report.cluster_connections_closed);
+}
+
+#[test]
+fn test_unit_churn_analyzer_settings() {
+    let settings = ChurnAnalyzerSettings::new(Duration::from_secs(120))
+        .with_interval(Duration::from_secs(2))
+        .with_threshold_per_minute(10.0);
+    assert_eq!(Duration::from_secs(120), settings.window);
+    assert_eq!(Duration::from_secs(2), settings.interval);
+    assert_eq!(10.0, settings.threshold_per_minute);
+
+    assert_eq!(
+        DEFAULT_CHURN_THRESHOLD_PER_MINUTE,
+        ChurnAnalyzerSettings::default().threshold_per_minute
+    );
+}
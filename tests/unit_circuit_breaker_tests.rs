@@ -0,0 +1,133 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod test_helpers;
+
+use std::thread;
+use std::time::Duration;
+
+use rabbitmq_http_client::blocking_api::ClientBuilder;
+use rabbitmq_http_client::circuit_breaker::{CircuitBreaker, CircuitBreakerSettings, CircuitState};
+use rabbitmq_http_client::error::Error;
+
+const COOL_DOWN: Duration = Duration::from_millis(100);
+
+#[test]
+fn test_unit_circuit_breaker_opens_after_consecutive_failures() {
+    let cb = CircuitBreaker::new(CircuitBreakerSettings::new(3, COOL_DOWN));
+
+    cb.record_failure();
+    cb.record_failure();
+    cb.record_success();
+    cb.record_failure();
+    cb.record_failure();
+    assert_eq!(CircuitState::Closed, cb.state());
+    assert!(cb.check().is_ok());
+
+    cb.record_failure();
+    assert_eq!(CircuitState::Open, cb.state());
+    let retry_after = cb.check().unwrap_err();
+    assert!(retry_after <= COOL_DOWN);
+}
+
+#[test]
+fn test_unit_circuit_breaker_half_open_state() {
+    let cb = CircuitBreaker::new(CircuitBreakerSettings::new(1, COOL_DOWN));
+
+    cb.record_failure();
+    assert_eq!(CircuitState::Open, cb.state());
+
+    thread::sleep(COOL_DOWN);
+    assert_eq!(CircuitState::HalfOpen, cb.state());
+    assert!(cb.check().is_ok());
+
+    // a failed trial request opens the circuit again
+    cb.record_failure();
+    assert_eq!(CircuitState::Open, cb.state());
+
+    thread::sleep(COOL_DOWN);
+    cb.record_success();
+    assert_eq!(CircuitState::Closed, cb.state());
+}
+
+#[test]
+fn test_unit_circuit_breaker_lets_a_single_trial_request_through() {
+    let cb = CircuitBreaker::new(CircuitBreakerSettings::new(1, COOL_DOWN));
+
+    cb.record_failure();
+    thread::sleep(COOL_DOWN);
+
+    assert!(cb.check().is_ok());
+    // the outcome of the trial request is not known yet
+    let retry_after = cb.check().unwrap_err();
+    assert!(retry_after <= COOL_DOWN);
+    assert_eq!(CircuitState::HalfOpen, cb.state());
+
+    cb.record_success();
+    assert!(cb.check().is_ok());
+    assert!(cb.check().is_ok());
+}
+
+#[test]
+fn test_unit_circuit_breaker_inconclusive_trial_request() {
+    let cb = CircuitBreaker::new(CircuitBreakerSettings::new(1, COOL_DOWN));
+
+    cb.record_failure();
+    thread::sleep(COOL_DOWN);
+
+    assert!(cb.check().is_ok());
+    assert!(cb.check().is_err());
+    cb.record_inconclusive();
+    assert_eq!(CircuitState::HalfOpen, cb.state());
+    assert!(cb.check().is_ok());
+}
+
+#[test]
+fn test_unit_circuit_breaker_lost_trial_request() {
+    let cb = CircuitBreaker::new(CircuitBreakerSettings::new(1, COOL_DOWN));
+
+    cb.record_failure();
+    thread::sleep(COOL_DOWN);
+
+    assert!(cb.check().is_ok());
+    assert!(cb.check().is_err());
+    // the trial request never reported its outcome, e.g. because it was cancelled
+    thread::sleep(COOL_DOWN);
+    assert!(cb.check().is_ok());
+}
+
+#[test]
+fn test_unit_client_with_circuit_breaker() {
+    let rc = ClientBuilder::new()
+        .with_endpoint("http://127.0.0.1:1/api")
+        .with_circuit_breaker(CircuitBreakerSettings::new(2, Duration::from_secs(60)))
+        .build();
+    assert_eq!(Some(CircuitState::Closed), rc.circuit_state());
+
+    assert!(matches!(rc.list_vhosts(), Err(Error::RequestError { .. })));
+    assert!(matches!(rc.list_vhosts(), Err(Error::RequestError { .. })));
+    assert_eq!(Some(CircuitState::Open), rc.circuit_state());
+
+    match rc.list_vhosts() {
+        Err(Error::CircuitOpen { retry_after }) => assert!(retry_after > Duration::from_secs(50)),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn test_unit_client_without_circuit_breaker() {
+    let rc = ClientBuilder::new()
+        .with_endpoint("http://127.0.0.1:1/api")
+        .build();
+    assert_eq!(None, rc.circuit_state());
+}
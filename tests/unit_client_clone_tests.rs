@@ -0,0 +1,105 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod test_helpers;
+
+use std::thread;
+
+use rabbitmq_http_client::blocking_api::{Client, ClientBuilder};
+use rabbitmq_http_client::error::Error;
+use rabbitmq_http_client::protection::ProtectedEntities;
+use test_helpers::serve_once;
+
+const UNREACHABLE_ENDPOINT: &str = "http://127.0.0.1:1/api";
+
+#[test]
+fn test_unit_clones_share_configuration() {
+    let rc = ClientBuilder::new()
+        .with_endpoint(UNREACHABLE_ENDPOINT)
+        .with_max_response_size(1024)
+        .build();
+    let clone = rc.clone();
+
+    assert_eq!(format!("{:?}", rc), format!("{:?}", clone));
+}
+
+#[test]
+fn test_unit_clones_share_rotated_credentials() {
+    let rc = Client::new(UNREACHABLE_ENDPOINT, "monitoring", "password");
+    let clone = rc.clone();
+    clone.update_credentials("rotated", "rotated-password");
+
+    assert!(format!("{:?}", rc).contains("rotated"));
+}
+
+#[test]
+fn test_unit_node_clients_share_rotated_credentials() {
+    let server = serve_once(
+        "200 OK",
+        &[("content-type", "application/json")],
+        r#"[{"name": "rabbit@sunnyside", "running": true, "uptime": 360000, "run_queue": 1,
+             "processors": 8, "os_pid": "4242", "fd_total": 1048576, "proc_total": 1048576,
+             "mem_limit": 6871947673, "mem_alarm": false, "disk_free_limit": 50000000,
+             "disk_free_alarm": false, "rates_mode": "basic",
+             "enabled_plugins": ["rabbitmq_management"], "being_drained": false}]"#,
+    );
+    let rc = Client::new(
+        server.endpoint(),
+        "monitoring".to_owned(),
+        "password".to_owned(),
+    );
+    let nodes = rc
+        .discover_node_clients(|_| UNREACHABLE_ENDPOINT.to_owned())
+        .unwrap();
+    server.request();
+    rc.update_credentials("rotated", "rotated-password");
+
+    let (name, node_client) = &nodes[0];
+    assert_eq!("rabbit@sunnyside", name);
+    assert!(format!("{:?}", node_client).contains("rotated"));
+}
+
+#[test]
+fn test_unit_protection_override_does_not_affect_other_clones() {
+    let rc = ClientBuilder::new()
+        .with_endpoint(UNREACHABLE_ENDPOINT)
+        .with_protected_entities(ProtectedEntities::new().with_virtual_host("prod"))
+        .build();
+    let clone = rc.clone();
+    let overridden = rc.with_protection_overridden();
+
+    // the request is issued (and fails since the endpoint is unreachable)
+    let result = overridden.delete_vhost("prod", false);
+    assert!(matches!(result, Err(Error::RequestError { .. })));
+
+    let result = rc.delete_vhost("prod", false);
+    assert!(matches!(result, Err(Error::ProtectedEntity { .. })));
+    let result = clone.delete_vhost("prod", false);
+    assert!(matches!(result, Err(Error::ProtectedEntity { .. })));
+}
+
+#[test]
+fn test_unit_clones_can_be_moved_to_other_threads() {
+    let rc = Client::new(UNREACHABLE_ENDPOINT, "guest", "guest");
+
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let clone = rc.clone();
+            thread::spawn(move || clone.list_nodes().is_err())
+        })
+        .collect();
+
+    for handle in handles {
+        assert!(handle.join().unwrap());
+    }
+}
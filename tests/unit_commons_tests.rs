@@ -0,0 +1,76 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod test_helpers;
+
+use rabbitmq_http_client::commons::{
+    BindingDestinationType, PolicyTarget, UserLimitTarget, VirtualHostLimitTarget,
+};
+use rabbitmq_http_client::error::{ConversionError, Error as APIClientError, HttpClientError};
+
+#[test]
+fn test_unit_policy_target_from_str() {
+    assert_eq!(
+        PolicyTarget::QuorumQueues,
+        "quorum_queues".parse::<PolicyTarget>().unwrap()
+    );
+    assert_eq!(PolicyTarget::All, "all".parse::<PolicyTarget>().unwrap());
+
+    let err = "quorum-queues".parse::<PolicyTarget>().unwrap_err();
+    assert!(matches!(
+        err,
+        ConversionError::UnsupportedValue { ref value, .. } if value == "quorum-queues"
+    ));
+
+    // the infallible conversion still falls back to a default
+    assert_eq!(PolicyTarget::Queues, PolicyTarget::from("quorum-queues"));
+}
+
+#[test]
+fn test_unit_unsupported_value_conversion_into_client_error() {
+    let err: HttpClientError = "quorum-queues".parse::<PolicyTarget>().unwrap_err().into();
+    assert!(matches!(
+        err,
+        APIClientError::UnsupportedValue { ref type_name, ref value }
+            if type_name == "policy target" && value == "quorum-queues"
+    ));
+}
+
+#[test]
+fn test_unit_binding_destination_type_from_str() {
+    assert_eq!(
+        BindingDestinationType::Exchange,
+        "exchange".parse::<BindingDestinationType>().unwrap()
+    );
+    assert!("topic".parse::<BindingDestinationType>().is_err());
+    assert_eq!(
+        BindingDestinationType::Queue,
+        BindingDestinationType::from("topic")
+    );
+}
+
+#[test]
+fn test_unit_limit_targets_from_str() {
+    assert_eq!(
+        VirtualHostLimitTarget::MaxQueues,
+        "max-queues".parse::<VirtualHostLimitTarget>().unwrap()
+    );
+    assert!("max-channels".parse::<VirtualHostLimitTarget>().is_err());
+
+    assert_eq!(
+        UserLimitTarget::MaxChannels,
+        "max-channels".parse::<UserLimitTarget>().unwrap()
+    );
+    let err = "max-queues".parse::<UserLimitTarget>().unwrap_err();
+    assert_eq!("'max-queues' is not a valid user limit", err.to_string());
+}
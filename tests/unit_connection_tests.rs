@@ -0,0 +1,326 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod test_helpers;
+
+use rabbitmq_http_client::blocking_api::ClientBuilder;
+use rabbitmq_http_client::commons::{ConnectionProtocol, SupportedProtocol};
+use rabbitmq_http_client::responses::{
+    AmqpSession, Channel, Connection, ConnectionState, MaxMessageSize, TopConnections,
+};
+use test_helpers::serve_once;
+
+const CONNECTION_JSON: &str = r#"{
+    "name": "127.0.0.1:61012 -> 127.0.0.1:5672",
+    "node": "rabbit@sunnyside",
+    "state": "running",
+    "protocol": "AMQP 0-9-1",
+    "user": "guest",
+    "connected_at": 1747411200000,
+    "host": "127.0.0.1",
+    "port": 5672,
+    "peer_host": "127.0.0.1",
+    "peer_port": 61012,
+    "channel_max": 2047,
+    "channels": 1,
+    "recv_oct": 4096,
+    "recv_oct_details": {"rate": 12.5},
+    "send_oct": 8192,
+    "send_oct_details": {"rate": 25.0},
+    "recv_cnt": 32,
+    "recv_cnt_details": {"rate": 0.5},
+    "send_cnt": 64,
+    "send_cnt_details": {"rate": 1.0},
+    "client_properties": {
+        "connection_name": "orders-service-1",
+        "platform": "Rust",
+        "product": "amqprs",
+        "version": "2.1.0"
+    }
+}"#;
+
+#[test]
+fn test_unit_deserialize_connection_network_traffic_counters() {
+    let conn: Connection = serde_json::from_str(CONNECTION_JSON).unwrap();
+
+    assert_eq!(Some(4096), conn.bytes_received);
+    assert_eq!(Some(8192), conn.bytes_sent);
+    assert_eq!(Some(32), conn.packets_received);
+    assert_eq!(Some(64), conn.packets_sent);
+    assert_eq!(12.5, conn.bytes_received_details.unwrap().rate);
+    assert_eq!(25.0, conn.bytes_sent_details.unwrap().rate);
+    assert_eq!(0.5, conn.packets_received_details.unwrap().rate);
+    assert_eq!(1.0, conn.packets_sent_details.unwrap().rate);
+}
+
+#[test]
+fn test_unit_deserialize_connection_without_network_traffic_counters() {
+    let mut val: serde_json::Value = serde_json::from_str(CONNECTION_JSON).unwrap();
+    let obj = val.as_object_mut().unwrap();
+    for key in ["recv_oct", "send_oct", "recv_cnt", "send_cnt"] {
+        obj.remove(key);
+        obj.remove(&format!("{}_details", key));
+    }
+    let conn: Connection = serde_json::from_value(val).unwrap();
+
+    assert!(conn.bytes_received.is_none());
+    assert!(conn.bytes_sent_details.is_none());
+    assert_eq!("orders-service-1", conn.client_properties.connection_name);
+}
+
+#[test]
+fn test_unit_deserialize_connection_state() {
+    let conn: Connection = serde_json::from_str(CONNECTION_JSON).unwrap();
+    assert_eq!(ConnectionState::Running, conn.state);
+    assert!(!conn.state.is_blocked());
+
+    let mut val: serde_json::Value = serde_json::from_str(CONNECTION_JSON).unwrap();
+    val["state"] = serde_json::json!("blocked");
+    let conn: Connection = serde_json::from_value(val.clone()).unwrap();
+    assert_eq!(ConnectionState::Blocked, conn.state);
+    assert!(conn.state.is_blocked());
+
+    val["state"] = serde_json::json!("hibernating");
+    let conn: Connection = serde_json::from_value(val.clone()).unwrap();
+    assert_eq!(ConnectionState::Other("hibernating".to_owned()), conn.state);
+    assert_eq!("hibernating", conn.state.to_string());
+
+    val.as_object_mut().unwrap().remove("state");
+    let conn: Connection = serde_json::from_value(val).unwrap();
+    assert_eq!(ConnectionState::Unknown, conn.state);
+}
+
+#[test]
+fn test_unit_deserialize_connection_protocol() {
+    let conn: Connection = serde_json::from_str(CONNECTION_JSON).unwrap();
+    assert_eq!(ConnectionProtocol::AMQP091, conn.protocol);
+    assert!(conn.protocol.is_amqp());
+
+    let mut val: serde_json::Value = serde_json::from_str(CONNECTION_JSON).unwrap();
+    val["protocol"] = serde_json::json!("MQTT 3.1.1");
+    let conn: Connection = serde_json::from_value(val).unwrap();
+    assert_eq!(ConnectionProtocol::MQTT("3.1.1".to_owned()), conn.protocol);
+    assert!(conn.protocol.is_mqtt());
+    assert_eq!("MQTT 3.1.1", conn.protocol.to_string());
+}
+
+#[test]
+fn test_unit_connection_protocol_serialization_round_trip() {
+    for wire in ["AMQP 0-9-1", "AMQP 1-0", "MQTT 3.1.1", "Web STOMP 1.2"] {
+        let proto: ConnectionProtocol = serde_json::from_value(serde_json::json!(wire)).unwrap();
+        assert_eq!(
+            serde_json::json!(wire),
+            serde_json::to_value(&proto).unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_unit_connection_protocol_conversions() {
+    assert_eq!(
+        ConnectionProtocol::AMQP10,
+        ConnectionProtocol::from("AMQP 1-0")
+    );
+    assert_eq!(
+        ConnectionProtocol::STOMPOverWebSockets("1.2".to_owned()),
+        ConnectionProtocol::from("Web STOMP 1.2")
+    );
+    assert_eq!(
+        ConnectionProtocol::MQTTOverWebSockets("5.0".to_owned()),
+        ConnectionProtocol::from("Web MQTT 5.0")
+    );
+    assert_eq!(
+        ConnectionProtocol::Other("Direct 0-9-1".to_owned()),
+        ConnectionProtocol::from("Direct 0-9-1")
+    );
+
+    assert_eq!(
+        SupportedProtocol::AMQP,
+        SupportedProtocol::from(&ConnectionProtocol::AMQP10)
+    );
+    assert_eq!(
+        SupportedProtocol::STOMP,
+        SupportedProtocol::from(&ConnectionProtocol::STOMP("1.2".to_owned()))
+    );
+}
+
+#[test]
+fn test_unit_top_connections_by_bytes_received() {
+    let conn: Connection = serde_json::from_str(CONNECTION_JSON).unwrap();
+    let mut c1 = conn.clone();
+    c1.name = "c1".to_owned();
+    c1.bytes_received = Some(100);
+    let mut c2 = conn.clone();
+    c2.name = "c2".to_owned();
+    c2.bytes_received = None;
+    let mut c3 = conn;
+    c3.name = "c3".to_owned();
+    c3.bytes_received = Some(5000);
+
+    let top = vec![c1, c2, c3].top_by_bytes_received(2);
+    let names: Vec<&str> = top.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(vec!["c3", "c1"], names);
+}
+
+#[test]
+fn test_unit_deserialize_channel_node() {
+    let ch: Channel = serde_json::from_value(serde_json::json!({
+        "number": 1,
+        "name": "127.0.0.1:61012 -> 127.0.0.1:5672 (1)",
+        "connection_details": {
+            "name": "127.0.0.1:61012 -> 127.0.0.1:5672",
+            "peer_host": "127.0.0.1",
+            "peer_port": 61012
+        },
+        "node": "rabbit@sunnyside",
+        "vhost": "/",
+        "state": "running",
+        "consumer_count": 1,
+        "confirm": false,
+        "prefetch_count": 10,
+        "messages_unacknowledged": 0,
+        "messages_unconfirmed": 0
+    }))
+    .unwrap();
+
+    assert_eq!(1, ch.id);
+    assert_eq!("rabbit@sunnyside", ch.node);
+}
+
+#[test]
+fn test_unit_deserialize_amqp10_sessions() {
+    let json = r#"[{
+        "channel_number": 0,
+        "handle_max": 4294967295,
+        "next_incoming_id": 12,
+        "incoming_window": 400,
+        "next_outgoing_id": 7,
+        "remote_incoming_window": 65536,
+        "remote_outgoing_window": 4294967295,
+        "outgoing_unsettled_deliveries": 2,
+        "incoming_links": [{
+            "handle": 1,
+            "link_name": "sender-1",
+            "target_address": "/queues/orders",
+            "delivery_count": 12,
+            "credit": 116,
+            "snd_settle_mode": "unsettled",
+            "max_message_size": 16777216,
+            "unconfirmed_messages": 0
+        }, {
+            "handle": 2,
+            "link_name": "anonymous-sender",
+            "target_address": null,
+            "max_message_size": "unlimited"
+        }],
+        "outgoing_links": [{
+            "handle": 0,
+            "link_name": "receiver-1",
+            "source_address": "/queues/orders",
+            "queue_name": "orders",
+            "queue_type": "quorum",
+            "send_settled": false,
+            "max_message_size": "unlimited",
+            "delivery_count": 7,
+            "credit": 93
+        }]
+    }]"#;
+    let sessions: Vec<AmqpSession> = serde_json::from_str(json).unwrap();
+
+    assert_eq!(1, sessions.len());
+    let s = &sessions[0];
+    assert_eq!(0, s.channel_number);
+    assert_eq!(Some(2), s.outgoing_unsettled_deliveries);
+
+    assert_eq!(2, s.incoming_links.len());
+    let l = &s.incoming_links[0];
+    assert_eq!(Some("/queues/orders".to_owned()), l.target_address);
+    assert_eq!(Some(MaxMessageSize::Bytes(16777216)), l.max_message_size);
+    assert_eq!(None, s.incoming_links[1].target_address);
+    assert_eq!(
+        Some(MaxMessageSize::Unlimited),
+        s.incoming_links[1].max_message_size
+    );
+
+    let l = &s.outgoing_links[0];
+    assert_eq!(Some("orders".to_owned()), l.queue_name);
+    assert_eq!(Some(false), l.send_settled);
+    assert_eq!(Some(93), l.credit);
+}
+
+#[test]
+fn test_unit_deserialize_mqtt_connection_without_amqp_fields() {
+    let json = r#"{
+        "name": "127.0.0.1:53242 -> 127.0.0.1:1883",
+        "node": "rabbit@sunnyside",
+        "state": "running",
+        "protocol": "MQTT 5-0",
+        "user": "guest",
+        "connected_at": 1747411200000,
+        "host": "127.0.0.1",
+        "port": 1883,
+        "peer_host": "127.0.0.1",
+        "peer_port": 53242,
+        "client_properties": {}
+    }"#;
+    let c: Connection = serde_json::from_str(json).unwrap();
+
+    assert!(c.protocol.is_mqtt());
+    assert!(!c.has_channels());
+    assert_eq!(None, c.channel_max);
+    assert_eq!(0, c.channel_count);
+    assert_eq!(None, c.client_provided_name());
+    assert!(c.capabilities().is_none());
+    assert_eq!(Some("127.0.0.1:53242".to_owned()), c.client_address());
+}
+
+#[test]
+fn test_unit_deserialize_web_stomp_connection_without_peer_details() {
+    let json = r#"{
+        "name": "web-stomp 10.0.0.5:61000",
+        "node": "rabbit@sunnyside",
+        "protocol": "Web STOMP 1.2",
+        "user": "guest",
+        "connected_at": 1747411200000,
+        "peer_host": null,
+        "peer_port": null
+    }"#;
+    let c: Connection = serde_json::from_str(json).unwrap();
+
+    assert!(!c.has_channels());
+    assert_eq!(None, c.client_address());
+    assert_eq!("", c.client_properties.connection_name);
+}
+
+#[test]
+fn test_unit_connection_accessors_for_amqp091() {
+    let c: Connection = serde_json::from_str(CONNECTION_JSON).unwrap();
+
+    assert!(c.has_channels());
+    assert_eq!(Some("127.0.0.1:61012".to_owned()), c.client_address());
+}
+
+#[test]
+fn test_unit_close_connection_with_reason_ignores_missing_connections() {
+    let server = serve_once("404 Not Found", &[], "");
+
+    let rc = ClientBuilder::new()
+        .with_endpoint(server.endpoint())
+        .build();
+    let result = rc.close_connection("127.0.0.1:61012 -> 127.0.0.1:5672", Some("maintenance"));
+    let request = server.request().to_lowercase();
+
+    assert!(result.is_ok(), "close_connection returned {:?}", result);
+    assert!(request.starts_with("delete /api/connections/127%2e0%2e0%2e1%3a61012%20"));
+    assert!(request.contains("x-reason: maintenance"));
+}
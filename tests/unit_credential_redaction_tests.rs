@@ -0,0 +1,63 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod test_helpers;
+
+use rabbitmq_http_client::blocking_api::{Client, ClientBuilder};
+
+const SECRET: &str = "s3kr3t-pa55w0rd";
+
+#[test]
+fn test_unit_client_debug_output_redacts_password() {
+    let rc = Client::new("http://localhost:15672/api", "monitoring", SECRET);
+    let s = format!("{:?}", rc);
+
+    assert!(s.contains("monitoring"));
+    assert!(!s.contains(SECRET));
+}
+
+#[test]
+fn test_unit_client_builder_debug_output_redacts_passwords() {
+    let endpoint = format!("http://monitoring:{}@localhost:15672/api", SECRET);
+    let b = ClientBuilder::new()
+        .with_endpoint(&endpoint)
+        .with_basic_auth_credentials("monitoring", SECRET);
+    assert!(!format!("{:?}", b).contains(SECRET));
+
+    let rc = b.build();
+    let s = format!("{:?}", rc);
+    assert!(s.contains("localhost:15672"));
+    assert!(!s.contains(SECRET));
+}
+
+#[test]
+fn test_unit_request_error_redacts_endpoint_password() {
+    // nothing listens on this port, so the request fails before reaching any server
+    let endpoint = format!("http://monitoring:{}@127.0.0.1:1/api", SECRET);
+    let rc = Client::new(&endpoint, "monitoring", SECRET);
+
+    let err = rc.overview().unwrap_err();
+    assert!(!format!("{}", err).contains(SECRET));
+    assert!(!format!("{:?}", err).contains(SECRET));
+}
+
+#[test]
+fn test_unit_client_debug_output_after_credentials_update() {
+    let rc = Client::new("http://localhost:15672/api", "monitoring", "old-password");
+    rc.update_credentials("rotated", SECRET);
+    let s = format!("{:?}", rc);
+
+    assert!(s.contains("rotated"));
+    assert!(!s.contains("monitoring"));
+    assert!(!s.contains(SECRET));
+}
@@ -0,0 +1,162 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod test_helpers;
+
+use std::time::{Duration, Instant};
+
+use rabbitmq_http_client::blocking_api::ClientBuilder;
+use rabbitmq_http_client::error::Error;
+use rabbitmq_http_client::topology_generator::TopologyGenerator;
+use test_helpers::{serve_nothing_once, serve_once_and_hang};
+
+#[test]
+fn test_unit_no_requests_are_issued_after_the_deadline() {
+    let rc = ClientBuilder::new()
+        .with_endpoint("http://127.0.0.1:1/api")
+        .build();
+    let scoped = rc.with_deadline(Instant::now());
+
+    assert!(matches!(scoped.list_vhosts(), Err(Error::DeadlineExceeded)));
+    // the original client is not affected
+    assert!(rc.deadline().is_none());
+    assert!(matches!(rc.list_vhosts(), Err(Error::RequestError { .. })));
+}
+
+#[test]
+fn test_unit_deadline_is_propagated_to_composite_operations() {
+    let rc = ClientBuilder::new()
+        .with_endpoint("http://127.0.0.1:1/api")
+        .build()
+        .with_deadline(Instant::now());
+    let topology = TopologyGenerator::new("/")
+        .with_queues(10, "q.{n}")
+        .generate();
+
+    assert!(matches!(
+        rc.declare_topology(&topology, 4),
+        Err(Error::DeadlineExceeded)
+    ));
+    assert!(matches!(
+        rc.enable_all_stable_feature_flags(),
+        Err(Error::DeadlineExceeded)
+    ));
+}
+
+#[test]
+fn test_unit_requests_time_out_at_the_deadline() {
+    let server = serve_nothing_once(Duration::from_secs(2));
+
+    let rc = ClientBuilder::new()
+        .with_endpoint(server.endpoint())
+        .build()
+        .with_timeout(Duration::from_millis(200));
+    let started = Instant::now();
+    let result = rc.list_vhosts();
+
+    assert!(matches!(result, Err(Error::DeadlineExceeded)));
+    assert!(started.elapsed() < Duration::from_secs(2));
+    server.request();
+}
+
+#[test]
+fn test_unit_await_ready_does_not_wait_for_hanging_checks() {
+    let server = serve_nothing_once(Duration::from_secs(2));
+
+    let rc = ClientBuilder::new()
+        .with_endpoint(server.endpoint())
+        .build();
+    let started = Instant::now();
+    let result = rc.await_ready(Duration::from_millis(300));
+
+    assert!(matches!(result, Err(Error::NotReady { .. })));
+    assert!(started.elapsed() < Duration::from_secs(2));
+    server.request();
+}
+
+// a chunked body that is never completed
+const INCOMPLETE_BODY: &str = "5\r\n[{\"na\r\n";
+const JSON_AND_CHUNKED: [(&str, &str); 2] = [
+    ("content-type", "application/json"),
+    ("transfer-encoding", "chunked"),
+];
+
+#[test]
+fn test_unit_body_reads_time_out_at_the_deadline() {
+    let server = serve_once_and_hang(
+        "200 OK",
+        &JSON_AND_CHUNKED,
+        INCOMPLETE_BODY,
+        Duration::from_secs(2),
+    );
+
+    let rc = ClientBuilder::new()
+        .with_endpoint(server.endpoint())
+        .build()
+        .with_timeout(Duration::from_millis(300));
+    let result = rc.list_vhosts();
+
+    assert!(
+        matches!(result, Err(Error::DeadlineExceeded)),
+        "{:?}",
+        result
+    );
+    server.request();
+}
+
+#[test]
+fn test_unit_streamed_body_reads_time_out_at_the_deadline() {
+    let server = serve_once_and_hang(
+        "200 OK",
+        &JSON_AND_CHUNKED,
+        INCOMPLETE_BODY,
+        Duration::from_secs(2),
+    );
+
+    let rc = ClientBuilder::new()
+        .with_endpoint(server.endpoint())
+        .build()
+        .with_timeout(Duration::from_millis(300));
+    let mut out = Vec::new();
+    let result = rc.export_definitions_to_writer(&mut out);
+
+    assert!(
+        matches!(result, Err(Error::DeadlineExceeded)),
+        "{:?}",
+        result
+    );
+    server.request();
+}
+
+#[test]
+fn test_unit_error_body_reads_time_out_at_the_deadline() {
+    let server = serve_once_and_hang(
+        "500 Internal Server Error",
+        &JSON_AND_CHUNKED,
+        INCOMPLETE_BODY,
+        Duration::from_secs(2),
+    );
+
+    let rc = ClientBuilder::new()
+        .with_endpoint(server.endpoint())
+        .build()
+        .with_timeout(Duration::from_millis(300));
+    let result = rc.list_vhosts();
+
+    assert!(
+        matches!(result, Err(Error::DeadlineExceeded)),
+        "{:?}",
+        result
+    );
+    server.request();
+}
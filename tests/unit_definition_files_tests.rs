@@ -0,0 +1,71 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod test_helpers;
+
+use rabbitmq_http_client::blocking_api::Client;
+use rabbitmq_http_client::error::Error;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::test_helpers::{endpoint, PASSWORD, USERNAME};
+
+fn temporary_file(name: &str, contents: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(name);
+    fs::write(&path, contents).unwrap();
+    path
+}
+
+#[test]
+fn test_unit_restore_definitions_from_a_missing_file() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let path = std::env::temp_dir().join("rust.http.api.unit.definitions.missing.json");
+    let _ = fs::remove_file(&path);
+
+    let result = rc.restore_definitions_from_file(&path);
+    assert!(matches!(result, Err(Error::IoError { .. })));
+}
+
+#[test]
+fn test_unit_restore_definitions_from_a_file_with_malformed_json() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let path = temporary_file(
+        "rust.http.api.unit.definitions.malformed.json",
+        r#"{"vhosts": ["#,
+    );
+
+    let result = rc.restore_definitions_from_file(&path);
+    assert!(matches!(result, Err(Error::InvalidDefinitions { .. })));
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_unit_restore_definitions_from_a_file_that_is_not_a_definitions_document() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let path = temporary_file(
+        "rust.http.api.unit.definitions.unrelated.json",
+        r#"{"name": "not-definitions", "items": []}"#,
+    );
+
+    let result = rc.restore_definitions_from_file(&path);
+    assert!(matches!(result, Err(Error::InvalidDefinitions { .. })));
+
+    fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,170 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod test_helpers;
+
+use rabbitmq_http_client::commons::QueueType;
+use rabbitmq_http_client::requests::DefinitionOperation;
+use rabbitmq_http_client::responses::ClusterDefinitionSet;
+
+const DEFINITIONS_JSON: &str = r#"{
+    "rabbitmq_version": "4.1.0",
+    "users": [
+        {
+            "name": "app",
+            "password_hash": "CZGtMFp48hNvlKFZqF/4gKu/i3cMtoSkmgsGTHP07Yi8mCkY",
+            "hashing_algorithm": "rabbit_password_hashing_sha256",
+            "tags": ["management", "monitoring"],
+            "limits": {}
+        }
+    ],
+    "vhosts": [
+        {
+            "name": "events",
+            "description": "",
+            "tags": [],
+            "default_queue_type": "quorum",
+            "metadata": {"description": "", "tags": [], "default_queue_type": "quorum"}
+        }
+    ],
+    "permissions": [
+        {"user": "app", "vhost": "events", "configure": ".*", "write": ".*", "read": "^events\\."}
+    ],
+    "parameters": [
+        {
+            "vhost": "events",
+            "component": "federation-upstream",
+            "name": "up.1",
+            "value": {"uri": "amqp://upstream.local"}
+        }
+    ],
+    "policies": [
+        {
+            "vhost": "events",
+            "name": "limits",
+            "pattern": "^events\\.",
+            "apply-to": "queues",
+            "definition": {"max-length": 1000},
+            "priority": 1
+        }
+    ],
+    "queues": [
+        {
+            "name": "events.1",
+            "vhost": "events",
+            "durable": true,
+            "auto_delete": false,
+            "arguments": {"x-queue-type": "stream"}
+        },
+        {
+            "name": "events.2",
+            "vhost": "events",
+            "durable": true,
+            "auto_delete": false,
+            "arguments": {}
+        }
+    ],
+    "exchanges": [
+        {
+            "name": "events.topic",
+            "vhost": "events",
+            "type": "topic",
+            "durable": true,
+            "auto_delete": false,
+            "internal": false,
+            "arguments": {}
+        }
+    ],
+    "bindings": [
+        {
+            "source": "events.topic",
+            "vhost": "events",
+            "destination": "events.1",
+            "destination_type": "queue",
+            "routing_key": "events.#",
+            "arguments": {}
+        }
+    ]
+}"#;
+
+#[test]
+fn test_unit_definition_operations_follow_import_order() {
+    let defs: ClusterDefinitionSet = serde_json::from_str(DEFINITIONS_JSON).unwrap();
+    let ops = defs.operations();
+
+    assert_eq!(9, ops.len());
+    assert!(matches!(ops[0], DefinitionOperation::CreateVirtualHost(_)));
+    assert!(matches!(ops[1], DefinitionOperation::CreateUser(_)));
+    assert!(matches!(ops[2], DefinitionOperation::DeclarePermissions(_)));
+    assert!(matches!(
+        ops[3],
+        DefinitionOperation::UpsertRuntimeParameter(_)
+    ));
+    assert!(matches!(ops[4], DefinitionOperation::DeclarePolicy(_)));
+    assert!(matches!(
+        ops[5],
+        DefinitionOperation::DeclareExchange { .. }
+    ));
+    assert!(matches!(ops[6], DefinitionOperation::DeclareQueue { .. }));
+    assert!(matches!(ops[7], DefinitionOperation::DeclareQueue { .. }));
+    assert!(matches!(ops[8], DefinitionOperation::DeclareBinding(_)));
+}
+
+#[test]
+fn test_unit_definition_operations_convert_objects() {
+    let defs: ClusterDefinitionSet = serde_json::from_str(DEFINITIONS_JSON).unwrap();
+    let ops = defs.operations();
+
+    match &ops[2] {
+        DefinitionOperation::DeclarePermissions(p) => {
+            assert_eq!("app", p.user);
+            assert_eq!("events", p.vhost);
+            assert_eq!("^events\\.", p.read);
+        }
+        _ => panic!("expected a permissions operation"),
+    }
+
+    match &ops[3] {
+        DefinitionOperation::UpsertRuntimeParameter(p) => {
+            assert_eq!("federation-upstream", p.component);
+            assert_eq!("amqp://upstream.local", p.value["uri"]);
+        }
+        _ => panic!("expected a runtime parameter operation"),
+    }
+
+    match &ops[6] {
+        DefinitionOperation::DeclareQueue { vhost, params } => {
+            assert_eq!("events", *vhost);
+            assert_eq!("events.1", params.name);
+            assert_eq!(QueueType::Stream, params.queue_type);
+            assert!(params.durable);
+        }
+        _ => panic!("expected a queue operation"),
+    }
+}
+
+#[test]
+fn test_unit_definition_operations_display() {
+    let defs: ClusterDefinitionSet = serde_json::from_str(DEFINITIONS_JSON).unwrap();
+    let ops = defs.operations();
+
+    assert_eq!("create user 'app'", ops[1].to_string());
+    assert_eq!(
+        "declare queue 'events.2' in virtual host 'events'",
+        ops[7].to_string()
+    );
+    assert_eq!(
+        "bind queue 'events.1' to exchange 'events.topic' in virtual host 'events'",
+        ops[8].to_string()
+    );
+}
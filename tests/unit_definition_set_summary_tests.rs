@@ -0,0 +1,109 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod test_helpers;
+
+use rabbitmq_http_client::responses::{
+    ClusterDefinitionSet, DEPRECATED_FEATURE_CLASSIC_QUEUE_MIRRORING,
+    DEPRECATED_FEATURE_TRANSIENT_NON_EXCLUSIVE_QUEUES,
+};
+use serde_json::json;
+
+fn definitions(policies: serde_json::Value, queues: serde_json::Value) -> ClusterDefinitionSet {
+    serde_json::from_value(json!({
+        "rabbitmq_version": "3.13.7",
+        "users": [
+            {"name": "guest", "password_hash": "hash", "tags": ["administrator"], "limits": {}}
+        ],
+        "vhosts": [
+            {"name": "/", "description": "", "tags": [], "metadata": {"description": "", "tags": []}},
+            {"name": "events", "description": "", "tags": [], "metadata": {"description": "", "tags": []}}
+        ],
+        "permissions": [
+            {"user": "guest", "vhost": "/", "configure": ".*", "write": ".*", "read": ".*"}
+        ],
+        "parameters": [],
+        "policies": policies,
+        "queues": queues,
+        "exchanges": [
+            {"name": "events.topic", "vhost": "events", "type": "topic", "durable": true,
+             "auto_delete": false, "internal": false, "arguments": {}}
+        ],
+        "bindings": [
+            {"source": "events.topic", "vhost": "events", "destination": "cq.1",
+             "destination_type": "queue", "routing_key": "#", "arguments": {}}
+        ]
+    }))
+    .unwrap()
+}
+
+fn queue(name: &str, durable: bool, args: serde_json::Value) -> serde_json::Value {
+    json!({"name": name, "vhost": "events", "durable": durable, "auto_delete": false, "arguments": args})
+}
+
+#[test]
+fn test_unit_definition_set_summary_counts() {
+    let defs = definitions(
+        json!([]),
+        json!([
+            queue("cq.1", true, json!({})),
+            queue("cq.2", true, json!({"x-queue-type": "classic"})),
+            queue("qq.1", true, json!({"x-queue-type": "quorum"})),
+            queue("sq.1", true, json!({"x-queue-type": "stream"})),
+            queue("dq.1", true, json!({"x-queue-type": "delayed"}))
+        ]),
+    );
+    let summary = defs.summary();
+
+    assert_eq!(Some("3.13.7".to_owned()), summary.server_version);
+    assert_eq!(1, summary.user_count);
+    assert_eq!(2, summary.virtual_host_count);
+    assert_eq!(1, summary.permission_count);
+    assert_eq!(0, summary.runtime_parameter_count);
+    assert_eq!(0, summary.policy_count);
+    assert_eq!(1, summary.exchange_count);
+    assert_eq!(2, summary.classic_queue_count);
+    assert_eq!(1, summary.quorum_queue_count);
+    assert_eq!(1, summary.stream_count);
+    assert_eq!(1, summary.other_queue_count);
+    assert_eq!(5, summary.queue_count());
+    assert_eq!(1, summary.binding_count);
+    assert!(!summary.uses_deprecated_features());
+}
+
+#[test]
+fn test_unit_definition_set_summary_detects_deprecated_features() {
+    let defs = definitions(
+        json!([
+            {"vhost": "events", "name": "ha", "pattern": "^cq", "apply-to": "queues",
+             "definition": {"ha-mode": "all"}, "priority": 0}
+        ]),
+        json!([queue("cq.1", false, json!({}))]),
+    );
+    let summary = defs.summary();
+
+    assert!(summary.uses_deprecated_features());
+    assert_eq!(
+        vec![
+            DEPRECATED_FEATURE_CLASSIC_QUEUE_MIRRORING.to_owned(),
+            DEPRECATED_FEATURE_TRANSIENT_NON_EXCLUSIVE_QUEUES.to_owned()
+        ],
+        summary.deprecated_features
+    );
+
+    let s = summary.to_string();
+    assert!(s.contains("classic queues: 1"));
+    assert!(
+        s.contains("deprecated features used: classic_queue_mirroring, transient_nonexcl_queues")
+    );
+}
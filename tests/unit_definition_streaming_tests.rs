@@ -0,0 +1,84 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod test_helpers;
+
+use std::io::Cursor;
+
+use rabbitmq_http_client::blocking_api::ClientBuilder;
+use test_helpers::{chunked, serve_once, OneShotServer};
+
+// serves a single response with a chunked body
+fn serve_chunked_once(status: &str, body: &str) -> OneShotServer {
+    serve_once(
+        status,
+        &[
+            ("content-type", "application/json"),
+            ("transfer-encoding", "chunked"),
+        ],
+        &chunked(body),
+    )
+}
+
+#[test]
+fn test_unit_export_definitions_to_writer() {
+    let body = r#"{"rabbit_version":"4.1.0","vhosts":[{"name":"/"}],"queues":[]}"#;
+    let server = serve_chunked_once("200 OK", body);
+
+    let rc = ClientBuilder::new()
+        .with_endpoint(server.endpoint())
+        .build();
+    let mut out = Vec::new();
+    let written = rc.export_definitions_to_writer(&mut out).unwrap();
+    let request = server.request();
+
+    assert!(request.starts_with("GET /api/definitions "));
+    assert_eq!(body.len() as u64, written);
+    assert_eq!(body.as_bytes(), out.as_slice());
+}
+
+#[test]
+fn test_unit_export_vhost_definitions_to_writer() {
+    let body = r#"{"rabbit_version":"4.1.0","queues":[]}"#;
+    let server = serve_chunked_once("200 OK", body);
+
+    let rc = ClientBuilder::new()
+        .with_endpoint(server.endpoint())
+        .build();
+    let mut out = Vec::new();
+    rc.export_vhost_definitions_to_writer("events/eu", &mut out)
+        .unwrap();
+    let request = server.request();
+
+    assert!(request.starts_with("GET /api/definitions/events%2Feu "));
+    assert_eq!(body.as_bytes(), out.as_slice());
+}
+
+#[test]
+fn test_unit_import_definitions_from_reader() {
+    let definitions = r#"{"vhosts":[{"name":"streamed"}],"queues":[]}"#;
+    let server = serve_chunked_once("204 No Content", "");
+
+    let rc = ClientBuilder::new()
+        .with_endpoint(server.endpoint())
+        .build();
+    let result = rc.import_definitions_from_reader(Cursor::new(definitions.as_bytes().to_vec()));
+    let request = server.request();
+
+    assert!(result.is_ok(), "import returned {:?}", result);
+    assert!(request.starts_with("POST /api/definitions "));
+    assert!(request
+        .to_lowercase()
+        .contains("content-type: application/json"));
+    assert!(request.contains(definitions));
+}
@@ -0,0 +1,64 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod test_helpers;
+
+use rabbitmq_http_client::responses::{DeprecatedFeatureList, DeprecationPhase};
+
+#[test]
+fn test_unit_deserialize_deprecated_features() {
+    let json = r#"[
+        {
+            "name": "transient_nonexcl_queues",
+            "desc": "Transient non-exclusive queues",
+            "deprecation_phase": "permitted_by_default",
+            "doc_url": "https://blog.rabbitmq.com/posts/2021/08/4.0-deprecation-announcements/#removal-of-transient-non-exclusive-queues",
+            "provided_by": "rabbit"
+        },
+        {
+            "name": "classic_queue_mirroring",
+            "desc": "Classic mirrored queues",
+            "deprecation_phase": "removed",
+            "doc_url": "https://blog.rabbitmq.com/posts/2021/08/4.0-deprecation-announcements/#removal-of-classic-queue-mirroring",
+            "provided_by": "rabbit"
+        },
+        {
+            "name": "some_future_feature",
+            "desc": "A phase this client does not know about",
+            "deprecation_phase": "soft_removed",
+            "doc_url": "",
+            "provided_by": "rabbit"
+        }
+    ]"#;
+    let features: DeprecatedFeatureList = serde_json::from_str(json).unwrap();
+
+    assert_eq!(3, features.0.len());
+    assert_eq!(
+        DeprecationPhase::PermittedByDefault,
+        features.0[0].deprecation_phase
+    );
+    assert_eq!(DeprecationPhase::Removed, features.0[1].deprecation_phase);
+    assert_eq!(DeprecationPhase::Undefined, features.0[2].deprecation_phase);
+}
+
+#[test]
+fn test_unit_deprecation_phase_display() {
+    assert_eq!(
+        "denied_by_default",
+        DeprecationPhase::DeniedByDefault.to_string()
+    );
+    assert_eq!(
+        "permitted_by_default",
+        String::from(DeprecationPhase::PermittedByDefault)
+    );
+}
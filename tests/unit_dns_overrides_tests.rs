@@ -0,0 +1,84 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod test_helpers;
+
+use std::net::{IpAddr, Ipv4Addr};
+
+use rabbitmq_http_client::blocking_api::ClientBuilder;
+use rabbitmq_http_client::error::Error;
+use reqwest::Client as HttpClient;
+use test_helpers::serve_once;
+
+#[test]
+fn test_unit_dns_overrides_keep_the_original_hostname() {
+    let server = serve_once("200 OK", &[("content-type", "application/json")], "[]");
+    let port = server.port();
+
+    // .invalid is guaranteed to never resolve
+    let endpoint = format!("http://rabbitmq.example.invalid:{}/api", port);
+    let rc = ClientBuilder::new()
+        .with_endpoint(endpoint.as_str())
+        .with_dns_overrides(&[("rabbitmq.example.invalid", IpAddr::V4(Ipv4Addr::LOCALHOST))])
+        .build();
+    let result = rc.list_vhosts();
+    let request = server.request().to_lowercase();
+
+    assert!(result.unwrap().is_empty());
+    assert!(request.contains(&format!("host: rabbitmq.example.invalid:{}", port)));
+}
+
+#[test]
+fn test_unit_dns_overrides_cannot_be_combined_with_a_custom_client() {
+    let result = ClientBuilder::new()
+        .with_client(HttpClient::new())
+        .with_dns_overrides(&[("rabbitmq.example.invalid", IpAddr::V4(Ipv4Addr::LOCALHOST))])
+        .try_build();
+
+    assert!(matches!(result, Err(Error::InvalidClientSettings { .. })));
+}
+
+#[test]
+fn test_unit_custom_client_is_used() {
+    let server = serve_once("200 OK", &[("content-type", "application/json")], "[]");
+    let client = HttpClient::builder()
+        .user_agent("custom-agent/1.0")
+        .build()
+        .unwrap();
+
+    let rc = ClientBuilder::new()
+        .with_endpoint(server.endpoint())
+        .with_client(client)
+        .try_build()
+        .unwrap();
+    let result = rc.list_vhosts();
+    let request = server.request().to_lowercase();
+
+    assert!(result.unwrap().is_empty());
+    assert!(
+        request.contains("user-agent: custom-agent/1.0"),
+        "{}",
+        request
+    );
+}
+
+#[cfg(feature = "http2")]
+#[test]
+fn test_unit_http2_prior_knowledge_cannot_be_combined_with_a_custom_client() {
+    let result = ClientBuilder::new()
+        .with_http2_prior_knowledge()
+        .with_client(HttpClient::new())
+        .try_build();
+
+    assert!(matches!(result, Err(Error::InvalidClientSettings { .. })));
+}
@@ -0,0 +1,97 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod test_helpers;
+
+use rabbitmq_http_client::commons::ExchangeType;
+use rabbitmq_http_client::requests::ExchangeParams;
+use rabbitmq_http_client::responses::{ExchangeInfo, ExchangeInfoWithoutVirtualHost};
+use serde_json::json;
+
+#[test]
+fn test_unit_exchange_info_to_exchange_params_conversion() {
+    let input = r#"{
+        "arguments": {"alternate-exchange": "amq.fanout"},
+        "auto_delete": false,
+        "durable": true,
+        "internal": false,
+        "name": "events.topic",
+        "type": "topic",
+        "user_who_performed_action": "guest",
+        "vhost": "/"
+    }"#;
+    let x: ExchangeInfo = serde_json::from_str(input).unwrap();
+    let params = ExchangeParams::from(&x);
+
+    assert_eq!("events.topic", params.name);
+    assert_eq!(ExchangeType::Topic, params.exchange_type);
+    assert!(params.durable);
+    assert!(!params.auto_delete);
+    assert_eq!(
+        json!("amq.fanout"),
+        params.arguments.unwrap()["alternate-exchange"]
+    );
+}
+
+#[test]
+fn test_unit_exchange_info_to_exchange_params_conversion_with_a_plugin_type() {
+    let input = r#"{
+        "arguments": {},
+        "auto_delete": true,
+        "durable": false,
+        "name": "events.hashing",
+        "type": "x-custom-plugin-type"
+    }"#;
+    let x: ExchangeInfoWithoutVirtualHost = serde_json::from_str(input).unwrap();
+    let params = ExchangeParams::from(&x);
+
+    assert_eq!(
+        ExchangeType::Plugin("x-custom-plugin-type".to_owned()),
+        params.exchange_type
+    );
+    assert!(params.auto_delete);
+    assert!(!params.durable);
+    assert!(params.arguments.is_none());
+}
+
+#[test]
+fn test_unit_exchange_params_defaults_and_setters() {
+    let params = ExchangeParams::default();
+    assert_eq!("", params.name);
+    assert_eq!(ExchangeType::Direct, params.exchange_type);
+    assert!(params.durable);
+    assert!(!params.auto_delete);
+    assert!(params.arguments.is_none());
+
+    let params = ExchangeParams::default()
+        .with_name("events.topic")
+        .with_exchange_type(ExchangeType::Topic)
+        .with_durable(false)
+        .with_auto_delete(true)
+        .with_arguments(rabbitmq_http_client::x_args! {"alternate-exchange" => "amq.fanout"});
+    assert_eq!("events.topic", params.name);
+    assert_eq!(ExchangeType::Topic, params.exchange_type);
+    assert!(!params.durable);
+    assert!(params.auto_delete);
+    assert_eq!(
+        json!("amq.fanout"),
+        params.arguments.unwrap()["alternate-exchange"]
+    );
+
+    let updated = ExchangeParams {
+        name: "events.fanout",
+        exchange_type: ExchangeType::Fanout,
+        ..Default::default()
+    };
+    assert!(updated.durable);
+}
@@ -15,6 +15,7 @@ mod test_helpers;
 
 use rabbitmq_http_client::{
     commons::MessageTransferAcknowledgementMode,
+    requests::{FederationUpstreamParams, QueueFederationParams, RuntimeParameterDefinition},
     responses::{FederationLink, FederationType, FederationUpstream, RuntimeParameter},
 };
 
@@ -81,6 +82,8 @@ fn test_unit_deserialize_federation_upstream_case2() {
     assert_eq!("amqp://localhost:5673/%2f", upstream.uri);
     assert_eq!("fed.ex.up", upstream.exchange.unwrap());
     assert_eq!(1, upstream.max_hops.unwrap());
+    assert_eq!(Some(100), upstream.prefetch_count);
+    assert_eq!(None, upstream.bind_using_nowait);
     assert_eq!(
         MessageTransferAcknowledgementMode::WhenConfirmed,
         upstream.ack_mode
@@ -148,3 +151,47 @@ fn test_unit_deserialize_federation_link_case1() {
     assert_eq!(link.upstream, "up-1");
     assert_eq!(link.consumer_tag.unwrap(), "hgksdh98s7f98au9u");
 }
+
+#[test]
+fn test_unit_federation_upstream_without_a_uri() {
+    let json = r#"
+        {
+          "value": {
+            "ack-mode": "on-confirm"
+          },
+          "vhost": "/",
+          "component": "federation-upstream",
+          "name": "up-3"
+        }
+    "#;
+
+    let param: RuntimeParameter = serde_json::from_str(json).unwrap();
+    assert!(FederationUpstream::try_from(param).is_err());
+}
+
+#[test]
+fn test_unit_federation_upstream_params_round_trip() {
+    let mut params = FederationUpstreamParams::new_queue_federation_upstream(
+        "/",
+        "up-4",
+        "amqp://localhost:5673/%2f",
+        QueueFederationParams::new("fed.cq.4"),
+    );
+    params.prefetch_count = 500;
+    params.bind_using_nowait = true;
+    let def = RuntimeParameterDefinition::from(params);
+
+    let param: RuntimeParameter = serde_json::from_value(serde_json::json!({
+        "name": def.name,
+        "vhost": def.vhost,
+        "component": def.component,
+        "value": def.value,
+    }))
+    .unwrap();
+    let upstream = FederationUpstream::try_from(param).unwrap();
+
+    assert_eq!("amqp://localhost:5673/%2f", upstream.uri);
+    assert_eq!(Some(500), upstream.prefetch_count);
+    assert_eq!(Some(true), upstream.bind_using_nowait);
+    assert_eq!(Some("fed.cq.4".to_owned()), upstream.queue);
+}
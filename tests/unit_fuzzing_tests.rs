@@ -0,0 +1,107 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg(feature = "arbitrary")]
+
+mod test_helpers;
+
+use arbitrary::{Arbitrary, Unstructured};
+use rabbitmq_http_client::commons::QueueType;
+use rabbitmq_http_client::fuzzing::{arbitrary_name, MAX_GENERATED_NAME_LENGTH};
+use rabbitmq_http_client::requests::{OwnedExchangeParams, OwnedPolicyParams, OwnedQueueParams};
+use rabbitmq_http_client::responses::{BindingInfo, ExchangeInfo, QueueDefinition};
+use serde_json::Value;
+
+const ITERATIONS: usize = 200;
+
+// a deterministic source of "random" bytes, so that failures are reproducible
+fn pseudorandom_bytes(seed: u64, len: usize) -> Vec<u8> {
+    let mut state = seed;
+    (0..len)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 33) as u8
+        })
+        .collect()
+}
+
+fn generate<T: for<'a> Arbitrary<'a>>(seed: u64) -> T {
+    let bytes = pseudorandom_bytes(seed, 4096);
+    let mut u = Unstructured::new(&bytes);
+    T::arbitrary(&mut u).unwrap()
+}
+
+fn assert_serde_round_trip<T>(val: &T)
+where
+    T: serde::Serialize + serde::de::DeserializeOwned,
+{
+    let json: Value = serde_json::to_value(val).unwrap();
+    let decoded: T = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(json, serde_json::to_value(&decoded).unwrap());
+}
+
+#[test]
+fn test_unit_arbitrary_names_are_valid() {
+    for seed in 0..ITERATIONS as u64 {
+        let bytes = pseudorandom_bytes(seed, 256);
+        let name = arbitrary_name(&mut Unstructured::new(&bytes)).unwrap();
+
+        assert!(!name.is_empty());
+        assert!(name.len() <= MAX_GENERATED_NAME_LENGTH);
+        assert!(!name.starts_with("amq."));
+    }
+}
+
+#[test]
+fn test_unit_arbitrary_queue_params_are_valid() {
+    for seed in 0..ITERATIONS as u64 {
+        let owned: OwnedQueueParams = generate(seed);
+        let params = owned.as_params();
+        let args = params.arguments.unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&params.queue_type).unwrap(),
+            args["x-queue-type"]
+        );
+        if params.queue_type != QueueType::Classic {
+            assert!(params.durable);
+            assert!(!params.auto_delete);
+        }
+        if params.queue_type == QueueType::Stream {
+            assert!(!args.contains_key("x-max-length"));
+        }
+    }
+}
+
+#[test]
+fn test_unit_arbitrary_exchange_and_policy_params() {
+    for seed in 0..ITERATIONS as u64 {
+        let exchange: OwnedExchangeParams = generate(seed);
+        assert!(!exchange.as_params().name.is_empty());
+
+        let policy: OwnedPolicyParams = generate(seed);
+        assert!(policy.pattern.starts_with('^'));
+        assert!(policy.definition.contains_key("max-length"));
+    }
+}
+
+#[test]
+fn test_unit_arbitrary_definitions_serde_round_trip() {
+    for seed in 0..ITERATIONS as u64 {
+        assert_serde_round_trip(&generate::<QueueDefinition>(seed));
+        assert_serde_round_trip(&generate::<ExchangeInfo>(seed));
+        assert_serde_round_trip(&generate::<BindingInfo>(seed));
+    }
+}
@@ -0,0 +1,233 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod test_helpers;
+
+use rabbitmq_http_client::blocking_api::ClientBuilder;
+use rabbitmq_http_client::commons::TimeUnit;
+use rabbitmq_http_client::error::Error;
+use rabbitmq_http_client::responses::HealthCheckFailureDetails;
+use serde_json::json;
+use test_helpers::{serve_once, OneShotServer};
+
+#[test]
+fn test_unit_deserialize_quorum_critical_failure_details() {
+    let json = r#"{
+        "status": "failed",
+        "reason": "There are quorum queues that would lose their quorum if the target node is shut down",
+        "queues": [
+            {"name": "qq.1", "readable_name": "queue 'qq.1' in vhost '/'", "virtual_host": "/", "type": "quorum"}
+        ]
+    }"#;
+    let details: HealthCheckFailureDetails = serde_json::from_str(json).unwrap();
+
+    match details {
+        HealthCheckFailureDetails::NodeIsQuorumCritical(d) => {
+            assert_eq!(1, d.queues.len());
+            assert_eq!("qq.1", d.queues[0].name);
+        }
+        other => panic!("unexpected failure details: {:?}", other),
+    }
+}
+
+#[test]
+fn test_unit_deserialize_mirror_sync_critical_failure_details() {
+    let json = r#"{
+        "status": "failed",
+        "reason": "There are classic mirrored queues without online synchronised mirrors",
+        "queues": [
+            {"name": "cmq.1", "readable_name": "queue 'cmq.1' in vhost 'events'", "virtual_host": "events"}
+        ]
+    }"#;
+    let details: HealthCheckFailureDetails = serde_json::from_str(json).unwrap();
+
+    assert_eq!(
+        "There are classic mirrored queues without online synchronised mirrors",
+        details.reason()
+    );
+    match details {
+        HealthCheckFailureDetails::NodeIsMirrorSyncCritical(d) => {
+            assert_eq!(1, d.queues.len());
+            assert_eq!("cmq.1", d.queues[0].name);
+            assert_eq!("events", d.queues[0].vhost);
+        }
+        other => panic!("unexpected failure details: {:?}", other),
+    }
+}
+
+#[test]
+fn test_unit_deserialize_certificate_expiration_failure_details() {
+    let json = r#"{
+        "status": "failed",
+        "reason": "Certificates expiring",
+        "expired": [
+            {
+                "node": "rabbit@sunnyside",
+                "protocol": "amqp/ssl",
+                "interface": "[::]",
+                "port": 5671,
+                "certfile": "/etc/rabbitmq/server_certificate.pem",
+                "cacertfile": "/etc/rabbitmq/ca_certificate.pem",
+                "expiry_date": "2025-06-01T00:00:00Z"
+            }
+        ]
+    }"#;
+    let details: HealthCheckFailureDetails = serde_json::from_str(json).unwrap();
+
+    assert_eq!("Certificates expiring", details.reason());
+    match details {
+        HealthCheckFailureDetails::CertificateExpiration(d) => {
+            assert_eq!(1, d.expired.len());
+            assert_eq!(5671, d.expired[0].port);
+            assert_eq!("amqp/ssl", d.expired[0].protocol);
+        }
+        other => panic!("unexpected failure details: {:?}", other),
+    }
+}
+
+#[test]
+fn test_unit_deserialize_virtual_hosts_failure_details() {
+    let json = r#"{
+        "status": "failed",
+        "reason": "Some virtual hosts are down",
+        "virtual-hosts": ["events", "orders"]
+    }"#;
+    let details: HealthCheckFailureDetails = serde_json::from_str(json).unwrap();
+
+    match details {
+        HealthCheckFailureDetails::VirtualHostsDown(d) => {
+            assert_eq!(
+                vec!["events".to_owned(), "orders".to_owned()],
+                d.virtual_hosts
+            );
+        }
+        other => panic!("unexpected failure details: {:?}", other),
+    }
+}
+
+#[test]
+fn test_unit_deserialize_port_listener_failure_details() {
+    let json = r#"{
+        "status": "failed",
+        "reason": "No active listener",
+        "missing": 1883,
+        "ports": [5672, 15672]
+    }"#;
+    let details: HealthCheckFailureDetails = serde_json::from_str(json).unwrap();
+
+    match details {
+        HealthCheckFailureDetails::NoActivePortListener(d) => assert_eq!(1883, d.inactive_port),
+        other => panic!("unexpected failure details: {:?}", other),
+    }
+}
+
+#[test]
+fn test_unit_unknown_failure_details_fall_back_to_raw() {
+    let details = HealthCheckFailureDetails::from_body(
+        r#"{"status": "failed", "reason": "Node is not ready", "since": 12}"#,
+    );
+    assert_eq!("Node is not ready", details.reason());
+    match details {
+        HealthCheckFailureDetails::Raw(body) => assert_eq!(Some(&json!(12)), body.get("since")),
+        other => panic!("unexpected failure details: {:?}", other),
+    }
+
+    let details = HealthCheckFailureDetails::from_body("Service Unavailable");
+    assert_eq!("Service Unavailable", details.reason());
+}
+
+#[test]
+fn test_unit_time_unit_conversions() {
+    assert_eq!("weeks", String::from(TimeUnit::Weeks));
+    assert_eq!("days", TimeUnit::default().to_string());
+}
+
+// serves a single 503 response with the given body
+fn serve_failure_once(body: &str) -> OneShotServer {
+    serve_once(
+        "503 Service Unavailable",
+        &[("content-type", "application/json")],
+        body,
+    )
+}
+
+#[test]
+fn test_unit_metadata_store_initialized_health_check_failure() {
+    let server =
+        serve_failure_once(r#"{"status":"failed","reason":"Metadata store is not initialized"}"#);
+
+    let rc = ClientBuilder::new()
+        .with_endpoint(server.endpoint())
+        .build();
+    let result = rc.health_check_metadata_store_initialized_with_data();
+    let request = server.request();
+
+    assert!(request.starts_with("GET /api/health/checks/metadata-store/initialized/with-data "));
+    match result.unwrap_err() {
+        Error::HealthCheckFailed { path, details, .. } => {
+            assert_eq!("health/checks/metadata-store/initialized/with-data", path);
+            assert_eq!("Metadata store is not initialized", details.reason());
+        }
+        other => panic!("unexpected error: {:?}", other),
+    }
+}
+
+#[test]
+fn test_unit_quorum_queues_without_elected_leaders_health_check_failure() {
+    let server = serve_failure_once(
+        r#"{
+            "status": "failed",
+            "reason": "Some quorum queues do not have elected leaders",
+            "queues": [
+                {"name": "qq.1", "readable_name": "queue 'qq.1' in vhost 'events'", "virtual_host": "events", "type": "quorum"}
+            ]
+        }"#,
+    );
+
+    let rc = ClientBuilder::new()
+        .with_endpoint(server.endpoint())
+        .build();
+    let result = rc.health_check_quorum_queues_without_elected_leaders_in("events", Some("^qq\\."));
+    let request = server.request();
+
+    assert!(request.starts_with(
+        "GET /api/health/checks/quorum-queues-without-elected-leaders/vhost/events/pattern/%5Eqq%5C%2E "
+    ));
+    match result.unwrap_err() {
+        Error::HealthCheckFailed {
+            details: HealthCheckFailureDetails::QuorumQueuesWithoutElectedLeaders(d),
+            ..
+        } => {
+            assert_eq!(1, d.queues.len());
+            assert_eq!("qq.1", d.queues[0].name);
+            assert_eq!("events", d.queues[0].vhost);
+        }
+        other => panic!("unexpected error: {:?}", other),
+    }
+}
+
+#[test]
+fn test_unit_failed_aliveness_test() {
+    let server = serve_failure_once(r#"{"status":"failed","reason":"vhost_not_running"}"#);
+
+    let rc = ClientBuilder::new()
+        .with_endpoint(server.endpoint())
+        .build();
+    let result = rc.aliveness_test("events");
+    let request = server.request();
+
+    assert!(request.starts_with("GET /api/aliveness-test/events "));
+    let outcome = result.unwrap();
+    assert!(!outcome.is_ok());
+    assert_eq!(Some("vhost_not_running".to_owned()), outcome.reason);
+}
@@ -0,0 +1,82 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod test_helpers;
+
+use std::sync::{Arc, Mutex};
+
+use rabbitmq_http_client::blocking_api::ClientBuilder;
+use rabbitmq_http_client::lenient_listings::{LenientListings, SkippedElement};
+use rabbitmq_http_client::responses::User;
+use serde_json::json;
+use test_helpers::serve_once;
+
+const USERS_JSON: &str = r#"[
+    {"name": "alice", "tags": ["management"], "password_hash": "abc"},
+    {"name": 42, "tags": ["management"], "password_hash": "def"},
+    {"name": "bob", "tags": [], "password_hash": "ghi"}
+]"#;
+
+#[test]
+fn test_unit_lenient_listings_skip_and_report_malformed_elements() {
+    let skipped: Arc<Mutex<Vec<SkippedElement>>> = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&skipped);
+    let lenient = LenientListings::reporting_to(move |e| sink.lock().unwrap().push(e.clone()));
+
+    let elements: Vec<serde_json::Value> = serde_json::from_str(USERS_JSON).unwrap();
+    let users: Vec<User> = lenient.deserialize_elements(elements);
+
+    let names: Vec<&str> = users.iter().map(|u| u.name.as_str()).collect();
+    assert_eq!(vec!["alice", "bob"], names);
+
+    let skipped = skipped.lock().unwrap();
+    assert_eq!(1, skipped.len());
+    assert_eq!(1, skipped[0].index);
+    assert_eq!(json!(42), skipped[0].element["name"]);
+    assert!(!skipped[0].error.is_empty());
+}
+
+#[test]
+fn test_unit_client_with_lenient_listings() {
+    let server = serve_once(
+        "200 OK",
+        &[("content-type", "application/json")],
+        USERS_JSON,
+    );
+
+    let rc = ClientBuilder::new()
+        .with_endpoint(server.endpoint())
+        .with_lenient_listings(LenientListings::skip_silently())
+        .build();
+    let result = rc.list_users();
+    server.request();
+
+    assert_eq!(2, result.unwrap().len());
+}
+
+#[test]
+fn test_unit_client_without_lenient_listings() {
+    let server = serve_once(
+        "200 OK",
+        &[("content-type", "application/json")],
+        USERS_JSON,
+    );
+
+    let rc = ClientBuilder::new()
+        .with_endpoint(server.endpoint())
+        .build();
+    let result = rc.list_users();
+    server.request();
+
+    assert!(result.is_err());
+}
@@ -0,0 +1,132 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod test_helpers;
+
+use rabbitmq_http_client::blocking_api::Client;
+use rabbitmq_http_client::commons::PayloadEncoding;
+use rabbitmq_http_client::error::Error;
+use rabbitmq_http_client::responses::GetMessage;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::test_helpers::{endpoint, PASSWORD, USERNAME};
+
+const UNREACHABLE_ENDPOINT: &str = "http://127.0.0.1:1/api";
+
+fn temporary_file(name: &str, contents: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(name);
+    fs::write(&path, contents).unwrap();
+    path
+}
+
+#[test]
+fn test_unit_publish_messages_from_a_missing_file() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let path = std::env::temp_dir().join("rust.http.api.unit.messages.missing.jsonl");
+    let _ = fs::remove_file(&path);
+
+    let result = rc.publish_messages_from_file("/", &path, None, None, |_| {});
+    assert!(matches!(result, Err(Error::IoError { .. })));
+}
+
+#[test]
+fn test_unit_publish_messages_from_a_file_with_a_malformed_line() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let line = r#"{"payload_bytes":2,"redelivered":false,"exchange":"","routing_key":"q","message_count":0,"properties":[],"payload":"m1","payload_encoding":"string"}"#;
+    let path = temporary_file(
+        "rust.http.api.unit.messages.malformed.jsonl",
+        &format!("{}\n\n{{\"payload\": \n", line),
+    );
+
+    let mut published = 0;
+    let result = rc.publish_messages_from_file("/", &path, None, None, |n| published = n);
+    match result {
+        Err(Error::InvalidMessageFile { reason }) => assert!(reason.starts_with("line 3:")),
+        other => panic!("expected an invalid message file error, got {:?}", other),
+    }
+    // nothing is published unless the entire file is valid
+    assert_eq!(0, published);
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_unit_publish_messages_from_an_empty_file() {
+    let rc = Client::new(UNREACHABLE_ENDPOINT, USERNAME, PASSWORD);
+    let path = temporary_file("rust.http.api.unit.messages.empty.jsonl", "\n");
+
+    let result = rc.publish_messages_from_file("/", &path, None, None, |_| {});
+    assert_eq!(0, result.unwrap());
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_unit_publish_messages_from_a_file_reports_request_errors() {
+    let rc = Client::new(UNREACHABLE_ENDPOINT, USERNAME, PASSWORD);
+
+    let msg = GetMessage {
+        payload_bytes: 3,
+        redelivered: true,
+        exchange: "".to_owned(),
+        routing_key: "rust.tests.q".to_owned(),
+        message_count: 0,
+        properties: Default::default(),
+        payload: "AQID".to_owned(),
+        payload_encoding: PayloadEncoding::Base64,
+    };
+    let line = serde_json::to_string(&msg).unwrap();
+    let path = temporary_file("rust.http.api.unit.messages.unreachable.jsonl", &line);
+
+    let result = rc.publish_messages_from_file("/", &path, None, None, |_| {});
+    assert!(matches!(result, Err(Error::RequestError { .. })));
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_unit_drain_queue_to_file_reports_request_errors() {
+    let rc = Client::new(UNREACHABLE_ENDPOINT, USERNAME, PASSWORD);
+    let path = std::env::temp_dir().join("rust.http.api.unit.messages.drain.jsonl");
+    let _ = fs::remove_file(&path);
+
+    let result = rc.drain_queue_to_file("/", "rust.tests.q", &path, 10, |_| {});
+    assert!(matches!(result, Err(Error::RequestError { .. })));
+
+    let _ = fs::remove_file(&path);
+}
+
+#[test]
+fn test_unit_drain_queue_to_file_does_not_overwrite_existing_files() {
+    let rc = Client::new(UNREACHABLE_ENDPOINT, USERNAME, PASSWORD);
+    let path = temporary_file(
+        "rust.http.api.unit.messages.drain.existing.jsonl",
+        "evidence\n",
+    );
+
+    let result = rc.drain_queue_to_file("/", "rust.tests.q", &path, 10, |_| {});
+    match result {
+        Err(Error::IoError { error, .. }) => {
+            assert_eq!(std::io::ErrorKind::AlreadyExists, error.kind())
+        }
+        other => panic!("expected an I/O error, got {:?}", other),
+    }
+    assert_eq!("evidence\n", fs::read_to_string(&path).unwrap());
+
+    fs::remove_file(&path).unwrap();
+}
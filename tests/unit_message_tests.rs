@@ -0,0 +1,154 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod test_helpers;
+
+use rabbitmq_http_client::commons::PayloadEncoding;
+use rabbitmq_http_client::requests::{
+    self, MessageReplayParams, DEFAULT_MESSAGE_REPLAY_MAX_MESSAGES,
+};
+use rabbitmq_http_client::responses::{GetMessage, MessageProperties};
+use serde_json::json;
+
+#[test]
+fn test_unit_deserialize_get_message_with_typed_properties() {
+    let input = r#"{
+        "payload_bytes": 5,
+        "redelivered": true,
+        "exchange": "events",
+        "routing_key": "orders.created",
+        "message_count": 3,
+        "properties": {
+            "content_type": "application/json",
+            "content_encoding": "utf-8",
+            "delivery_mode": 2,
+            "priority": 5,
+            "correlation_id": "c-1",
+            "reply_to": "amq.rabbitmq.reply-to",
+            "expiration": "60000",
+            "message_id": "m-1",
+            "timestamp": 1747411200,
+            "headers": {"x-retries": 3},
+            "app_id": "orders-service"
+        },
+        "payload": "hello",
+        "payload_encoding": "string"
+    }"#;
+    let msg: GetMessage = serde_json::from_str(input).unwrap();
+    let props = msg.properties;
+
+    assert_eq!(Some("application/json".to_owned()), props.content_type);
+    assert_eq!(Some("utf-8".to_owned()), props.content_encoding);
+    assert_eq!(Some(2), props.delivery_mode);
+    assert_eq!(Some(5), props.priority);
+    assert_eq!(Some("c-1".to_owned()), props.correlation_id);
+    assert_eq!(Some("amq.rabbitmq.reply-to".to_owned()), props.reply_to);
+    assert_eq!(Some("60000".to_owned()), props.expiration);
+    assert_eq!(Some("m-1".to_owned()), props.message_id);
+    assert_eq!(Some(1747411200), props.timestamp);
+    assert_eq!(json!(3), props.headers.unwrap()["x-retries"]);
+    assert_eq!(1, props.extras.len());
+    assert_eq!(json!("orders-service"), props.extras["app_id"]);
+}
+
+#[test]
+fn test_unit_deserialize_get_message_with_empty_properties() {
+    // the HTTP API returns an empty list when a message has no properties
+    let input = r#"{
+        "payload_bytes": 5,
+        "redelivered": false,
+        "exchange": "",
+        "routing_key": "qq.1",
+        "message_count": 0,
+        "properties": [],
+        "payload": "hello",
+        "payload_encoding": "string"
+    }"#;
+    let msg: GetMessage = serde_json::from_str(input).unwrap();
+
+    assert_eq!(MessageProperties::default(), msg.properties);
+}
+
+#[test]
+fn test_unit_get_message_decoded_payload() {
+    let mut val = json!({
+        "payload_bytes": 4,
+        "redelivered": false,
+        "exchange": "",
+        "routing_key": "qq.1",
+        "message_count": 0,
+        "properties": [],
+        "payload": "3q2+7w==",
+        "payload_encoding": "base64"
+    });
+    let msg: GetMessage = serde_json::from_value(val.clone()).unwrap();
+    assert_eq!(PayloadEncoding::Base64, msg.payload_encoding);
+    assert_eq!(vec![0xde, 0xad, 0xbe, 0xef], msg.decoded_payload().unwrap());
+
+    val["payload"] = json!("not base64!");
+    let msg: GetMessage = serde_json::from_value(val.clone()).unwrap();
+    assert!(msg.decoded_payload().is_err());
+
+    val["payload"] = json!("hello");
+    val["payload_encoding"] = json!("string");
+    let msg: GetMessage = serde_json::from_value(val).unwrap();
+    assert_eq!(PayloadEncoding::String, msg.payload_encoding);
+    assert_eq!(b"hello".to_vec(), msg.decoded_payload().unwrap());
+}
+
+#[test]
+fn test_unit_serialize_message_properties_skips_unset_fields() {
+    let props = MessageProperties {
+        delivery_mode: Some(2),
+        ..Default::default()
+    };
+    let val = serde_json::to_value(&props).unwrap();
+
+    assert_eq!(json!({"delivery_mode": 2}), val);
+}
+
+#[test]
+fn test_unit_message_properties_conversion_to_publishing_properties() {
+    let mut props = MessageProperties {
+        delivery_mode: Some(2),
+        correlation_id: Some("c-1".to_owned()),
+        ..Default::default()
+    };
+    props
+        .extras
+        .insert("app_id".to_owned(), json!("orders-service"));
+    let m = requests::MessageProperties::from(&props);
+
+    assert_eq!(3, m.len());
+    assert_eq!(json!(2), m["delivery_mode"]);
+    assert_eq!(json!("c-1"), m["correlation_id"]);
+    assert_eq!(json!("orders-service"), m["app_id"]);
+}
+
+#[test]
+fn test_unit_message_replay_params_next_batch_size() {
+    let mut params = MessageReplayParams::to_queue("/", "orders.dlq", "orders");
+    params.batch_size = 10;
+    assert_eq!(10, params.next_batch_size(0));
+    assert_eq!(10, params.next_batch_size(1000));
+    assert_eq!(
+        0,
+        params.next_batch_size(DEFAULT_MESSAGE_REPLAY_MAX_MESSAGES)
+    );
+
+    params.max_messages = 25;
+    assert_eq!(10, params.next_batch_size(0));
+    assert_eq!(5, params.next_batch_size(20));
+    assert_eq!(0, params.next_batch_size(25));
+    assert_eq!(0, params.next_batch_size(30));
+}
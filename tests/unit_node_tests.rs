@@ -0,0 +1,135 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod test_helpers;
+
+use rabbitmq_http_client::commons::RatesMode;
+use rabbitmq_http_client::responses::{
+    ClusterNode, NodeMemoryFootprint, NodeMemoryPercentages, NodeMemoryRelativeFootprint,
+};
+use serde_json::json;
+
+fn cluster_node(name: &str) -> ClusterNode {
+    serde_json::from_value(json!({
+        "name": name,
+        "running": true,
+        "uptime": 360000,
+        "run_queue": 1,
+        "processors": 8,
+        "os_pid": "4242",
+        "fd_total": 1048576,
+        "proc_total": 1048576,
+        "mem_limit": 6871947673u64,
+        "mem_alarm": false,
+        "disk_free_limit": 50000000,
+        "disk_free_alarm": false,
+        "rates_mode": "basic",
+        "enabled_plugins": ["rabbitmq_management"],
+        "being_drained": false
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_unit_deserialize_cluster_node() {
+    let node = cluster_node("rabbit@sunnyside");
+
+    assert!(node.running);
+    assert_eq!(4242, node.os_pid);
+    assert_eq!(RatesMode::Basic, node.rates_mode);
+}
+
+#[test]
+fn test_unit_cluster_node_hostname() {
+    assert_eq!(
+        "node1.eng.megacorp.local",
+        cluster_node("rabbit@node1.eng.megacorp.local").hostname()
+    );
+    assert_eq!("sunnyside", cluster_node("sunnyside").hostname());
+}
+
+fn memory_footprint(total: u64) -> NodeMemoryFootprint {
+    let mut breakdown = serde_json::Map::new();
+    for key in [
+        "connection_readers",
+        "connection_writers",
+        "connection_channels",
+        "connection_other",
+        "queue_procs",
+        "quorum_queue_procs",
+        "stream_queue_procs",
+        "stream_queue_replica_reader_procs",
+        "stream_queue_coordinator_procs",
+        "plugins",
+        "metadata_store",
+        "other_proc",
+        "metrics",
+        "mgmt_db",
+        "mnesia",
+        "quorum_ets",
+        "metadata_store_ets",
+        "other_ets",
+        "binary",
+        "msg_index",
+        "atom",
+        "other_system",
+        "allocated_unused",
+        "reserved_unallocated",
+    ] {
+        breakdown.insert(key.to_owned(), json!(0));
+    }
+    breakdown.insert("code".to_owned(), json!(total / 4));
+    breakdown.insert("binary".to_owned(), json!(total / 2));
+    breakdown.insert("strategy".to_owned(), json!("rss"));
+    breakdown.insert(
+        "total".to_owned(),
+        json!({"rss": total, "allocated": total, "erlang": total}),
+    );
+
+    serde_json::from_value(json!({ "memory": breakdown })).unwrap()
+}
+
+#[test]
+fn test_unit_node_memory_footprint_percentages() {
+    let percentages = memory_footprint(1000).percentages();
+
+    assert_eq!(25.0, percentages.code);
+    assert_eq!(50.0, percentages.binary_heap);
+    assert_eq!(0.0, percentages.plugins);
+}
+
+#[test]
+fn test_unit_node_memory_footprint_percentages_with_a_zero_total() {
+    let percentages = memory_footprint(0).percentages();
+
+    assert_eq!(NodeMemoryPercentages::default(), percentages);
+}
+
+#[test]
+fn test_unit_deserialize_relative_node_memory_footprint() {
+    let footprint: NodeMemoryRelativeFootprint = serde_json::from_value(json!({
+        "memory": {
+            "code": 12,
+            "binary": 30,
+            "queue_procs": 5,
+            "strategy": "rss",
+            "total": {"rss": 100, "allocated": 100, "erlang": 100}
+        }
+    }))
+    .unwrap();
+
+    assert_eq!(12.0, footprint.breakdown.code);
+    assert_eq!(30.0, footprint.breakdown.binary_heap);
+    assert_eq!(5.0, footprint.breakdown.classic_queue_procs);
+    assert_eq!(0.0, footprint.breakdown.metrics);
+}
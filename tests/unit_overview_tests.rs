@@ -0,0 +1,179 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod test_helpers;
+
+use rabbitmq_http_client::commons::{RatesMode, SupportedProtocol};
+use rabbitmq_http_client::responses::{Listener, Overview};
+
+const OVERVIEW_JSON: &str = r#"{
+    "cluster_name": "rabbit@sunnyside",
+    "node": "rabbit@sunnyside",
+    "erlang_full_version": "Erlang/OTP 27 [erts-15.2] [source] [64-bit]",
+    "erlang_version": "27.2",
+    "rabbitmq_version": "4.1.0",
+    "product_name": "RabbitMQ",
+    "product_version": "4.1.0",
+    "statistics_db_event_queue": 0,
+    "rates_mode": "detailed",
+    "churn_rates": {
+        "channel_closed": 10,
+        "channel_created": 12,
+        "connection_closed": 4,
+        "connection_created": 6,
+        "queue_created": 3,
+        "queue_declared": 5,
+        "queue_deleted": 1
+    },
+    "queue_totals": {
+        "messages": 120,
+        "messages_details": {"rate": 2.5},
+        "messages_ready": 100,
+        "messages_ready_details": {"rate": 2.0},
+        "messages_unacknowledged": 20,
+        "messages_unacknowledged_details": {"rate": 0.5}
+    },
+    "object_totals": {
+        "channels": 2,
+        "connections": 1,
+        "consumers": 1,
+        "exchanges": 8,
+        "queues": 3
+    },
+    "message_stats": {
+        "ack": 900,
+        "ack_details": {"rate": 9.0},
+        "confirm": 1000,
+        "confirm_details": {"rate": 10.0},
+        "deliver_get": 950,
+        "deliver_get_details": {"rate": 9.5},
+        "deliver_no_ack": 50,
+        "deliver_no_ack_details": {"rate": 0.5},
+        "drop_unroutable": 3,
+        "drop_unroutable_details": {"rate": 0.0},
+        "publish": 1020,
+        "publish_details": {"rate": 10.2},
+        "redeliver": 7,
+        "redeliver_details": {"rate": 0.1},
+        "return_unroutable": 0,
+        "return_unroutable_details": {"rate": 0.0}
+    }
+}"#;
+
+#[test]
+fn test_unit_deserialize_overview_queue_totals() {
+    let ov: Overview = serde_json::from_str(OVERVIEW_JSON).unwrap();
+
+    assert_eq!(120, ov.queue_totals.messages);
+    assert_eq!(100, ov.queue_totals.messages_ready_for_delivery);
+    assert_eq!(
+        20,
+        ov.queue_totals
+            .messages_delivered_but_unacknowledged_by_consumers
+    );
+    assert_eq!(2.5, ov.queue_totals.messages_details.rate);
+}
+
+#[test]
+fn test_unit_deserialize_overview_message_stats() {
+    let ov: Overview = serde_json::from_str(OVERVIEW_JSON).unwrap();
+    let ms = ov.message_stats;
+
+    assert_eq!(Some(1020), ms.publishing_count);
+    assert_eq!(Some(950), ms.delivery_count);
+    assert_eq!(Some(1000), ms.publisher_confirmation_count);
+    assert_eq!(Some(900), ms.consumer_acknowledgement_count);
+    assert_eq!(Some(50), ms.delivery_with_automatic_acknowledgement_count);
+    assert_eq!(Some(7), ms.redelivery_count);
+    assert_eq!(Some(3), ms.unroutable_dropped_message_count);
+    assert_eq!(Some(0), ms.unroutable_returned_message_count);
+    assert_eq!(10.2, ms.publishing_details.unwrap().rate);
+}
+
+#[test]
+fn test_unit_deserialize_overview_with_empty_message_stats() {
+    let mut val: serde_json::Value = serde_json::from_str(OVERVIEW_JSON).unwrap();
+    val["message_stats"] = serde_json::json!({});
+    let ov: Overview = serde_json::from_value(val).unwrap();
+
+    assert!(ov.message_stats.publishing_count.is_none());
+    assert!(ov.message_stats.publishing_details.is_none());
+}
+
+#[test]
+fn test_unit_deserialize_churn_rates_with_details() {
+    let mut val: serde_json::Value = serde_json::from_str(OVERVIEW_JSON).unwrap();
+    val["churn_rates"]["connection_created_details"] = serde_json::json!({"rate": 1.5});
+    val["churn_rates"]["queue_deleted_details"] = serde_json::json!({
+        "rate": 0.2,
+        "avg": 0.25,
+        "avg_rate": 0.2,
+        "samples": [
+            {"sample": 3, "timestamp": 1747411200000u64},
+            {"sample": 1, "timestamp": 1747411195000u64}
+        ]
+    });
+    let ov: Overview = serde_json::from_value(val).unwrap();
+    let cr = ov.churn_rates;
+
+    assert_eq!(6, cr.connection_created);
+    assert_eq!(1.5, cr.connection_created_details.unwrap().rate);
+    assert!(cr.connection_closed_details.is_none());
+
+    let qd = cr.queue_deleted_details.unwrap();
+    assert_eq!(0.2, qd.rate);
+    assert_eq!(Some(0.25), qd.avg);
+    let samples = qd.samples.unwrap();
+    assert_eq!(2, samples.len());
+    assert_eq!(3, samples[0].sample);
+    assert_eq!(1747411200000, samples[0].timestamp);
+}
+
+#[test]
+fn test_unit_deserialize_overview_rates_mode() {
+    let ov: Overview = serde_json::from_str(OVERVIEW_JSON).unwrap();
+    assert_eq!(RatesMode::Detailed, ov.rates_mode);
+
+    let mut val: serde_json::Value = serde_json::from_str(OVERVIEW_JSON).unwrap();
+    val["rates_mode"] = serde_json::json!("none");
+    let ov: Overview = serde_json::from_value(val).unwrap();
+    assert_eq!(RatesMode::None, ov.rates_mode);
+}
+
+#[test]
+fn test_unit_rates_mode_conversions() {
+    assert_eq!(RatesMode::Basic, RatesMode::from("basic"));
+    assert_eq!(RatesMode::Detailed, RatesMode::from("detailed"));
+    assert_eq!(RatesMode::None, RatesMode::from("none"));
+    assert_eq!("detailed", String::from(RatesMode::Detailed));
+}
+
+#[test]
+fn test_unit_deserialize_listeners() {
+    let json = r#"[
+        {"node": "rabbit@sunnyside", "protocol": "amqp", "ip_address": "::", "port": 5672, "socket_opts": {"backlog": 128}},
+        {"node": "rabbit@sunnyside", "protocol": "http/prometheus", "ip_address": "::", "port": 15692, "socket_opts": {}}
+    ]"#;
+    let listeners: Vec<Listener> = serde_json::from_str(json).unwrap();
+
+    assert_eq!(2, listeners.len());
+    assert_eq!("rabbit@sunnyside", listeners[0].node);
+    assert_eq!(5672, listeners[0].port);
+    assert_eq!("::", listeners[0].interface);
+    assert_eq!(SupportedProtocol::AMQP, listeners[0].supported_protocol());
+    assert_eq!("http/prometheus", listeners[1].protocol);
+    assert_eq!(
+        SupportedProtocol::Prometheus,
+        listeners[1].supported_protocol()
+    );
+}
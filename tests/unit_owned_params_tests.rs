@@ -0,0 +1,199 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod test_helpers;
+
+use rabbitmq_http_client::commons::{ExchangeType, PolicyTarget, QueueType};
+use rabbitmq_http_client::requests::{
+    ExchangeParams, OwnedExchangeParams, OwnedPolicyParams, OwnedQueueParams, OwnedStreamParams,
+    OwnedUserParams, OwnedVirtualHostParams, QueueParams, StreamParams, VirtualHostParams,
+};
+use rabbitmq_http_client::x_args;
+use serde_json::{json, Map};
+
+fn queue_name(i: usize) -> String {
+    format!("events.{}", i)
+}
+
+#[test]
+fn test_unit_owned_queue_params_can_be_built_dynamically() {
+    let owned: Vec<OwnedQueueParams> = (0..3)
+        .map(|i| {
+            OwnedQueueParams::new(
+                queue_name(i),
+                QueueType::Quorum,
+                true,
+                false,
+                x_args! {"x-max-length" => 1000},
+            )
+        })
+        .collect();
+
+    let handle = std::thread::spawn(move || owned);
+    let owned = handle.join().unwrap();
+
+    let params = owned[2].as_params();
+    assert_eq!("events.2", params.name);
+    assert_eq!(QueueType::Quorum, params.queue_type);
+    let args = params.arguments.unwrap();
+    assert_eq!(json!("quorum"), args["x-queue-type"]);
+    assert_eq!(json!(1000), args["x-max-length"]);
+}
+
+#[test]
+fn test_unit_owned_queue_params_round_trip() {
+    let params = QueueParams::new_stream("stream.1", None);
+    let owned = OwnedQueueParams::from(&params);
+    let converted = owned.as_params();
+
+    assert_eq!(params.name, converted.name);
+    assert_eq!(params.queue_type, converted.queue_type);
+    assert_eq!(params.durable, converted.durable);
+    assert_eq!(params.arguments, converted.arguments);
+}
+
+#[test]
+fn test_unit_owned_virtual_host_params_round_trip() {
+    let params = VirtualHostParams {
+        name: "events",
+        description: Some("Event processing"),
+        tags: Some(vec!["production", "events"]),
+        default_queue_type: Some(QueueType::Quorum),
+        tracing: true,
+    };
+    let owned = OwnedVirtualHostParams::from(&params);
+    assert_eq!(
+        Some(vec!["production".to_owned(), "events".to_owned()]),
+        owned.tags
+    );
+
+    let converted = owned.as_params();
+    assert_eq!("events", converted.name);
+    assert_eq!(Some("Event processing"), converted.description);
+    assert_eq!(Some(vec!["production", "events"]), converted.tags);
+    assert_eq!(Some(QueueType::Quorum), converted.default_queue_type);
+    assert!(converted.tracing);
+
+    let named = OwnedVirtualHostParams::named(String::from("staging"));
+    assert_eq!("staging", named.as_params().name);
+}
+
+#[test]
+fn test_unit_owned_exchange_stream_user_and_policy_params() {
+    let exchange = ExchangeParams::durable_topic("events.topic", None);
+    let owned = OwnedExchangeParams::from(&exchange);
+    assert_eq!(ExchangeType::Topic, owned.as_params().exchange_type);
+    assert_eq!(
+        owned,
+        OwnedExchangeParams::new("events.topic", ExchangeType::Topic, true, false, None)
+    );
+
+    let stream = StreamParams::with_expiration_and_length_limit("stream.1", "7D", 1000);
+    let owned = OwnedStreamParams::from(&stream);
+    assert_eq!("7D", owned.as_params().expiration);
+    assert_eq!(Some(1000), owned.as_params().max_length_bytes);
+    assert_eq!("stream.1", OwnedStreamParams::new("stream.1", "1D").name);
+
+    let user = OwnedUserParams::new("alice", "hash", "monitoring");
+    assert_eq!("monitoring", user.as_params().tags);
+
+    let mut definition = Map::new();
+    definition.insert("max-length".to_owned(), json!(100));
+    let policy = OwnedPolicyParams::new(
+        "/",
+        "limit",
+        "^events\\.",
+        PolicyTarget::Queues,
+        5,
+        definition,
+    );
+    let params = policy.as_params();
+    assert_eq!("limit", params.name);
+    assert_eq!(5, params.priority);
+    assert_eq!(policy, OwnedPolicyParams::from(&params));
+}
+
+#[test]
+fn test_unit_owned_queue_params_deserialization() {
+    let owned: Vec<OwnedQueueParams> = serde_json::from_value(json!([
+        {"name": "events.1", "queue_type": "quorum", "arguments": {"x-max-length": 1000}},
+        {"name": "events.2"}
+    ]))
+    .unwrap();
+
+    let params = owned[0].as_params();
+    assert_eq!(QueueType::Quorum, params.queue_type);
+    assert!(params.durable);
+    assert!(!params.exclusive);
+    let args = params.arguments.unwrap();
+    assert_eq!(json!("quorum"), args["x-queue-type"]);
+    assert_eq!(json!(1000), args["x-max-length"]);
+
+    let params = owned[1].as_params();
+    assert_eq!(QueueType::Classic, params.queue_type);
+    assert_eq!(json!("classic"), params.arguments.unwrap()["x-queue-type"]);
+}
+
+#[test]
+fn test_unit_owned_params_serialization_round_trip() {
+    let queue = OwnedQueueParams::new("events.1", QueueType::Stream, true, false, None);
+    let json = serde_json::to_value(&queue).unwrap();
+    assert_eq!(queue, serde_json::from_value(json).unwrap());
+
+    let exchange = OwnedExchangeParams::new(
+        "events.hash",
+        ExchangeType::ConsistentHashing,
+        true,
+        false,
+        None,
+    );
+    let json = serde_json::to_value(&exchange).unwrap();
+    assert_eq!(json!("x-consistent-hash"), json["exchange_type"]);
+    assert_eq!(exchange, serde_json::from_value(json).unwrap());
+}
+
+#[test]
+fn test_unit_owned_exchange_virtual_host_user_and_policy_params_deserialization() {
+    let exchange: OwnedExchangeParams =
+        serde_json::from_value(json!({"name": "events.topic", "exchange_type": "topic"})).unwrap();
+    assert_eq!(
+        OwnedExchangeParams::new("events.topic", ExchangeType::Topic, true, false, None),
+        exchange
+    );
+
+    let vhost: OwnedVirtualHostParams = serde_json::from_value(
+        json!({"name": "events", "tags": ["production"], "default_queue_type": "quorum"}),
+    )
+    .unwrap();
+    assert_eq!(Some(QueueType::Quorum), vhost.default_queue_type);
+    assert!(!vhost.tracing);
+
+    let user: OwnedUserParams =
+        serde_json::from_value(json!({"name": "alice", "password_hash": "hash"})).unwrap();
+    assert_eq!("", user.tags);
+
+    let policy: OwnedPolicyParams = serde_json::from_value(json!({
+        "vhost": "/",
+        "name": "limit",
+        "pattern": "^events\\.",
+        "apply_to": "queues",
+        "definition": {"max-length": 100}
+    }))
+    .unwrap();
+    assert_eq!(PolicyTarget::Queues, policy.apply_to);
+    assert_eq!(0, policy.priority);
+
+    let stream: OwnedStreamParams =
+        serde_json::from_value(json!({"name": "stream.1", "expiration": "7D"})).unwrap();
+    assert_eq!(OwnedStreamParams::new("stream.1", "7D"), stream);
+}
@@ -0,0 +1,143 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod test_helpers;
+
+use std::error::Error as StdError;
+
+use rabbitmq_http_client::blocking_api::ClientBuilder;
+use rabbitmq_http_client::error::Error;
+use rabbitmq_http_client::password_hashing::{self, HashingAlgorithm};
+use test_helpers::serve_once;
+
+// salt 0x908DC60A and password "test12", as in the Credentials and Passwords guide
+const SALT: [u8; 4] = [0x90, 0x8D, 0xC6, 0x0A];
+const PASSWORD: &str = "test12";
+
+#[test]
+fn test_unit_base64_encoded_salted_password_hash_sha256() {
+    assert_eq!(
+        "kI3GCqW5JLMJa4iX1lo7X4D6XbYqlLgxIs30+P6tENUV2POR",
+        password_hashing::base64_encoded_salted_password_hash_sha256(&SALT, PASSWORD)
+    );
+}
+
+#[test]
+fn test_unit_base64_encoded_salted_password_hash_sha512() {
+    assert_eq!(
+        "kI3GChuNuIYf8lRbCCxZjgjKwsY19ns6+uFO0zcXRBGA/XGJPYD8OWMy7EB8TaOmAzjP2azv84GbINYwX2cDWb4DHnc=",
+        password_hashing::base64_encoded_salted_password_hash_sha512(&SALT, PASSWORD)
+    );
+}
+
+#[test]
+fn test_unit_hashing_algorithm() {
+    assert_eq!(HashingAlgorithm::Sha256, HashingAlgorithm::default());
+    assert_eq!(
+        password_hashing::base64_encoded_salted_password_hash_sha256(&SALT, PASSWORD),
+        HashingAlgorithm::Sha256.base64_encoded_salted_password_hash(&SALT, PASSWORD)
+    );
+    assert_eq!(
+        password_hashing::salted_password_hash_sha512(&SALT, PASSWORD),
+        HashingAlgorithm::Sha512.salt_and_hash(&SALT, PASSWORD)
+    );
+    assert_eq!(
+        "rabbit_password_hashing_sha512",
+        HashingAlgorithm::Sha512.to_string()
+    );
+}
+
+#[test]
+fn test_unit_hashing_algorithm_serialization() {
+    assert_eq!(
+        HashingAlgorithm::Sha512,
+        "rabbit_password_hashing_sha512"
+            .parse::<HashingAlgorithm>()
+            .unwrap()
+    );
+    assert!("rabbit_password_hashing_md5"
+        .parse::<HashingAlgorithm>()
+        .is_err());
+
+    assert_eq!(
+        "\"rabbit_password_hashing_sha256\"",
+        serde_json::to_string(&HashingAlgorithm::Sha256).unwrap()
+    );
+    let algorithm: HashingAlgorithm =
+        serde_json::from_str("\"rabbit_password_hashing_sha512\"").unwrap();
+    assert_eq!(HashingAlgorithm::Sha512, algorithm);
+}
+
+#[test]
+fn test_unit_hash_password() {
+    let server = serve_once(
+        "200 OK",
+        &[("content-type", "application/json")],
+        r#"{"ok":"kI3GCqW5JLMJa4iX1lo7X4D6XbYqlLgxIs30+P6tENUV2POR"}"#,
+    );
+
+    let rc = ClientBuilder::new()
+        .with_endpoint(server.endpoint())
+        .build();
+    let result = rc.hash_password(PASSWORD);
+    let request = server.request();
+
+    assert!(request.starts_with("GET /api/auth/hash_password/test12 "));
+    assert_eq!(
+        "kI3GCqW5JLMJa4iX1lo7X4D6XbYqlLgxIs30+P6tENUV2POR",
+        result.unwrap()
+    );
+}
+
+#[test]
+fn test_unit_hash_password_error_responses_do_not_include_the_password() {
+    let server = serve_once(
+        "500 Internal Server Error",
+        &[("content-type", "application/json")],
+        r#"{"error":"internal_server_error"}"#,
+    );
+
+    let rc = ClientBuilder::new()
+        .with_endpoint(server.endpoint())
+        .build();
+    let err = rc.hash_password(PASSWORD).unwrap_err();
+    server.request();
+
+    match &err {
+        Error::ServerErrorResponse { url, .. } => {
+            assert_eq!(
+                "/api/auth/hash_password/redacted",
+                url.as_ref().unwrap().path()
+            );
+        }
+        other => panic!("unexpected error: {:?}", other),
+    }
+    assert!(!format!("{:?}", err).contains(PASSWORD));
+}
+
+#[test]
+fn test_unit_hash_password_request_errors_do_not_include_the_password() {
+    let rc = ClientBuilder::new()
+        .with_endpoint("http://127.0.0.1:1/api")
+        .build();
+    let err = rc.hash_password(PASSWORD).unwrap_err();
+
+    match &err {
+        Error::RequestError { error, .. } => {
+            assert!(!error.to_string().contains(PASSWORD));
+            assert!(!format!("{:?}", error.source()).contains(PASSWORD));
+        }
+        other => panic!("unexpected error: {:?}", other),
+    }
+    assert!(!format!("{:?}", err).contains(PASSWORD));
+}
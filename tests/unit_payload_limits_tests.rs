@@ -0,0 +1,157 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod test_helpers;
+
+use std::sync::{Arc, Mutex};
+
+use rabbitmq_http_client::blocking_api::ClientBuilder;
+use rabbitmq_http_client::error::Error;
+use rabbitmq_http_client::payload_limits::{
+    PayloadSizeCheck, PayloadSizeGuard, PayloadSizeWarning, DEFAULT_BROKER_MAX_MESSAGE_SIZE,
+};
+use rabbitmq_http_client::requests::MessageProperties;
+
+const UNREACHABLE_ENDPOINT: &str = "http://127.0.0.1:1/api";
+
+#[test]
+fn test_unit_payload_size_guard_check() {
+    let guard = PayloadSizeGuard::new(1000);
+    assert_eq!(900, guard.warning_threshold());
+
+    assert_eq!(PayloadSizeCheck::WithinLimit, guard.check(900));
+    assert_eq!(
+        PayloadSizeCheck::NearLimit(PayloadSizeWarning {
+            size: 901,
+            limit: 1000
+        }),
+        guard.check(901)
+    );
+    assert_eq!(
+        PayloadSizeCheck::NearLimit(PayloadSizeWarning {
+            size: 1000,
+            limit: 1000
+        }),
+        guard.check(1000)
+    );
+    assert_eq!(
+        PayloadSizeCheck::TooLarge {
+            size: 1001,
+            limit: 1000
+        },
+        guard.check(1001)
+    );
+
+    let guard = guard.with_warning_ratio(0.5);
+    assert_eq!(500, guard.warning_threshold());
+    assert_eq!(
+        DEFAULT_BROKER_MAX_MESSAGE_SIZE,
+        PayloadSizeGuard::default().max_size()
+    );
+}
+
+#[test]
+fn test_unit_publish_rejects_oversized_payloads_before_sending() {
+    let rc = ClientBuilder::new()
+        .with_endpoint(UNREACHABLE_ENDPOINT)
+        .with_payload_size_guard(PayloadSizeGuard::new(16))
+        .build();
+
+    let payload = "a".repeat(17);
+    let result = rc.publish_message(
+        "/",
+        "amq.direct",
+        "rk",
+        &payload,
+        MessageProperties::default(),
+    );
+    match result {
+        Err(Error::PayloadTooLarge { size, limit }) => {
+            assert_eq!(17, size);
+            assert_eq!(16, limit);
+        }
+        other => panic!("expected PayloadTooLarge, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_unit_publish_warns_about_payloads_near_the_limit() {
+    let warnings = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&warnings);
+    let guard =
+        PayloadSizeGuard::new(100).with_warning_callback(move |w| sink.lock().unwrap().push(w));
+    let rc = ClientBuilder::new()
+        .with_endpoint(UNREACHABLE_ENDPOINT)
+        .with_payload_size_guard(guard)
+        .build();
+
+    // the payload passes the check, so the request is attempted and fails
+    let payload = "a".repeat(95);
+    let result = rc.publish_message(
+        "/",
+        "amq.direct",
+        "rk",
+        &payload,
+        MessageProperties::default(),
+    );
+    assert!(matches!(result, Err(Error::RequestError { .. })));
+
+    let small = rc.publish_message("/", "amq.direct", "rk", "a", MessageProperties::default());
+    assert!(matches!(small, Err(Error::RequestError { .. })));
+
+    assert_eq!(
+        vec![PayloadSizeWarning {
+            size: 95,
+            limit: 100
+        }],
+        *warnings.lock().unwrap()
+    );
+}
+
+#[test]
+fn test_unit_publish_binary_message_checks_the_decoded_size() {
+    let rc = ClientBuilder::new()
+        .with_endpoint(UNREACHABLE_ENDPOINT)
+        .with_payload_size_guard(PayloadSizeGuard::new(16))
+        .build();
+
+    let payload = vec![0xffu8; 17];
+    let result = rc.publish_binary_message(
+        "/",
+        "amq.direct",
+        "rk",
+        &payload,
+        MessageProperties::default(),
+    );
+    assert!(
+        matches!(
+            result,
+            Err(Error::PayloadTooLarge {
+                size: 17,
+                limit: 16
+            })
+        ),
+        "publish_binary_message returned {:?}",
+        result
+    );
+
+    let payload = vec![0xffu8; 16];
+    let result = rc.publish_binary_message(
+        "/",
+        "amq.direct",
+        "rk",
+        &payload,
+        MessageProperties::default(),
+    );
+    assert!(matches!(result, Err(Error::RequestError { .. })));
+}
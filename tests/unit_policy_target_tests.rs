@@ -1,4 +1,4 @@
-use rabbitmq_http_client::commons::PolicyTarget;
+use rabbitmq_http_client::commons::{PolicyTarget, QueueType};
 
 // Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
 //
@@ -50,3 +50,26 @@ fn test_unit_policy_target_does_apply_to() {
     assert!(!PolicyTarget::Exchanges.does_apply_to(PolicyTarget::ClassicQueues));
     assert!(!PolicyTarget::Exchanges.does_apply_to(PolicyTarget::Streams));
 }
+
+#[test]
+fn test_unit_policy_target_does_apply_to_queue_type() {
+    assert!(PolicyTarget::All.does_apply_to_queue_type(QueueType::Quorum));
+    assert!(PolicyTarget::Queues.does_apply_to_queue_type(QueueType::Classic));
+    assert!(PolicyTarget::Queues.does_apply_to_queue_type(QueueType::Stream));
+    assert!(PolicyTarget::Queues.does_apply_to_queue_type(QueueType::Delayed));
+
+    assert!(PolicyTarget::QuorumQueues.does_apply_to_queue_type(QueueType::Quorum));
+    assert!(!PolicyTarget::QuorumQueues.does_apply_to_queue_type(QueueType::Classic));
+    assert!(!PolicyTarget::ClassicQueues.does_apply_to_queue_type(QueueType::Stream));
+    assert!(!PolicyTarget::ClassicQueues.does_apply_to_queue_type(QueueType::Delayed));
+    assert!(PolicyTarget::Streams.does_apply_to_queue_type(QueueType::Stream));
+    assert!(!PolicyTarget::Exchanges.does_apply_to_queue_type(QueueType::Quorum));
+}
+
+#[test]
+fn test_unit_policy_target_does_apply_to_exchanges() {
+    assert!(PolicyTarget::All.does_apply_to_exchanges());
+    assert!(PolicyTarget::Exchanges.does_apply_to_exchanges());
+    assert!(!PolicyTarget::Queues.does_apply_to_exchanges());
+    assert!(!PolicyTarget::Streams.does_apply_to_exchanges());
+}
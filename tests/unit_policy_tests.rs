@@ -14,6 +14,7 @@
 mod test_helpers;
 
 use rabbitmq_http_client::commons::PolicyTarget;
+use rabbitmq_http_client::requests::PolicyParams;
 use rabbitmq_http_client::responses::{Policy, PolicyDefinition, PolicyDefinitionOps};
 use serde_json::{json, Map};
 
@@ -350,3 +351,72 @@ fn test_unit_policy_does_match_case3() {
         PolicyTarget::Streams
     ));
 }
+
+#[test]
+fn test_unit_policy_to_policy_params_conversion() {
+    let mut m = Map::new();
+    m.insert("max-length".to_owned(), json!(1000));
+    m.insert("overflow".to_owned(), json!("reject-publish"));
+    let p = Policy {
+        name: "policy.1".to_owned(),
+        vhost: "events".to_owned(),
+        pattern: "^events".to_owned(),
+        apply_to: PolicyTarget::QuorumQueues,
+        priority: 11,
+        definition: PolicyDefinition(Some(m)),
+    };
+
+    let mut params = PolicyParams::from(&p);
+    assert_eq!("policy.1", params.name);
+    assert_eq!("events", params.vhost);
+    assert_eq!("^events", params.pattern);
+    assert_eq!(PolicyTarget::QuorumQueues, params.apply_to);
+    assert_eq!(11, params.priority);
+    assert_eq!(json!(1000), params.definition["max-length"]);
+
+    // the typical workflow: fetch a policy, tweak it, re-declare it
+    params.priority = 20;
+    params
+        .definition
+        .insert("max-length".to_owned(), json!(5000));
+    assert_eq!(20, params.priority);
+    assert_eq!(json!(5000), params.definition["max-length"]);
+    assert_eq!(json!("reject-publish"), params.definition["overflow"]);
+    // the original policy is not affected
+    assert_eq!(11, p.priority);
+    assert_eq!(
+        Some(&json!(1000)),
+        p.definition.0.as_ref().unwrap().get("max-length")
+    );
+}
+
+#[test]
+fn test_unit_policy_params_defaults_and_setters() {
+    let params = PolicyParams::default();
+    assert_eq!("/", params.vhost);
+    assert_eq!(PolicyTarget::Queues, params.apply_to);
+    assert_eq!(0, params.priority);
+    assert!(params.definition.is_empty());
+
+    let mut definition = Map::new();
+    definition.insert("max-length".to_owned(), json!(1000));
+    let params = PolicyParams::default()
+        .with_vhost("events")
+        .with_name("limit")
+        .with_pattern("^events\\.")
+        .with_apply_to(PolicyTarget::QuorumQueues)
+        .with_priority(5)
+        .with_definition(definition);
+    assert_eq!("events", params.vhost);
+    assert_eq!("limit", params.name);
+    assert_eq!("^events\\.", params.pattern);
+    assert_eq!(PolicyTarget::QuorumQueues, params.apply_to);
+    assert_eq!(5, params.priority);
+    assert_eq!(json!(1000), params.definition["max-length"]);
+
+    let updated = PolicyParams {
+        name: "another",
+        ..params
+    };
+    assert_eq!("events", updated.vhost);
+}
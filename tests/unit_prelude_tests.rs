@@ -0,0 +1,43 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod test_helpers;
+
+use rabbitmq_http_client::prelude::*;
+
+#[test]
+fn test_unit_prelude_reexports_clients() {
+    let _ = BlockingClient::new("http://localhost:15672/api", "guest", "guest");
+    let _ = AsyncClient::new("http://localhost:15672/api", "guest", "guest");
+    let _ = BlockingClientBuilder::new().build();
+    let _ = AsyncClientBuilder::new().build();
+}
+
+#[test]
+fn test_unit_prelude_reexports_common_types() {
+    let params = QueueParams::new_quorum_queue("qq.1", None);
+    assert_eq!(QueueType::Quorum, params.queue_type);
+    assert_eq!(
+        PolicyTarget::QuorumQueues,
+        PolicyTarget::from(params.queue_type)
+    );
+
+    let q: QueueSummary =
+        serde_json::from_str(r#"{"name": "qq.1", "vhost": "/", "type": "quorum", "messages": 0}"#)
+            .unwrap();
+    assert_eq!(QueueType::Quorum, q.queue_type());
+
+    // names shared by request and response types are available via their modules
+    let _: Option<requests::Permissions> = None;
+    let _: Option<responses::Permissions> = None;
+}
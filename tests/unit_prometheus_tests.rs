@@ -0,0 +1,234 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg(feature = "prometheus")]
+mod test_helpers;
+
+use rabbitmq_http_client::error::Error;
+use rabbitmq_http_client::prometheus::{
+    BlockingPrometheusClient, MetricType, Metrics, MetricsEndpoint,
+};
+use test_helpers::serve_once;
+
+const METRICS_TEXT: &str = r#"
+# TYPE rabbitmq_queue_messages gauge
+# HELP rabbitmq_queue_messages Sum of ready and unacknowledged messages
+rabbitmq_queue_messages{vhost="/",queue="qq.1"} 10
+rabbitmq_queue_messages{vhost="/",queue="qq.2"} 0
+rabbitmq_queue_messages{vhost="events",queue="say \"hi\""} 2.5
+# TYPE rabbitmq_connections_opened_total counter
+# HELP rabbitmq_connections_opened_total Total number of connections opened
+rabbitmq_connections_opened_total 120 1747411200000
+# TYPE rabbitmq_raft_entry_commit_latency_seconds summary
+rabbitmq_raft_entry_commit_latency_seconds{quantile="0.5"} 0.004
+rabbitmq_raft_entry_commit_latency_seconds_sum 1.5
+rabbitmq_raft_entry_commit_latency_seconds_count 300
+# a comment that is not HELP or TYPE
+rabbitmq_build_info{rabbitmq_version="4.1.0"} 1
+rabbitmq_disk_space_available_limit_bytes +Inf
+"#;
+
+#[test]
+fn test_unit_prometheus_parse_metric_families() {
+    let metrics = Metrics::parse(METRICS_TEXT).unwrap();
+    assert_eq!(5, metrics.len());
+
+    let queue_messages = metrics.family("rabbitmq_queue_messages").unwrap();
+    assert_eq!(MetricType::Gauge, queue_messages.metric_type);
+    assert_eq!(
+        Some("Sum of ready and unacknowledged messages"),
+        queue_messages.help.as_deref()
+    );
+    assert_eq!(3, queue_messages.samples.len());
+    assert_eq!(12.5, queue_messages.sum());
+
+    assert_eq!(
+        Some(120.0),
+        metrics.value("rabbitmq_connections_opened_total", &[])
+    );
+    assert_eq!(
+        MetricType::Counter,
+        metrics
+            .family("rabbitmq_connections_opened_total")
+            .unwrap()
+            .metric_type
+    );
+}
+
+#[test]
+fn test_unit_prometheus_parse_summary_samples_belong_to_their_family() {
+    let metrics = Metrics::parse(METRICS_TEXT).unwrap();
+    let latency = metrics
+        .family("rabbitmq_raft_entry_commit_latency_seconds")
+        .unwrap();
+
+    assert_eq!(MetricType::Summary, latency.metric_type);
+    assert_eq!(3, latency.samples.len());
+    assert_eq!(
+        "rabbitmq_raft_entry_commit_latency_seconds_count",
+        latency.samples[2].name
+    );
+}
+
+#[test]
+fn test_unit_prometheus_parse_untyped_and_special_values() {
+    let metrics = Metrics::parse(METRICS_TEXT).unwrap();
+
+    let build_info = metrics.family("rabbitmq_build_info").unwrap();
+    assert_eq!(MetricType::Untyped, build_info.metric_type);
+    assert_eq!(
+        Some(1.0),
+        build_info.value(&[("rabbitmq_version", "4.1.0")])
+    );
+
+    let limit = metrics
+        .value("rabbitmq_disk_space_available_limit_bytes", &[])
+        .unwrap();
+    assert!(limit.is_infinite());
+}
+
+#[test]
+fn test_unit_prometheus_queue_values() {
+    let metrics = Metrics::parse(METRICS_TEXT).unwrap();
+    let values = metrics.queue_values("rabbitmq_queue_messages");
+
+    assert_eq!(3, values.len());
+    assert_eq!("qq.1", values[0].queue);
+    assert_eq!(10.0, values[0].value);
+    assert_eq!("events", values[2].vhost);
+    assert_eq!("say \"hi\"", values[2].queue);
+
+    assert!(metrics.queue_values("rabbitmq_build_info").is_empty());
+}
+
+#[test]
+fn test_unit_prometheus_parse_selected_families() {
+    let metrics = Metrics::parse_selected(
+        METRICS_TEXT,
+        &[
+            "rabbitmq_queue_messages",
+            "rabbitmq_raft_entry_commit_latency_seconds",
+        ],
+    )
+    .unwrap();
+
+    assert_eq!(2, metrics.len());
+    assert!(metrics.family("rabbitmq_build_info").is_none());
+    assert_eq!(
+        3,
+        metrics
+            .family("rabbitmq_raft_entry_commit_latency_seconds")
+            .unwrap()
+            .samples
+            .len()
+    );
+}
+
+#[test]
+fn test_unit_prometheus_parse_reports_invalid_lines() {
+    let err = Metrics::parse("rabbitmq_queue_messages 1\nrabbitmq_queue_messages{vhost=\"/} 1\n")
+        .unwrap_err();
+    assert!(err.to_string().contains("line 2"), "{}", err);
+
+    assert!(Metrics::parse("rabbitmq_queue_messages abc").is_err());
+    assert!(Metrics::parse("rabbitmq_queue_messages").is_err());
+}
+
+#[test]
+fn test_unit_prometheus_metrics_endpoint_paths() {
+    assert_eq!("metrics", MetricsEndpoint::Aggregated.path());
+    assert_eq!("metrics/per-object", MetricsEndpoint::PerObject.path());
+
+    let detailed = MetricsEndpoint::Detailed {
+        families: vec!["queue_coarse_metrics".to_owned()],
+        vhosts: vec!["events".to_owned()],
+    };
+    assert_eq!("metrics/detailed", detailed.path());
+    assert_eq!(
+        vec![("family", "queue_coarse_metrics"), ("vhost", "events")],
+        detailed.query()
+    );
+}
+
+#[test]
+fn test_unit_prometheus_client_surfaces_request_errors() {
+    let pc = BlockingPrometheusClient::new("http://127.0.0.1:1");
+    assert!(pc.scrape(&MetricsEndpoint::Aggregated).is_err());
+}
+
+#[test]
+fn test_unit_prometheus_client_maps_404_to_not_found() {
+    let server = serve_once("404 Not Found", &[("content-type", "text/plain")], "");
+
+    let pc = BlockingPrometheusClient::new(&server.endpoint());
+    let result = pc.scrape(&MetricsEndpoint::PerObject);
+    server.request();
+
+    assert!(matches!(result, Err(Error::NotFound)));
+}
+
+#[test]
+fn test_unit_prometheus_client_includes_error_response_bodies() {
+    let server = serve_once(
+        "503 Service Unavailable",
+        &[("content-type", "text/plain")],
+        "the node is booting",
+    );
+
+    let pc = BlockingPrometheusClient::new(&server.endpoint());
+    let result = pc.scrape(&MetricsEndpoint::Aggregated);
+    server.request();
+
+    match result {
+        Err(Error::ServerErrorResponse {
+            status_code, body, ..
+        }) => {
+            assert_eq!(503, status_code.as_u16());
+            assert_eq!(Some("the node is booting"), body.as_deref());
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_unit_blocking_prometheus_client_used_in_a_multi_threaded_runtime() {
+    let pc = BlockingPrometheusClient::new("http://127.0.0.1:1");
+
+    let result = pc.scrape(&MetricsEndpoint::Aggregated);
+    assert!(matches!(result, Err(Error::RequestError { .. })));
+    // the owned runtime must not panic when dropped in an async context
+    drop(pc);
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_unit_blocking_prometheus_client_used_in_a_single_threaded_runtime() {
+    let pc = BlockingPrometheusClient::new("http://127.0.0.1:1");
+
+    let result = pc.scrape(&MetricsEndpoint::Aggregated);
+    assert!(matches!(result, Err(Error::BlockingNotSupported { .. })));
+    drop(pc);
+}
+
+#[test]
+fn test_unit_blocking_prometheus_client_with_a_multi_threaded_runtime_handle() {
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(1)
+        .enable_all()
+        .build()
+        .unwrap();
+    let pc = BlockingPrometheusClient::new("http://127.0.0.1:1")
+        .with_runtime_handle(runtime.handle().clone());
+
+    let result = pc.scrape(&MetricsEndpoint::Aggregated);
+    assert!(matches!(result, Err(Error::RequestError { .. })));
+}
@@ -0,0 +1,145 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod test_helpers;
+
+use rabbitmq_http_client::blocking_api::ClientBuilder;
+use rabbitmq_http_client::error::Error;
+use rabbitmq_http_client::protection::{matches_pattern, ProtectedEntities};
+
+// nothing listens on this port, so requests that are not refused
+// by the client fail with a request error
+const UNREACHABLE_ENDPOINT: &str = "http://127.0.0.1:1/api";
+
+#[test]
+fn test_unit_matches_pattern() {
+    assert!(matches_pattern("/", "/"));
+    assert!(!matches_pattern("/", "/prod"));
+
+    assert!(matches_pattern("prod-*", "prod-"));
+    assert!(matches_pattern("prod-*", "prod-eu-1"));
+    assert!(!matches_pattern("prod-*", "staging-prod-1"));
+
+    assert!(matches_pattern("*", ""));
+    assert!(matches_pattern("*.dlq", "orders.dlq"));
+    assert!(!matches_pattern("*.dlq", "orders.dlq.archive"));
+    assert!(matches_pattern("*-*-1", "prod-eu-1"));
+    assert!(!matches_pattern("a*a", "a"));
+}
+
+#[test]
+fn test_unit_protected_entities() {
+    let pe = ProtectedEntities::new()
+        .with_virtual_host("/")
+        .with_virtual_host("prod-*")
+        .with_name("audit.*");
+
+    assert!(!pe.is_empty());
+    assert!(ProtectedEntities::new().is_empty());
+
+    assert!(pe.is_virtual_host_protected("/"));
+    assert!(pe.is_virtual_host_protected("prod-eu"));
+    assert!(!pe.is_virtual_host_protected("staging"));
+
+    assert!(pe.is_object_protected("prod-eu", "orders"));
+    assert!(pe.is_object_protected("staging", "audit.events"));
+    assert!(!pe.is_object_protected("staging", "orders"));
+}
+
+#[test]
+fn test_unit_destructive_operations_on_protected_entities_are_refused() {
+    let rc = ClientBuilder::new()
+        .with_endpoint(UNREACHABLE_ENDPOINT)
+        .with_protected_entities(
+            ProtectedEntities::new()
+                .with_virtual_host("prod-*")
+                .with_name("admin"),
+        )
+        .build();
+
+    let err = rc.delete_vhost("prod-eu", false).unwrap_err();
+    assert!(matches!(err, Error::ProtectedEntity { ref name, .. } if name == "prod-eu"));
+
+    let err = rc.delete_queue("prod-eu", "orders", true).unwrap_err();
+    assert!(matches!(
+        err,
+        Error::ProtectedEntity { ref kind, vhost: Some(ref vh), .. } if kind == "queue" && vh == "prod-eu"
+    ));
+
+    assert!(matches!(
+        rc.purge_queue("prod-eu", "orders"),
+        Err(Error::ProtectedEntity { .. })
+    ));
+    assert!(matches!(
+        rc.delete_exchange("prod-us", "events", true),
+        Err(Error::ProtectedEntity { .. })
+    ));
+    assert!(matches!(
+        rc.delete_user("admin", true),
+        Err(Error::ProtectedEntity { .. })
+    ));
+    assert!(matches!(
+        rc.delete_users(vec!["temp", "admin"]),
+        Err(Error::ProtectedEntity { .. })
+    ));
+    assert!(matches!(
+        rc.close_user_connections("admin", None),
+        Err(Error::ProtectedEntity { .. })
+    ));
+
+    // not protected, so the request is issued (and fails)
+    assert!(matches!(
+        rc.delete_queue("staging", "orders", true),
+        Err(Error::RequestError { .. })
+    ));
+}
+
+#[test]
+fn test_unit_protection_can_be_overridden() {
+    let rc = ClientBuilder::new()
+        .with_endpoint(UNREACHABLE_ENDPOINT)
+        .with_protected_entities(ProtectedEntities::new().with_virtual_host("/"))
+        .build();
+
+    assert!(matches!(
+        rc.delete_vhost("/", true),
+        Err(Error::ProtectedEntity { .. })
+    ));
+
+    let overridden = rc.with_protection_overridden();
+    assert!(overridden.is_protection_overridden());
+    assert!(matches!(
+        overridden.delete_vhost("/", true),
+        Err(Error::RequestError { .. })
+    ));
+
+    assert!(!rc.is_protection_overridden());
+    assert!(matches!(
+        rc.delete_vhost("/", true),
+        Err(Error::ProtectedEntity { .. })
+    ));
+}
+
+#[test]
+fn test_unit_protected_entity_error_message() {
+    let rc = ClientBuilder::new()
+        .with_endpoint(UNREACHABLE_ENDPOINT)
+        .with_protected_entities(ProtectedEntities::new().with_virtual_host("/"))
+        .build();
+
+    let err = rc.delete_vhost("/", true).unwrap_err();
+    assert_eq!(
+        "virtual host '/' is protected from destructive operations",
+        err.to_string()
+    );
+}
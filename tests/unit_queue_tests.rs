@@ -14,10 +14,13 @@
 mod test_helpers;
 
 use rabbitmq_http_client::commons::{PolicyTarget, QueueType};
+use rabbitmq_http_client::requests::QueueParams;
 use rabbitmq_http_client::responses::{
-    NamedPolicyTargetObject, Policy, PolicyDefinition, QueueInfo, QueueOps,
+    NamedPolicyTargetObject, Policy, PolicyDefinition, QueueInfo, QueueInfoFilters, QueueOps,
+    QueueState, QueueSummary, TargetRamCount, TopQueues,
 };
 use serde_json::{json, Map};
+use std::time::Duration;
 
 #[test]
 fn test_unit_queue_type_from_str() {
@@ -54,6 +57,28 @@ fn test_unit_queue_type_from_str() {
     );
 }
 
+#[test]
+fn test_unit_queue_type_from_string() {
+    assert_eq!(QueueType::Delayed, QueueType::from("delayed".to_owned()));
+    assert_eq!(QueueType::Quorum, QueueType::from("Quorum".to_owned()));
+}
+
+#[test]
+fn test_unit_unsupported_queue_type_is_preserved() {
+    let q = queue_info("jms.1", "x-jms-queue", json!({}));
+    assert_eq!(
+        QueueType::Unsupported("x-jms-queue".to_owned()),
+        q.queue_type()
+    );
+
+    let params = QueueParams::from(&q);
+    let val = serde_json::to_value(&params).unwrap();
+    assert_eq!(json!("x-jms-queue"), val["arguments"]["x-queue-type"]);
+
+    let qt: QueueType = serde_json::from_value(json!("x-jms-queue")).unwrap();
+    assert_eq!(QueueType::Unsupported("x-jms-queue".to_owned()), qt);
+}
+
 #[test]
 fn test_unit_policy_target_type_case1() {
     let input = r#"{
@@ -497,3 +522,397 @@ fn test_unit_queue_info_policy_matching_case4() {
     assert!(!cq.does_match(&p));
     assert!(!p.does_match_object(&cq));
 }
+
+#[test]
+fn test_unit_queue_info_with_consumer_details() {
+    let input = r#"{
+        "arguments": {
+          "x-queue-type": "quorum"
+        },
+        "auto_delete": false,
+        "consumers": 1,
+        "consumer_details": [
+          {
+            "arguments": {},
+            "ack_required": true,
+            "active": true,
+            "activity_status": "up",
+            "channel_details": {
+              "connection_name": "127.0.0.1:61012 -> 127.0.0.1:5672",
+              "name": "127.0.0.1:61012 -> 127.0.0.1:5672 (1)",
+              "node": "rabbit@sunnyside",
+              "number": 1,
+              "peer_host": "127.0.0.1",
+              "peer_port": 61012,
+              "user": "guest"
+            },
+            "consumer_tag": "ctag1.8a2d",
+            "consumer_timeout": 1800000,
+            "exclusive": false,
+            "prefetch_count": 50,
+            "queue": {
+              "name": "qq.1",
+              "vhost": "/"
+            }
+          }
+        ],
+        "durable": true,
+        "exclusive": false,
+        "leader": "rabbit@sunnyside",
+        "members": ["rabbit@sunnyside"],
+        "online": ["rabbit@sunnyside"],
+        "memory": 47316,
+        "messages": 0,
+        "name": "qq.1",
+        "node": "rabbit@sunnyside",
+        "state": "running",
+        "type": "quorum",
+        "vhost": "/"
+    }"#;
+    let qq = serde_json::from_str::<QueueInfo>(input).unwrap();
+
+    let consumers = qq.consumer_details.unwrap();
+    assert_eq!(1, consumers.len());
+    let consumer = consumers.first().unwrap();
+    assert_eq!("ctag1.8a2d", consumer.consumer_tag);
+    assert!(consumer.manual_ack);
+    assert_eq!(50, consumer.prefetch_count);
+    assert_eq!("qq.1", consumer.queue.name);
+    assert_eq!(1, consumer.channel_details.as_ref().unwrap().id);
+}
+
+#[test]
+fn test_unit_queue_info_without_consumer_details() {
+    let input = r#"{
+        "arguments": {},
+        "auto_delete": false,
+        "durable": true,
+        "exclusive": false,
+        "name": "cq.1",
+        "type": "classic",
+        "vhost": "/"
+    }"#;
+    let cq = serde_json::from_str::<QueueInfo>(input).unwrap();
+
+    assert!(cq.consumer_details.is_none());
+}
+
+fn queue_info(name: &str, queue_type: &str, extra: serde_json::Value) -> QueueInfo {
+    let mut val = json!({
+        "arguments": {},
+        "auto_delete": false,
+        "durable": true,
+        "exclusive": false,
+        "name": name,
+        "type": queue_type,
+        "vhost": "/"
+    });
+    for (k, v) in extra.as_object().unwrap() {
+        val[k] = v.clone();
+    }
+    serde_json::from_value(val).unwrap()
+}
+
+fn names(qs: &[QueueInfo]) -> Vec<&str> {
+    qs.iter().map(|q| q.name.as_str()).collect()
+}
+
+#[test]
+fn test_unit_queue_info_state() {
+    let q = queue_info("qq.1", "quorum", json!({"state": "minority"}));
+    assert_eq!(QueueState::Minority, q.state);
+
+    let q = queue_info("cq.1", "classic", json!({"state": "running"}));
+    assert_eq!(QueueState::Running, q.state);
+
+    let q = queue_info("cq.2", "classic", json!({}));
+    assert_eq!(QueueState::Unknown, q.state);
+
+    assert_eq!(QueueState::Idle, QueueState::from("idle"));
+    assert_eq!(
+        QueueState::Other("reconciling".to_owned()),
+        QueueState::from("reconciling")
+    );
+    assert_eq!("flow", String::from(QueueState::Flow));
+}
+
+#[test]
+fn test_unit_queue_info_filters() {
+    let queues = vec![
+        queue_info(
+            "cq.idle",
+            "classic",
+            json!({"idle_since": "2020-01-01T10:00:00.000+00:00", "policy": "cq.ttl"}),
+        ),
+        queue_info(
+            "cq.legacy.idle",
+            "classic",
+            json!({"idle_since": "2020-01-01 10:00:00", "messages": 10}),
+        ),
+        queue_info("cq.busy", "classic", json!({"consumers": 2, "messages": 5})),
+        queue_info("qq.1", "quorum", json!({"policy": "cq.ttl"})),
+    ];
+
+    assert_eq!(
+        vec!["cq.idle", "cq.legacy.idle", "qq.1"],
+        names(&queues.clone().without_consumers())
+    );
+    assert_eq!(vec!["cq.idle", "qq.1"], names(&queues.clone().empty()));
+    assert_eq!(
+        vec!["cq.idle", "qq.1"],
+        names(&queues.clone().matching_policy("cq.ttl"))
+    );
+    assert_eq!(
+        vec!["qq.1"],
+        names(&queues.clone().of_type(QueueType::Quorum))
+    );
+    assert_eq!(
+        vec!["cq.idle", "cq.legacy.idle"],
+        names(&queues.clone().idle_longer_than(Duration::from_secs(3600)))
+    );
+    assert_eq!(
+        vec!["cq.idle"],
+        names(
+            &queues
+                .of_type(QueueType::Classic)
+                .without_consumers()
+                .empty()
+        )
+    );
+}
+
+#[test]
+fn test_unit_queue_info_idle_since_timestamp() {
+    let q1 = queue_info(
+        "cq.1",
+        "classic",
+        json!({"idle_since": "2025-05-16T10:20:30.000+00:00"}),
+    );
+    assert_eq!(
+        1747390830,
+        q1.idle_since_timestamp().unwrap().unix_timestamp()
+    );
+
+    let q2 = queue_info(
+        "cq.2",
+        "classic",
+        json!({"idle_since": "2025-05-16 10:20:30"}),
+    );
+    assert_eq!(
+        1747390830,
+        q2.idle_since_timestamp().unwrap().unix_timestamp()
+    );
+
+    let q3 = queue_info("cq.3", "classic", json!({}));
+    assert!(q3.idle_since_timestamp().is_none());
+    assert!(!q3.is_idle_longer_than(Duration::from_secs(1)));
+}
+
+#[test]
+fn test_unit_top_queues() {
+    let queues = vec![
+        queue_info("q.a", "classic", json!({"messages": 10, "memory": 300})),
+        queue_info("q.b", "classic", json!({"messages": 30, "memory": 100})),
+        queue_info("q.c", "quorum", json!({"messages": 20, "memory": 200})),
+    ];
+
+    assert_eq!(
+        vec!["q.b", "q.c"],
+        names(&queues.clone().top_by_message_count(2))
+    );
+    assert_eq!(vec!["q.a"], names(&queues.clone().top_by_memory(1)));
+    assert_eq!(
+        vec!["q.a", "q.c", "q.b"],
+        names(&queues.clone().top_by_memory(10))
+    );
+    assert!(queues.top_by_message_count(0).is_empty());
+}
+
+#[test]
+fn test_unit_queue_info_to_queue_params_conversion() {
+    let qq = queue_info(
+        "qq.1",
+        "quorum",
+        json!({
+            "arguments": {"x-queue-type": "quorum", "x-delivery-limit": 10},
+            "consumers": 3,
+            "messages": 100
+        }),
+    );
+    let params = QueueParams::from(&qq);
+
+    assert_eq!("qq.1", params.name);
+    assert_eq!(QueueType::Quorum, params.queue_type);
+    assert!(params.durable);
+    assert!(!params.auto_delete);
+    assert!(!params.exclusive);
+    let args = params.arguments.unwrap();
+    assert_eq!(2, args.len());
+    assert_eq!(json!("quorum"), args["x-queue-type"]);
+    assert_eq!(json!(10), args["x-delivery-limit"]);
+}
+
+#[test]
+fn test_unit_queue_info_to_queue_params_conversion_uses_reported_type() {
+    // the argument map does not carry x-queue-type, the reported type must be used
+    let cq = queue_info(
+        "cq.1",
+        "classic",
+        json!({"auto_delete": true, "arguments": {"x-max-length": 1000}}),
+    );
+    let params = QueueParams::from(&cq);
+
+    assert_eq!(QueueType::Classic, params.queue_type);
+    assert!(params.auto_delete);
+    let args = params.arguments.unwrap();
+    assert_eq!(json!("classic"), args["x-queue-type"]);
+    assert_eq!(json!(1000), args["x-max-length"]);
+}
+
+#[test]
+fn test_unit_deserialize_queue_summary() {
+    let json = r#"[
+        {"name": "qq.1", "vhost": "/", "type": "quorum", "messages": 12, "messages_ready": 10, "messages_unacknowledged": 2},
+        {"name": "cq.1", "vhost": "events", "type": "classic"}
+    ]"#;
+    let summaries: Vec<QueueSummary> = serde_json::from_str(json).unwrap();
+
+    assert_eq!(2, summaries.len());
+    assert_eq!(QueueType::Quorum, summaries[0].queue_type());
+    assert_eq!(12, summaries[0].message_count);
+    assert_eq!(10, summaries[0].ready_message_count);
+    assert_eq!(2, summaries[0].unacknowledged_message_count);
+    assert!(!summaries[0].is_empty());
+
+    // metrics may not have been emitted yet
+    assert_eq!("events", summaries[1].vhost);
+    assert_eq!(
+        PolicyTarget::ClassicQueues,
+        summaries[1].policy_target_type()
+    );
+    assert!(summaries[1].is_empty());
+}
+
+#[test]
+fn test_unit_queue_info_to_queue_summary_conversion() {
+    let q = queue_info(
+        "qq.1",
+        "quorum",
+        json!({"messages": 100, "messages_unacknowledged": 40, "consumers": 3}),
+    );
+    let summary = QueueSummary::from(q);
+
+    assert_eq!("qq.1", summary.name);
+    assert_eq!("/", summary.vhost);
+    assert_eq!(100, summary.message_count);
+    assert_eq!(60, summary.ready_message_count);
+    assert_eq!(40, summary.unacknowledged_message_count);
+}
+
+#[test]
+fn test_unit_queue_info_raft_status_fields() {
+    let q = queue_info(
+        "qq.1",
+        "quorum",
+        json!({
+            "open_files": {"rabbit@node1": 3, "rabbit@node2": 2},
+            "term": 4,
+            "commit_index": 1200,
+            "last_applied": 1150,
+            "log_size": 4096
+        }),
+    );
+    assert_eq!(Some(4), q.raft_term);
+    assert_eq!(Some(1200), q.commit_index);
+    assert_eq!(Some(1150), q.last_applied_index);
+    assert_eq!(Some(4096), q.log_size);
+    assert_eq!(Some(50), q.applied_index_lag());
+    assert_eq!(Some(5), q.total_open_files());
+
+    let cq = queue_info("cq.1", "classic", json!({}));
+    assert!(cq.open_files.is_none());
+    assert!(cq.applied_index_lag().is_none());
+    assert!(cq.total_open_files().is_none());
+}
+
+#[test]
+fn test_unit_queue_info_backing_queue_status() {
+    let q = queue_info(
+        "cq.1",
+        "classic",
+        json!({
+            "backing_queue_status": {
+                "avg_ack_egress_rate": 0.5,
+                "avg_ack_ingress_rate": 0.5,
+                "avg_egress_rate": 12.25,
+                "avg_ingress_rate": 14.0,
+                "delta": ["delta", "todo", "todo", "todo", "todo"],
+                "len": 120,
+                "mode": "default",
+                "next_deliver_seq_id": 380,
+                "next_seq_id": 500,
+                "num_pending_acks": 3,
+                "num_unconfirmed": 0,
+                "target_ram_count": "infinity",
+                "version": 2
+            }
+        }),
+    );
+    let bqs = q.backing_queue_status.unwrap();
+    assert_eq!(Some("default".to_owned()), bqs.mode);
+    assert_eq!(Some(2), bqs.version);
+    assert_eq!(Some(TargetRamCount::Infinity), bqs.target_ram_count);
+    assert_eq!(Some(120), bqs.length);
+    assert_eq!(Some(500), bqs.next_seq_id);
+    assert_eq!(14.0, bqs.avg_ingress_rate);
+    assert_eq!(12.25, bqs.avg_egress_rate);
+
+    let q = queue_info(
+        "cq.2",
+        "classic",
+        json!({"backing_queue_status": {"mode": "lazy", "target_ram_count": 0}}),
+    );
+    let bqs = q.backing_queue_status.unwrap();
+    assert_eq!(Some(TargetRamCount::Count(0)), bqs.target_ram_count);
+    assert_eq!(0.0, bqs.avg_ingress_rate);
+
+    let qq = queue_info("qq.1", "quorum", json!({}));
+    assert!(qq.backing_queue_status.is_none());
+}
+
+#[test]
+fn test_unit_target_ram_count_serialization() {
+    assert_eq!(json!("infinity"), json!(TargetRamCount::Infinity));
+    assert_eq!(json!(100), json!(TargetRamCount::Count(100)));
+    assert!(serde_json::from_value::<TargetRamCount>(json!("unlimited")).is_err());
+}
+
+#[test]
+fn test_unit_queue_info_operator_policy_and_effective_policy_definition() {
+    let q = queue_info(
+        "cq.1",
+        "classic",
+        json!({
+            "policy": "limits",
+            "operator_policy": "op-limits",
+            "effective_policy_definition": {"max-length": 1000, "overflow": "reject-publish"}
+        }),
+    );
+    assert_eq!(Some("limits".to_owned()), q.policy);
+    assert_eq!(Some("op-limits".to_owned()), q.operator_policy);
+    let epd = q.effective_policy_definition.0.unwrap();
+    assert_eq!(Some(&json!(1000)), epd.get("max-length"));
+    assert_eq!(Some(&json!("reject-publish")), epd.get("overflow"));
+
+    // an empty definition is returned as an empty list
+    let q = queue_info(
+        "cq.2",
+        "classic",
+        json!({"operator_policy": null, "effective_policy_definition": []}),
+    );
+    assert!(q.operator_policy.is_none());
+    assert!(q.effective_policy_definition.is_empty());
+
+    let q = queue_info("cq.3", "classic", json!({}));
+    assert!(q.effective_policy_definition.is_empty());
+}
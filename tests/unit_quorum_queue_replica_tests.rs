@@ -0,0 +1,89 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod test_helpers;
+
+use rabbitmq_http_client::blocking_api::{Client, ClientBuilder};
+use rabbitmq_http_client::commons::QuorumQueueGrowthStrategy;
+use test_helpers::{serve_once, OneShotServer};
+
+type TestClient = Client<String, &'static str, &'static str>;
+
+// responds to a single request with a 204, returns a client that uses the server
+fn serve_no_content_once() -> (TestClient, OneShotServer) {
+    let server = serve_once("204 No Content", &[], "");
+    let rc = ClientBuilder::new()
+        .with_endpoint(server.endpoint())
+        .build();
+    (rc, server)
+}
+
+#[test]
+fn test_unit_add_quorum_queue_replica() {
+    let (rc, server) = serve_no_content_once();
+    let result = rc.add_quorum_queue_replica("events", "orders", "rabbit@hostname2");
+    let request = server.request().to_lowercase();
+
+    assert!(
+        result.is_ok(),
+        "add_quorum_queue_replica returned {:?}",
+        result
+    );
+    assert!(request.starts_with("post /api/queues/quorum/events/orders/replicas/add "));
+    assert!(request.ends_with(r#"{"node":"rabbit@hostname2"}"#));
+}
+
+#[test]
+fn test_unit_delete_quorum_queue_replica() {
+    let (rc, server) = serve_no_content_once();
+    let result = rc.delete_quorum_queue_replica("events", "orders", "rabbit@hostname2");
+    let request = server.request().to_lowercase();
+
+    assert!(
+        result.is_ok(),
+        "delete_quorum_queue_replica returned {:?}",
+        result
+    );
+    assert!(request.starts_with("delete /api/queues/quorum/events/orders/replicas/delete "));
+    assert!(request.ends_with(r#"{"node":"rabbit@hostname2"}"#));
+}
+
+#[test]
+fn test_unit_grow_quorum_queue() {
+    let (rc, server) = serve_no_content_once();
+    let result = rc.grow_quorum_queue(
+        "rabbit@hostname2",
+        "events",
+        "^orders",
+        QuorumQueueGrowthStrategy::Even,
+    );
+    let request = server.request().to_lowercase();
+
+    assert!(result.is_ok(), "grow_quorum_queue returned {:?}", result);
+    assert!(request.starts_with("post /api/queues/quorum/replicas/on/rabbit%40hostname2/grow "));
+    let body: serde_json::Value =
+        serde_json::from_str(request.split_once("\r\n\r\n").unwrap().1).unwrap();
+    assert_eq!("even", body["strategy"]);
+    assert_eq!("events", body["vhost_pattern"]);
+    assert_eq!("^orders", body["queue_pattern"]);
+}
+
+#[test]
+fn test_unit_shrink_quorum_queue() {
+    let (rc, server) = serve_no_content_once();
+    let result = rc.shrink_quorum_queue("rabbit@hostname2");
+    let request = server.request().to_lowercase();
+
+    assert!(result.is_ok(), "shrink_quorum_queue returned {:?}", result);
+    assert!(request.starts_with("delete /api/queues/quorum/replicas/on/rabbit%40hostname2/shrink "));
+}
@@ -0,0 +1,114 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg(feature = "render")]
+
+mod test_helpers;
+
+use rabbitmq_http_client::render::{OutputFormat, Render, RenderError};
+use rabbitmq_http_client::responses::{ExchangeInfo, QueueInfo};
+use serde_json::json;
+
+fn exchanges() -> Vec<ExchangeInfo> {
+    serde_json::from_value(json!([
+        {
+            "name": "events.topic",
+            "vhost": "/",
+            "type": "topic",
+            "durable": true,
+            "auto_delete": false,
+            "arguments": {}
+        },
+        {
+            "name": "yes",
+            "vhost": "staging: eu",
+            "type": "fanout",
+            "durable": false,
+            "auto_delete": true,
+            "arguments": {"alternate-exchange": "amq.fanout"}
+        }
+    ]))
+    .unwrap()
+}
+
+#[test]
+fn test_unit_output_format_parsing() {
+    assert_eq!(OutputFormat::Json, "json".parse().unwrap());
+    assert_eq!(OutputFormat::Yaml, "YAML".parse().unwrap());
+    assert_eq!(OutputFormat::Yaml, "yml".parse().unwrap());
+    assert_eq!(OutputFormat::Plain, "plain".parse().unwrap());
+    assert_eq!(OutputFormat::Table, OutputFormat::default());
+    assert_eq!("table", OutputFormat::Table.to_string());
+
+    let result = "xml".parse::<OutputFormat>();
+    assert!(matches!(result, Err(RenderError::UnsupportedFormat(s)) if s == "xml"));
+}
+
+#[test]
+fn test_unit_render_as_json() {
+    let out = exchanges().render(OutputFormat::Json).unwrap();
+    let val: serde_json::Value = serde_json::from_str(&out).unwrap();
+
+    assert_eq!(2, val.as_array().unwrap().len());
+    assert_eq!(json!("events.topic"), val[0]["name"]);
+    assert_eq!(json!("topic"), val[0]["type"]);
+}
+
+#[test]
+fn test_unit_render_as_yaml() {
+    let xs = exchanges();
+    let out = xs.render(OutputFormat::Yaml).unwrap();
+
+    assert!(out.starts_with("- name: events.topic\n"));
+    assert!(out.contains("  type: topic\n"));
+    // strings that would be parsed as something else are quoted
+    assert!(!out.contains("vhost: staging: eu\n"));
+
+    // the output is parsed back into the same values
+    let parsed: serde_json::Value = serde_yaml_ng::from_str(&out).unwrap();
+    assert_eq!(serde_json::to_value(&xs).unwrap(), parsed);
+}
+
+#[test]
+fn test_unit_render_as_plain_text_and_table() {
+    let xs = exchanges();
+
+    let plain = xs.render(OutputFormat::Plain).unwrap();
+    let lines: Vec<&str> = plain.lines().collect();
+    assert_eq!(2, lines.len());
+    assert!(lines[0].starts_with("events.topic\t/\ttopic\t"));
+
+    let table = xs.render(OutputFormat::Table).unwrap();
+    assert!(table.contains("name"));
+    assert!(table.contains("events.topic"));
+}
+
+#[test]
+fn test_unit_render_as_plain_text_escapes_tabs_and_line_breaks() {
+    let mut xs = exchanges();
+    xs[0].name = "events\ttopic\nv2\\".to_owned();
+
+    let plain = xs.render(OutputFormat::Plain).unwrap();
+    let lines: Vec<&str> = plain.lines().collect();
+    assert_eq!(2, lines.len());
+    assert!(lines[0].starts_with("events\\ttopic\\nv2\\\\\t/\ttopic\t"));
+}
+
+#[test]
+fn test_unit_render_an_empty_listing() {
+    let xs: Vec<QueueInfo> = Vec::new();
+
+    assert_eq!("[]", xs.render(OutputFormat::Json).unwrap());
+    assert_eq!("[]\n", xs.render(OutputFormat::Yaml).unwrap());
+    assert_eq!("", xs.render(OutputFormat::Plain).unwrap());
+}
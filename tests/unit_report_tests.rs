@@ -0,0 +1,253 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod test_helpers;
+
+use rabbitmq_http_client::report::{
+    ClusterReport, ConsumerUtilisationReport, EntityCounts, DEFAULT_CONSUMER_SATURATION_THRESHOLD,
+};
+use rabbitmq_http_client::responses::{ClusterNode, Consumer, Overview, QueueInfo, QueueSummary};
+use serde_json::json;
+
+fn overview() -> Overview {
+    serde_json::from_value(json!({
+        "cluster_name": "rabbit@sunnyside",
+        "node": "rabbit@sunnyside",
+        "erlang_full_version": "Erlang/OTP 27 [erts-15.2] [source] [64-bit]",
+        "erlang_version": "27.2",
+        "rabbitmq_version": "4.1.0",
+        "product_name": "RabbitMQ",
+        "product_version": "4.1.0",
+        "statistics_db_event_queue": 0,
+        "churn_rates": {
+            "channel_closed": 0,
+            "channel_created": 0,
+            "connection_closed": 0,
+            "connection_created": 0,
+            "queue_created": 0,
+            "queue_declared": 0,
+            "queue_deleted": 0
+        },
+        "queue_totals": {
+            "messages": 1110,
+            "messages_details": {"rate": 0.0},
+            "messages_ready": 1110,
+            "messages_ready_details": {"rate": 0.0},
+            "messages_unacknowledged": 0,
+            "messages_unacknowledged_details": {"rate": 0.0}
+        },
+        "object_totals": {
+            "channels": 2,
+            "connections": 1,
+            "consumers": 1,
+            "exchanges": 8,
+            "queues": 3
+        },
+        "message_stats": {},
+        "listeners": [
+            {"node": "rabbit@a", "protocol": "amqp", "port": 5672, "ip_address": "::"},
+            {"node": "rabbit@a", "protocol": "amqp", "port": 5672, "ip_address": "0.0.0.0"},
+            {"node": "rabbit@a", "protocol": "http", "port": 15672, "ip_address": "::"},
+            {"node": "rabbit@b", "protocol": "amqp/ssl", "port": 5671, "ip_address": "::"}
+        ]
+    }))
+    .unwrap()
+}
+
+fn node(name: &str, mem_alarm: bool) -> ClusterNode {
+    serde_json::from_value(json!({
+        "name": name,
+        "running": true,
+        "uptime": 360000,
+        "run_queue": 1,
+        "processors": 8,
+        "os_pid": "4242",
+        "fd_total": 1048576,
+        "proc_total": 1048576,
+        "mem_limit": 6871947673u64,
+        "mem_alarm": mem_alarm,
+        "disk_free_limit": 50000000,
+        "disk_free_alarm": false,
+        "rates_mode": "basic",
+        "enabled_plugins": ["rabbitmq_management"],
+        "being_drained": false
+    }))
+    .unwrap()
+}
+
+fn queue(name: &str, messages: u64) -> QueueSummary {
+    serde_json::from_value(json!({
+        "name": name,
+        "vhost": "/",
+        "type": "quorum",
+        "messages": messages
+    }))
+    .unwrap()
+}
+
+fn report() -> ClusterReport {
+    let counts = EntityCounts {
+        virtual_hosts: 2,
+        users: 3,
+        ..Default::default()
+    };
+    let queues = vec![queue("q.1", 10), queue("q.2", 1000), queue("q.3", 100)];
+    ClusterReport::new(
+        &overview(),
+        &[node("rabbit@a", false), node("rabbit@b", true)],
+        counts,
+        queues,
+        2,
+    )
+}
+
+#[test]
+fn test_unit_cluster_report_top_queues() {
+    let report = report();
+    let names: Vec<&str> = report.top_queues.iter().map(|q| q.name.as_str()).collect();
+
+    assert_eq!(vec!["q.2", "q.3"], names);
+}
+
+#[test]
+fn test_unit_cluster_report_nodes_with_alarms() {
+    let report = report();
+    let nodes = report.nodes_with_alarms();
+
+    assert_eq!(1, nodes.len());
+    assert_eq!("rabbit@b", nodes[0].name);
+    assert!(nodes[0].memory_alarm);
+}
+
+#[test]
+fn test_unit_cluster_report_listener_matrix() {
+    let report = report();
+    let listeners = &report.listeners;
+
+    // listeners bound to multiple interfaces are reported once
+    assert_eq!(&[5672], listeners.ports("rabbit@a", "amqp"));
+    assert_eq!(&[15672], listeners.ports("rabbit@a", "http"));
+    assert!(listeners.ports("rabbit@b", "http").is_empty());
+    assert_eq!(vec!["amqp", "amqp/ssl", "http"], listeners.protocols());
+}
+
+#[test]
+fn test_unit_cluster_report_display() {
+    let report = report();
+    let s = report.to_string();
+
+    assert!(s.contains("Cluster: rabbit@sunnyside"));
+    assert!(s.contains("RabbitMQ version: 4.1.0"));
+    assert!(s.contains("rabbit@b: memory alarm"));
+    assert!(s.contains("rabbit@a: amqp: 5672; http: 15672"));
+    assert!(s.contains("virtual hosts: 2"));
+    assert!(s.contains("q.2 in virtual host /: 1000 messages"));
+}
+
+fn queue_info(vhost: &str, name: &str, extra: serde_json::Value) -> QueueInfo {
+    let mut val = json!({
+        "arguments": {},
+        "auto_delete": false,
+        "durable": true,
+        "exclusive": false,
+        "name": name,
+        "type": "quorum",
+        "vhost": vhost
+    });
+    for (k, v) in extra.as_object().unwrap() {
+        val[k] = v.clone();
+    }
+    serde_json::from_value(val).unwrap()
+}
+
+fn consumer(vhost: &str, queue: &str, tag: &str, prefetch_count: u32) -> Consumer {
+    serde_json::from_value(json!({
+        "arguments": {},
+        "ack_required": true,
+        "active": true,
+        "channel_details": {},
+        "consumer_tag": tag,
+        "consumer_timeout": 1800000,
+        "exclusive": false,
+        "prefetch_count": prefetch_count,
+        "queue": {"name": queue, "vhost": vhost}
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_unit_consumer_utilisation_report() {
+    let queues = vec![
+        // keeps up
+        queue_info(
+            "/",
+            "orders",
+            json!({"consumers": 2, "consumer_utilisation": 1.0, "messages": 10, "messages_unacknowledged": 10}),
+        ),
+        // low utilisation with messages ready
+        queue_info(
+            "/",
+            "invoices",
+            json!({"consumers": 1, "consumer_utilisation": 0.25, "messages": 500, "messages_unacknowledged": 20}),
+        ),
+        // no consumers
+        queue_info("events", "audit", json!({"messages": 7})),
+    ];
+    let consumers = vec![
+        consumer("/", "orders", "ctag.1", 10),
+        consumer("/", "orders", "ctag.2", 10),
+        consumer("/", "invoices", "ctag.3", 0),
+    ];
+
+    let report =
+        ConsumerUtilisationReport::new(&queues, &consumers, DEFAULT_CONSUMER_SATURATION_THRESHOLD);
+    assert_eq!(3, report.queues.len());
+
+    let invoices = &report.queues[0];
+    assert_eq!("invoices", invoices.name);
+    assert_eq!(1, invoices.consumer_count);
+    assert_eq!(1, invoices.unlimited_prefetch_consumer_count);
+    assert_eq!(480, invoices.messages_ready);
+    assert_eq!(20, invoices.ack_backlog);
+    assert!(invoices.saturated);
+
+    let orders = &report.queues[1];
+    assert_eq!(2, orders.consumer_count);
+    assert_eq!(2, orders.manual_ack_consumer_count);
+    assert_eq!(20, orders.total_prefetch);
+    assert!(!orders.saturated);
+
+    let saturated = report.saturated();
+    assert_eq!(1, saturated.len());
+    assert_eq!("invoices", saturated[0].name);
+
+    let without_consumers = report.without_consumers();
+    assert_eq!(1, without_consumers.len());
+    assert_eq!("audit", without_consumers[0].name);
+    assert!(!without_consumers[0].saturated);
+}
+
+#[test]
+fn test_unit_consumer_utilisation_report_flags_exhausted_prefetch() {
+    let queues = vec![queue_info(
+        "/",
+        "orders",
+        json!({"consumers": 1, "consumer_utilisation": 1.0, "messages": 5, "messages_unacknowledged": 5}),
+    )];
+    let consumers = vec![consumer("/", "orders", "ctag.1", 5)];
+
+    let report =
+        ConsumerUtilisationReport::new(&queues, &consumers, DEFAULT_CONSUMER_SATURATION_THRESHOLD);
+    assert!(report.queues[0].saturated);
+    assert_eq!(0, report.queues[0].messages_ready);
+}
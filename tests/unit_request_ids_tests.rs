@@ -0,0 +1,166 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod test_helpers;
+
+use rabbitmq_http_client::blocking_api::ClientBuilder;
+use rabbitmq_http_client::error::Error;
+use rabbitmq_http_client::request_ids::{RequestIds, REQUEST_ID_HEADER, TRACEPARENT_HEADER};
+use test_helpers::{chunked, serve_once};
+
+#[test]
+fn test_unit_random_request_ids() {
+    let ids = RequestIds::random();
+    assert_eq!(REQUEST_ID_HEADER, ids.header());
+
+    let (a, b) = (ids.next_id(), ids.next_id());
+    assert_eq!(32, a.len());
+    assert!(a.chars().all(|c| c.is_ascii_hexdigit()));
+    assert_ne!(a, b);
+}
+
+#[test]
+fn test_unit_trace_parent_request_ids() {
+    let ids = RequestIds::trace_parent();
+    assert_eq!(TRACEPARENT_HEADER, ids.header());
+
+    let id = ids.next_id();
+    let parts: Vec<&str> = id.split('-').collect();
+    assert_eq!(4, parts.len());
+    assert_eq!("00", parts[0]);
+    assert_eq!(32, parts[1].len());
+    assert_eq!(16, parts[2].len());
+    assert_eq!("01", parts[3]);
+}
+
+#[test]
+fn test_unit_fixed_and_custom_request_ids() {
+    assert_eq!("op-42", RequestIds::fixed("op-42").next_id());
+
+    let ids = RequestIds::custom(|| "generated".to_owned()).with_header("x-correlation-id");
+    assert_eq!("x-correlation-id", ids.header());
+    assert_eq!("generated", ids.next_id());
+}
+
+#[test]
+fn test_unit_request_id_is_included_in_request_errors() {
+    let rc = ClientBuilder::new()
+        .with_endpoint("http://127.0.0.1:1/api")
+        .with_request_ids(RequestIds::fixed("op-42"))
+        .build();
+
+    let err = rc.list_nodes().unwrap_err();
+    assert!(matches!(err, Error::RequestError { .. }));
+    assert_eq!(Some("op-42"), err.request_id());
+}
+
+#[test]
+fn test_unit_request_id_is_sent_and_included_in_error_responses() {
+    let server = serve_once(
+        "500 Internal Server Error",
+        &[("content-type", "application/json")],
+        r#"{"error":"internal_server_error"}"#,
+    );
+
+    let rc = ClientBuilder::new()
+        .with_endpoint(server.endpoint())
+        .with_request_ids(RequestIds::fixed("op-42"))
+        .build();
+    let err = rc.list_nodes().unwrap_err();
+    let request = server.request().to_lowercase();
+
+    assert!(request.contains("x-request-id: op-42"), "{}", request);
+    assert!(matches!(err, Error::ServerErrorResponse { .. }));
+    assert_eq!(Some("op-42"), err.request_id());
+}
+
+#[test]
+fn test_unit_request_id_is_included_in_body_read_errors() {
+    let body = format!("[{}]", vec!["{}"; 100].join(","));
+    let server = serve_once(
+        "200 OK",
+        &[
+            ("content-type", "application/json"),
+            ("transfer-encoding", "chunked"),
+        ],
+        &chunked(&body),
+    );
+
+    let rc = ClientBuilder::new()
+        .with_endpoint(server.endpoint())
+        .with_max_response_size(64)
+        .with_request_ids(RequestIds::fixed("op-42"))
+        .build();
+    let err = rc.list_vhosts().unwrap_err();
+    server.request();
+
+    assert!(matches!(err, Error::ResponseTooLarge { .. }));
+    assert_eq!(Some("op-42"), err.request_id());
+}
+
+#[test]
+fn test_unit_request_id_is_included_in_deserialization_errors() {
+    let server = serve_once(
+        "200 OK",
+        &[("content-type", "application/json")],
+        r#"{"unexpected": true}"#,
+    );
+
+    let rc = ClientBuilder::new()
+        .with_endpoint(server.endpoint())
+        .with_request_ids(RequestIds::fixed("op-42"))
+        .build();
+    let err = rc.list_vhosts().unwrap_err();
+    server.request();
+
+    assert!(matches!(err, Error::RequestError { .. }));
+    assert_eq!(Some("op-42"), err.request_id());
+}
+
+#[test]
+fn test_unit_request_id_is_included_in_streamed_body_errors() {
+    let body = format!(
+        r#"{{"rabbit_version":"4.1.0","queues":[{}]}}"#,
+        vec!["{}"; 100].join(",")
+    );
+    let server = serve_once(
+        "200 OK",
+        &[
+            ("content-type", "application/json"),
+            ("transfer-encoding", "chunked"),
+        ],
+        &chunked(&body),
+    );
+
+    let rc = ClientBuilder::new()
+        .with_endpoint(server.endpoint())
+        .with_max_response_size(64)
+        .with_request_ids(RequestIds::fixed("op-42"))
+        .build();
+    let mut out = Vec::new();
+    let err = rc.export_definitions_to_writer(&mut out).unwrap_err();
+    server.request();
+
+    assert!(matches!(err, Error::ResponseTooLarge { .. }));
+    assert_eq!(Some("op-42"), err.request_id());
+}
+
+#[test]
+fn test_unit_no_request_id_by_default() {
+    let rc = ClientBuilder::new()
+        .with_endpoint("http://127.0.0.1:1/api")
+        .build();
+
+    let err = rc.list_nodes().unwrap_err();
+    assert_eq!(None, err.request_id());
+}
@@ -0,0 +1,185 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod test_helpers;
+
+use rabbitmq_http_client::blocking_api::ClientBuilder;
+use rabbitmq_http_client::error::Error;
+
+use test_helpers::{chunked, serve_once};
+
+const JSON: (&str, &str) = ("content-type", "application/json");
+const CHUNKED: (&str, &str) = ("transfer-encoding", "chunked");
+
+#[test]
+fn test_unit_response_within_limit() {
+    let server = serve_once("200 OK", &[JSON], "[]");
+
+    let rc = ClientBuilder::new()
+        .with_endpoint(server.endpoint())
+        .with_max_response_size(1024)
+        .build();
+    let result = rc.list_vhosts();
+    server.request();
+
+    assert!(result.unwrap().is_empty());
+}
+
+#[test]
+fn test_unit_response_over_limit_with_content_length() {
+    let body = format!("[{}]", vec!["{}"; 100].join(","));
+    let size = body.len() as u64;
+    let server = serve_once("200 OK", &[JSON], &body);
+
+    let rc = ClientBuilder::new()
+        .with_endpoint(server.endpoint())
+        .with_max_response_size(64)
+        .build();
+    let result = rc.list_vhosts();
+    server.request();
+
+    match result.unwrap_err() {
+        Error::ResponseTooLarge {
+            url,
+            content_length,
+            limit,
+            ..
+        } => {
+            assert_eq!(Some(size), content_length);
+            assert_eq!(64, limit);
+            assert!(url.unwrap().path().ends_with("/vhosts"));
+        }
+        other => panic!("unexpected error: {:?}", other),
+    }
+}
+
+#[test]
+fn test_unit_chunked_response_within_limit() {
+    let server = serve_once("200 OK", &[JSON, CHUNKED], &chunked("[]"));
+
+    let rc = ClientBuilder::new()
+        .with_endpoint(server.endpoint())
+        .with_max_response_size(1024)
+        .build();
+    let result = rc.list_vhosts();
+    server.request();
+
+    assert!(result.unwrap().is_empty());
+}
+
+#[test]
+fn test_unit_chunked_response_over_limit() {
+    let body = format!("[{}]", vec!["{}"; 100].join(","));
+    let server = serve_once("200 OK", &[JSON, CHUNKED], &chunked(&body));
+
+    let rc = ClientBuilder::new()
+        .with_endpoint(server.endpoint())
+        .with_max_response_size(64)
+        .build();
+    let result = rc.list_vhosts();
+    server.request();
+
+    match result.unwrap_err() {
+        Error::ResponseTooLarge {
+            url,
+            content_length: None,
+            limit: 64,
+            ..
+        } => assert!(url.unwrap().path().ends_with("/vhosts")),
+        other => panic!("unexpected error: {:?}", other),
+    }
+}
+
+#[test]
+fn test_unit_chunked_definitions_streamed_within_limit() {
+    let body = r#"{"rabbit_version":"4.1.0","queues":[]}"#;
+    let server = serve_once("200 OK", &[JSON, CHUNKED], &chunked(body));
+
+    let rc = ClientBuilder::new()
+        .with_endpoint(server.endpoint())
+        .with_max_response_size(1024)
+        .build();
+    let mut out = Vec::new();
+    let result = rc.export_definitions_to_writer(&mut out);
+    server.request();
+
+    assert_eq!(body.len() as u64, result.unwrap());
+    assert_eq!(body.as_bytes(), out.as_slice());
+}
+
+#[test]
+fn test_unit_chunked_definitions_streamed_over_limit() {
+    let first = r#"{"rabbit_version":"4.1.0","#;
+    let second = format!(r#""queues":[{}]}}"#, vec!["{}"; 100].join(","));
+    let body = format!("{:x}\r\n{}\r\n{}", first.len(), first, chunked(&second));
+    let server = serve_once("200 OK", &[JSON, CHUNKED], &body);
+
+    let rc = ClientBuilder::new()
+        .with_endpoint(server.endpoint())
+        .with_max_response_size(64)
+        .build();
+    let mut out = Vec::new();
+    let result = rc.export_definitions_to_writer(&mut out);
+    server.request();
+
+    assert!(matches!(
+        result.unwrap_err(),
+        Error::ResponseTooLarge {
+            content_length: None,
+            limit: 64,
+            ..
+        }
+    ));
+    // nothing beyond the limit is written
+    assert!(out.len() <= 64);
+}
+
+#[test]
+fn test_unit_error_response_bodies_are_truncated_at_the_limit() {
+    let body = "x".repeat(1000);
+    let server = serve_once("502 Bad Gateway", &[("content-type", "text/html")], &body);
+
+    let rc = ClientBuilder::new()
+        .with_endpoint(server.endpoint())
+        .with_max_response_size(64)
+        .build();
+    let result = rc.list_vhosts();
+    server.request();
+
+    match result.unwrap_err() {
+        Error::ServerErrorResponse { body, .. } => assert_eq!(Some("x".repeat(64)), body),
+        other => panic!("unexpected error: {:?}", other),
+    }
+}
+
+#[test]
+fn test_unit_chunked_error_response_bodies_are_truncated_at_the_limit() {
+    let body = "x".repeat(1000);
+    let server = serve_once(
+        "403 Forbidden",
+        &[("content-type", "text/html"), CHUNKED],
+        &chunked(&body),
+    );
+
+    let rc = ClientBuilder::new()
+        .with_endpoint(server.endpoint())
+        .with_max_response_size(64)
+        .build();
+    let result = rc.list_vhosts();
+    server.request();
+
+    match result.unwrap_err() {
+        Error::ClientErrorResponse { body, .. } => assert_eq!(Some("x".repeat(64)), body),
+        other => panic!("unexpected error: {:?}", other),
+    }
+}
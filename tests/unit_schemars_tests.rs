@@ -0,0 +1,57 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg(feature = "schemars")]
+
+mod test_helpers;
+
+use rabbitmq_http_client::commons::QueueType;
+use rabbitmq_http_client::requests::QueueParams;
+use rabbitmq_http_client::responses::{Overview, QueueInfo};
+use schemars::schema_for;
+
+#[test]
+fn test_unit_json_schema_of_a_response_type() {
+    let schema = serde_json::to_value(schema_for!(QueueInfo)).unwrap();
+    let props = schema["properties"].as_object().unwrap();
+
+    assert!(props.contains_key("name"));
+    assert!(props.contains_key("vhost"));
+    assert!(props.contains_key("durable"));
+}
+
+#[test]
+fn test_unit_json_schema_of_a_nested_response_type() {
+    let schema = serde_json::to_value(schema_for!(Overview)).unwrap();
+
+    assert!(schema["properties"]
+        .as_object()
+        .unwrap()
+        .contains_key("churn_rates"));
+    assert!(schema["$defs"]
+        .as_object()
+        .unwrap()
+        .contains_key("ChurnRates"));
+}
+
+#[test]
+fn test_unit_json_schema_of_request_types() {
+    let schema = serde_json::to_value(schema_for!(QueueParams)).unwrap();
+    assert!(schema["properties"]
+        .as_object()
+        .unwrap()
+        .contains_key("queue_type"));
+
+    let schema = serde_json::to_value(schema_for!(QueueType)).unwrap();
+    assert!(schema.is_object());
+}
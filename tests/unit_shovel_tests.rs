@@ -0,0 +1,78 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod test_helpers;
+
+use rabbitmq_http_client::responses::{Shovel, ShovelPublishingState, ShovelState, ShovelType};
+
+#[test]
+fn test_unit_deserialize_terminated_shovel() {
+    let json = r#"{
+        "node": "rabbit@sunnyside",
+        "name": "shovel.1",
+        "vhost": "/",
+        "type": "dynamic",
+        "state": "terminated",
+        "reason": "needed a restart"
+    }"#;
+    let shovel: Shovel = serde_json::from_str(json).unwrap();
+
+    assert_eq!(ShovelType::Dynamic, shovel.typ);
+    assert_eq!(ShovelState::Terminated, shovel.state);
+    assert!(shovel.source_uri.is_none());
+    assert_eq!(Some("needed a restart".to_owned()), shovel.last_error);
+}
+
+#[test]
+fn test_unit_shovel_state_conversions() {
+    assert_eq!(
+        ShovelState::Terminated,
+        ShovelState::from("terminated".to_owned())
+    );
+    assert_eq!(
+        ShovelState::Unknown,
+        ShovelState::from("stopping".to_owned())
+    );
+    assert_eq!("terminated", String::from(ShovelState::Terminated));
+    assert_eq!("terminated", ShovelState::Terminated.to_string());
+}
+
+#[test]
+fn test_unit_deserialize_running_shovel_status() {
+    let json = r#"{
+        "node": "rabbit@sunnyside",
+        "timestamp": "2025-05-20 10:15:00",
+        "name": "shovel.2",
+        "vhost": "events",
+        "type": "dynamic",
+        "state": "running",
+        "blocked_status": "blocked",
+        "src_uri": "amqp://upstream",
+        "src_protocol": "amqp091",
+        "src_queue": "orders",
+        "dest_uri": "amqp://downstream",
+        "dest_protocol": "amqp091",
+        "dest_queue": "orders.replica"
+    }"#;
+    let shovel: Shovel = serde_json::from_str(json).unwrap();
+
+    assert_eq!(ShovelState::Running, shovel.state);
+    assert_eq!(
+        Some(ShovelPublishingState::Blocked),
+        shovel.publishing_state
+    );
+    assert_eq!(Some("amqp://upstream".to_owned()), shovel.source_uri);
+    assert_eq!(Some("orders.replica".to_owned()), shovel.destination);
+    assert_eq!(Some("2025-05-20 10:15:00".to_owned()), shovel.timestamp);
+    assert!(shovel.last_error.is_none());
+}
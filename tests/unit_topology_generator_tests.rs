@@ -0,0 +1,128 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod test_helpers;
+
+use rabbitmq_http_client::commons::{ExchangeType, PolicyTarget, QueueType};
+use rabbitmq_http_client::requests::DefinitionOperation;
+use rabbitmq_http_client::responses::QueueOps;
+use rabbitmq_http_client::topology_generator::TopologyGenerator;
+use serde_json::{json, Map};
+
+#[test]
+fn test_unit_topology_generator_defaults_to_an_empty_topology() {
+    let defs = TopologyGenerator::new("/").generate();
+
+    assert!(defs.queues.is_empty());
+    assert!(defs.exchanges.is_empty());
+    assert!(defs.bindings.is_empty());
+    assert!(defs.policies.is_empty());
+}
+
+#[test]
+fn test_unit_topology_generator_queue_names_and_types() {
+    let defs = TopologyGenerator::new("bench")
+        .with_queues(4, "bench.q.{n}")
+        .with_queue_types(vec![QueueType::Quorum, QueueType::Classic])
+        .generate();
+
+    assert_eq!(4, defs.queues.len());
+    assert_eq!("bench.q.0", defs.queues[0].name);
+    assert_eq!("bench.q.3", defs.queues[3].name);
+    assert!(defs.queues.iter().all(|q| q.vhost == "bench" && q.durable));
+
+    assert_eq!(QueueType::Quorum, defs.queues[0].queue_type());
+    assert_eq!(QueueType::Classic, defs.queues[1].queue_type());
+    assert_eq!(QueueType::Quorum, defs.queues[2].queue_type());
+}
+
+#[test]
+fn test_unit_topology_generator_queue_argument_distribution() {
+    let mut short_ttl = Map::new();
+    short_ttl.insert("x-message-ttl".to_owned(), json!(1000));
+    let mut long_ttl = Map::new();
+    long_ttl.insert("x-message-ttl".to_owned(), json!(60000));
+
+    let defs = TopologyGenerator::new("/")
+        .with_queues(3, "q.{n}")
+        .with_queue_arguments(vec![short_ttl.clone(), short_ttl, long_ttl])
+        .generate();
+
+    let ttls: Vec<_> = defs
+        .queues
+        .iter()
+        .map(|q| q.arguments.0["x-message-ttl"].clone())
+        .collect();
+    assert_eq!(vec![json!(1000), json!(1000), json!(60000)], ttls);
+    assert_eq!(json!("classic"), defs.queues[2].arguments.0["x-queue-type"]);
+}
+
+#[test]
+fn test_unit_topology_generator_bindings() {
+    let defs = TopologyGenerator::new("/")
+        .with_queues(3, "q.{n}")
+        .with_exchanges(2, "x.{n}", ExchangeType::Topic)
+        .with_bindings_per_queue(5)
+        .generate();
+
+    assert_eq!(2, defs.exchanges.len());
+    assert_eq!("topic", defs.exchanges[1].exchange_type);
+    // capped at the number of exchanges
+    assert_eq!(6, defs.bindings.len());
+
+    let q2: Vec<_> = defs
+        .bindings
+        .iter()
+        .filter(|b| b.destination == "q.2")
+        .map(|b| b.source.as_str())
+        .collect();
+    assert_eq!(vec!["x.0", "x.1"], q2);
+    assert!(defs.bindings.iter().all(|b| b.routing_key == b.destination));
+}
+
+#[test]
+fn test_unit_topology_generator_policies() {
+    let mut definition = Map::new();
+    definition.insert("max-length".to_owned(), json!(100));
+
+    let defs = TopologyGenerator::new("/")
+        .with_policies(
+            2,
+            "limit.{n}",
+            "^q\\.{n}$",
+            PolicyTarget::QuorumQueues,
+            definition,
+        )
+        .generate();
+
+    assert_eq!(2, defs.policies.len());
+    assert_eq!("limit.1", defs.policies[1].name);
+    assert_eq!("^q\\.1$", defs.policies[1].pattern);
+    assert_eq!(PolicyTarget::QuorumQueues, defs.policies[1].apply_to);
+}
+
+#[test]
+fn test_unit_topology_generator_operations() {
+    let defs = TopologyGenerator::new("/")
+        .with_queues(2, "q.{n}")
+        .with_exchanges(1, "x.{n}", ExchangeType::Fanout)
+        .with_bindings_per_queue(1)
+        .generate();
+    let ops = defs.operations();
+
+    assert_eq!(5, ops.len());
+    assert!(matches!(
+        ops.last(),
+        Some(DefinitionOperation::DeclareBinding(_))
+    ));
+}
@@ -0,0 +1,111 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod test_helpers;
+
+use rabbitmq_http_client::password_hashing::HashingAlgorithm;
+use rabbitmq_http_client::requests::{UserParams, UserParamsWithPassword};
+use rabbitmq_http_client::responses::{CurrentUser, User};
+
+#[test]
+fn test_unit_user_params_defaults_and_setters() {
+    let params = UserParams::default();
+    assert_eq!("", params.name);
+    assert_eq!("", params.password_hash);
+    assert_eq!("", params.tags);
+
+    let params = UserParams::default()
+        .with_name("alice")
+        .with_password_hash("hash")
+        .with_tags("monitoring");
+    assert_eq!("alice", params.name);
+    assert_eq!("hash", params.password_hash);
+    assert_eq!("monitoring", params.tags);
+
+    let updated = UserParams {
+        tags: "administrator",
+        ..params
+    };
+    assert_eq!("alice", updated.name);
+    assert_eq!("administrator", updated.tags);
+}
+
+#[test]
+fn test_unit_serialize_user_params_with_hashing_algorithm() {
+    let params = UserParams::default()
+        .with_name("alice")
+        .with_password_hash("hash");
+    let val = serde_json::to_value(&params).unwrap();
+    assert!(val.get("hashing_algorithm").is_none());
+
+    let params = params.with_hashing_algorithm(HashingAlgorithm::Sha512);
+    let val = serde_json::to_value(&params).unwrap();
+    assert_eq!("rabbit_password_hashing_sha512", val["hashing_algorithm"]);
+}
+
+#[test]
+fn test_unit_serialize_user_params_with_password() {
+    let params = UserParamsWithPassword::new("alice", "s3kRe7", "monitoring");
+    let val = serde_json::to_value(&params).unwrap();
+
+    assert_eq!("s3kRe7", val["password"]);
+    assert_eq!("monitoring", val["tags"]);
+    assert!(val.get("password_hash").is_none());
+}
+
+#[test]
+fn test_unit_deserialize_current_user() {
+    let json = r#"{"name": "ops", "tags": ["monitoring", "policymaker"]}"#;
+    let user: CurrentUser = serde_json::from_str(json).unwrap();
+
+    assert_eq!("ops", user.name);
+    assert!(user.has_tag("monitoring"));
+    assert!(!user.is_administrator());
+}
+
+#[test]
+fn test_unit_deserialize_user_with_hashing_algorithm() {
+    let json = r#"{
+        "name": "alice",
+        "password_hash": "kI3GChuNuIYf8lRbCCxZjgjKwsY19ns6",
+        "hashing_algorithm": "rabbit_password_hashing_sha512",
+        "tags": ["monitoring"],
+        "limits": {}
+    }"#;
+    let user: User = serde_json::from_str(json).unwrap();
+
+    assert_eq!(
+        Some(HashingAlgorithm::Sha512),
+        user.parsed_hashing_algorithm().unwrap()
+    );
+    // a new hash is not necessarily produced with the same algorithm
+    let updated = user.with_password_hash("hash".to_owned());
+    assert_eq!(None, updated.hashing_algorithm);
+}
+
+#[test]
+fn test_unit_deserialize_user_with_unsupported_hashing_algorithm() {
+    let json = r#"{
+        "name": "legacy",
+        "password_hash": "kI3GChuNuIYf8lRbCCxZjgjKwsY19ns6",
+        "hashing_algorithm": "rabbit_password_hashing_md5",
+        "tags": []
+    }"#;
+    let user: User = serde_json::from_str(json).unwrap();
+
+    assert_eq!(
+        Some("rabbit_password_hashing_md5"),
+        user.hashing_algorithm.as_deref()
+    );
+    assert!(user.parsed_hashing_algorithm().is_err());
+}
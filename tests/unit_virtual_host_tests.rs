@@ -0,0 +1,159 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod test_helpers;
+
+use rabbitmq_http_client::commons::QueueType;
+use rabbitmq_http_client::requests::{VirtualHostChanges, VirtualHostParams};
+use rabbitmq_http_client::responses::VirtualHost;
+
+#[test]
+fn test_unit_virtual_host_to_virtual_host_params_conversion() {
+    let input = r#"{
+        "name": "events",
+        "description": "Event processing",
+        "tags": ["production", "events"],
+        "default_queue_type": "quorum",
+        "metadata": {
+            "description": "Event processing",
+            "tags": ["production", "events"],
+            "default_queue_type": "quorum"
+        },
+        "tracing": true,
+        "cluster_state": {"rabbit@sunnyside": "running"}
+    }"#;
+    let vh: VirtualHost = serde_json::from_str(input).unwrap();
+    let params = VirtualHostParams::from(&vh);
+
+    assert_eq!("events", params.name);
+    assert_eq!(Some("Event processing"), params.description);
+    assert_eq!(Some(vec!["production", "events"]), params.tags);
+    assert_eq!(Some(QueueType::Quorum), params.default_queue_type);
+    assert!(params.tracing);
+}
+
+#[test]
+fn test_unit_virtual_host_to_virtual_host_params_conversion_uses_metadata() {
+    let input = r#"{
+        "name": "events",
+        "metadata": {
+            "description": "Event processing",
+            "tags": ["staging"],
+            "default_queue_type": "stream"
+        }
+    }"#;
+    let vh: VirtualHost = serde_json::from_str(input).unwrap();
+    let params = VirtualHostParams::from(&vh);
+
+    assert_eq!(Some("Event processing"), params.description);
+    assert_eq!(Some(vec!["staging"]), params.tags);
+    assert_eq!(Some(QueueType::Stream), params.default_queue_type);
+    assert!(!params.tracing);
+}
+
+#[test]
+fn test_unit_deserialize_virtual_host_message_stats() {
+    let input = r#"{
+        "name": "events",
+        "metadata": {"description": "", "tags": []},
+        "messages": 15,
+        "messages_details": {"rate": 0.0},
+        "messages_ready": 10,
+        "messages_unacknowledged": 5,
+        "message_stats": {
+            "publish": 300,
+            "publish_details": {"rate": 2.5},
+            "deliver_get": 285,
+            "deliver_get_details": {"rate": 2.4}
+        }
+    }"#;
+    let vh: VirtualHost = serde_json::from_str(input).unwrap();
+
+    assert_eq!(Some(15), vh.messages);
+    assert_eq!(Some(10), vh.messages_ready_for_delivery);
+    assert_eq!(
+        Some(5),
+        vh.messages_delivered_but_unacknowledged_by_consumers
+    );
+    let ms = vh.message_stats.unwrap();
+    assert_eq!(Some(300), ms.publishing_count);
+    assert_eq!(Some(285), ms.delivery_count);
+    assert_eq!(2.5, ms.publishing_details.unwrap().rate);
+}
+
+#[test]
+fn test_unit_deserialize_virtual_host_without_message_stats() {
+    let input = r#"{
+        "name": "events",
+        "metadata": {"description": "", "tags": []}
+    }"#;
+    let vh: VirtualHost = serde_json::from_str(input).unwrap();
+
+    assert!(vh.messages.is_none());
+    assert!(vh.message_stats.is_none());
+    assert!(serde_json::to_value(&vh).unwrap().get("messages").is_none());
+}
+
+#[test]
+fn test_unit_deserialize_virtual_host_protected_from_deletion() {
+    let input = r#"{
+        "name": "events",
+        "metadata": {"description": "", "tags": []},
+        "protected_from_deletion": true
+    }"#;
+    let vh: VirtualHost = serde_json::from_str(input).unwrap();
+    assert!(vh.protected_from_deletion);
+
+    let input = r#"{
+        "name": "events",
+        "metadata": {"description": "", "tags": []}
+    }"#;
+    let vh: VirtualHost = serde_json::from_str(input).unwrap();
+    assert!(!vh.protected_from_deletion);
+}
+
+#[test]
+fn test_unit_virtual_host_changes_keep_unchanged_metadata() {
+    let params = VirtualHostParams {
+        name: "events",
+        description: Some("Event processing"),
+        tags: Some(vec!["production", "events"]),
+        default_queue_type: Some(QueueType::Quorum),
+        tracing: true,
+    };
+    let changes = VirtualHostChanges::new().with_description("Event sourcing");
+    let amended = changes.apply_to(params);
+
+    assert_eq!("events", amended.name);
+    assert_eq!(Some("Event sourcing"), amended.description);
+    assert_eq!(Some(vec!["production", "events"]), amended.tags);
+    assert_eq!(Some(QueueType::Quorum), amended.default_queue_type);
+    assert!(amended.tracing);
+}
+
+#[test]
+fn test_unit_virtual_host_changes_replace_tags_and_settings() {
+    let params = VirtualHostParams::named("events");
+    let changes = VirtualHostChanges::new()
+        .with_tags(vec!["staging"])
+        .with_default_queue_type(QueueType::Stream)
+        .with_tracing(true);
+    assert!(!changes.is_empty());
+    assert!(VirtualHostChanges::new().is_empty());
+
+    let amended = changes.apply_to(params);
+    assert!(amended.description.is_none());
+    assert_eq!(Some(vec!["staging"]), amended.tags);
+    assert_eq!(Some(QueueType::Stream), amended.default_queue_type);
+    assert!(amended.tracing);
+}
@@ -0,0 +1,114 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod test_helpers;
+
+use rabbitmq_http_client::responses::Policy;
+use rabbitmq_http_client::watch::{SnapshotDiffer, WatchEvent, WatchSettings};
+use serde_json::json;
+
+fn policy(name: &str, pattern: &str, priority: i16) -> Policy {
+    serde_json::from_value(json!({
+        "name": name,
+        "vhost": "/",
+        "pattern": pattern,
+        "apply-to": "queues",
+        "priority": priority,
+        "definition": {"max-length": 100}
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_unit_snapshot_differ_emits_initial_snapshot() {
+    let mut differ = SnapshotDiffer::new(true);
+    let events = differ.diff(vec![policy("b", ".*", 0), policy("a", ".*", 0)]);
+
+    assert_eq!(2, events.len());
+    assert!(matches!(&events[0], WatchEvent::Added(p) if p.name == "a"));
+    assert!(matches!(&events[1], WatchEvent::Added(p) if p.name == "b"));
+}
+
+#[test]
+fn test_unit_watch_settings() {
+    let settings = WatchSettings::default();
+    assert!(settings.emit_initial);
+    assert!(!settings.with_emit_initial(false).emit_initial);
+}
+
+#[test]
+fn test_unit_snapshot_differ_with_a_baseline() {
+    let mut differ = SnapshotDiffer::new(false);
+    assert!(differ.diff(vec![policy("a", ".*", 0)]).is_empty());
+    assert!(differ.diff(vec![policy("a", ".*", 0)]).is_empty());
+}
+
+#[test]
+fn test_unit_snapshot_differ_detects_additions_removals_and_changes() {
+    let mut differ = SnapshotDiffer::new(false);
+    differ.diff(vec![
+        policy("a", ".*", 0),
+        policy("b", ".*", 0),
+        policy("c", ".*", 0),
+    ]);
+
+    let events = differ.diff(vec![
+        policy("a", ".*", 0),
+        policy("b", "^events\\.", 0),
+        policy("d", ".*", 1),
+    ]);
+
+    assert_eq!(3, events.len());
+    match &events[0] {
+        WatchEvent::Changed { before, after } => {
+            assert_eq!("b", after.name);
+            assert_eq!(".*", before.pattern);
+            assert_eq!("^events\\.", after.pattern);
+        }
+        other => panic!("expected a change, got {:?}", other),
+    }
+    assert!(matches!(&events[1], WatchEvent::Added(p) if p.name == "d"));
+    assert!(matches!(&events[2], WatchEvent::Removed(p) if p.name == "c"));
+    assert_eq!("c", events[2].item().name);
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn test_unit_watch_listing_stream() {
+    use futures_util::{pin_mut, StreamExt};
+    use rabbitmq_http_client::watch::watch_listing;
+    use std::time::Duration;
+
+    let mut snapshots = vec![
+        Err("unreachable"),
+        Ok(vec![policy("b", ".*", 0)]),
+        Ok(vec![policy("a", ".*", 0)]),
+    ];
+    let settings = WatchSettings::new(Duration::from_millis(1));
+    let stream = watch_listing(settings, move || {
+        let snapshot = snapshots.pop().unwrap();
+        async move { snapshot }
+    });
+    pin_mut!(stream);
+
+    let mut events = Vec::new();
+    while let Some(event) = stream.next().await {
+        events.push(event);
+    }
+
+    assert_eq!(4, events.len());
+    assert!(matches!(&events[0], Ok(WatchEvent::Added(p)) if p.name == "a"));
+    assert!(matches!(&events[1], Ok(WatchEvent::Added(p)) if p.name == "b"));
+    assert!(matches!(&events[2], Ok(WatchEvent::Removed(p)) if p.name == "a"));
+    assert_eq!(Err("unreachable"), events[3].clone().map(|_| ()));
+}
@@ -0,0 +1,75 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod test_helpers;
+
+use rabbitmq_http_client::requests::{x_arguments, QueueParams, XArguments};
+use rabbitmq_http_client::x_args;
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+
+#[test]
+fn test_unit_x_args_macro() {
+    let args = x_args! {
+        "x-max-length" => 10_000,
+        "x-overflow" => "reject-publish",
+        "x-single-active-consumer" => true,
+        "x-dead-letter-strategy" => json!({"at-most-once": null}),
+    };
+
+    let mut expected = Map::new();
+    expected.insert("x-max-length".to_owned(), json!(10_000));
+    expected.insert("x-overflow".to_owned(), json!("reject-publish"));
+    expected.insert("x-single-active-consumer".to_owned(), json!(true));
+    expected.insert(
+        "x-dead-letter-strategy".to_owned(),
+        json!({"at-most-once": null}),
+    );
+    assert_eq!(Some(expected), args);
+}
+
+#[test]
+fn test_unit_x_args_macro_with_variables() {
+    let key = String::from("x-max-age");
+    let max_age = "7D";
+    let args = x_args! { key => max_age };
+    assert_eq!(json!("7D"), args.unwrap()["x-max-age"]);
+
+    let empty: XArguments = x_args! {};
+    assert_eq!(Some(Map::new()), empty);
+}
+
+#[test]
+fn test_unit_x_arguments_from_hash_map() {
+    let mut m: HashMap<&str, Value> = HashMap::new();
+    m.insert("x-max-length", json!(500));
+    m.insert("x-queue-leader-locator", json!("balanced"));
+
+    let args = x_arguments(m).unwrap();
+    assert_eq!(2, args.len());
+    assert_eq!(json!(500), args["x-max-length"]);
+    assert_eq!(json!("balanced"), args["x-queue-leader-locator"]);
+}
+
+#[test]
+fn test_unit_x_arguments_with_queue_params() {
+    let params = QueueParams::new_quorum_queue(
+        "qq.1",
+        x_arguments([("x-delivery-limit", 10), ("x-max-length", 1000)]),
+    );
+    let args = params.arguments.unwrap();
+
+    assert_eq!(json!("quorum"), args["x-queue-type"]);
+    assert_eq!(json!(10), args["x-delivery-limit"]);
+    assert_eq!(json!(1000), args["x-max-length"]);
+}